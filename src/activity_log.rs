@@ -0,0 +1,108 @@
+//! Optional audit trail of actions taken on notifications.
+//!
+//! When enabled, each action is appended to `logs/activity.jsonl` in the
+//! config directory as one JSON object per line, e.g.
+//! `{"at":"2026-01-08T09:14:03Z","action":"mute","account":"octocat","id":"123"}`.
+//! Rule decisions carry the id of the rule that made them.
+
+use std::collections::HashSet;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use chrono::Utc;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Rule decisions already written this run. Rules are re-evaluated on every
+/// refresh, so without this each hidden item would be logged again and again.
+static LOGGED_DECISIONS: Mutex<Option<HashSet<(String, Action)>>> = Mutex::new(None);
+
+/// Forget logged decisions past this many, so the set can't grow unbounded.
+const MAX_REMEMBERED_DECISIONS: usize = 5000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    MarkRead,
+    MarkDone,
+    Mute,
+    /// A rule hid the notification.
+    RuleHide,
+    /// A rule marked the notification Important.
+    RulePriority,
+}
+
+impl Action {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::MarkRead => "mark_read",
+            Self::MarkDone => "mark_done",
+            Self::Mute => "mute",
+            Self::RuleHide => "rule_hide",
+            Self::RulePriority => "rule_priority",
+        }
+    }
+}
+
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Where entries are appended.
+pub fn path() -> Option<PathBuf> {
+    crate::diagnostics::log_directory().map(|dir| dir.join("activity.jsonl"))
+}
+
+/// Record an action the user took on a thread.
+pub fn record(action: Action, account: &str, id: &str) {
+    if ENABLED.load(Ordering::Relaxed) {
+        append(action, account, id, None);
+    }
+}
+
+/// Record a rule decision, once per notification and action per run.
+pub fn record_rule(action: Action, account: &str, id: &str, rule_id: &str) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+
+    {
+        let mut logged = LOGGED_DECISIONS.lock().unwrap_or_else(|e| e.into_inner());
+        let logged = logged.get_or_insert_with(HashSet::new);
+        if logged.len() >= MAX_REMEMBERED_DECISIONS {
+            logged.clear();
+        }
+        if !logged.insert((id.to_string(), action)) {
+            return;
+        }
+    }
+
+    append(action, account, id, Some(rule_id));
+}
+
+fn append(action: Action, account: &str, id: &str, rule_id: Option<&str>) {
+    let Some(path) = path() else {
+        return;
+    };
+
+    let mut entry = serde_json::json!({
+        "at": Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+        "action": action.as_str(),
+        "account": account,
+        "id": id,
+    });
+    if let Some(rule_id) = rule_id {
+        entry["rule"] = rule_id.into();
+    }
+
+    let result = path
+        .parent()
+        .map_or(Ok(()), std::fs::create_dir_all)
+        .and_then(|()| OpenOptions::new().create(true).append(true).open(&path))
+        .and_then(|mut file| writeln!(file, "{entry}"));
+    if let Err(e) = result {
+        tracing::warn!(path = %path.display(), error = %e, "Failed to write activity log");
+    }
+}
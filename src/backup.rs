@@ -0,0 +1,326 @@
+//! Whole-configuration backup: settings, rules and the accounts with their
+//! per-account settings in one JSON file, so moving to a new machine is
+//! "back up, restore, sign in".
+//!
+//! Tokens and proxy credentials live in the keyring and never go into the
+//! file. After a restore each listed account has to sign in again.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::settings::{AppSettings, StoredAccount};
+use crate::ui::screens::settings::rule_engine::NotificationRuleSet;
+
+/// Bumped when the file layout changes in a way older builds can't read.
+const BACKUP_VERSION: u32 = 2;
+
+/// Suggested file name for the save dialog.
+pub const DEFAULT_FILE_NAME: &str = "gittop-backup.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigBackup {
+    pub version: u32,
+    pub exported_at: DateTime<Utc>,
+    /// Settings with the account list taken out; see `accounts`.
+    pub settings: AppSettings,
+    pub rules: NotificationRuleSet,
+    /// Accounts and their per-account settings, in switcher order. No tokens.
+    #[serde(deserialize_with = "accounts_or_usernames")]
+    pub accounts: Vec<StoredAccount>,
+    /// Whether GitTop started on login; `None` in version 1 backups.
+    #[serde(default)]
+    pub start_on_boot: Option<bool>,
+}
+
+impl ConfigBackup {
+    pub fn capture(
+        settings: &AppSettings,
+        rules: &NotificationRuleSet,
+        start_on_boot: bool,
+    ) -> Self {
+        let mut settings = settings.clone();
+        let accounts = std::mem::take(&mut settings.accounts);
+        // The password stays in this machine's keyring
+        settings.proxy.has_credentials = false;
+
+        Self {
+            version: BACKUP_VERSION,
+            exported_at: Utc::now(),
+            settings,
+            rules: rules.clone(),
+            accounts,
+            start_on_boot: Some(start_on_boot),
+        }
+    }
+
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| e.to_string())
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let backup: Self =
+            serde_json::from_str(json).map_err(|e| format!("Not a GitTop backup: {e}"))?;
+        if backup.version > BACKUP_VERSION {
+            return Err("This backup is from a newer GitTop; update first".to_string());
+        }
+        Ok(backup)
+    }
+
+    /// The backed-up settings, keeping `current`'s accounts (they have
+    /// tokens here) and adding the backup's other accounts after them,
+    /// marked as awaiting sign-in.
+    /// Accounts in both keep their token expiry but take the backup's
+    /// per-account settings.
+    pub fn restored_settings(&self, current: &AppSettings) -> AppSettings {
+        let mut settings = self.settings.clone();
+        settings.accounts = current.accounts.clone();
+        for backed_up in &self.accounts {
+            match settings
+                .accounts
+                .iter_mut()
+                .find(|a| a.username.eq_ignore_ascii_case(&backed_up.username))
+            {
+                Some(account) => {
+                    account.display_name = backed_up.display_name.clone();
+                    account.power_mode = backed_up.power_mode;
+                    account.pinned = backed_up.pinned.clone();
+                    account.reminders = backed_up.reminders.clone();
+                }
+                None => settings.accounts.push(StoredAccount {
+                    is_active: false,
+                    awaiting_sign_in: true,
+                    ..backed_up.clone()
+                }),
+            }
+        }
+        // Credentials saved for the same proxy on this machine still apply
+        settings.proxy.has_credentials =
+            current.proxy.has_credentials && settings.proxy.url == current.proxy.url;
+        settings
+    }
+}
+
+/// Version 1 backups list bare usernames.
+fn accounts_or_usernames<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<StoredAccount>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Entry {
+        Account(StoredAccount),
+        Username(String),
+    }
+
+    let entries = Vec::<Entry>::deserialize(deserializer)?;
+    Ok(entries
+        .into_iter()
+        .map(|entry| match entry {
+            Entry::Account(account) => account,
+            Entry::Username(username) => StoredAccount {
+                username,
+                is_active: false,
+                display_name: None,
+                power_mode: None,
+                pinned: Vec::new(),
+                token_expires_at: None,
+                token_expiry_dismissed: None,
+                reminders: Vec::new(),
+                awaiting_sign_in: false,
+            },
+        })
+        .collect())
+}
+
+/// Ask where to save `backup` and write it there. Returns the file name, or
+/// `None` if the dialog was cancelled.
+pub async fn save_with_dialog(backup: ConfigBackup) -> Result<Option<String>, String> {
+    let json = backup.to_json()?;
+    let Some(file) = rfd::AsyncFileDialog::new()
+        .set_title("Back up GitTop configuration")
+        .set_file_name(DEFAULT_FILE_NAME)
+        .add_filter("JSON", &["json"])
+        .save_file()
+        .await
+    else {
+        return Ok(None);
+    };
+    file.write(json.as_bytes())
+        .await
+        .map_err(|e| format!("Couldn't write {}: {e}", file.file_name()))?;
+    Ok(Some(file.file_name()))
+}
+
+/// Ask for a backup file and read it. `None` if the dialog was cancelled.
+pub async fn open_with_dialog() -> Result<Option<ConfigBackup>, String> {
+    let Some(file) = rfd::AsyncFileDialog::new()
+        .set_title("Restore GitTop configuration")
+        .add_filter("JSON", &["json"])
+        .pick_file()
+        .await
+    else {
+        return Ok(None);
+    };
+    let bytes = file.read().await;
+    let json = String::from_utf8(bytes).map_err(|_| "Not a GitTop backup".to_string())?;
+    ConfigBackup::from_json(&json).map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account(username: &str, is_active: bool) -> StoredAccount {
+        StoredAccount {
+            username: username.to_string(),
+            is_active,
+            display_name: None,
+            power_mode: None,
+            pinned: Vec::new(),
+            token_expires_at: None,
+            token_expiry_dismissed: None,
+            reminders: Vec::new(),
+            awaiting_sign_in: false,
+        }
+    }
+
+    #[test]
+    fn test_restore_keeps_signed_in_accounts_and_appends_backed_up_ones() {
+        let mut old_machine = AppSettings {
+            accounts: vec![account("alice", true), account("bob", false)],
+            ..Default::default()
+        };
+        old_machine.proxy.has_credentials = true;
+        let backup = ConfigBackup::from_json(
+            &ConfigBackup::capture(&old_machine, &NotificationRuleSet::default(), false)
+                .to_json()
+                .unwrap(),
+        )
+        .unwrap();
+
+        let backed_up: Vec<_> = backup
+            .accounts
+            .iter()
+            .map(|a| a.username.as_str())
+            .collect();
+        assert_eq!(backed_up, ["alice", "bob"]);
+        assert!(backup.settings.accounts.is_empty());
+        assert!(!backup.settings.proxy.has_credentials);
+
+        let new_machine = AppSettings {
+            accounts: vec![account("Bob", true)],
+            ..Default::default()
+        };
+        let restored = backup.restored_settings(&new_machine);
+
+        let names: Vec<_> = restored
+            .accounts
+            .iter()
+            .map(|a| a.username.as_str())
+            .collect();
+        assert_eq!(names, ["Bob", "alice"]);
+        assert!(restored.accounts[0].is_active);
+        assert!(!restored.accounts[1].is_active);
+    }
+
+    #[test]
+    fn test_restore_carries_per_account_settings() {
+        let due = Utc::now();
+        let old_machine = AppSettings {
+            accounts: vec![
+                StoredAccount {
+                    display_name: Some("Work".to_string()),
+                    power_mode: Some(true),
+                    pinned: vec!["42".to_string()],
+                    token_expires_at: Some(due),
+                    reminders: vec![crate::settings::Reminder {
+                        notification_id: "7".to_string(),
+                        title: "Review".to_string(),
+                        repo_full_name: "owner/repo".to_string(),
+                        url: None,
+                        due,
+                    }],
+                    ..account("alice", true)
+                },
+                StoredAccount {
+                    display_name: Some("Home".to_string()),
+                    ..account("bob", false)
+                },
+            ],
+            ..Default::default()
+        };
+        let json = ConfigBackup::capture(&old_machine, &NotificationRuleSet::default(), true)
+            .to_json()
+            .unwrap();
+        let backup = ConfigBackup::from_json(&json).unwrap();
+        assert_eq!(backup.start_on_boot, Some(true));
+
+        // Bob is signed in here, Alice only comes back from the backup
+        let new_machine = AppSettings {
+            accounts: vec![account("bob", true)],
+            ..Default::default()
+        };
+        let restored = backup.restored_settings(&new_machine);
+
+        let bob = &restored.accounts[0];
+        assert_eq!(bob.display_name.as_deref(), Some("Home"));
+        assert!(bob.is_active);
+        let alice = &restored.accounts[1];
+        assert_eq!(alice.username, "alice");
+        assert!(!alice.is_active);
+        assert_eq!(alice.display_name.as_deref(), Some("Work"));
+        assert_eq!(alice.power_mode, Some(true));
+        assert_eq!(alice.pinned, ["42"]);
+        assert_eq!(alice.token_expires_at, Some(due));
+        assert_eq!(alice.reminders.len(), 1);
+    }
+
+    #[test]
+    fn test_restored_accounts_survive_a_start_without_a_token() {
+        use crate::github::session::{RestoreReport, SessionError};
+
+        let old_machine = AppSettings {
+            accounts: vec![StoredAccount {
+                display_name: Some("Work".to_string()),
+                pinned: vec!["42".to_string()],
+                ..account("alice", true)
+            }],
+            ..Default::default()
+        };
+        let backup = ConfigBackup::capture(&old_machine, &NotificationRuleSet::default(), false);
+        let mut restored = backup.restored_settings(&AppSettings::default());
+        assert!(restored.accounts[0].awaiting_sign_in);
+
+        // Next start: there's no token for alice on this machine yet
+        let mut report = RestoreReport::default();
+        report.record(
+            "alice",
+            Err(SessionError::AccountNotFound("alice".to_string())),
+        );
+        assert!(!report.apply(&mut restored));
+        assert_eq!(restored.accounts[0].display_name.as_deref(), Some("Work"));
+        assert_eq!(restored.accounts[0].pinned, ["42"]);
+
+        // Once signed in, a token that goes missing later drops it as usual
+        restored.set_active_account("alice");
+        assert!(!restored.accounts[0].awaiting_sign_in);
+        assert!(report.apply(&mut restored));
+        assert!(restored.accounts.is_empty());
+    }
+
+    #[test]
+    fn test_version_one_backups_still_restore() {
+        let mut backup = ConfigBackup::capture(
+            &AppSettings::default(),
+            &NotificationRuleSet::default(),
+            false,
+        );
+        backup.version = 1;
+        let mut json: serde_json::Value = serde_json::from_str(&backup.to_json().unwrap()).unwrap();
+        json["accounts"] = serde_json::json!(["alice"]);
+        json.as_object_mut().unwrap().remove("start_on_boot");
+
+        let backup = ConfigBackup::from_json(&json.to_string()).unwrap();
+        assert_eq!(backup.accounts[0].username, "alice");
+        assert_eq!(backup.start_on_boot, None);
+    }
+}
@@ -0,0 +1,133 @@
+//! Opening GitHub links in the browser.
+//!
+//! Uses the system default unless the user configured a custom command
+//! template, e.g. `chrome --profile-directory="Profile 2" {url}`, for
+//! people who keep work and personal browser profiles apart.
+
+use std::process::{Command, Stdio};
+use std::sync::Mutex;
+
+/// Placeholder replaced with the link being opened.
+pub const URL_PLACEHOLDER: &str = "{url}";
+
+// Parsed program + arguments of the active template, if any.
+static COMMAND: Mutex<Option<Vec<String>>> = Mutex::new(None);
+
+/// Use `template` for every subsequent [`open`]. `None` or an empty string
+/// restores the system default browser.
+pub fn apply(template: Option<&str>) -> Result<(), String> {
+    let parsed = match template.map(str::trim).filter(|t| !t.is_empty()) {
+        Some(template) => Some(parse(template)?),
+        None => None,
+    };
+
+    tracing::info!(custom = parsed.is_some(), "Browser command updated");
+    *COMMAND.lock().unwrap_or_else(|e| e.into_inner()) = parsed;
+    Ok(())
+}
+
+/// Open `url` with the configured command, falling back to the system
+/// default browser if the command can't be started.
+pub fn open(url: &str) -> Result<(), String> {
+    let command = COMMAND.lock().unwrap_or_else(|e| e.into_inner()).clone();
+
+    if let Some(parts) = command {
+        let mut args = parts.iter().map(|part| part.replace(URL_PLACEHOLDER, url));
+        let program = args.next().unwrap_or_default();
+        match Command::new(&program)
+            .args(args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+        {
+            Ok(mut child) => {
+                // Reap it once it exits so it doesn't linger as a zombie
+                std::thread::spawn(move || {
+                    let _ = child.wait();
+                });
+                return Ok(());
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, program, "Custom browser command failed, using default");
+            }
+        }
+    }
+
+    open::that(url).map_err(|e| e.to_string())
+}
+
+/// Split a template into program and arguments. Double quotes group words
+/// containing spaces; the template must name a program and contain
+/// [`URL_PLACEHOLDER`].
+fn parse(template: &str) -> Result<Vec<String>, String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    let mut has_part = false;
+
+    for c in template.chars() {
+        match c {
+            '"' => {
+                in_quotes = !in_quotes;
+                has_part = true;
+            }
+            c if c.is_whitespace() && !in_quotes => {
+                if has_part {
+                    parts.push(std::mem::take(&mut current));
+                    has_part = false;
+                }
+            }
+            c => {
+                current.push(c);
+                has_part = true;
+            }
+        }
+    }
+
+    if in_quotes {
+        return Err("Unmatched quote in browser command".to_string());
+    }
+    if has_part {
+        parts.push(current);
+    }
+
+    match parts.first() {
+        None => Err("Browser command is empty".to_string()),
+        Some(program) if program.contains(URL_PLACEHOLDER) => {
+            Err("Browser command must start with a program".to_string())
+        }
+        Some(_) if !parts.iter().any(|p| p.contains(URL_PLACEHOLDER)) => {
+            Err(format!("Browser command must contain {URL_PLACEHOLDER}"))
+        }
+        Some(_) => Ok(parts),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_keeps_quoted_arguments_together() {
+        let parts =
+            parse(r#""C:\Program Files\Chrome\chrome.exe" --profile-directory="Profile 2" {url}"#)
+                .unwrap();
+        assert_eq!(
+            parts,
+            vec![
+                r"C:\Program Files\Chrome\chrome.exe",
+                "--profile-directory=Profile 2",
+                "{url}",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_templates() {
+        assert!(parse("firefox").is_err());
+        assert!(parse("{url}").is_err());
+        assert!(parse(r#"firefox "{url}"#).is_err());
+        assert!(parse("   ").is_err());
+    }
+}
@@ -1,13 +1,44 @@
-#![allow(dead_code)] // Infrastructure prepared for Phase 1, will be used when integrated
+#![allow(dead_code)] // Read status and ETag storage are not wired up yet
 //! Disk Cache - Sled-backed persistent storage.
 //!
-//! Stores notification read status, sync timestamps, and cached responses.
+//! Stores notification read status, sync timestamps, what has already been
+//! notified, snoozed notifications, and cached responses.
 
-use chrono::{DateTime, Utc};
+use crate::github::NotificationView;
+use chrono::{DateTime, TimeDelta, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::PathBuf;
+use std::sync::OnceLock;
 use thiserror::Error;
 
+/// Entries not written for this long are evicted by `prune`.
+const ENTRY_TTL: TimeDelta = TimeDelta::days(30);
+
+/// Upper bound on cached keys + values before the oldest entries are evicted.
+const MAX_CACHE_BYTES: u64 = 32 * 1024 * 1024;
+
+/// Trees holding cached data. Every insert also records a write time in
+/// `STAMPS_TREE` under `"<tree>\0<key>"` so maintenance can age entries out.
+const DATA_TREES: [&str; 7] = [
+    "read_status",
+    "sync_meta",
+    "etags",
+    "bodies",
+    "notifications",
+    "seen",
+    "snoozed",
+];
+const STAMPS_TREE: &str = "stamps";
+
+/// Layout of the cached notification list. Bump when `NotificationView`
+/// changes in a way an older blob can't be read as; stale lists are then
+/// dropped instead of failing to load.
+const NOTIFICATIONS_VERSION: u32 = 1;
+
+/// Process-wide database handle; sled locks the directory on open.
+static SHARED_DB: OnceLock<sled::Db> = OnceLock::new();
+
 /// Cache-related errors.
 #[derive(Debug, Error)]
 pub enum CacheError {
@@ -37,17 +68,39 @@ pub struct SyncMetadata {
     pub notification_count: usize,
 }
 
+/// Cached notification list with the layout version it was written in.
+#[derive(Serialize, Deserialize)]
+struct VersionedNotifications<N> {
+    version: u32,
+    notifications: N,
+}
+
+/// Result of a maintenance pass.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PruneStats {
+    pub expired: usize,
+    pub evicted: usize,
+}
+
 /// Sled-backed persistent cache.
+#[derive(Clone)]
 pub struct DiskCache {
     db: sled::Db,
 }
 
 impl DiskCache {
     /// Opens the cache at the default location.
+    ///
+    /// The underlying database is opened once per process and shared.
     pub fn open() -> Result<Self, CacheError> {
+        if let Some(db) = SHARED_DB.get() {
+            return Ok(Self { db: db.clone() });
+        }
         let path = Self::cache_path()?;
         let db = sled::open(path)?;
-        Ok(Self { db })
+        Ok(Self {
+            db: SHARED_DB.get_or_init(|| db).clone(),
+        })
     }
 
     /// Gets the cache directory path.
@@ -63,10 +116,8 @@ impl DiskCache {
 
     /// Save read status for a notification.
     pub fn save_read_status(&self, notification_id: &str, is_read: bool) -> Result<(), CacheError> {
-        let tree = self.db.open_tree("read_status")?;
         let value = if is_read { b"1" } else { b"0" };
-        tree.insert(notification_id.as_bytes(), value)?;
-        Ok(())
+        self.insert("read_status", notification_id.as_bytes(), value)
     }
 
     /// Load read status for a notification.
@@ -88,11 +139,9 @@ impl DiskCache {
         account: &str,
         metadata: &SyncMetadata,
     ) -> Result<(), CacheError> {
-        let tree = self.db.open_tree("sync_meta")?;
         let json =
             serde_json::to_vec(metadata).map_err(|e| CacheError::Serialization(e.to_string()))?;
-        tree.insert(account.as_bytes(), json)?;
-        Ok(())
+        self.insert("sync_meta", account.as_bytes(), &json)
     }
 
     /// Load sync metadata for an account.
@@ -108,6 +157,141 @@ impl DiskCache {
         }
     }
 
+    // =========================================================================
+    // Notification Lists (per-account, per-mode)
+    // =========================================================================
+
+    /// Save the last fetched notification list so the next launch can render
+    /// it before the network responds.
+    pub fn save_notifications(
+        &self,
+        account: &str,
+        show_all: bool,
+        participating: bool,
+        notifications: &[NotificationView],
+    ) -> Result<(), CacheError> {
+        let json = serde_json::to_vec(&VersionedNotifications {
+            version: NOTIFICATIONS_VERSION,
+            notifications,
+        })
+        .map_err(|e| CacheError::Serialization(e.to_string()))?;
+        self.insert(
+            "notifications",
+            notifications_key(account, show_all, participating).as_bytes(),
+            &json,
+        )
+    }
+
+    /// Load the cached notification list for an account. A list written by
+    /// another version of the layout counts as missing.
+    pub fn load_notifications(
+        &self,
+        account: &str,
+        show_all: bool,
+        participating: bool,
+    ) -> Result<Option<Vec<NotificationView>>, CacheError> {
+        let tree = self.db.open_tree("notifications")?;
+        let Some(bytes) =
+            tree.get(notifications_key(account, show_all, participating).as_bytes())?
+        else {
+            return Ok(None);
+        };
+        match serde_json::from_slice::<VersionedNotifications<Vec<NotificationView>>>(&bytes) {
+            Ok(cached) if cached.version == NOTIFICATIONS_VERSION => Ok(Some(cached.notifications)),
+            // Unversioned lists from before this, or a different layout
+            _ => {
+                tracing::debug!("Ignoring cached notifications from another version");
+                Ok(None)
+            }
+        }
+    }
+
+    // =========================================================================
+    // Seen Notifications (per-account)
+    // =========================================================================
+
+    /// Record the `updated_at` each notification had when we last alerted on
+    /// it, so a restart doesn't alert on it again.
+    pub fn save_seen<'a>(
+        &self,
+        account: &str,
+        seen: impl IntoIterator<Item = (&'a str, DateTime<Utc>)>,
+    ) -> Result<(), CacheError> {
+        for (id, updated_at) in seen {
+            self.insert(
+                "seen",
+                &account_key(account, id),
+                &updated_at.timestamp().to_be_bytes(),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Load the seen `updated_at` of every notification for an account.
+    pub fn load_seen(&self, account: &str) -> Result<HashMap<String, DateTime<Utc>>, CacheError> {
+        self.load_timestamps("seen", account)
+    }
+
+    /// Read a per-account tree of notification id -> timestamp.
+    fn load_timestamps(
+        &self,
+        tree: &str,
+        account: &str,
+    ) -> Result<HashMap<String, DateTime<Utc>>, CacheError> {
+        let prefix = account_key(account, "");
+        let mut seen = HashMap::new();
+        for item in self.db.open_tree(tree)?.scan_prefix(&prefix) {
+            let (key, value) = item?;
+            let id = String::from_utf8_lossy(&key[prefix.len()..]).into_owned();
+            let at = value
+                .as_ref()
+                .try_into()
+                .ok()
+                .map(i64::from_be_bytes)
+                .and_then(|secs| DateTime::from_timestamp(secs, 0));
+            if let Some(at) = at {
+                seen.insert(id, at);
+            }
+        }
+        Ok(seen)
+    }
+
+    // =========================================================================
+    // Snoozed Notifications (per-account)
+    // =========================================================================
+
+    /// Hide a notification until `wake`.
+    pub fn save_snooze(
+        &self,
+        account: &str,
+        id: &str,
+        wake: DateTime<Utc>,
+    ) -> Result<(), CacheError> {
+        self.insert(
+            "snoozed",
+            &account_key(account, id),
+            &wake.timestamp().to_be_bytes(),
+        )
+    }
+
+    /// Forget a snooze, e.g. once it has woken up.
+    pub fn remove_snooze(&self, account: &str, id: &str) -> Result<(), CacheError> {
+        let key = account_key(account, id);
+        self.db.open_tree("snoozed")?.remove(&key)?;
+        self.db
+            .open_tree(STAMPS_TREE)?
+            .remove(stamp_key("snoozed", &key))?;
+        Ok(())
+    }
+
+    /// Load the wake time of every snoozed notification for an account.
+    pub fn load_snoozed(
+        &self,
+        account: &str,
+    ) -> Result<HashMap<String, DateTime<Utc>>, CacheError> {
+        self.load_timestamps("snoozed", account)
+    }
+
     // =========================================================================
     // ETag Cache
     // =========================================================================
@@ -115,14 +299,10 @@ impl DiskCache {
     /// Store an ETag and cached response body for a URL.
     pub fn save_etag_response(&self, url: &str, etag: &str, body: &[u8]) -> Result<(), CacheError> {
         // Store ETag separately for fast lookup (HEAD-like checks)
-        let etags = self.db.open_tree("etags")?;
-        etags.insert(url.as_bytes(), etag.as_bytes())?;
+        self.insert("etags", url.as_bytes(), etag.as_bytes())?;
 
         // Store body separately
-        let bodies = self.db.open_tree("bodies")?;
-        bodies.insert(url.as_bytes(), body)?;
-
-        Ok(())
+        self.insert("bodies", url.as_bytes(), body)
     }
 
     /// Get cached ETag for a URL.
@@ -151,4 +331,141 @@ impl DiskCache {
         self.db.flush()?;
         Ok(())
     }
+
+    // =========================================================================
+    // Maintenance
+    // =========================================================================
+
+    /// Insert into a data tree and record the write time.
+    fn insert(&self, tree: &str, key: &[u8], value: &[u8]) -> Result<(), CacheError> {
+        self.db.open_tree(tree)?.insert(key, value)?;
+        let stamp = Utc::now().timestamp().to_be_bytes();
+        self.db
+            .open_tree(STAMPS_TREE)?
+            .insert(stamp_key(tree, key), &stamp)?;
+        Ok(())
+    }
+
+    /// Size of the database files on disk, in bytes.
+    pub fn size_on_disk(&self) -> u64 {
+        self.db.size_on_disk().unwrap_or(0)
+    }
+
+    /// Evict entries older than the TTL, then the oldest entries until the
+    /// cached data fits within the size budget.
+    pub fn prune(&self) -> Result<PruneStats, CacheError> {
+        let stamps = self.db.open_tree(STAMPS_TREE)?;
+        let cutoff = (Utc::now() - ENTRY_TTL).timestamp();
+        let mut stats = PruneStats::default();
+
+        let mut live = Vec::new();
+        for item in stamps.iter() {
+            let (key, value) = item?;
+            let written = value
+                .as_ref()
+                .try_into()
+                .map(i64::from_be_bytes)
+                .unwrap_or(0);
+            if written < cutoff {
+                self.remove_stamped(&key)?;
+                stats.expired += 1;
+            } else {
+                live.push((written, key));
+            }
+        }
+
+        let mut total = self.data_bytes()?;
+        if total > MAX_CACHE_BYTES {
+            live.sort_by_key(|(written, _)| *written);
+            for (_, key) in live {
+                if total <= MAX_CACHE_BYTES {
+                    break;
+                }
+                total = total.saturating_sub(self.remove_stamped(&key)?);
+                stats.evicted += 1;
+            }
+        }
+
+        self.db.flush()?;
+        Ok(stats)
+    }
+
+    /// Remove all cached data.
+    pub fn clear(&self) -> Result<(), CacheError> {
+        for name in self.db.tree_names() {
+            // The default tree can't be dropped, only emptied
+            if name.as_ref() == b"__sled__default" {
+                continue;
+            }
+            self.db.drop_tree(name)?;
+        }
+        self.db.clear()?;
+        self.db.flush()?;
+        Ok(())
+    }
+
+    /// Remove a stamped entry and return the number of bytes it occupied.
+    fn remove_stamped(&self, stamp: &[u8]) -> Result<u64, CacheError> {
+        self.db.open_tree(STAMPS_TREE)?.remove(stamp)?;
+        let Some(split) = stamp.iter().position(|b| *b == 0) else {
+            return Ok(0);
+        };
+        let (tree, key) = (&stamp[..split], &stamp[split + 1..]);
+        let removed = self.db.open_tree(tree)?.remove(key)?;
+        Ok(removed.map_or(0, |v| (key.len() + v.len()) as u64))
+    }
+
+    /// Total bytes of keys and values across the data trees.
+    fn data_bytes(&self) -> Result<u64, CacheError> {
+        let mut total = 0u64;
+        for name in DATA_TREES {
+            for item in self.db.open_tree(name)?.iter() {
+                let (k, v) = item?;
+                total += (k.len() + v.len()) as u64;
+            }
+        }
+        Ok(total)
+    }
+}
+
+fn notifications_key(account: &str, show_all: bool, participating: bool) -> String {
+    let mode = if show_all { "all" } else { "unread" };
+    if participating {
+        format!("{account}:{mode}:participating")
+    } else {
+        format!("{account}:{mode}")
+    }
+}
+
+fn account_key(account: &str, id: &str) -> Vec<u8> {
+    let mut key = Vec::with_capacity(account.len() + 1 + id.len());
+    key.extend_from_slice(account.as_bytes());
+    key.push(0);
+    key.extend_from_slice(id.as_bytes());
+    key
+}
+
+fn stamp_key(tree: &str, key: &[u8]) -> Vec<u8> {
+    let mut stamp = Vec::with_capacity(tree.len() + 1 + key.len());
+    stamp.extend_from_slice(tree.as_bytes());
+    stamp.push(0);
+    stamp.extend_from_slice(key);
+    stamp
+}
+
+/// Open the cache and run a maintenance pass. Failures are logged only.
+pub fn run_maintenance() {
+    let result = DiskCache::open().and_then(|cache| {
+        let stats = cache.prune()?;
+        Ok((stats, cache.size_on_disk()))
+    });
+    match result {
+        Ok((stats, size)) => tracing::info!(
+            expired = stats.expired,
+            evicted = stats.evicted,
+            size_bytes = size,
+            "Cache maintenance complete"
+        ),
+        Err(e) => tracing::warn!(error = %e, "Cache maintenance failed"),
+    }
 }
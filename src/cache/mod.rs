@@ -4,5 +4,4 @@
 
 mod disk;
 
-#[allow(unused_imports)]
-pub use disk::{CacheError, DiskCache};
+pub use disk::{DiskCache, SyncMetadata, run_maintenance};
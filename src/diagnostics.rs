@@ -1,12 +1,31 @@
 //! Diagnostics and crash reporting.
 
+use std::collections::VecDeque;
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 use std::time::SystemTime;
 
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 
 use crate::github::redaction::redact_secrets;
+use crate::settings::AppSettings;
+
+/// How many user-visible errors we remember for the settings panel.
+const MAX_RECENT_ERRORS: usize = 20;
+
+/// Recent user-visible errors, oldest first.
+static RECENT_ERRORS: Mutex<VecDeque<RecordedError>> = Mutex::new(VecDeque::new());
+
+/// A user-visible error, kept so flaky failures can be reported after the
+/// banner has gone away.
+#[derive(Debug, Clone)]
+pub struct RecordedError {
+    pub at: DateTime<Utc>,
+    /// Broad category, e.g. "Network" or "RateLimited".
+    pub category: String,
+    pub message: String,
+}
 
 #[derive(Debug, Clone)]
 pub struct CrashNotice {
@@ -94,6 +113,95 @@ pub fn clear_crash_notice() {
     }
 }
 
+/// Remember an error for the settings panel and the debug report.
+pub fn record_error(category: &str, message: &str) {
+    let mut recent = RECENT_ERRORS.lock().unwrap_or_else(|e| e.into_inner());
+    if recent.len() >= MAX_RECENT_ERRORS {
+        recent.pop_front();
+    }
+    recent.push_back(RecordedError {
+        at: Utc::now(),
+        category: category.to_string(),
+        message: redact_secrets(message),
+    });
+}
+
+/// Errors recorded this run, newest first.
+pub fn recent_errors() -> Vec<RecordedError> {
+    let recent = RECENT_ERRORS.lock().unwrap_or_else(|e| e.into_inner());
+    recent.iter().rev().cloned().collect()
+}
+
+/// Rule counts for the debug report, without the rules themselves.
+#[derive(Debug, Clone, Copy)]
+pub struct RuleSummary {
+    pub enabled: bool,
+    pub account: usize,
+    pub org: usize,
+    pub subject_type: usize,
+    pub actor: usize,
+    pub active: usize,
+}
+
+/// Environment summary for bug reports.
+///
+/// Deliberately leaves out tokens, usernames, and the proxy URL/credentials.
+pub fn debug_report(settings: &AppSettings, rules: RuleSummary) -> String {
+    let yes_no = |b: bool| if b { "yes" } else { "no" };
+
+    let recent_errors: String = recent_errors()
+        .into_iter()
+        .map(|e| {
+            // Error text can echo an account name back from the API
+            let message = settings
+                .accounts
+                .iter()
+                .fold(e.message, |m, a| m.replace(&a.username, "<account>"));
+            format!("\n  {} [{}] {}", e.at.to_rfc3339(), e.category, message)
+        })
+        .collect();
+    let recent_errors = if recent_errors.is_empty() {
+        " none".to_string()
+    } else {
+        recent_errors
+    };
+
+    let session = std::env::var("XDG_SESSION_TYPE").unwrap_or_else(|_| "n/a".to_string());
+
+    format!(
+        "GitTop debug report\n\
+Version: {}\n\
+OS: {} ({})\n\
+Session type: {}\n\
+Theme: {}\n\
+Icon theme: {:?}\n\
+Power mode: {}\n\
+Minimize to tray: {}\n\
+Proxy enabled: {}\n\
+Accounts: {}\n\
+Rules enabled: {}\n\
+Rules: {} account, {} org, {} type, {} actor ({} active)\n\
+Recent errors:{}\n",
+        env!("CARGO_PKG_VERSION"),
+        std::env::consts::OS,
+        std::env::consts::ARCH,
+        session,
+        settings.theme,
+        settings.icon_theme,
+        yes_no(settings.power_mode),
+        yes_no(settings.minimize_to_tray),
+        yes_no(settings.proxy.enabled),
+        settings.accounts.len(),
+        yes_no(rules.enabled),
+        rules.account,
+        rules.org,
+        rules.subject_type,
+        rules.actor,
+        rules.active,
+        recent_errors,
+    )
+}
+
 pub fn log_directory() -> Option<PathBuf> {
     config_dir_base().map(|p| p.join("logs"))
 }
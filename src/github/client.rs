@@ -1,19 +1,29 @@
 //! GitHub API client using Personal Access Tokens.
 
-use reqwest::header::{ACCEPT, AUTHORIZATION, HeaderMap, HeaderValue, USER_AGENT};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use reqwest::header::{ACCEPT, AUTHORIZATION, HeaderMap, HeaderValue};
 use serde::Deserialize;
 use thiserror::Error;
 
+use super::throttle::Throttle;
 use super::types::{Notification, NotificationView, UserInfo};
 
 /// GitHub API base URL.
 const GITHUB_API_URL: &str = "https://api.github.com";
 
+/// Back-off for a throttled response that doesn't say how long to wait.
+/// GitHub asks for at least a minute in that case.
+const DEFAULT_THROTTLE_SECS: u64 = 60;
+
 /// Errors that can occur when interacting with the GitHub API.
 #[derive(Debug, Error, Clone)]
 pub enum GitHubError {
-    #[error("HTTP request failed: {0}")]
-    Request(String),
+    /// Couldn't reach GitHub (offline, DNS, timeout, proxy).
+    #[error("Network error: {0}")]
+    Network(String),
 
     #[error("Invalid or expired token")]
     Unauthorized,
@@ -21,13 +31,106 @@ pub enum GitHubError {
     #[error("Rate limit exceeded")]
     RateLimited,
 
+    /// Secondary (abuse) rate limit: GitHub asked us to wait this many seconds.
+    #[error("Temporarily throttled by GitHub, retry after {retry_after}s")]
+    Throttled { retry_after: u64 },
+
+    /// The token works but can't read notifications; it needs a new scope.
+    #[error("Token is missing the notifications scope: {0}")]
+    MissingScope(String),
+
+    /// No access to this resource (SSO, repo permissions, fine-grained
+    /// token limits). The token itself is fine.
+    #[error("Access forbidden: {0}")]
+    Forbidden(String),
+
     #[error("GitHub API error: {status} - {message}")]
     Api { status: u16, message: String },
+
+    /// Anything else, e.g. a response we couldn't parse.
+    #[error("{0}")]
+    Other(String),
+}
+
+/// Broad category of a [`GitHubError`], for choosing how the UI responds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// Retry, or check the connection and proxy settings.
+    Network,
+    /// Re-authenticate.
+    Auth,
+    /// Wait for the limit to reset.
+    RateLimited,
+    /// GitHub itself is having trouble; retry later.
+    Server,
+    Other,
+}
+
+impl GitHubError {
+    /// The token itself was rejected (revoked, expired, or missing the
+    /// notifications scope).
+    pub fn is_auth_error(&self) -> bool {
+        self.kind() == ErrorKind::Auth
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            GitHubError::Network(_) => ErrorKind::Network,
+            GitHubError::Unauthorized | GitHubError::MissingScope(_) => ErrorKind::Auth,
+            GitHubError::RateLimited | GitHubError::Throttled { .. } => ErrorKind::RateLimited,
+            // 407 comes from a proxy that wants credentials, not from GitHub
+            GitHubError::Api { status: 407, .. } => ErrorKind::Network,
+            GitHubError::Api { status, .. } if *status >= 500 => ErrorKind::Server,
+            GitHubError::Forbidden(_) | GitHubError::Api { .. } | GitHubError::Other(_) => {
+                ErrorKind::Other
+            }
+        }
+    }
 }
 
 impl From<reqwest::Error> for GitHubError {
     fn from(e: reqwest::Error) -> Self {
-        GitHubError::Request(e.to_string())
+        if e.is_decode() {
+            GitHubError::Other(e.to_string())
+        } else {
+            GitHubError::Network(e.to_string())
+        }
+    }
+}
+
+/// Core API quota from the `X-RateLimit-*` headers of the last response.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    pub remaining: u32,
+    pub limit: u32,
+    /// When `remaining` goes back to `limit`.
+    pub reset: DateTime<Utc>,
+}
+
+impl RateLimit {
+    fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let number = |name: &str| -> Option<u64> { headers.get(name)?.to_str().ok()?.parse().ok() };
+        Some(Self {
+            remaining: number("x-ratelimit-remaining")?.try_into().ok()?,
+            limit: number("x-ratelimit-limit")?.try_into().ok()?,
+            reset: DateTime::from_timestamp(number("x-ratelimit-reset")?.try_into().ok()?, 0)?,
+        })
+    }
+
+    /// Under a tenth of the quota left before it resets.
+    pub fn is_low(&self, now: DateTime<Utc>) -> bool {
+        now < self.reset && self.remaining < self.limit / 10
+    }
+
+    /// How often to auto-refresh: `base` normally, and while the quota is
+    /// low, slow enough to spread what's left over the time until reset
+    /// (and at least four times `base`).
+    pub fn paced_interval(&self, base: Duration, now: DateTime<Utc>) -> Duration {
+        if !self.is_low(now) {
+            return base;
+        }
+        let until_reset = (self.reset - now).to_std().unwrap_or_default();
+        (until_reset / self.remaining.max(1)).max(base * 4)
     }
 }
 
@@ -45,6 +148,10 @@ struct GitHubUser {
 pub struct GitHubClient {
     client: reqwest::Client,
     token: String,
+    /// Shared by clones, so any copy sees the latest quota.
+    rate_limit: Arc<Mutex<Option<RateLimit>>>,
+    /// Back-off for this token after GitHub throttled it; shared by clones.
+    throttle: Throttle,
 }
 
 impl GitHubClient {
@@ -59,17 +166,7 @@ impl GitHubClient {
         token: impl Into<String>,
         proxy_settings: &crate::settings::ProxySettings,
     ) -> Result<Self, GitHubError> {
-        let (username, password) = if proxy_settings.has_credentials {
-            crate::github::proxy_keyring::load_proxy_credentials(&proxy_settings.url)
-                .map_err(|e| {
-                    GitHubError::Request(format!("Failed to load proxy credentials: {}", e))
-                })?
-                .map(|(u, p)| (Some(u), Some(p)))
-                .unwrap_or((None, None))
-        } else {
-            (None, None)
-        };
-
+        let (username, password) = super::http::load_proxy_credentials(proxy_settings)?;
         Self::new_with_proxy_and_credentials(token, proxy_settings, username, password)
     }
 
@@ -87,7 +184,6 @@ impl GitHubClient {
             ACCEPT,
             HeaderValue::from_static("application/vnd.github+json"),
         );
-        headers.insert(USER_AGENT, HeaderValue::from_static("GitTop/0.1.0"));
         headers.insert(
             "X-GitHub-Api-Version",
             HeaderValue::from_static("2022-11-28"),
@@ -98,69 +194,153 @@ impl GitHubClient {
                 .map_err(|_| GitHubError::Unauthorized)?,
         );
 
-        let mut client_builder = reqwest::Client::builder()
-            .default_headers(headers)
-            .pool_idle_timeout(std::time::Duration::from_secs(30))
-            .pool_max_idle_per_host(1);
-
-        // Configure proxy if enabled
-        if proxy_settings.enabled && !proxy_settings.url.is_empty() {
-            let mut proxy_builder = reqwest::Proxy::all(&proxy_settings.url)
-                .map_err(|e| GitHubError::Request(format!("Invalid proxy URL: {}", e)))?;
-
-            if let Some(user) = username
-                && !user.is_empty()
-            {
-                let pass = password.as_deref().unwrap_or("");
-                proxy_builder = proxy_builder.basic_auth(&user, pass);
-            }
-
-            client_builder = client_builder.proxy(proxy_builder);
-        }
+        let client_builder = super::http::http_client_builder(proxy_settings, username, password)?
+            .default_headers(headers);
 
         let client = client_builder.build()?;
 
-        Ok(Self { client, token })
+        Ok(Self {
+            client,
+            token,
+            rate_limit: Arc::default(),
+            throttle: Throttle::default(),
+        })
+    }
+
+    /// Sends `request` unless GitHub has asked this account to back off.
+    async fn send(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, GitHubError> {
+        if let Some(left) = self.throttle.throttled_for() {
+            return Err(GitHubError::Throttled {
+                retry_after: left.as_secs() + 1,
+            });
+        }
+        Ok(request.send().await?)
     }
 
     /// Validates and handles the response status.
     async fn handle_response(
+        &self,
         response: reqwest::Response,
     ) -> Result<reqwest::Response, GitHubError> {
         let status = response.status();
-
         if status.is_success() {
-            Ok(response)
-        } else if status.as_u16() == 401 {
-            Err(GitHubError::Unauthorized)
-        } else if status.as_u16() == 403 {
-            Err(GitHubError::RateLimited)
+            return Ok(response);
+        }
+
+        let headers = response.headers().clone();
+        let message = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Unknown error".to_string());
+        let error = Self::error_for(status.as_u16(), &headers, message);
+        if let GitHubError::Throttled { retry_after } = error {
+            self.throttle.throttle_for(Duration::from_secs(retry_after));
+        }
+        Err(error)
+    }
+
+    /// The error for a failed response, from its status, headers and body.
+    fn error_for(status: u16, headers: &HeaderMap, message: String) -> GitHubError {
+        let throttling = matches!(status, 403 | 429);
+        if status == 401 {
+            GitHubError::Unauthorized
+        } else if let Some(retry_after) = Self::retry_after(headers)
+            && throttling
+        {
+            // Secondary rate limits usually say exactly how long to back off
+            GitHubError::Throttled { retry_after }
+        } else if throttling
+            && headers
+                .get("x-ratelimit-remaining")
+                .is_some_and(|v| v.as_bytes() == b"0")
+        {
+            // The hourly quota is used up
+            GitHubError::RateLimited
+        } else if status == 429 || (status == 403 && is_secondary_limit(&message)) {
+            // Secondary limits don't always send headers; only the body tells
+            GitHubError::Throttled {
+                retry_after: DEFAULT_THROTTLE_SECS,
+            }
+        } else if status == 403 && lacks_notifications_scope(headers, &message) {
+            GitHubError::MissingScope(message)
+        } else if status == 403 {
+            GitHubError::Forbidden(message)
         } else {
-            let message = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            Err(GitHubError::Api {
-                status: status.as_u16(),
-                message,
-            })
+            GitHubError::Api { status, message }
         }
     }
 
+    /// Time left before this account may call GitHub again, or `None` if
+    /// it isn't throttled.
+    pub fn throttled_for(&self) -> Option<Duration> {
+        self.throttle.throttled_for()
+    }
+
+    /// Clear a throttle whose time is up. Returns true exactly once per expiry.
+    pub fn take_expired_throttle(&self) -> bool {
+        self.throttle.take_expired()
+    }
+
+    /// Quota reported by the most recent notifications fetch.
+    pub fn rate_limit(&self) -> Option<RateLimit> {
+        *self.rate_limit.lock().ok()?
+    }
+
+    fn record_rate_limit(&self, response: &reqwest::Response) {
+        if let Some(limit) = RateLimit::from_headers(response.headers())
+            && let Ok(mut latest) = self.rate_limit.lock()
+        {
+            *latest = Some(limit);
+        }
+    }
+
+    /// Seconds from a `Retry-After` header, if the response has one.
+    fn retry_after(headers: &HeaderMap) -> Option<u64> {
+        headers
+            .get(reqwest::header::RETRY_AFTER)?
+            .to_str()
+            .ok()?
+            .trim()
+            .parse()
+            .ok()
+    }
+
+    /// Expiry from the `GitHub-Authentication-Token-Expiration` header, sent
+    /// for tokens that have one, e.g. `2026-03-24 08:00:00 UTC`.
+    fn token_expiration(headers: &HeaderMap) -> Option<DateTime<Utc>> {
+        let value = headers
+            .get("github-authentication-token-expiration")?
+            .to_str()
+            .ok()?
+            .trim();
+        DateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S %z")
+            .map(|at| at.with_timezone(&Utc))
+            .or_else(|_| {
+                chrono::NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S UTC")
+                    .map(|at| at.and_utc())
+            })
+            .ok()
+    }
+
     /// Fetches the authenticated user's information.
     /// This is used to validate the token and get user details.
     pub async fn get_authenticated_user(&self) -> Result<UserInfo, GitHubError> {
         let url = format!("{}/user", GITHUB_API_URL);
 
-        let response = self.client.get(&url).send().await?;
-        let response = Self::handle_response(response).await?;
+        let response = self.send(self.client.get(&url)).await?;
+        let response = self.handle_response(response).await?;
 
+        let token_expires_at = Self::token_expiration(response.headers());
         let user: GitHubUser = response.json().await?;
         Ok(UserInfo {
             login: user.login,
             name: user.name,
             avatar_url: user.avatar_url,
             html_url: user.html_url,
+            token_expires_at,
         })
     }
 
@@ -194,15 +374,27 @@ impl GitHubClient {
         Ok((client, user))
     }
 
-    /// Fetches the user's notifications.
-    pub async fn get_notifications(&self, all: bool) -> Result<Vec<Notification>, GitHubError> {
-        let url = format!(
-            "{}/notifications?all={}&participating=false",
-            GITHUB_API_URL, all
+    /// Fetches the user's notifications. `participating` limits them to
+    /// threads the user is directly involved in (mentioned, assigned, ...),
+    /// `since` to those updated after that time.
+    pub async fn get_notifications(
+        &self,
+        all: bool,
+        participating: bool,
+        since: Option<DateTime<Utc>>,
+    ) -> Result<Vec<Notification>, GitHubError> {
+        let mut url = format!(
+            "{}/notifications?all={}&participating={}",
+            GITHUB_API_URL, all, participating
         );
+        if let Some(since) = since {
+            url.push_str("&since=");
+            url.push_str(&since.to_rfc3339_opts(SecondsFormat::Secs, true));
+        }
 
-        let response = self.client.get(&url).send().await?;
-        let response = Self::handle_response(response).await?;
+        let response = self.send(self.client.get(&url)).await?;
+        self.record_rate_limit(&response);
+        let response = self.handle_response(response).await?;
         Ok(response.json().await?)
     }
 
@@ -211,9 +403,11 @@ impl GitHubClient {
     pub async fn get_notification_views(
         &self,
         all: bool,
+        participating: bool,
+        since: Option<DateTime<Utc>>,
         account: &str,
     ) -> Result<Vec<NotificationView>, GitHubError> {
-        let notifications = self.get_notifications(all).await?;
+        let notifications = self.get_notifications(all, participating, since).await?;
         let account = account.to_string();
         Ok(notifications
             .into_iter()
@@ -228,8 +422,8 @@ impl GitHubClient {
             GITHUB_API_URL, notification_id
         );
 
-        let response = self.client.patch(&url).send().await?;
-        Self::handle_response(response).await.map(|_| ())
+        let response = self.send(self.client.patch(&url)).await?;
+        self.handle_response(response).await.map(|_| ())
     }
 
     /// Marks all notifications as read.
@@ -237,21 +431,47 @@ impl GitHubClient {
         let url = format!("{}/notifications", GITHUB_API_URL);
 
         let response = self
-            .client
-            .put(&url)
-            .json(&serde_json::json!({}))
-            .send()
+            .send(self.client.put(&url).json(&serde_json::json!({})))
+            .await?;
+
+        self.handle_response(response).await.map(|_| ())
+    }
+
+    /// Marks all notifications in a repository (`owner/name`) as read.
+    pub async fn mark_repo_as_read(&self, repo_full_name: &str) -> Result<(), GitHubError> {
+        let url = format!("{}/repos/{}/notifications", GITHUB_API_URL, repo_full_name);
+
+        let response = self
+            .send(self.client.put(&url).json(&serde_json::json!({})))
             .await?;
 
-        Self::handle_response(response).await.map(|_| ())
+        self.handle_response(response).await.map(|_| ())
     }
 
     /// Marks a thread as "done" (removes it from inbox).
     pub async fn mark_thread_as_done(&self, thread_id: &str) -> Result<(), GitHubError> {
         let url = format!("{}/notifications/threads/{}", GITHUB_API_URL, thread_id);
 
-        let response = self.client.delete(&url).send().await?;
-        Self::handle_response(response).await.map(|_| ())
+        let response = self.send(self.client.delete(&url)).await?;
+        self.handle_response(response).await.map(|_| ())
+    }
+
+    /// Mutes a thread: GitHub stops notifying about it until it's unmuted.
+    pub async fn mute_thread(&self, thread_id: &str) -> Result<(), GitHubError> {
+        let url = format!(
+            "{}/notifications/threads/{}/subscription",
+            GITHUB_API_URL, thread_id
+        );
+
+        let response = self
+            .send(
+                self.client
+                    .put(&url)
+                    .json(&serde_json::json!({ "ignored": true })),
+            )
+            .await?;
+
+        self.handle_response(response).await.map(|_| ())
     }
 
     /// Fetches Issue details from an API URL.
@@ -262,7 +482,7 @@ impl GitHubClient {
         &self,
         url: &str,
     ) -> Result<super::subject_details::IssueDetails, GitHubError> {
-        let response = self.client.get(url).send().await?;
+        let response = self.send(self.client.get(url)).await?;
 
         let status = response.status();
         if status.as_u16() == 404 {
@@ -272,7 +492,7 @@ impl GitHubClient {
             });
         }
 
-        let response = Self::handle_response(response).await?;
+        let response = self.handle_response(response).await?;
         Ok(response.json().await?)
     }
 
@@ -284,7 +504,7 @@ impl GitHubClient {
         &self,
         url: &str,
     ) -> Result<super::subject_details::PullRequestDetails, GitHubError> {
-        let response = self.client.get(url).send().await?;
+        let response = self.send(self.client.get(url)).await?;
 
         let status = response.status();
         if status.as_u16() == 404 {
@@ -294,7 +514,7 @@ impl GitHubClient {
             });
         }
 
-        let response = Self::handle_response(response).await?;
+        let response = self.handle_response(response).await?;
         Ok(response.json().await?)
     }
 
@@ -305,7 +525,7 @@ impl GitHubClient {
         &self,
         url: &str,
     ) -> Result<super::subject_details::CommentDetails, GitHubError> {
-        let response = self.client.get(url).send().await?;
+        let response = self.send(self.client.get(url)).await?;
 
         let status = response.status();
         if status.as_u16() == 404 {
@@ -315,7 +535,7 @@ impl GitHubClient {
             });
         }
 
-        let response = Self::handle_response(response).await?;
+        let response = self.handle_response(response).await?;
         Ok(response.json().await?)
     }
 
@@ -406,6 +626,107 @@ impl GitHubClient {
         }
     }
 
+    /// Fetches the combined check-run conclusion for a notification subject.
+    /// Repos without check runs fall back to the older commit statuses.
+    ///
+    /// Works for pull request and commit subject URLs; any other subject
+    /// returns `Ok(None)` since there is no commit to look checks up for.
+    pub async fn get_ci_conclusion(
+        &self,
+        subject_url: &str,
+    ) -> Result<Option<super::types::CiConclusion>, GitHubError> {
+        use super::types::CiConclusion;
+
+        #[derive(Deserialize)]
+        struct PullHead {
+            head: PullHeadRef,
+        }
+        #[derive(Deserialize)]
+        struct PullHeadRef {
+            sha: String,
+        }
+        #[derive(Deserialize)]
+        struct CheckRuns {
+            check_runs: Vec<CheckRun>,
+        }
+        #[derive(Deserialize)]
+        struct CheckRun {
+            status: String,
+            conclusion: Option<String>,
+        }
+        #[derive(Deserialize)]
+        struct CombinedStatus {
+            state: String,
+            total_count: u32,
+        }
+
+        let (repo_url, sha) = if let Some((repo_url, _)) = subject_url.split_once("/pulls/") {
+            let response = self.send(self.client.get(subject_url)).await?;
+            let response = self.handle_response(response).await?;
+            let pull: PullHead = response.json().await?;
+            (repo_url.to_string(), pull.head.sha)
+        } else if let Some((repo_url, sha)) = subject_url.split_once("/commits/") {
+            (repo_url.to_string(), sha.to_string())
+        } else {
+            return Ok(None);
+        };
+
+        let url = format!("{}/commits/{}/check-runs?per_page=100", repo_url, sha);
+        let response = self.send(self.client.get(&url)).await?;
+        let response = self.handle_response(response).await?;
+        let runs: CheckRuns = response.json().await?;
+
+        if runs.check_runs.is_empty() {
+            let url = format!("{}/commits/{}/status", repo_url, sha);
+            let response = self.send(self.client.get(&url)).await?;
+            let response = self.handle_response(response).await?;
+            let combined: CombinedStatus = response.json().await?;
+            return Ok(match combined.state.as_str() {
+                _ if combined.total_count == 0 => None,
+                "success" => Some(CiConclusion::Success),
+                "failure" | "error" => Some(CiConclusion::Failure),
+                _ => Some(CiConclusion::Pending),
+            });
+        }
+
+        let failed = runs.check_runs.iter().any(|run| {
+            matches!(
+                run.conclusion.as_deref(),
+                Some("failure" | "timed_out" | "action_required" | "startup_failure")
+            )
+        });
+        let running = runs.check_runs.iter().any(|run| run.status != "completed");
+
+        Ok(Some(if failed {
+            CiConclusion::Failure
+        } else if running {
+            CiConclusion::Pending
+        } else {
+            CiConclusion::Success
+        }))
+    }
+
+    /// Fetches the login of whoever is behind a subject or comment API URL.
+    ///
+    /// Comments, issues and pull requests carry a `user`; releases carry an
+    /// `author`. Returns `Ok(None)` when neither is present.
+    pub async fn get_actor(&self, url: &str) -> Result<Option<String>, GitHubError> {
+        #[derive(Deserialize)]
+        struct Actor {
+            login: String,
+        }
+        #[derive(Deserialize)]
+        struct Subject {
+            user: Option<Actor>,
+            author: Option<Actor>,
+        }
+
+        let response = self.send(self.client.get(url)).await?;
+        let response = self.handle_response(response).await?;
+        let subject: Subject = response.json().await?;
+        Ok(subject.user.or(subject.author).map(|actor| actor.login))
+    }
+
     /// Fetches Discussion details via GraphQL API.
     ///
     /// Discussions are not available via REST API, so we use the GraphQL endpoint.
@@ -441,7 +762,7 @@ impl GitHubClient {
 
         let body = serde_json::json!({ "query": query });
 
-        let response = self.client.post(GRAPHQL_URL).json(&body).send().await?;
+        let response = self.send(self.client.post(GRAPHQL_URL).json(&body)).await?;
 
         let status = response.status();
         if !status.is_success() {
@@ -531,3 +852,213 @@ fn parse_discussion_url(url: &str) -> Option<(String, String, u64)> {
 
     Some((owner, repo, number))
 }
+
+/// GitHub's secondary (abuse) limit errors all say so in the message, e.g.
+/// "You have exceeded a secondary rate limit".
+fn is_secondary_limit(message: &str) -> bool {
+    message
+        .to_ascii_lowercase()
+        .contains("secondary rate limit")
+}
+
+/// A 403 because the token wasn't granted the scope notifications need,
+/// told either by the message or by GitHub's OAuth scope headers.
+fn lacks_notifications_scope(headers: &HeaderMap, message: &str) -> bool {
+    let message = message.to_ascii_lowercase();
+    if message.contains("scope") && message.contains("notifications") {
+        return true;
+    }
+    let scopes = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.split(',').map(str::trim).any(|s| s == "notifications"))
+    };
+    scopes("x-accepted-oauth-scopes") == Some(true) && scopes("x-oauth-scopes") == Some(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.insert(*name, HeaderValue::from_str(value).unwrap());
+        }
+        headers
+    }
+
+    fn error_for(status: u16, pairs: &[(&'static str, &str)], body: &str) -> GitHubError {
+        GitHubClient::error_for(status, &headers(pairs), body.to_string())
+    }
+
+    #[test]
+    fn test_retry_after_throttles_for_that_long() {
+        for status in [403, 429] {
+            assert!(matches!(
+                error_for(status, &[("retry-after", "120")], ""),
+                GitHubError::Throttled { retry_after: 120 }
+            ));
+        }
+    }
+
+    #[test]
+    fn test_secondary_limit_without_headers_throttles_for_a_minute() {
+        let body = r#"{"message":"You have exceeded a secondary rate limit. Please wait a few minutes before you try again."}"#;
+        let error = error_for(403, &[], body);
+        assert!(matches!(
+            error,
+            GitHubError::Throttled {
+                retry_after: DEFAULT_THROTTLE_SECS
+            }
+        ));
+        assert_eq!(error.kind(), ErrorKind::RateLimited);
+        assert!(!error.is_auth_error());
+    }
+
+    #[test]
+    fn test_bare_429_is_throttled() {
+        let error = error_for(429, &[], "");
+        assert!(matches!(
+            error,
+            GitHubError::Throttled {
+                retry_after: DEFAULT_THROTTLE_SECS
+            }
+        ));
+    }
+
+    #[test]
+    fn test_exhausted_quota_is_rate_limited() {
+        for status in [403, 429] {
+            assert!(matches!(
+                error_for(status, &[("x-ratelimit-remaining", "0")], ""),
+                GitHubError::RateLimited
+            ));
+        }
+    }
+
+    #[test]
+    fn test_other_statuses() {
+        assert!(matches!(error_for(401, &[], ""), GitHubError::Unauthorized));
+        assert!(matches!(
+            error_for(502, &[("retry-after", "5")], "Bad gateway"),
+            GitHubError::Api { status: 502, .. }
+        ));
+    }
+
+    #[test]
+    fn test_only_missing_scope_403_needs_reauthentication() {
+        let no_access = error_for(
+            403,
+            &[("x-ratelimit-remaining", "4000")],
+            "Resource not accessible by personal access token",
+        );
+        assert!(matches!(no_access, GitHubError::Forbidden(_)));
+        assert!(!no_access.is_auth_error());
+
+        let sso = error_for(
+            403,
+            &[],
+            "Resource protected by organization SAML enforcement.",
+        );
+        assert!(!sso.is_auth_error());
+
+        let by_message = error_for(403, &[], "Missing the 'notifications' scope.");
+        assert!(matches!(by_message, GitHubError::MissingScope(_)));
+        assert!(by_message.is_auth_error());
+
+        let by_headers = error_for(
+            403,
+            &[
+                ("x-accepted-oauth-scopes", "notifications, repo"),
+                ("x-oauth-scopes", "read:user"),
+            ],
+            "Forbidden",
+        );
+        assert!(by_headers.is_auth_error());
+    }
+
+    fn rate_limit(remaining: u32, reset_in: i64, now: DateTime<Utc>) -> RateLimit {
+        RateLimit {
+            remaining,
+            limit: 5000,
+            reset: now + chrono::TimeDelta::seconds(reset_in),
+        }
+    }
+
+    #[test]
+    fn test_rate_limit_from_headers() {
+        let limit = RateLimit::from_headers(&headers(&[
+            ("x-ratelimit-remaining", "42"),
+            ("x-ratelimit-limit", "5000"),
+            ("x-ratelimit-reset", "1700000000"),
+        ]))
+        .unwrap();
+        assert_eq!(limit.remaining, 42);
+        assert_eq!(limit.limit, 5000);
+        assert_eq!(limit.reset.timestamp(), 1_700_000_000);
+        assert!(RateLimit::from_headers(&headers(&[("x-ratelimit-remaining", "42")])).is_none());
+    }
+
+    #[test]
+    fn test_paced_interval_keeps_base_while_quota_is_healthy() {
+        let now = Utc::now();
+        let base = Duration::from_secs(60);
+        assert_eq!(rate_limit(4000, 3600, now).paced_interval(base, now), base);
+        // Exactly a tenth left isn't low yet
+        assert_eq!(rate_limit(500, 3600, now).paced_interval(base, now), base);
+    }
+
+    #[test]
+    fn test_paced_interval_spreads_low_quota_until_reset() {
+        let now = Utc::now();
+        let base = Duration::from_secs(60);
+        // 100 calls over an hour: one every 36s, held to at least 4x base
+        assert_eq!(
+            rate_limit(100, 3600, now).paced_interval(base, now),
+            base * 4
+        );
+        // 10 calls over an hour: one every 6 minutes
+        assert_eq!(
+            rate_limit(10, 3600, now).paced_interval(base, now),
+            Duration::from_secs(360)
+        );
+    }
+
+    #[test]
+    fn test_paced_interval_with_no_quota_waits_for_reset() {
+        let now = Utc::now();
+        let base = Duration::from_secs(60);
+        assert_eq!(
+            rate_limit(0, 3600, now).paced_interval(base, now),
+            Duration::from_secs(3600)
+        );
+    }
+
+    #[test]
+    fn test_paced_interval_ignores_a_reset_that_has_passed() {
+        let now = Utc::now();
+        let base = Duration::from_secs(60);
+        let stale = rate_limit(0, -5, now);
+        assert!(!stale.is_low(now));
+        assert_eq!(stale.paced_interval(base, now), base);
+    }
+
+    #[test]
+    fn test_token_expiration_header_formats() {
+        let expected = DateTime::parse_from_rfc3339("2026-03-24T08:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let expiration = |value: &str| {
+            GitHubClient::token_expiration(&headers(&[(
+                "github-authentication-token-expiration",
+                value,
+            )]))
+        };
+        assert_eq!(expiration("2026-03-24 08:00:00 UTC"), Some(expected));
+        assert_eq!(expiration("2026-03-24 09:00:00 +0100"), Some(expected));
+        assert_eq!(expiration("next tuesday"), None);
+        assert_eq!(GitHubClient::token_expiration(&HeaderMap::new()), None);
+    }
+}
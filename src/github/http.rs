@@ -0,0 +1,70 @@
+//! Shared HTTP client construction.
+//!
+//! Every request GitTop makes goes through a client built here, so the
+//! user's proxy settings apply to auth, notifications and update checks alike.
+
+use std::time::Duration;
+
+use reqwest::ClientBuilder;
+
+use super::client::GitHubError;
+use crate::settings::ProxySettings;
+
+/// Sent with every request; GitHub rejects requests without one.
+pub const USER_AGENT: &str = concat!("GitTop/", env!("CARGO_PKG_VERSION"));
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Build an HTTP client that honours `proxy`.
+///
+/// Proxy credentials are loaded from the keyring when the settings say
+/// they're stored there.
+pub fn build_http_client(proxy: &ProxySettings) -> Result<reqwest::Client, GitHubError> {
+    let (username, password) = load_proxy_credentials(proxy)?;
+    Ok(http_client_builder(proxy, username, password)?.build()?)
+}
+
+/// Load the stored credentials for `proxy`, if it has any.
+pub(crate) fn load_proxy_credentials(
+    proxy: &ProxySettings,
+) -> Result<(Option<String>, Option<String>), GitHubError> {
+    if !proxy.has_credentials {
+        return Ok((None, None));
+    }
+
+    let credentials = super::proxy_keyring::load_proxy_credentials(&proxy.url)
+        .map_err(|e| GitHubError::Network(format!("Failed to load proxy credentials: {}", e)))?;
+    Ok(credentials
+        .map(|(u, p)| (Some(u), Some(p)))
+        .unwrap_or((None, None)))
+}
+
+/// The builder behind [`build_http_client`], for callers that add default
+/// headers on top.
+pub(crate) fn http_client_builder(
+    proxy: &ProxySettings,
+    username: Option<String>,
+    password: Option<String>,
+) -> Result<ClientBuilder, GitHubError> {
+    let mut builder = reqwest::Client::builder()
+        .user_agent(USER_AGENT)
+        .connect_timeout(CONNECT_TIMEOUT)
+        .pool_idle_timeout(Duration::from_secs(30))
+        .pool_max_idle_per_host(1);
+
+    if proxy.enabled && !proxy.url.is_empty() {
+        let mut proxy_builder = reqwest::Proxy::all(&proxy.url)
+            .map_err(|e| GitHubError::Network(format!("Invalid proxy URL: {}", e)))?;
+
+        if let Some(user) = username
+            && !user.is_empty()
+        {
+            let pass = password.as_deref().unwrap_or("");
+            proxy_builder = proxy_builder.basic_auth(&user, pass);
+        }
+
+        builder = builder.proxy(proxy_builder);
+    }
+
+    Ok(builder)
+}
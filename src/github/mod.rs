@@ -2,14 +2,17 @@
 
 pub mod auth;
 pub mod client;
+pub mod http;
 pub mod keyring;
 pub mod proxy_keyring;
 pub mod redaction;
 pub mod session;
 pub mod subject_details;
+pub mod throttle;
 pub mod types;
 
-pub use client::{GitHubClient, GitHubError};
+pub use client::{ErrorKind, GitHubClient, GitHubError, RateLimit};
+pub use http::build_http_client;
 pub use session::SessionManager;
 pub use subject_details::NotificationSubjectDetail;
 pub use types::*;
@@ -26,6 +26,59 @@ pub enum SessionError {
     NetworkError(String),
 }
 
+impl SessionError {
+    /// The account can't come back without the user adding it again (token
+    /// missing or rejected). Anything else may clear up on its own.
+    pub fn is_permanent(&self) -> bool {
+        matches!(self, SessionError::AccountNotFound(_))
+    }
+}
+
+/// Result of restoring the saved accounts at startup.
+#[derive(Debug, Default)]
+pub struct RestoreReport {
+    /// Accounts that are gone for good and should be forgotten.
+    pub removed: Vec<String>,
+    /// Last transient failure (network, proxy, rate limit, keyring). Those
+    /// accounts are kept so the next start can retry.
+    pub transient_error: Option<String>,
+}
+
+impl RestoreReport {
+    /// Record the outcome of restoring `username`.
+    pub fn record(&mut self, username: &str, result: Result<(), SessionError>) {
+        let Err(error) = result else {
+            return;
+        };
+
+        if error.is_permanent() {
+            self.removed.push(username.to_string());
+            return;
+        }
+
+        tracing::warn!(
+            username = %username,
+            error = %error,
+            "Could not restore saved session, keeping account"
+        );
+        self.transient_error = Some(match error {
+            SessionError::NetworkError(msg) => msg,
+            other => other.to_string(),
+        });
+    }
+
+    /// Forget the removed accounts, except ones restored from a backup
+    /// that haven't been signed in to yet: they never had a token here.
+    /// Returns true if settings changed.
+    pub fn apply(&self, settings: &mut crate::settings::AppSettings) -> bool {
+        let before = settings.accounts.len();
+        settings
+            .accounts
+            .retain(|a| a.awaiting_sign_in || !self.removed.contains(&a.username));
+        settings.accounts.len() != before
+    }
+}
+
 /// An authenticated session for a single account.
 #[derive(Debug, Clone)]
 pub struct Session {
@@ -65,7 +118,7 @@ impl SessionManager {
                     let _ = keyring::delete_token(username);
                     return Err(SessionError::AccountNotFound(username.to_string()));
                 }
-                Err(GitHubError::Request(msg)) => {
+                Err(GitHubError::Network(msg) | GitHubError::Other(msg)) => {
                     // Connection/network error - keep account, report network issue
                     return Err(SessionError::NetworkError(redact_secrets(&msg)));
                 }
@@ -80,7 +133,14 @@ impl SessionManager {
                         status, safe_message
                     )));
                 }
-                Err(GitHubError::RateLimited) => {
+                Err(GitHubError::Forbidden(message) | GitHubError::MissingScope(message)) => {
+                    // Token is valid but lacks access - keep it so the user can fix scopes
+                    return Err(SessionError::NetworkError(format!(
+                        "Access forbidden: {}",
+                        redact_secrets(&message)
+                    )));
+                }
+                Err(GitHubError::RateLimited | GitHubError::Throttled { .. }) => {
                     // Rate limited - definitely keep account, just can't fetch now
                     return Err(SessionError::NetworkError(
                         "GitHub rate limit exceeded".to_string(),
@@ -136,6 +196,18 @@ impl SessionManager {
         self.sessions.insert(username, session);
     }
 
+    /// Swap in a fresh token for an existing account, leaving other sessions
+    /// and the primary selection untouched.
+    pub fn replace_session(&mut self, session: Session) -> Result<(), SessionError> {
+        if !self.sessions.contains_key(&session.username) {
+            return Err(SessionError::AccountNotFound(session.username));
+        }
+        keyring::save_token(&session.username, session.client.token())?;
+        tracing::info!("Account token replaced");
+        self.sessions.insert(session.username.clone(), session);
+        Ok(())
+    }
+
     /// Get the primary session.
     pub fn primary(&self) -> Option<&Session> {
         self.primary
@@ -151,7 +223,6 @@ impl SessionManager {
     }
 
     /// Set which account is primary.
-    #[allow(dead_code)]
     pub fn set_primary(&mut self, username: &str) {
         if self.sessions.contains_key(username) {
             self.primary = Some(username.to_string());
@@ -164,7 +235,6 @@ impl SessionManager {
     }
 
     /// Get a specific session by username.
-    #[allow(dead_code)]
     pub fn get(&self, username: &str) -> Option<&Session> {
         self.sessions.get(username)
     }
@@ -202,3 +272,66 @@ impl SessionManager {
         self.sessions.len()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::{AppSettings, StoredAccount};
+
+    fn settings_with(usernames: &[&str]) -> AppSettings {
+        AppSettings {
+            accounts: usernames
+                .iter()
+                .map(|u| StoredAccount {
+                    username: u.to_string(),
+                    is_active: false,
+                    display_name: None,
+                    power_mode: None,
+                    pinned: Vec::new(),
+                    token_expires_at: None,
+                    token_expiry_dismissed: None,
+                    reminders: Vec::new(),
+                    awaiting_sign_in: false,
+                })
+                .collect(),
+            ..AppSettings::default()
+        }
+    }
+
+    #[test]
+    fn test_network_down_at_startup_keeps_accounts() {
+        let mut settings = settings_with(&["alice", "bob", "carol"]);
+        let mut report = RestoreReport::default();
+
+        report.record(
+            "alice",
+            Err(SessionError::NetworkError("connection refused".into())),
+        );
+        report.record("bob", Err(SessionError::GitHub(GitHubError::RateLimited)));
+        report.record(
+            "carol",
+            Err(SessionError::Keyring(KeyringError::Internal(
+                "locked".into(),
+            ))),
+        );
+
+        assert!(!report.apply(&mut settings));
+        assert!(report.removed.is_empty());
+        assert!(report.transient_error.is_some());
+        assert_eq!(settings.accounts.len(), 3);
+    }
+
+    #[test]
+    fn test_missing_token_removes_only_that_account() {
+        let mut settings = settings_with(&["alice", "bob"]);
+        let mut report = RestoreReport::default();
+
+        report.record("alice", Err(SessionError::AccountNotFound("alice".into())));
+        report.record("bob", Err(SessionError::NetworkError("timed out".into())));
+
+        assert!(report.apply(&mut settings));
+        assert_eq!(report.removed, vec!["alice".to_string()]);
+        assert_eq!(settings.accounts.len(), 1);
+        assert_eq!(settings.accounts[0].username, "bob");
+    }
+}
@@ -0,0 +1,84 @@
+//! Back-off after GitHub throttles an account.
+//!
+//! Any throttled response stops every request made with that token, not
+//! just the one that got it: the limit is per user, so CI lookups or bulk
+//! actions would keep tripping it while the refresh waits. Other signed-in
+//! accounts have their own limits and keep going.
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// When requests for one token may resume. Clones share the deadline.
+#[derive(Debug, Clone, Default)]
+pub struct Throttle {
+    until: Arc<Mutex<Option<Instant>>>,
+}
+
+impl Throttle {
+    /// Stop calling GitHub for `retry_after`, as a secondary rate limit
+    /// asked. Never cuts short a longer back-off that's already running.
+    pub fn throttle_for(&self, retry_after: Duration) {
+        tracing::warn!(
+            secs = retry_after.as_secs(),
+            "Throttled by GitHub, pausing requests"
+        );
+        if let Ok(mut guard) = self.until.lock() {
+            let until = Instant::now() + retry_after;
+            *guard = Some(guard.map_or(until, |existing| existing.max(until)));
+        }
+    }
+
+    /// Time left before requests may resume, or `None` if not throttled.
+    pub fn throttled_for(&self) -> Option<Duration> {
+        self.until
+            .lock()
+            .ok()
+            .and_then(|guard| *guard)
+            .and_then(|until| until.checked_duration_since(Instant::now()))
+            .filter(|left| !left.is_zero())
+    }
+
+    /// Clear a throttle whose time is up. Returns true exactly once per expiry.
+    pub fn take_expired(&self) -> bool {
+        let Ok(mut guard) = self.until.lock() else {
+            return false;
+        };
+        if guard.is_some_and(|until| until <= Instant::now()) {
+            *guard = None;
+            tracing::info!("Throttle ended, resuming requests");
+            return true;
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shorter_retry_after_keeps_the_longer_throttle() {
+        let throttle = Throttle::default();
+        throttle.throttle_for(Duration::from_secs(600));
+        throttle.throttle_for(Duration::from_secs(5));
+        assert!(throttle.throttled_for().unwrap() > Duration::from_secs(500));
+    }
+
+    #[test]
+    fn test_throttles_are_separate_per_token() {
+        let throttled = Throttle::default();
+        let other = Throttle::default();
+        throttled.throttle_for(Duration::from_secs(60));
+        assert!(throttled.clone().throttled_for().is_some());
+        assert!(other.throttled_for().is_none());
+    }
+
+    #[test]
+    fn test_expired_throttle_is_taken_once() {
+        let throttle = Throttle::default();
+        throttle.throttle_for(Duration::ZERO);
+        assert!(throttle.throttled_for().is_none());
+        assert!(throttle.take_expired());
+        assert!(!throttle.take_expired());
+    }
+}
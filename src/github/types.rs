@@ -10,6 +10,10 @@ pub struct UserInfo {
     pub name: Option<String>,
     pub avatar_url: String,
     pub html_url: String,
+    /// When the token expires, if GitHub said so (fine-grained and
+    /// expiring classic tokens).
+    #[serde(default)]
+    pub token_expires_at: Option<DateTime<Utc>>,
 }
 
 /// A GitHub notification from the notifications API.
@@ -159,6 +163,31 @@ impl std::fmt::Display for NotificationReason {
     }
 }
 
+/// Outcome of the checks behind a CI activity notification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CiConclusion {
+    Success,
+    Failure,
+    Pending,
+}
+
+impl CiConclusion {
+    /// Guess the conclusion from a check suite notification title.
+    ///
+    /// GitHub titles these like "CI workflow run failed for main branch",
+    /// which is all we have when the subject carries no API URL.
+    pub fn from_title(title: &str) -> Option<Self> {
+        let title = title.to_ascii_lowercase();
+        if title.contains("failed") || title.contains("timed out") {
+            Some(Self::Failure)
+        } else if title.contains("succeeded") || title.contains("passed") {
+            Some(Self::Success)
+        } else {
+            None
+        }
+    }
+}
+
 /// Repository information from the notification.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Repository {
@@ -178,7 +207,7 @@ pub struct Owner {
 }
 
 /// Frontend-friendly notification format for the UI.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NotificationView {
     pub id: String,
     pub title: String,
@@ -198,11 +227,19 @@ pub struct NotificationView {
     pub is_private: bool,
     /// The GitHub account (username) this notification belongs to.
     pub account: String,
+    /// Check outcome for CI activity, once known.
+    pub ci_conclusion: Option<CiConclusion>,
+    /// Login of whoever triggered the notification, once looked up.
+    pub actor: Option<String>,
 }
 
 impl NotificationView {
     /// Create a NotificationView from a Notification with the account name.
     pub fn from_notification(n: Notification, account: impl Into<String>) -> Self {
+        let ci_conclusion = (n.reason == NotificationReason::CiActivity)
+            .then(|| CiConclusion::from_title(&n.subject.title))
+            .flatten();
+
         Self {
             id: n.id,
             title: n.subject.title,
@@ -217,6 +254,8 @@ impl NotificationView {
             avatar_url: n.repository.owner.avatar_url,
             is_private: n.repository.private,
             account: account.into(),
+            ci_conclusion,
+            actor: None,
         }
     }
 
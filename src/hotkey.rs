@@ -0,0 +1,90 @@
+//! Global show/hide keyboard shortcut.
+//!
+//! Backed by the `global-hotkey` crate. On Linux/FreeBSD this only works under
+//! X11; Wayland compositors don't allow clients to grab keys globally, so the
+//! shortcut is skipped there and the tray remains the way back in.
+
+use std::cell::RefCell;
+use std::str::FromStr;
+
+use global_hotkey::hotkey::HotKey;
+use global_hotkey::{GlobalHotKeyEvent, GlobalHotKeyManager, HotKeyState};
+
+struct Registration {
+    manager: GlobalHotKeyManager,
+    hotkey: HotKey,
+}
+
+impl Drop for Registration {
+    fn drop(&mut self) {
+        if let Err(e) = self.manager.unregister(self.hotkey) {
+            tracing::debug!(error = %e, "Failed to unregister global hotkey");
+        }
+    }
+}
+
+thread_local! {
+    // The manager must stay on the thread that created it (main/UI thread).
+    static REGISTRATION: RefCell<Option<Registration>> = const { RefCell::new(None) };
+}
+
+/// Register `spec` (e.g. `"Ctrl+Shift+G"`) as the show/hide shortcut,
+/// replacing any previous one. `None` or an empty string unbinds it.
+pub fn apply(spec: Option<&str>) -> Result<(), String> {
+    unregister();
+
+    let Some(spec) = spec.map(str::trim).filter(|s| !s.is_empty()) else {
+        return Ok(());
+    };
+
+    let hotkey = HotKey::from_str(spec).map_err(|e| e.to_string())?;
+
+    if !is_supported() {
+        tracing::warn!(
+            shortcut = spec,
+            "Global shortcuts are unavailable on Wayland"
+        );
+        return Err("Global shortcuts are not supported on Wayland".to_string());
+    }
+
+    let manager = GlobalHotKeyManager::new().map_err(|e| e.to_string())?;
+    manager.register(hotkey).map_err(|e| e.to_string())?;
+
+    tracing::info!(shortcut = spec, "Global shortcut registered");
+    REGISTRATION.with(|r| *r.borrow_mut() = Some(Registration { manager, hotkey }));
+    Ok(())
+}
+
+/// Drop the current shortcut registration, if any.
+pub fn unregister() {
+    REGISTRATION.with(|r| r.borrow_mut().take());
+}
+
+/// Returns true if the registered shortcut was pressed since the last poll.
+pub fn poll_pressed() -> bool {
+    let registered = REGISTRATION.with(|r| r.borrow().as_ref().map(|reg| reg.hotkey.id()));
+    let Some(id) = registered else {
+        return false;
+    };
+
+    let mut pressed = false;
+    while let Ok(event) = GlobalHotKeyEvent::receiver().try_recv() {
+        pressed |= event.id() == id && event.state() == HotKeyState::Pressed;
+    }
+    pressed
+}
+
+/// Whether the current session can register global shortcuts at all.
+pub fn is_supported() -> bool {
+    #[cfg(any(target_os = "linux", target_os = "freebsd"))]
+    {
+        let wayland = std::env::var("XDG_SESSION_TYPE").is_ok_and(|t| t == "wayland")
+            || std::env::var_os("WAYLAND_DISPLAY").is_some();
+        !wayland
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "freebsd")))]
+    {
+        true
+    }
+}
@@ -0,0 +1,244 @@
+//! UI strings looked up by key, so the interface can be translated.
+//!
+//! Views call `tr(Key::SignIn)` instead of writing the English literal.
+//! English is the reference table; any other language only lists what it
+//! has translated and falls back to English for the rest, so a partial
+//! translation is still usable.
+//!
+//! Adding a language means a `Language` variant and a table like `german`.
+//! Strings with a value in them use a `{}` placeholder; fill it with `trf`.
+//!
+//! Only the login screen, the settings frame and the notification header go
+//! through here so far; the rest of the UI is still English literals.
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use serde::{Deserialize, Serialize};
+
+static CURRENT: AtomicU8 = AtomicU8::new(0);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum Language {
+    #[default]
+    English,
+    German,
+}
+
+impl Language {
+    pub const ALL: [Self; 2] = [Self::English, Self::German];
+}
+
+impl std::fmt::Display for Language {
+    /// Each language is named in itself, so it can be found without reading
+    /// the current one.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::English => write!(f, "English"),
+            Self::German => write!(f, "Deutsch"),
+        }
+    }
+}
+
+pub fn set_language(language: Language) {
+    CURRENT.store(language as u8, Ordering::Relaxed);
+}
+
+pub fn language() -> Language {
+    let current = CURRENT.load(Ordering::Relaxed);
+    Language::ALL
+        .into_iter()
+        .find(|&language| language as u8 == current)
+        .unwrap_or_default()
+}
+
+/// A translatable UI string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Key {
+    // -- Login --
+    LoginTagline,
+    LoginAddAccountTagline,
+    /// `{}`: the account being re-authenticated.
+    LoginReauthTagline,
+    TokenLabel,
+    Authenticating,
+    SignIn,
+    AddAccount,
+    UpdateToken,
+    GenerateNewToken,
+    RequiredScopes,
+    ProxySettings,
+    Cancel,
+    EnterToken,
+
+    // -- Settings frame --
+    Back,
+    Settings,
+    PowerMode,
+    General,
+    Accounts,
+    NetworkProxy,
+    Language,
+    LanguageDescription,
+
+    // -- Notification header --
+    Notifications,
+    Syncing,
+    NotSynced,
+    SyncedJustNow,
+    /// `{}`: how long ago, e.g. "5m".
+    SyncedAgo,
+    /// `{}`: a date.
+    SyncedOn,
+    /// `{}`: the time refreshes resume.
+    PausedUntil,
+    Resume,
+    Unread,
+    All,
+    HideRead,
+    Mentions,
+    Participating,
+    MarkAllRead,
+    MarkAllDone,
+    SearchNotifications,
+    /// `{}`: remaining/limit, e.g. "4821/5000".
+    ApiQuota,
+}
+
+/// The string for `key` in the current language.
+pub fn tr(key: Key) -> &'static str {
+    lookup(language(), key)
+}
+
+fn lookup(language: Language, key: Key) -> &'static str {
+    let translated = match language {
+        Language::English => None,
+        Language::German => german(key),
+    };
+    translated.unwrap_or_else(|| english(key))
+}
+
+/// `tr` with its `{}` placeholder filled in.
+pub fn trf(key: Key, value: impl std::fmt::Display) -> String {
+    tr(key).replacen("{}", &value.to_string(), 1)
+}
+
+fn english(key: Key) -> &'static str {
+    match key {
+        Key::LoginTagline => "Runs lighter than your IDE's status bar.",
+        Key::LoginAddAccountTagline => {
+            "Add another GitHub account. You stay signed in to the others."
+        }
+        Key::LoginReauthTagline => "Enter a new token for {}. Other accounts and rules are kept.",
+        Key::TokenLabel => "GitHub Personal Access Token",
+        Key::Authenticating => "Authenticating...",
+        Key::SignIn => "Sign In",
+        Key::AddAccount => "Add Account",
+        Key::UpdateToken => "Update Token",
+        Key::GenerateNewToken => "Generate New Token",
+        Key::RequiredScopes => "Required scopes: notifications, repo",
+        Key::ProxySettings => "Proxy Settings",
+        Key::Cancel => "Cancel",
+        Key::EnterToken => "Please enter your token",
+
+        Key::Back => "Back",
+        Key::Settings => "Settings",
+        Key::PowerMode => "Power Mode",
+        Key::General => "General",
+        Key::Accounts => "Accounts",
+        Key::NetworkProxy => "Network Proxy",
+        Key::Language => "Language",
+        Key::LanguageDescription => "Partly translated; the rest stays in English",
+
+        Key::Notifications => "Notifications",
+        Key::Syncing => "Syncing...",
+        Key::NotSynced => "Not synced",
+        Key::SyncedJustNow => "Synced just now",
+        Key::SyncedAgo => "Synced {} ago",
+        Key::SyncedOn => "Synced {}",
+        Key::PausedUntil => "Paused — resuming at {}",
+        Key::Resume => "Resume",
+        Key::Unread => "Unread",
+        Key::All => "All",
+        Key::HideRead => "Hide read",
+        Key::Mentions => "@ Mentions",
+        Key::Participating => "Participating",
+        Key::MarkAllRead => "Mark all read",
+        Key::MarkAllDone => "Mark all done",
+        Key::SearchNotifications => "Search notifications",
+        Key::ApiQuota => "API: {}",
+    }
+}
+
+fn german(key: Key) -> Option<&'static str> {
+    match key {
+        Key::LoginTagline => Some("Leichter als die Statusleiste deiner IDE."),
+        Key::LoginAddAccountTagline => {
+            Some("Weiteres GitHub-Konto hinzufügen. Du bleibst bei den anderen angemeldet.")
+        }
+        Key::LoginReauthTagline => {
+            Some("Neues Token für {} eingeben. Andere Konten und Regeln bleiben erhalten.")
+        }
+        Key::TokenLabel => Some("GitHub Personal Access Token"),
+        Key::Authenticating => Some("Anmeldung läuft..."),
+        Key::SignIn => Some("Anmelden"),
+        Key::AddAccount => Some("Konto hinzufügen"),
+        Key::UpdateToken => Some("Token aktualisieren"),
+        Key::GenerateNewToken => Some("Neues Token erstellen"),
+        Key::RequiredScopes => Some("Benötigte Scopes: notifications, repo"),
+        Key::ProxySettings => Some("Proxy-Einstellungen"),
+        Key::Cancel => Some("Abbrechen"),
+        Key::EnterToken => Some("Bitte gib dein Token ein"),
+
+        Key::Back => Some("Zurück"),
+        Key::Settings => Some("Einstellungen"),
+        Key::General => Some("Allgemein"),
+        Key::Accounts => Some("Konten"),
+        Key::NetworkProxy => Some("Netzwerk-Proxy"),
+        Key::Language => Some("Sprache"),
+        Key::LanguageDescription => Some("Teilweise übersetzt; der Rest bleibt Englisch"),
+
+        Key::Notifications => Some("Benachrichtigungen"),
+        Key::Syncing => Some("Synchronisiere..."),
+        Key::NotSynced => Some("Nicht synchronisiert"),
+        Key::SyncedJustNow => Some("Gerade synchronisiert"),
+        Key::SyncedAgo => Some("Vor {} synchronisiert"),
+        Key::SyncedOn => Some("Synchronisiert {}"),
+        Key::PausedUntil => Some("Pausiert — weiter um {}"),
+        Key::Resume => Some("Fortsetzen"),
+        Key::Unread => Some("Ungelesen"),
+        Key::All => Some("Alle"),
+        Key::HideRead => Some("Gelesene ausblenden"),
+        Key::Mentions => Some("@ Erwähnungen"),
+        Key::Participating => Some("Beteiligt"),
+        Key::MarkAllRead => Some("Alle als gelesen markieren"),
+        Key::MarkAllDone => Some("Alle als erledigt markieren"),
+        Key::SearchNotifications => Some("Benachrichtigungen durchsuchen"),
+        // Product terms, kept in English
+        Key::PowerMode | Key::ApiQuota => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_untranslated_keys_fall_back_to_english() {
+        assert_eq!(lookup(Language::German, Key::SignIn), "Anmelden");
+        assert_eq!(german(Key::ApiQuota), None);
+        assert_eq!(
+            lookup(Language::German, Key::ApiQuota),
+            english(Key::ApiQuota)
+        );
+        assert_eq!(lookup(Language::English, Key::SignIn), "Sign In");
+    }
+
+    #[test]
+    fn test_language_round_trips() {
+        for language in Language::ALL {
+            set_language(language);
+            assert_eq!(self::language(), language);
+        }
+        set_language(Language::default());
+    }
+}
@@ -0,0 +1,293 @@
+//! Optional local HTTP endpoint for scripting GitTop.
+//!
+//! Listens on 127.0.0.1 only, and only when enabled in settings. Requests
+//! become `TrayCommand`s that the tray poll picks up, so a script, a Stream
+//! Deck button or `curl -X POST localhost:<port>/refresh` goes through the
+//! same path as the tray menu. Requests must be addressed to
+//! `localhost:<port>` or `127.0.0.1:<port>`; other `Host`s are refused.
+//!
+//! - `POST /refresh` - fetch notifications now
+//! - `POST /show` - bring the window up
+//! - `GET /unread` - `{"unread": N}` as of the last refresh
+
+use std::collections::VecDeque;
+use std::io::{Read, Write};
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use crate::tray::TrayCommand;
+
+pub const DEFAULT_PORT: u16 = 47_823;
+
+/// Largest request head we bother reading; bodies are ignored.
+const MAX_REQUEST_BYTES: usize = 8 * 1024;
+const ACCEPT_POLL_INTERVAL: Duration = Duration::from_millis(100);
+const CLIENT_TIMEOUT: Duration = Duration::from_secs(2);
+
+struct Server {
+    port: u16,
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        // Wait for the listener to close so its port can be bound again
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+static SERVER: Mutex<Option<Server>> = Mutex::new(None);
+static PENDING: Mutex<VecDeque<TrayCommand>> = Mutex::new(VecDeque::new());
+static UNREAD: AtomicUsize = AtomicUsize::new(0);
+
+/// Serve on `port`, replacing any running server. `None` stops it.
+pub fn apply(port: Option<u16>) -> Result<(), String> {
+    let mut server = SERVER.lock().unwrap_or_else(|e| e.into_inner());
+    if server.as_ref().map(|s| s.port) == port {
+        return Ok(());
+    }
+    server.take();
+
+    let Some(port) = port else {
+        tracing::info!("Local API stopped");
+        return Ok(());
+    };
+
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port))
+        .map_err(|e| format!("Can't listen on port {port}: {e}"))?;
+    listener.set_nonblocking(true).map_err(|e| e.to_string())?;
+
+    let stop = Arc::new(AtomicBool::new(false));
+    let flag = Arc::clone(&stop);
+    let thread = thread::Builder::new()
+        .name("local-api".into())
+        .spawn(move || serve(listener, port, &flag))
+        .map_err(|e| e.to_string())?;
+
+    tracing::info!(port, "Local API listening");
+    *server = Some(Server {
+        port,
+        stop,
+        thread: Some(thread),
+    });
+    Ok(())
+}
+
+/// Next command received since the last poll.
+pub fn poll() -> Option<TrayCommand> {
+    PENDING.lock().ok()?.pop_front()
+}
+
+/// Record the unread count reported by `GET /unread`.
+pub fn set_unread(count: usize) {
+    UNREAD.store(count, Ordering::Relaxed);
+}
+
+fn serve(listener: TcpListener, port: u16, stop: &AtomicBool) {
+    while !stop.load(Ordering::Relaxed) {
+        match listener.accept() {
+            Ok((stream, _)) => {
+                if let Err(e) = handle(stream, port) {
+                    tracing::debug!(error = %e, "Local API request failed");
+                }
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                thread::sleep(ACCEPT_POLL_INTERVAL);
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "Local API stopped accepting connections");
+                return;
+            }
+        }
+    }
+}
+
+fn handle(mut stream: TcpStream, port: u16) -> std::io::Result<()> {
+    stream.set_nonblocking(false)?;
+    stream.set_read_timeout(Some(CLIENT_TIMEOUT))?;
+    stream.set_write_timeout(Some(CLIENT_TIMEOUT))?;
+
+    let head = read_head(&mut stream)?;
+    let response = respond(&head, port);
+    if let Some(cmd) = response.command
+        && let Ok(mut pending) = PENDING.lock()
+    {
+        pending.push_back(cmd);
+    }
+
+    let reply = format!(
+        "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        response.body.len(),
+        response.body
+    );
+    stream.write_all(reply.as_bytes())
+}
+
+fn read_head(stream: &mut TcpStream) -> std::io::Result<String> {
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 1024];
+    while buf.len() < MAX_REQUEST_BYTES && !buf.windows(4).any(|w| w == b"\r\n\r\n") {
+        let n = stream.read(&mut chunk)?;
+        if n == 0 {
+            break;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+    }
+    Ok(String::from_utf8_lossy(&buf).into_owned())
+}
+
+#[derive(Debug)]
+struct Response {
+    status: &'static str,
+    body: String,
+    command: Option<TrayCommand>,
+}
+
+impl Response {
+    fn ok(command: TrayCommand) -> Self {
+        Self {
+            status: "202 Accepted",
+            body: r#"{"ok":true}"#.to_string(),
+            command: Some(command),
+        }
+    }
+
+    fn error(status: &'static str, message: &str) -> Self {
+        Self {
+            status,
+            body: format!(r#"{{"error":"{message}"}}"#),
+            command: None,
+        }
+    }
+}
+
+/// Route a request by its head (request line plus headers), as received on `port`.
+fn respond(head: &str, port: u16) -> Response {
+    let mut lines = head.lines();
+    let mut parts = lines.next().unwrap_or_default().split_whitespace();
+    let (Some(method), Some(path)) = (parts.next(), parts.next()) else {
+        return Response::error("400 Bad Request", "malformed request");
+    };
+
+    // Browsers send Origin on cross-site requests; scripts don't. Refusing
+    // them keeps web pages from poking the API through the user's browser.
+    let mut host = None;
+    for line in lines {
+        let Some((name, value)) = line.split_once(':') else {
+            continue;
+        };
+        if name.eq_ignore_ascii_case("origin") {
+            return Response::error("403 Forbidden", "cross-origin requests are not allowed");
+        }
+        if name.eq_ignore_ascii_case("host") {
+            host = Some(value.trim().to_ascii_lowercase());
+        }
+    }
+
+    // A DNS-rebound page reaches us under its own host name; only accept the
+    // names a local script would use.
+    let allowed = [format!("127.0.0.1:{port}"), format!("localhost:{port}")];
+    if !host.is_some_and(|host| allowed.contains(&host)) {
+        return Response::error("403 Forbidden", "unexpected host");
+    }
+
+    let path = path.split('?').next().unwrap_or(path);
+    match (method, path) {
+        ("POST", "/refresh") => Response::ok(TrayCommand::Refresh),
+        ("POST", "/show") => Response::ok(TrayCommand::ShowWindow),
+        ("GET", "/unread") => Response {
+            status: "200 OK",
+            body: format!(r#"{{"unread":{}}}"#, UNREAD.load(Ordering::Relaxed)),
+            command: None,
+        },
+        (_, "/refresh" | "/show" | "/unread") => {
+            Response::error("405 Method Not Allowed", "method not allowed")
+        }
+        _ => Response::error("404 Not Found", "not found"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PORT: u16 = DEFAULT_PORT;
+
+    fn request(line: &str) -> Response {
+        respond(
+            &format!("{line} HTTP/1.1\r\nHost: localhost:{PORT}\r\n\r\n"),
+            PORT,
+        )
+    }
+
+    #[test]
+    fn test_respond_routes_commands() {
+        let refresh = request("POST /refresh");
+        assert!(matches!(refresh.command, Some(TrayCommand::Refresh)));
+        let show = request("POST /show?from=deck");
+        assert!(matches!(show.command, Some(TrayCommand::ShowWindow)));
+        assert_eq!(show.status, "202 Accepted");
+    }
+
+    #[test]
+    fn test_respond_reports_unread() {
+        let unread = request("GET /unread");
+        assert_eq!(unread.status, "200 OK");
+        assert!(unread.body.starts_with(r#"{"unread":"#));
+        assert!(unread.command.is_none());
+    }
+
+    #[test]
+    fn test_respond_rejects_bad_requests() {
+        assert_eq!(request("GET /refresh").status, "405 Method Not Allowed");
+        assert_eq!(request("POST /quit").status, "404 Not Found");
+        assert_eq!(respond("", PORT).status, "400 Bad Request");
+        let cross_site = respond(
+            &format!(
+                "POST /show HTTP/1.1\r\nHost: localhost:{PORT}\r\nOrigin: https://example.com\r\n\r\n"
+            ),
+            PORT,
+        );
+        assert_eq!(cross_site.status, "403 Forbidden");
+        assert!(cross_site.command.is_none());
+    }
+
+    #[test]
+    fn test_respond_checks_host() {
+        let with_host = |host: &str| {
+            respond(
+                &format!("POST /refresh HTTP/1.1\r\nHost: {host}\r\n\r\n"),
+                PORT,
+            )
+        };
+        assert_eq!(
+            with_host(&format!("127.0.0.1:{PORT}")).status,
+            "202 Accepted"
+        );
+        assert_eq!(
+            with_host(&format!("LocalHost:{PORT}")).status,
+            "202 Accepted"
+        );
+
+        for host in [
+            "localhost".to_string(),
+            format!("localhost:{}", PORT + 1),
+            format!("evil.example:{PORT}"),
+        ] {
+            let response = with_host(&host);
+            assert_eq!(response.status, "403 Forbidden", "{host}");
+            assert!(response.command.is_none());
+        }
+        let no_host = respond("POST /refresh HTTP/1.1\r\n\r\n", PORT);
+        assert_eq!(no_host.status, "403 Forbidden");
+    }
+}
@@ -3,10 +3,17 @@
 //! GitTop - A beautiful native GitHub notification manager
 //! No browser engine required. Pure Rust. Pure performance.
 
+mod activity_log;
+mod backup;
+mod browser;
 mod cache;
 mod diagnostics;
 mod github;
+mod hotkey;
+mod i18n;
+mod local_api;
 mod platform;
+mod self_update;
 mod settings;
 mod specs;
 mod tray;
@@ -248,6 +255,7 @@ fn log_startup_diagnostics() {
         account_rules = rules.account_rules.len(),
         org_rules = rules.org_rules.len(),
         type_rules = rules.type_rules.len(),
+        actor_rules = rules.actor_rules.len(),
         active_rules = rules.active_rule_count(),
         high_impact_rules = rules.get_high_impact_rules().len(),
         "Rules snapshot"
@@ -285,7 +293,20 @@ fn main() -> iced::Result {
         }
     };
 
+    let startup_settings = settings::AppSettings::load();
+    if let Err(e) = hotkey::apply(startup_settings.global_hotkey.as_deref()) {
+        tracing::warn!(error = %e, "Global shortcut unavailable");
+    }
+    if let Err(e) = browser::apply(startup_settings.browser_command.as_deref()) {
+        tracing::warn!(error = %e, "Ignoring invalid browser command");
+    }
+    activity_log::set_enabled(startup_settings.activity_log);
+    if let Err(e) = local_api::apply(startup_settings.local_api_port()) {
+        tracing::warn!(error = %e, "Local API unavailable");
+    }
+
     let result = platform::run_app();
+    hotkey::unregister();
     if let Err(e) = result.as_ref() {
         diagnostics::write_fatal_error(e);
     }
@@ -15,16 +15,26 @@ pub fn enable_dark_mode() {
 
 /// System tray implementation using ksni (pure-Rust StatusNotifierItem).
 pub mod tray {
-    use crate::tray::TrayCommand;
-    use ksni::{self, Icon, Tray, menu::StandardItem};
+    use crate::tray::{TrayAccount, TrayCommand};
+    use ksni::{
+        self, Icon, Tray,
+        menu::{CheckmarkItem, StandardItem, SubMenu},
+    };
     use std::sync::mpsc::{self, Receiver, Sender};
     use std::sync::{Mutex, OnceLock};
 
     /// Global receiver for tray commands (set during TrayManager::new).
     static COMMAND_RECEIVER: OnceLock<Mutex<Receiver<TrayCommand>>> = OnceLock::new();
 
+    /// Handle to the running tray service, used to push account changes.
+    static TRAY_HANDLE: OnceLock<ksni::blocking::Handle<GitTopTray>> = OnceLock::new();
+
     struct GitTopTray {
         tx: Sender<TrayCommand>,
+        accounts: Vec<TrayAccount>,
+        active: Option<String>,
+        /// Unread count for the badge; `None` until the first refresh.
+        unread: Option<usize>,
     }
 
     impl Tray for GitTopTray {
@@ -41,16 +51,18 @@ pub mod tray {
         }
 
         fn icon_name(&self) -> String {
-            "gittop".into()
+            // The themed icon wins over the pixmap, so drop it once we draw our own
+            if self.unread.is_some() {
+                String::new()
+            } else {
+                "gittop".into()
+            }
         }
 
         fn icon_pixmap(&self) -> Vec<Icon> {
-            const ICON_BYTES: &[u8] = include_bytes!("../../assets/images/GitTop-256x256.png");
-
-            if let Ok(icon) = Self::load_png_icon(ICON_BYTES) {
-                vec![icon]
-            } else {
-                vec![]
+            match Self::load_icon(self.unread) {
+                Ok(icon) => vec![icon],
+                Err(_) => vec![],
             }
         }
 
@@ -62,7 +74,7 @@ pub mod tray {
         }
 
         fn menu(&self) -> Vec<ksni::MenuItem<Self>> {
-            vec![
+            let mut items: Vec<ksni::MenuItem<Self>> = vec![
                 StandardItem {
                     label: "Show GitTop".into(),
                     activate: Box::new(|tray: &mut Self| {
@@ -71,6 +83,30 @@ pub mod tray {
                     ..Default::default()
                 }
                 .into(),
+                StandardItem {
+                    label: "Toggle Mini Mode".into(),
+                    activate: Box::new(|tray: &mut Self| {
+                        let _ = tray.tx.send(TrayCommand::ToggleMiniMode);
+                    }),
+                    ..Default::default()
+                }
+                .into(),
+            ];
+
+            if self.accounts.len() > 1 {
+                items.push(
+                    SubMenu {
+                        label: "Switch Account".into(),
+                        submenu: self.accounts.iter().map(|a| self.account_item(a)).collect(),
+                        ..Default::default()
+                    }
+                    .into(),
+                );
+            }
+
+            items.push(self.pause_menu());
+
+            items.extend([
                 ksni::MenuItem::Separator,
                 StandardItem {
                     label: "Quit".into(),
@@ -80,20 +116,61 @@ pub mod tray {
                     ..Default::default()
                 }
                 .into(),
-            ]
+            ]);
+            items
         }
     }
 
     impl GitTopTray {
-        fn load_png_icon(bytes: &[u8]) -> Result<Icon, Box<dyn std::error::Error>> {
-            use image::ImageReader;
-            use std::io::Cursor;
+        fn pause_menu(&self) -> ksni::MenuItem<Self> {
+            let mut submenu: Vec<ksni::MenuItem<Self>> = crate::tray::PAUSE_OPTIONS
+                .iter()
+                .map(|&(minutes, label)| {
+                    StandardItem {
+                        label: label.into(),
+                        activate: Box::new(move |tray: &mut Self| {
+                            let _ = tray.tx.send(TrayCommand::PauseFor(minutes));
+                        }),
+                        ..Default::default()
+                    }
+                    .into()
+                })
+                .collect();
+            submenu.extend([
+                ksni::MenuItem::Separator,
+                StandardItem {
+                    label: "Resume".into(),
+                    activate: Box::new(|tray: &mut Self| {
+                        let _ = tray.tx.send(TrayCommand::Resume);
+                    }),
+                    ..Default::default()
+                }
+                .into(),
+            ]);
+
+            SubMenu {
+                label: "Pause Notifications".into(),
+                submenu,
+                ..Default::default()
+            }
+            .into()
+        }
 
-            let img = ImageReader::new(Cursor::new(bytes))
-                .with_guessed_format()?
-                .decode()?
-                .resize(32, 32, image::imageops::FilterType::Lanczos3)
-                .into_rgba8();
+        fn account_item(&self, account: &TrayAccount) -> ksni::MenuItem<Self> {
+            let username = account.username.clone();
+            CheckmarkItem {
+                label: account.label.clone(),
+                checked: self.active.as_deref() == Some(account.username.as_str()),
+                activate: Box::new(move |tray: &mut Self| {
+                    let _ = tray.tx.send(TrayCommand::SwitchAccount(username.clone()));
+                }),
+                ..Default::default()
+            }
+            .into()
+        }
+
+        fn load_icon(unread: Option<usize>) -> Result<Icon, image::ImageError> {
+            let img = crate::tray::render_icon(unread)?;
 
             let (width, height) = img.dimensions();
             let raw = img.into_raw();
@@ -128,20 +205,60 @@ pub mod tray {
                 .set(Mutex::new(rx))
                 .map_err(|_| "TrayManager already initialized")?;
 
-            let tray = GitTopTray { tx };
+            let tray = GitTopTray {
+                tx,
+                accounts: Vec::new(),
+                active: None,
+                unread: None,
+            };
 
             // Use blocking spawn API - spawns tray service in background thread
             let handle = tray.spawn()?;
+            let _ = TRAY_HANDLE.set(handle.clone());
 
             Ok(Self { handle })
         }
 
+        /// Rebuild the account submenu (only shown for 2+ accounts).
+        pub fn set_accounts(accounts: &[TrayAccount], active: Option<&str>) {
+            if let Some(handle) = TRAY_HANDLE.get() {
+                handle.update(|tray| {
+                    tray.accounts = accounts.to_vec();
+                    tray.active = active.map(String::from);
+                });
+            }
+        }
+
+        /// Redraw the icon for `unread`: muted at zero, badged otherwise.
+        pub fn set_unread_count(unread: usize) {
+            if let Some(handle) = TRAY_HANDLE.get() {
+                handle.update(|tray| tray.unread = Some(unread));
+            }
+        }
+
         pub fn poll_global_events() -> Option<TrayCommand> {
             COMMAND_RECEIVER.get()?.lock().ok()?.try_recv().ok()
         }
     }
 }
 
+/// GNOME's "Reduce animation" switch, read through `gsettings`.
+pub fn prefers_reduced_motion() -> bool {
+    std::process::Command::new("gsettings")
+        .args(["get", "org.gnome.desktop.interface", "enable-animations"])
+        .output()
+        .is_ok_and(|out| out.status.success() && out.stdout.trim_ascii() == b"false")
+}
+
+/// Play the freedesktop "message" sound via libcanberra, if installed.
+pub fn play_alert_sound() {
+    std::thread::spawn(|| {
+        let _ = std::process::Command::new("canberra-gtk-play")
+            .arg("--id=message-new-instant")
+            .status();
+    });
+}
+
 /// Reduce memory footprint.
 pub fn trim_memory() {
     // FreeBSD uses jemalloc by default.
@@ -159,7 +276,12 @@ pub fn trim_memory() {
 ///
 /// If `url` is provided, adds an "Open" action that opens the URL.
 /// Works with any DBus-compatible notification daemon.
-pub fn notify(title: &str, body: &str, url: Option<&str>) -> Result<(), notify_rust::error::Error> {
+pub fn notify(
+    title: &str,
+    body: &str,
+    url: Option<&str>,
+    thread: Option<super::ToastThread>,
+) -> Result<(), notify_rust::error::Error> {
     use notify_rust::Notification;
 
     let mut notification = Notification::new();
@@ -169,52 +291,44 @@ pub fn notify(title: &str, body: &str, url: Option<&str>) -> Result<(), notify_r
         .appname("GitTop")
         .timeout(5000); // 5 seconds
 
-    // Add action if URL provided
-    if let Some(url) = url {
+    if url.is_none() && thread.is_none() {
+        return notification.show().map(|_| ());
+    }
+
+    // Add actions for whatever the toast can act on
+    if url.is_some() {
         notification.action("open", "Open");
+    }
+    if thread.is_some() {
+        notification.action("mark_read", "Mark read");
+    }
 
-        // Show and handle action
-        let handle = notification.show()?;
+    // Show and handle action
+    let handle = notification.show()?;
 
-        let url_owned = url.to_string();
-        std::thread::spawn(move || {
-            handle.wait_for_action(|action| {
-                if action == "open" || action == "default" {
-                    let _ = open::that(&url_owned);
+    let url_owned = url.map(str::to_string);
+    std::thread::spawn(move || {
+        handle.wait_for_action(|action| match action {
+            "open" | "default" => {
+                if let Some(url) = &url_owned {
+                    let _ = crate::browser::open(url);
                 }
-            });
+            }
+            "mark_read" => {
+                if let Some(thread) = thread {
+                    thread.mark_read();
+                }
+            }
+            _ => {}
         });
-        Ok(())
-    } else {
-        notification.show().map(|_| ())
-    }
+    });
+    Ok(())
 }
 
 /// On-boot/autostart functionality for FreeBSD.
 ///
-/// TODO: Investigate rc.d or user-level autostart mechanism.
+/// Most FreeBSD desktops run an XDG-compliant session manager, so this is an
+/// entry in `~/.config/autostart` rather than an rc.d script.
 pub mod on_boot {
-    // Re-export the shared error type from the parent module
-    pub use crate::platform::on_boot::OnBootError;
-
-    /// Check if autostart is currently enabled.
-    ///
-    /// TODO: Investigate FreeBSD autostart mechanism
-    pub fn is_enabled() -> bool {
-        false
-    }
-
-    /// Enable autostart.
-    ///
-    /// TODO: Implement FreeBSD autostart
-    pub fn enable() -> Result<(), OnBootError> {
-        Err(OnBootError::NotSupported)
-    }
-
-    /// Disable autostart.
-    ///
-    /// TODO: Implement FreeBSD autostart
-    pub fn disable() -> Result<(), OnBootError> {
-        Err(OnBootError::NotSupported)
-    }
+    pub use crate::platform::xdg_autostart::{disable, enable, is_enabled};
 }
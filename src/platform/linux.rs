@@ -21,29 +21,20 @@ pub fn run_app() -> iced::Result {
 pub fn build_initial_window_settings() -> (window::Id, iced::Task<crate::ui::app::Message>) {
     let settings = AppSettings::load();
 
-    let size = iced::Size::new(
-        if settings.window_width >= 100.0 {
-            settings.window_width
-        } else {
-            800.0
-        },
-        if settings.window_height >= 100.0 {
-            settings.window_height
-        } else {
-            640.0
-        },
-    );
-
-    let position = match (settings.window_x, settings.window_y) {
-        (Some(x), Some(y)) if x > -10000 && y > -10000 => {
-            window::Position::Specific(iced::Point::new(x as f32, y as f32))
-        }
-        _ => window::Position::Centered,
-    };
+    let size = crate::ui::state::window_size(&settings);
+
+    let position = settings
+        .window_x
+        .zip(settings.window_y)
+        .and_then(|(x, y)| crate::settings::validated_window_position(x as f32, y as f32))
+        .map_or(window::Position::Centered, |(x, y)| {
+            window::Position::Specific(iced::Point::new(x, y))
+        });
 
     let window_settings = window::Settings {
         size,
         position,
+        level: crate::ui::state::window_level(&settings),
         platform_specific: window::settings::PlatformSpecific {
             application_id: "gittop".to_string(),
             ..Default::default()
@@ -55,6 +46,14 @@ pub fn build_initial_window_settings() -> (window::Id, iced::Task<crate::ui::app
     (id, task.discard())
 }
 
+/// Whether the window can be unmapped and shown again. X11 can; Wayland
+/// ignores hide requests, so there the window is closed and reopened.
+pub fn can_hide_window() -> bool {
+    let wayland = std::env::var("XDG_SESSION_TYPE").is_ok_and(|t| t == "wayland")
+        || std::env::var_os("WAYLAND_DISPLAY").is_some();
+    !wayland
+}
+
 /// Focus an existing GitTop window from another process (single-instance detection).
 /// Called when a second GitTop instance tries to launch.
 ///
@@ -69,16 +68,26 @@ pub fn enable_dark_mode() {}
 
 /// System tray implementation using ksni (pure-Rust StatusNotifierItem).
 pub mod tray {
-    use crate::tray::TrayCommand;
-    use ksni::{self, Icon, Tray, menu::StandardItem};
+    use crate::tray::{TrayAccount, TrayCommand};
+    use ksni::{
+        self, Icon, Tray,
+        menu::{CheckmarkItem, StandardItem, SubMenu},
+    };
     use std::sync::mpsc::{self, Receiver, Sender};
     use std::sync::{Mutex, OnceLock};
 
     /// Global receiver for tray commands (set during TrayManager::new).
     static COMMAND_RECEIVER: OnceLock<Mutex<Receiver<TrayCommand>>> = OnceLock::new();
 
+    /// Handle to the running tray service, used to push account changes.
+    static TRAY_HANDLE: OnceLock<ksni::blocking::Handle<GitTopTray>> = OnceLock::new();
+
     struct GitTopTray {
         tx: Sender<TrayCommand>,
+        accounts: Vec<TrayAccount>,
+        active: Option<String>,
+        /// Unread count for the badge; `None` until the first refresh.
+        unread: Option<usize>,
     }
 
     impl Tray for GitTopTray {
@@ -95,17 +104,18 @@ pub mod tray {
         }
 
         fn icon_name(&self) -> String {
-            "gittop".into()
+            // The themed icon wins over the pixmap, so drop it once we draw our own
+            if self.unread.is_some() {
+                String::new()
+            } else {
+                "gittop".into()
+            }
         }
 
         fn icon_pixmap(&self) -> Vec<Icon> {
-            // Fallback: embed the icon directly
-            const ICON_BYTES: &[u8] = include_bytes!("../../assets/images/GitTop-256x256.png");
-
-            if let Ok(icon) = Self::load_png_icon(ICON_BYTES) {
-                vec![icon]
-            } else {
-                vec![]
+            match Self::load_icon(self.unread) {
+                Ok(icon) => vec![icon],
+                Err(_) => vec![],
             }
         }
 
@@ -117,7 +127,7 @@ pub mod tray {
         }
 
         fn menu(&self) -> Vec<ksni::MenuItem<Self>> {
-            vec![
+            let mut items: Vec<ksni::MenuItem<Self>> = vec![
                 StandardItem {
                     label: "Show GitTop".into(),
                     activate: Box::new(|tray: &mut Self| {
@@ -126,6 +136,30 @@ pub mod tray {
                     ..Default::default()
                 }
                 .into(),
+                StandardItem {
+                    label: "Toggle Mini Mode".into(),
+                    activate: Box::new(|tray: &mut Self| {
+                        let _ = tray.tx.send(TrayCommand::ToggleMiniMode);
+                    }),
+                    ..Default::default()
+                }
+                .into(),
+            ];
+
+            if self.accounts.len() > 1 {
+                items.push(
+                    SubMenu {
+                        label: "Switch Account".into(),
+                        submenu: self.accounts.iter().map(|a| self.account_item(a)).collect(),
+                        ..Default::default()
+                    }
+                    .into(),
+                );
+            }
+
+            items.push(self.pause_menu());
+
+            items.extend([
                 ksni::MenuItem::Separator,
                 StandardItem {
                     label: "Quit".into(),
@@ -135,20 +169,61 @@ pub mod tray {
                     ..Default::default()
                 }
                 .into(),
-            ]
+            ]);
+            items
         }
     }
 
     impl GitTopTray {
-        fn load_png_icon(bytes: &[u8]) -> Result<Icon, Box<dyn std::error::Error>> {
-            use image::ImageReader;
-            use std::io::Cursor;
+        fn pause_menu(&self) -> ksni::MenuItem<Self> {
+            let mut submenu: Vec<ksni::MenuItem<Self>> = crate::tray::PAUSE_OPTIONS
+                .iter()
+                .map(|&(minutes, label)| {
+                    StandardItem {
+                        label: label.into(),
+                        activate: Box::new(move |tray: &mut Self| {
+                            let _ = tray.tx.send(TrayCommand::PauseFor(minutes));
+                        }),
+                        ..Default::default()
+                    }
+                    .into()
+                })
+                .collect();
+            submenu.extend([
+                ksni::MenuItem::Separator,
+                StandardItem {
+                    label: "Resume".into(),
+                    activate: Box::new(|tray: &mut Self| {
+                        let _ = tray.tx.send(TrayCommand::Resume);
+                    }),
+                    ..Default::default()
+                }
+                .into(),
+            ]);
 
-            let img = ImageReader::new(Cursor::new(bytes))
-                .with_guessed_format()?
-                .decode()?
-                .resize(32, 32, image::imageops::FilterType::Lanczos3)
-                .into_rgba8();
+            SubMenu {
+                label: "Pause Notifications".into(),
+                submenu,
+                ..Default::default()
+            }
+            .into()
+        }
+
+        fn account_item(&self, account: &TrayAccount) -> ksni::MenuItem<Self> {
+            let username = account.username.clone();
+            CheckmarkItem {
+                label: account.label.clone(),
+                checked: self.active.as_deref() == Some(account.username.as_str()),
+                activate: Box::new(move |tray: &mut Self| {
+                    let _ = tray.tx.send(TrayCommand::SwitchAccount(username.clone()));
+                }),
+                ..Default::default()
+            }
+            .into()
+        }
+
+        fn load_icon(unread: Option<usize>) -> Result<Icon, image::ImageError> {
+            let img = crate::tray::render_icon(unread)?;
 
             let (width, height) = img.dimensions();
             let raw = img.into_raw();
@@ -183,7 +258,12 @@ pub mod tray {
                 .set(Mutex::new(rx))
                 .map_err(|_| "TrayManager already initialized")?;
 
-            let tray = GitTopTray { tx };
+            let tray = GitTopTray {
+                tx,
+                accounts: Vec::new(),
+                active: None,
+                unread: None,
+            };
 
             // Check if running in Flatpak (file exists)
             let is_flatpak = std::path::Path::new("/.flatpak-info").exists();
@@ -191,10 +271,28 @@ pub mod tray {
             // Use blocking spawn API
             // For Flatpak, we must disable D-Bus name ownership as we can't own arbitrary names.
             let handle = tray.disable_dbus_name(is_flatpak).spawn()?;
+            let _ = TRAY_HANDLE.set(handle.clone());
 
             Ok(Self { handle })
         }
 
+        /// Rebuild the account submenu (only shown for 2+ accounts).
+        pub fn set_accounts(accounts: &[TrayAccount], active: Option<&str>) {
+            if let Some(handle) = TRAY_HANDLE.get() {
+                handle.update(|tray| {
+                    tray.accounts = accounts.to_vec();
+                    tray.active = active.map(String::from);
+                });
+            }
+        }
+
+        /// Redraw the icon for `unread`: muted at zero, badged otherwise.
+        pub fn set_unread_count(unread: usize) {
+            if let Some(handle) = TRAY_HANDLE.get() {
+                handle.update(|tray| tray.unread = Some(unread));
+            }
+        }
+
         pub fn poll_global_events() -> Option<TrayCommand> {
             COMMAND_RECEIVER.get()?.lock().ok()?.try_recv().ok()
         }
@@ -212,8 +310,31 @@ pub fn trim_memory() {
     }
 }
 
+/// GNOME's "Reduce animation" switch, read through `gsettings`.
+pub fn prefers_reduced_motion() -> bool {
+    std::process::Command::new("gsettings")
+        .args(["get", "org.gnome.desktop.interface", "enable-animations"])
+        .output()
+        .is_ok_and(|out| out.status.success() && out.stdout.trim_ascii() == b"false")
+}
+
+/// Play the freedesktop "message" sound via libcanberra, if installed.
+pub fn play_alert_sound() {
+    // Thread reaps the child so we don't leave a zombie behind.
+    std::thread::spawn(|| {
+        let _ = std::process::Command::new("canberra-gtk-play")
+            .arg("--id=message-new-instant")
+            .status();
+    });
+}
+
 /// Send a native Linux notification via DBus.
-pub fn notify(title: &str, body: &str, url: Option<&str>) -> Result<(), notify_rust::error::Error> {
+pub fn notify(
+    title: &str,
+    body: &str,
+    url: Option<&str>,
+    thread: Option<super::ToastThread>,
+) -> Result<(), notify_rust::error::Error> {
     use notify_rust::Notification;
 
     let mut notification = Notification::new();
@@ -224,25 +345,38 @@ pub fn notify(title: &str, body: &str, url: Option<&str>) -> Result<(), notify_r
         .icon("gittop")
         .timeout(5000);
 
-    if let Some(url) = url {
+    if url.is_none() && thread.is_none() {
+        return notification.show().map(|_| ());
+    }
+
+    if url.is_some() {
         notification.action("open", "Open");
-        notification.hint(notify_rust::Hint::ActionIcons(true));
+    }
+    if thread.is_some() {
+        notification.action("mark_read", "Mark read");
+    }
+    notification.hint(notify_rust::Hint::ActionIcons(true));
 
-        let handle = notification.show()?;
-        let url_owned = url.to_string();
+    let handle = notification.show()?;
+    let url_owned = url.map(str::to_string);
 
-        // Thread required because wait_for_action blocks.
-        std::thread::spawn(move || {
-            handle.wait_for_action(|action| {
-                if action == "open" || action == "default" {
-                    let _ = open::that(&url_owned);
+    // Thread required because wait_for_action blocks.
+    std::thread::spawn(move || {
+        handle.wait_for_action(|action| match action {
+            "open" | "default" => {
+                if let Some(url) = &url_owned {
+                    let _ = crate::browser::open(url);
                 }
-            });
+            }
+            "mark_read" => {
+                if let Some(thread) = thread {
+                    thread.mark_read();
+                }
+            }
+            _ => {}
         });
-        Ok(())
-    } else {
-        notification.show().map(|_| ())
-    }
+    });
+    Ok(())
 }
 
 pub mod on_boot {
@@ -253,6 +387,8 @@ pub mod on_boot {
     // Re-export the shared error type from the parent module
     pub use crate::platform::on_boot::OnBootError;
 
+    use crate::platform::xdg_autostart;
+
     /// The systemd user service unit file content.
     ///
     /// PassEnvironment inherits display variables from the user session,
@@ -289,9 +425,28 @@ WantedBy=default.target
             .unwrap_or(false)
     }
 
+    /// How autostart is set up on this system.
+    enum Backend {
+        Systemd,
+        /// An XDG autostart entry, for systems without systemd (OpenRC on
+        /// Artix, Gentoo, Devuan, ...). Most desktop session managers honor it.
+        Xdg,
+    }
+
+    /// Picks the same backend for all three operations. An existing XDG
+    /// entry wins, so autostart enabled without systemd can still be found
+    /// and removed if `systemctl --user` works later on.
+    fn backend() -> Backend {
+        if xdg_autostart::is_enabled() || !has_systemd() {
+            Backend::Xdg
+        } else {
+            Backend::Systemd
+        }
+    }
+
     pub fn is_enabled() -> bool {
-        if !has_systemd() {
-            return false;
+        if let Backend::Xdg = backend() {
+            return xdg_autostart::is_enabled();
         }
 
         Command::new("systemctl")
@@ -302,8 +457,8 @@ WantedBy=default.target
     }
 
     pub fn enable() -> Result<(), OnBootError> {
-        if !has_systemd() {
-            return Err(OnBootError::NotSupported);
+        if let Backend::Xdg = backend() {
+            return xdg_autostart::enable();
         }
 
         let exec_path = std::env::current_exe()
@@ -343,8 +498,8 @@ WantedBy=default.target
     }
 
     pub fn disable() -> Result<(), OnBootError> {
-        if !has_systemd() {
-            return Err(OnBootError::NotSupported);
+        if let Backend::Xdg = backend() {
+            return xdg_autostart::disable();
         }
 
         let disable = Command::new("systemctl")
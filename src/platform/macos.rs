@@ -18,19 +18,25 @@ pub fn enable_dark_mode() {
 
 /// System tray implementation using tray-icon (native macOS APIs).
 pub mod tray {
-    use crate::tray::TrayCommand;
-    use std::sync::OnceLock;
+    use crate::tray::{TrayAccount, TrayCommand};
+    use std::cell::RefCell;
     use tray_icon::{
         Icon, TrayIcon, TrayIconBuilder, TrayIconEvent,
-        menu::{Menu, MenuEvent, MenuId, MenuItem},
+        menu::{CheckMenuItem, Menu, MenuEvent, MenuItem, PredefinedMenuItem, Submenu},
     };
 
-    static MENU_IDS: OnceLock<MenuIds> = OnceLock::new();
+    const SHOW_ID: &str = "show";
+    const QUIT_ID: &str = "quit";
+    const RESUME_ID: &str = "resume";
+    const MINI_MODE_ID: &str = "mini_mode";
+    /// Pause entries use `pause:<minutes>` as their menu id.
+    const PAUSE_ID_PREFIX: &str = "pause:";
+    /// Account entries use `account:<username>` as their menu id.
+    const ACCOUNT_ID_PREFIX: &str = "account:";
 
-    #[derive(Debug)]
-    struct MenuIds {
-        show: MenuId,
-        quit: MenuId,
+    thread_local! {
+        // tray-icon handles are not Send; menus are rebuilt on the UI thread.
+        static TRAY: RefCell<Option<TrayIcon>> = const { RefCell::new(None) };
     }
 
     pub struct TrayManager {
@@ -40,41 +46,102 @@ pub mod tray {
 
     impl TrayManager {
         pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
-            let show_item = MenuItem::new("Show GitTop", true, None);
-            let quit_item = MenuItem::new("Quit", true, None);
+            let menu = Self::build_menu(&[], None)?;
 
-            MENU_IDS
-                .set(MenuIds {
-                    show: show_item.id().clone(),
-                    quit: quit_item.id().clone(),
-                })
-                .expect("TrayManager initialized twice");
-
-            let menu = Menu::new();
-            menu.append(&show_item)?;
-            menu.append(&quit_item)?;
-
-            let icon = Self::create_icon()?;
+            let icon = Self::create_icon(None)?;
             let tray = TrayIconBuilder::new()
                 .with_menu(Box::new(menu))
                 .with_tooltip("GitTop - GitHub Notifications")
                 .with_icon(icon)
                 .build()?;
 
+            TRAY.with(|t| *t.borrow_mut() = Some(tray.clone()));
             Ok(Self { tray })
         }
 
-        fn create_icon() -> Result<Icon, Box<dyn std::error::Error>> {
-            use image::ImageReader;
-            use std::io::Cursor;
+        /// Rebuild the menu with an account switcher (only shown for 2+ accounts).
+        fn build_menu(
+            accounts: &[TrayAccount],
+            active: Option<&str>,
+        ) -> Result<Menu, Box<dyn std::error::Error>> {
+            let menu = Menu::new();
+            menu.append(&MenuItem::with_id(SHOW_ID, "Show GitTop", true, None))?;
+            menu.append(&MenuItem::with_id(
+                MINI_MODE_ID,
+                "Toggle Mini Mode",
+                true,
+                None,
+            ))?;
+
+            if accounts.len() > 1 {
+                let submenu = Submenu::new("Switch Account", true);
+                for account in accounts {
+                    submenu.append(&CheckMenuItem::with_id(
+                        format!("{ACCOUNT_ID_PREFIX}{}", account.username),
+                        &account.label,
+                        true,
+                        active == Some(account.username.as_str()),
+                        None,
+                    ))?;
+                }
+                menu.append(&submenu)?;
+            }
+
+            let pause = Submenu::new("Pause Notifications", true);
+            for (minutes, label) in crate::tray::PAUSE_OPTIONS {
+                pause.append(&MenuItem::with_id(
+                    format!("{PAUSE_ID_PREFIX}{minutes}"),
+                    label,
+                    true,
+                    None,
+                ))?;
+            }
+            pause.append(&PredefinedMenuItem::separator())?;
+            pause.append(&MenuItem::with_id(RESUME_ID, "Resume", true, None))?;
+            menu.append(&pause)?;
+
+            menu.append(&PredefinedMenuItem::separator())?;
+            menu.append(&MenuItem::with_id(QUIT_ID, "Quit", true, None))?;
+            Ok(menu)
+        }
+
+        /// Replace the account submenu. Must be called from the UI thread.
+        pub fn set_accounts(accounts: &[TrayAccount], active: Option<&str>) {
+            let menu = match Self::build_menu(accounts, active) {
+                Ok(menu) => menu,
+                Err(e) => {
+                    tracing::warn!(error = %e, "Failed to rebuild tray menu");
+                    return;
+                }
+            };
+            TRAY.with(|t| {
+                if let Some(tray) = t.borrow().as_ref() {
+                    tray.set_menu(Some(Box::new(menu)));
+                }
+            });
+        }
 
-            const ICON_BYTES: &[u8] = include_bytes!("../../assets/images/GitTop-256x256.png");
+        /// Redraw the icon for `unread`: muted at zero, badged otherwise.
+        /// Must be called from the UI thread.
+        pub fn set_unread_count(unread: usize) {
+            let icon = match Self::create_icon(Some(unread)) {
+                Ok(icon) => icon,
+                Err(e) => {
+                    tracing::warn!(error = %e, "Failed to load tray icon");
+                    return;
+                }
+            };
+            TRAY.with(|t| {
+                if let Some(tray) = t.borrow().as_ref()
+                    && let Err(e) = tray.set_icon(Some(icon))
+                {
+                    tracing::warn!(error = %e, "Failed to swap tray icon");
+                }
+            });
+        }
 
-            let img = ImageReader::new(Cursor::new(ICON_BYTES))
-                .with_guessed_format()?
-                .decode()?
-                .resize(32, 32, image::imageops::FilterType::Lanczos3)
-                .into_rgba8();
+        fn create_icon(unread: Option<usize>) -> Result<Icon, Box<dyn std::error::Error>> {
+            let img = crate::tray::render_icon(unread)?;
 
             let (width, height) = img.dimensions();
             Icon::from_rgba(img.into_raw(), width, height).map_err(Into::into)
@@ -88,14 +155,21 @@ pub mod tray {
 
         fn poll_menu_events() -> Option<TrayCommand> {
             let event = MenuEvent::receiver().try_recv().ok()?;
-            let ids = MENU_IDS.get()?;
-
-            [
-                (&ids.show, TrayCommand::ShowWindow),
-                (&ids.quit, TrayCommand::Quit),
-            ]
-            .into_iter()
-            .find_map(|(id, cmd)| (event.id == *id).then_some(cmd))
+
+            match event.id.as_ref() {
+                SHOW_ID => Some(TrayCommand::ShowWindow),
+                QUIT_ID => Some(TrayCommand::Quit),
+                RESUME_ID => Some(TrayCommand::Resume),
+                MINI_MODE_ID => Some(TrayCommand::ToggleMiniMode),
+                id => id
+                    .strip_prefix(PAUSE_ID_PREFIX)
+                    .and_then(|minutes| minutes.parse().ok())
+                    .map(TrayCommand::PauseFor)
+                    .or_else(|| {
+                        id.strip_prefix(ACCOUNT_ID_PREFIX)
+                            .map(|username| TrayCommand::SwitchAccount(username.to_string()))
+                    }),
+            }
         }
 
         fn drain_tray_icon_events() {
@@ -108,6 +182,23 @@ pub mod tray {
     }
 }
 
+/// Play a system sound through `afplay`.
+pub fn play_alert_sound() {
+    std::thread::spawn(|| {
+        let _ = std::process::Command::new("afplay")
+            .arg("/System/Library/Sounds/Tink.aiff")
+            .status();
+    });
+}
+
+/// The "Reduce motion" accessibility setting.
+pub fn prefers_reduced_motion() -> bool {
+    std::process::Command::new("defaults")
+        .args(["read", "com.apple.universalaccess", "reduceMotion"])
+        .output()
+        .is_ok_and(|out| out.status.success() && out.stdout.trim_ascii() == b"1")
+}
+
 /// Reduce memory footprint.
 /// TODO: Could potentially use madvise or similar.
 pub fn trim_memory() {
@@ -126,11 +217,13 @@ pub fn trim_memory() {
 /// - Zero memory impact after send
 ///
 /// Note: macOS doesn't support click-to-open-URL natively via this API.
-/// The URL is included in the notification body as a fallback.
+/// The URL is included in the notification body as a fallback, and there
+/// is no "Mark read" action.
 pub fn notify(
     title: &str,
     body: &str,
     url: Option<&str>,
+    _thread: Option<super::ToastThread>,
 ) -> Result<(), mac_notification_sys::error::Error> {
     use mac_notification_sys::*;
 
@@ -15,6 +15,9 @@ pub(crate) mod linux;
 #[cfg(target_os = "freebsd")]
 pub(crate) mod freebsd;
 
+#[cfg(any(target_os = "linux", target_os = "freebsd"))]
+mod xdg_autostart;
+
 // ============================================================================
 // Platform dispatch macros
 // ============================================================================
@@ -86,6 +89,35 @@ pub fn trim_memory() {
     freebsd::trim_memory();
 }
 
+/// Play a short system alert sound.
+/// Best effort: if no sound backend is available, nothing happens.
+pub fn play_alert_sound() {
+    platform_call!(play_alert_sound);
+}
+
+/// Whether the OS asks apps to keep animations to a minimum.
+/// Read once per run; best effort, `false` when it can't be read.
+pub fn prefers_reduced_motion() -> bool {
+    static PREFERS: std::sync::OnceLock<bool> = std::sync::OnceLock::new();
+    *PREFERS.get_or_init(|| platform_return!(prefers_reduced_motion))
+}
+
+/// Whether the window manager honors always-on-top requests. Wayland
+/// compositors decide stacking themselves and ignore them.
+pub fn supports_window_level() -> bool {
+    #[cfg(any(target_os = "linux", target_os = "freebsd"))]
+    {
+        let wayland = std::env::var("XDG_SESSION_TYPE").is_ok_and(|t| t == "wayland")
+            || std::env::var_os("WAYLAND_DISPLAY").is_some();
+        !wayland
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "freebsd")))]
+    {
+        true
+    }
+}
+
 // Re-export platform-specific tray module
 #[cfg(target_os = "linux")]
 pub use linux::tray;
@@ -108,6 +140,8 @@ pub use macos::tray;
 /// - Zero persistent memory cost
 ///
 /// If `url` is provided, clicking the notification will open that URL.
+/// If `thread` is provided, a "Mark read" action marks that thread read
+/// without opening anything.
 ///
 /// Platform implementations:
 /// - Windows: WinRT toast notifications
@@ -118,18 +152,36 @@ pub fn notify(
     title: &str,
     body: &str,
     url: Option<&str>,
+    thread: Option<ToastThread>,
 ) -> Result<(), Box<dyn std::error::Error>> {
     #[cfg(windows)]
-    return windows::notify(title, body, url).map_err(|e| e.into());
+    return windows::notify(title, body, url, thread).map_err(|e| e.into());
 
     #[cfg(target_os = "macos")]
-    return macos::notify(title, body, url).map_err(|e| e.into());
+    return macos::notify(title, body, url, thread).map_err(|e| e.into());
 
     #[cfg(target_os = "linux")]
-    return linux::notify(title, body, url).map_err(|e| e.into());
+    return linux::notify(title, body, url, thread).map_err(|e| e.into());
 
     #[cfg(target_os = "freebsd")]
-    return freebsd::notify(title, body, url).map_err(|e| e.into());
+    return freebsd::notify(title, body, url, thread).map_err(|e| e.into());
+}
+
+/// The notification thread a toast is about.
+#[derive(Debug, Clone)]
+pub struct ToastThread {
+    pub account: String,
+    pub id: String,
+}
+
+impl ToastThread {
+    /// Route a "Mark read" click back into the app.
+    pub fn mark_read(self) {
+        crate::tray::send_toast_command(crate::tray::TrayCommand::MarkRead {
+            account: self.account,
+            thread_id: self.id,
+        });
+    }
 }
 
 /// Run the iced application.
@@ -148,10 +200,10 @@ pub fn run_app() -> iced::Result {
 /// Allows the application to start automatically when the user logs in.
 ///
 /// Platform support:
-/// - Linux: systemd user services (implemented), OpenRC (TODO)
+/// - Linux: systemd user services, or an XDG autostart entry without systemd (OpenRC etc.)
 /// - Windows: Registry (TODO)
 /// - macOS: LaunchAgents (TODO)
-/// - FreeBSD: (TODO)
+/// - FreeBSD: XDG autostart entry
 pub mod on_boot {
     use std::fmt;
     use std::io;
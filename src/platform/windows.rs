@@ -11,24 +11,22 @@ use std::ffi::CString;
 pub fn run_app() -> iced::Result {
     let settings = AppSettings::load();
 
-    let window_size = if settings.window_width >= 100.0 && settings.window_height >= 100.0 {
-        iced::Size::new(settings.window_width, settings.window_height)
-    } else {
-        iced::Size::new(800.0, 640.0)
-    };
-
-    let window_position = match (settings.window_x, settings.window_y) {
-        (Some(x), Some(y)) if x > -10000 && y > -10000 => {
-            Position::Specific(iced::Point::new(x as f32, y as f32))
-        }
-        _ => Position::Centered,
-    };
+    let window_size = crate::ui::state::window_size(&settings);
+
+    let window_position = settings
+        .window_x
+        .zip(settings.window_y)
+        .and_then(|(x, y)| crate::settings::validated_window_position(x as f32, y as f32))
+        .map_or(Position::Centered, |(x, y)| {
+            Position::Specific(iced::Point::new(x, y))
+        });
 
     let window_icon = load_window_icon();
 
     let window_settings = iced::window::Settings {
         size: window_size,
         position: window_position,
+        level: crate::ui::state::window_level(&settings),
         icon: window_icon,
         ..Default::default()
     };
@@ -127,21 +125,57 @@ pub fn enable_dark_mode() {
     }
 }
 
+/// Play the default system notification sound.
+pub fn play_alert_sound() {
+    use windows::Win32::UI::WindowsAndMessaging::{MB_OK, MessageBeep};
+
+    // SAFETY: MessageBeep only queues a sound, no pointers involved.
+    unsafe {
+        let _ = MessageBeep(MB_OK);
+    }
+}
+
+/// "Show animations in Windows" being off (Settings > Accessibility).
+pub fn prefers_reduced_motion() -> bool {
+    use windows::Win32::UI::WindowsAndMessaging::{
+        SPI_GETCLIENTAREAANIMATION, SYSTEM_PARAMETERS_INFO_UPDATE_FLAGS, SystemParametersInfoW,
+    };
+    use windows::core::BOOL;
+
+    let mut enabled = BOOL(1);
+    // SAFETY: pvParam points to a BOOL, as SPI_GETCLIENTAREAANIMATION expects.
+    let ok = unsafe {
+        SystemParametersInfoW(
+            SPI_GETCLIENTAREAANIMATION,
+            0,
+            Some(std::ptr::from_mut(&mut enabled).cast()),
+            SYSTEM_PARAMETERS_INFO_UPDATE_FLAGS(0),
+        )
+    };
+    ok.is_ok() && !enabled.as_bool()
+}
+
 /// System tray implementation using tray-icon (native Windows APIs).
 pub mod tray {
-    use crate::tray::TrayCommand;
-    use std::sync::OnceLock;
+    use crate::tray::{TrayAccount, TrayCommand};
+    use std::cell::RefCell;
     use tray_icon::{
         Icon, TrayIcon, TrayIconBuilder, TrayIconEvent,
-        menu::{Menu, MenuEvent, MenuId, MenuItem},
+        menu::{CheckMenuItem, Menu, MenuEvent, MenuItem, PredefinedMenuItem, Submenu},
     };
 
-    static MENU_IDS: OnceLock<MenuIds> = OnceLock::new();
-
-    #[derive(Debug)]
-    struct MenuIds {
-        show: MenuId,
-        quit: MenuId,
+    const SHOW_ID: &str = "show";
+    const QUIT_ID: &str = "quit";
+    const RESUME_ID: &str = "resume";
+    const MINI_MODE_ID: &str = "mini_mode";
+    /// Pause entries use `pause:<minutes>` as their menu id.
+    const PAUSE_ID_PREFIX: &str = "pause:";
+    /// Account entries use `account:<username>` as their menu id.
+    const ACCOUNT_ID_PREFIX: &str = "account:";
+
+    thread_local! {
+        // tray-icon handles are not Send; menus are rebuilt on the UI thread.
+        static TRAY: RefCell<Option<TrayIcon>> = const { RefCell::new(None) };
     }
 
     pub struct TrayManager {
@@ -151,41 +185,102 @@ pub mod tray {
 
     impl TrayManager {
         pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
-            let show_item = MenuItem::new("Show GitTop", true, None);
-            let quit_item = MenuItem::new("Quit", true, None);
-
-            MENU_IDS
-                .set(MenuIds {
-                    show: show_item.id().clone(),
-                    quit: quit_item.id().clone(),
-                })
-                .expect("TrayManager initialized twice");
+            let menu = Self::build_menu(&[], None)?;
 
-            let menu = Menu::new();
-            menu.append(&show_item)?;
-            menu.append(&quit_item)?;
-
-            let icon = Self::create_icon()?;
+            let icon = Self::create_icon(None)?;
             let tray = TrayIconBuilder::new()
                 .with_menu(Box::new(menu))
                 .with_tooltip("GitTop - GitHub Notifications")
                 .with_icon(icon)
                 .build()?;
 
+            TRAY.with(|t| *t.borrow_mut() = Some(tray.clone()));
             Ok(Self { tray })
         }
 
-        fn create_icon() -> Result<Icon, Box<dyn std::error::Error>> {
-            use image::ImageReader;
-            use std::io::Cursor;
+        /// Rebuild the menu with an account switcher (only shown for 2+ accounts).
+        fn build_menu(
+            accounts: &[TrayAccount],
+            active: Option<&str>,
+        ) -> Result<Menu, Box<dyn std::error::Error>> {
+            let menu = Menu::new();
+            menu.append(&MenuItem::with_id(SHOW_ID, "Show GitTop", true, None))?;
+            menu.append(&MenuItem::with_id(
+                MINI_MODE_ID,
+                "Toggle Mini Mode",
+                true,
+                None,
+            ))?;
+
+            if accounts.len() > 1 {
+                let submenu = Submenu::new("Switch Account", true);
+                for account in accounts {
+                    submenu.append(&CheckMenuItem::with_id(
+                        format!("{ACCOUNT_ID_PREFIX}{}", account.username),
+                        &account.label,
+                        true,
+                        active == Some(account.username.as_str()),
+                        None,
+                    ))?;
+                }
+                menu.append(&submenu)?;
+            }
 
-            const ICON_BYTES: &[u8] = include_bytes!("../../assets/images/GitTop-256x256.png");
+            let pause = Submenu::new("Pause Notifications", true);
+            for (minutes, label) in crate::tray::PAUSE_OPTIONS {
+                pause.append(&MenuItem::with_id(
+                    format!("{PAUSE_ID_PREFIX}{minutes}"),
+                    label,
+                    true,
+                    None,
+                ))?;
+            }
+            pause.append(&PredefinedMenuItem::separator())?;
+            pause.append(&MenuItem::with_id(RESUME_ID, "Resume", true, None))?;
+            menu.append(&pause)?;
+
+            menu.append(&PredefinedMenuItem::separator())?;
+            menu.append(&MenuItem::with_id(QUIT_ID, "Quit", true, None))?;
+            Ok(menu)
+        }
+
+        /// Replace the account submenu. Must be called from the UI thread.
+        pub fn set_accounts(accounts: &[TrayAccount], active: Option<&str>) {
+            let menu = match Self::build_menu(accounts, active) {
+                Ok(menu) => menu,
+                Err(e) => {
+                    tracing::warn!(error = %e, "Failed to rebuild tray menu");
+                    return;
+                }
+            };
+            TRAY.with(|t| {
+                if let Some(tray) = t.borrow().as_ref() {
+                    tray.set_menu(Some(Box::new(menu)));
+                }
+            });
+        }
+
+        /// Redraw the icon for `unread`: muted at zero, badged otherwise.
+        /// Must be called from the UI thread.
+        pub fn set_unread_count(unread: usize) {
+            let icon = match Self::create_icon(Some(unread)) {
+                Ok(icon) => icon,
+                Err(e) => {
+                    tracing::warn!(error = %e, "Failed to load tray icon");
+                    return;
+                }
+            };
+            TRAY.with(|t| {
+                if let Some(tray) = t.borrow().as_ref()
+                    && let Err(e) = tray.set_icon(Some(icon))
+                {
+                    tracing::warn!(error = %e, "Failed to swap tray icon");
+                }
+            });
+        }
 
-            let img = ImageReader::new(Cursor::new(ICON_BYTES))
-                .with_guessed_format()?
-                .decode()?
-                .resize(32, 32, image::imageops::FilterType::Lanczos3)
-                .into_rgba8();
+        fn create_icon(unread: Option<usize>) -> Result<Icon, Box<dyn std::error::Error>> {
+            let img = crate::tray::render_icon(unread)?;
 
             let (width, height) = img.dimensions();
             Icon::from_rgba(img.into_raw(), width, height).map_err(Into::into)
@@ -199,14 +294,21 @@ pub mod tray {
 
         fn poll_menu_events() -> Option<TrayCommand> {
             let event = MenuEvent::receiver().try_recv().ok()?;
-            let ids = MENU_IDS.get()?;
-
-            [
-                (&ids.show, TrayCommand::ShowWindow),
-                (&ids.quit, TrayCommand::Quit),
-            ]
-            .into_iter()
-            .find_map(|(id, cmd)| (event.id == *id).then_some(cmd))
+
+            match event.id.as_ref() {
+                SHOW_ID => Some(TrayCommand::ShowWindow),
+                QUIT_ID => Some(TrayCommand::Quit),
+                RESUME_ID => Some(TrayCommand::Resume),
+                MINI_MODE_ID => Some(TrayCommand::ToggleMiniMode),
+                id => id
+                    .strip_prefix(PAUSE_ID_PREFIX)
+                    .and_then(|minutes| minutes.parse().ok())
+                    .map(TrayCommand::PauseFor)
+                    .or_else(|| {
+                        id.strip_prefix(ACCOUNT_ID_PREFIX)
+                            .map(|username| TrayCommand::SwitchAccount(username.to_string()))
+                    }),
+            }
         }
 
         fn drain_tray_icon_events() {
@@ -236,18 +338,45 @@ pub fn notify(
     title: &str,
     body: &str,
     url: Option<&str>,
+    thread: Option<super::ToastThread>,
 ) -> Result<(), tauri_winrt_notification::Error> {
     use tauri_winrt_notification::{Duration, Toast};
 
+    // Each toast text field renders as its own line, so the first line of the
+    // body and the rest go into separate fields rather than relying on
+    // embedded newlines.
+    let (first, rest) = body.split_once('\n').unwrap_or((body, ""));
     let mut toast = Toast::new(Toast::POWERSHELL_APP_ID)
         .title(title)
-        .text1(body)
+        .text1(first)
         .duration(Duration::Short);
+    if !rest.is_empty() {
+        toast = toast.text2(rest);
+    }
+
+    if url.is_some() && thread.is_some() {
+        toast = toast.add_button("Open", "open");
+    }
+    if thread.is_some() {
+        toast = toast.add_button("Mark read", "mark_read");
+    }
 
-    if let Some(url) = url {
-        let url_owned = url.to_string();
-        toast = toast.on_activated(move |_action| {
-            let _ = open::that(&url_owned);
+    if url.is_some() || thread.is_some() {
+        let url_owned = url.map(str::to_string);
+        // Clicking the toast body activates it with no button argument
+        toast = toast.on_activated(move |action| {
+            match action.as_deref() {
+                Some("mark_read") => {
+                    if let Some(thread) = &thread {
+                        thread.clone().mark_read();
+                    }
+                }
+                _ => {
+                    if let Some(url) = &url_owned {
+                        let _ = crate::browser::open(url);
+                    }
+                }
+            }
             Ok(())
         });
     }
@@ -0,0 +1,52 @@
+//! Autostart through an XDG `.desktop` entry in `$XDG_CONFIG_HOME/autostart`.
+//!
+//! Understood by most desktop session managers on Linux and FreeBSD. FreeBSD
+//! uses it directly; Linux uses it when there's no systemd (e.g. OpenRC).
+
+use std::fs;
+use std::path::PathBuf;
+
+use super::on_boot::OnBootError;
+
+const DESKTOP_ENTRY_TEMPLATE: &str = r#"[Desktop Entry]
+Type=Application
+Name=GitTop
+Comment=GitHub Notifications Manager
+Exec="{EXEC_PATH}"
+Icon=gittop
+Terminal=false
+X-GNOME-Autostart-enabled=true
+"#;
+
+/// `$XDG_CONFIG_HOME/autostart/gittop.desktop`, or `~/.config/...` when unset.
+fn desktop_entry_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|p| p.join("autostart").join("gittop.desktop"))
+}
+
+pub fn is_enabled() -> bool {
+    desktop_entry_path().is_some_and(|p| p.exists())
+}
+
+pub fn enable() -> Result<(), OnBootError> {
+    let exec_path = std::env::current_exe()
+        .map_err(OnBootError::Io)?
+        .to_string_lossy()
+        .to_string();
+
+    let path = desktop_entry_path().ok_or(OnBootError::NotSupported)?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(
+        &path,
+        DESKTOP_ENTRY_TEMPLATE.replace("{EXEC_PATH}", &exec_path),
+    )?;
+    Ok(())
+}
+
+pub fn disable() -> Result<(), OnBootError> {
+    if let Some(path) = desktop_entry_path().filter(|p| p.exists()) {
+        fs::remove_file(&path)?;
+    }
+    Ok(())
+}
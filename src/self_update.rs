@@ -0,0 +1,185 @@
+//! In-app updates for Windows and macOS.
+//!
+//! Downloads the installer for the running platform from the GitHub release,
+//! checks it against the published SHA-256 checksum and launches it. The
+//! caller then exits so the installer can replace the running binary.
+//!
+//! Linux and FreeBSD builds are usually installed by a package manager, which
+//! owns the binary; there we only link to the release.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use sha2::{Digest, Sha256};
+
+use crate::settings::ProxySettings;
+use crate::update_checker::UpdateInfo;
+
+/// Why self-update can't be offered here, or `None` if it can.
+pub fn unsupported_reason() -> Option<&'static str> {
+    if cfg!(any(target_os = "windows", target_os = "macos")) {
+        None
+    } else if is_package_managed() {
+        Some("Installed by your package manager; update it from there")
+    } else {
+        Some("Not available on this platform; download new releases manually")
+    }
+}
+
+/// File types `launch_installer` knows how to run.
+const INSTALLER_EXTENSIONS: &[&str] = &["msi", "exe", "dmg", "pkg"];
+
+/// Whether `info` carries everything needed to install it in-app.
+pub fn can_install(info: &UpdateInfo) -> bool {
+    unsupported_reason().is_none()
+        && info.download_url.is_some()
+        && info
+            .download_name
+            .as_deref()
+            .is_some_and(|name| installer_extension(Path::new(name)).is_some())
+        && info.checksum_url.is_some()
+}
+
+/// The installer type of `path`, if it's one we can launch.
+fn installer_extension(path: &Path) -> Option<String> {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .map(str::to_lowercase)
+        .filter(|e| INSTALLER_EXTENSIONS.contains(&e.as_str()))
+}
+
+/// Download the installer described by `info` and verify its checksum.
+///
+/// Returns the path of the verified installer.
+pub async fn download(info: UpdateInfo, proxy: ProxySettings) -> Result<PathBuf, String> {
+    let (Some(url), Some(name), Some(checksum_url)) =
+        (info.download_url, info.download_name, info.checksum_url)
+    else {
+        return Err("Release has no installer for this platform".to_string());
+    };
+
+    let client = crate::update_checker::releases_client(&proxy)
+        .ok_or_else(|| "Could not create HTTP client".to_string())?;
+
+    let fetch = |url: String| {
+        let client = client.clone();
+        async move {
+            let response = client
+                .get(&url)
+                .timeout(crate::update_checker::DOWNLOAD_TIMEOUT)
+                .send()
+                .await
+                .and_then(|r| r.error_for_status())
+                .map_err(|e| format!("Download failed: {e}"))?;
+            response
+                .bytes()
+                .await
+                .map_err(|e| format!("Download failed: {e}"))
+        }
+    };
+
+    let sums = fetch(checksum_url).await?;
+    let expected = parse_checksum(&String::from_utf8_lossy(&sums), &name)
+        .ok_or_else(|| format!("No checksum published for {name}"))?;
+
+    let installer = fetch(url).await?;
+    let actual = format!("{:x}", Sha256::digest(&installer));
+    if !actual.eq_ignore_ascii_case(&expected) {
+        tracing::warn!(expected, actual, file = name, "Update checksum mismatch");
+        return Err("Checksum mismatch; the download was discarded".to_string());
+    }
+
+    let dir = std::env::temp_dir().join("gittop-update");
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    // Only keep the file name; never trust a path from the release
+    let file_name = Path::new(&name)
+        .file_name()
+        .ok_or_else(|| "Invalid installer name".to_string())?;
+    let path = dir.join(file_name);
+    std::fs::write(&path, &installer).map_err(|e| e.to_string())?;
+
+    tracing::info!(version = %info.latest, path = %path.display(), "Update downloaded and verified");
+    Ok(path)
+}
+
+/// Start the downloaded installer. The caller should exit afterwards.
+pub fn launch_installer(path: &Path) -> Result<(), String> {
+    let extension = installer_extension(path).unwrap_or_default();
+
+    let result = match extension.as_str() {
+        "msi" => Command::new("msiexec").arg("/i").arg(path).spawn(),
+        "exe" => Command::new(path).spawn(),
+        "dmg" | "pkg" => Command::new("open").arg(path).spawn(),
+        _ => return Err(format!("Don't know how to install {}", path.display())),
+    };
+
+    result.map(|_| ()).map_err(|e| e.to_string())
+}
+
+/// Pull the hash for `file_name` out of a checksum file.
+///
+/// Accepts a bare hash (per-file `.sha256`) or `sha256sum` output listing
+/// several files.
+fn parse_checksum(contents: &str, file_name: &str) -> Option<String> {
+    let is_hash = |s: &str| s.len() == 64 && s.chars().all(|c| c.is_ascii_hexdigit());
+
+    contents.lines().find_map(|line| {
+        let mut parts = line.split_whitespace();
+        let hash = parts.next().filter(|h| is_hash(h))?;
+        match parts.next() {
+            // `sha256sum` marks binary mode with a leading '*'
+            Some(name) if name.trim_start_matches('*') == file_name => Some(hash.to_string()),
+            Some(_) => None,
+            None => Some(hash.to_string()),
+        }
+    })
+}
+
+/// Whether the running binary lives somewhere a package manager owns.
+fn is_package_managed() -> bool {
+    if std::env::var_os("FLATPAK_ID").is_some() || std::env::var_os("SNAP").is_some() {
+        return true;
+    }
+
+    std::env::current_exe().is_ok_and(|exe| {
+        ["/usr/", "/nix/store/", "/opt/", "/snap/"]
+            .iter()
+            .any(|prefix| exe.starts_with(prefix))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HASH: &str = "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08";
+
+    #[test]
+    fn test_parse_checksum_reads_bare_hash_and_sums_lists() {
+        assert_eq!(
+            parse_checksum(&format!("{HASH}\n"), "GitTop.msi").as_deref(),
+            Some(HASH)
+        );
+
+        let other = "0".repeat(64);
+        let list = format!("{other}  GitTop.dmg\n{HASH} *GitTop.msi\n");
+        assert_eq!(parse_checksum(&list, "GitTop.msi").as_deref(), Some(HASH));
+        assert_eq!(parse_checksum(&list, "GitTop.exe"), None);
+    }
+
+    #[test]
+    fn test_parse_checksum_rejects_malformed_hashes() {
+        assert_eq!(parse_checksum("not-a-hash  GitTop.msi", "GitTop.msi"), None);
+        assert_eq!(parse_checksum(&HASH[..63], "GitTop.msi"), None);
+    }
+
+    #[test]
+    fn test_only_launchable_files_count_as_installers() {
+        for name in ["GitTop.msi", "GitTop-Setup.EXE", "GitTop.dmg", "GitTop.pkg"] {
+            assert!(installer_extension(Path::new(name)).is_some(), "{name}");
+        }
+        for name in ["GitTop.zip", "gittop.tar.gz", "gittop"] {
+            assert!(installer_extension(Path::new(name)).is_none(), "{name}");
+        }
+    }
+}
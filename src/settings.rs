@@ -3,6 +3,9 @@
 //! Stores user preferences like icon theme, app theme, and account list.
 
 use serde::{Deserialize, Serialize};
+
+use crate::github::NotificationReason;
+use crate::i18n::Language;
 use std::fs;
 use std::io;
 use std::path::PathBuf;
@@ -12,6 +15,127 @@ pub enum IconTheme {
     #[default]
     Svg,
     Emoji,
+    /// SVG while the window is visible, emoji while it's hidden in the tray.
+    Auto,
+}
+
+impl IconTheme {
+    pub const ALL: [Self; 3] = [Self::Svg, Self::Emoji, Self::Auto];
+}
+
+impl std::fmt::Display for IconTheme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Svg => write!(f, "SVG"),
+            Self::Emoji => write!(f, "Emoji"),
+            Self::Auto => write!(f, "Auto"),
+        }
+    }
+}
+
+/// How the notification list is split into groups.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum GroupingMode {
+    #[default]
+    Time,
+    Repository,
+    Type,
+}
+
+impl GroupingMode {
+    pub const ALL: [Self; 3] = [Self::Time, Self::Repository, Self::Type];
+}
+
+impl std::fmt::Display for GroupingMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Time => write!(f, "Time"),
+            Self::Repository => write!(f, "Repository"),
+            Self::Type => write!(f, "Type"),
+        }
+    }
+}
+
+/// How finely time grouping splits the list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum TimeBuckets {
+    /// Today, Yesterday, This Week, Older.
+    #[default]
+    Coarse,
+    /// One group per day.
+    Daily,
+}
+
+impl TimeBuckets {
+    pub const ALL: [Self; 2] = [Self::Coarse, Self::Daily];
+}
+
+impl std::fmt::Display for TimeBuckets {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Coarse => write!(f, "Today / Week / Older"),
+            Self::Daily => write!(f, "Per day"),
+        }
+    }
+}
+
+/// What opening a notification does to it on GitHub.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum OpenAction {
+    #[default]
+    MarkRead,
+    MarkDone,
+    Nothing,
+}
+
+impl OpenAction {
+    pub const ALL: [Self; 3] = [Self::MarkRead, Self::MarkDone, Self::Nothing];
+}
+
+impl std::fmt::Display for OpenAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MarkRead => write!(f, "Mark as read"),
+            Self::MarkDone => write!(f, "Mark as done"),
+            Self::Nothing => write!(f, "Do nothing"),
+        }
+    }
+}
+
+/// How far back notifications are fetched, via the API's `since` parameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum FetchWindow {
+    Day,
+    Week,
+    #[default]
+    Month,
+    All,
+}
+
+impl FetchWindow {
+    pub const ALL: [Self; 4] = [Self::Day, Self::Week, Self::Month, Self::All];
+
+    /// Oldest update time to fetch, or `None` for everything.
+    pub fn since(self) -> Option<chrono::DateTime<chrono::Utc>> {
+        let days = match self {
+            Self::Day => 1,
+            Self::Week => 7,
+            Self::Month => 30,
+            Self::All => return None,
+        };
+        Some(chrono::Utc::now() - chrono::Duration::days(days))
+    }
+}
+
+impl std::fmt::Display for FetchWindow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Day => write!(f, "24 hours"),
+            Self::Week => write!(f, "7 days"),
+            Self::Month => write!(f, "30 days"),
+            Self::All => write!(f, "All"),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -89,11 +213,47 @@ impl TryFrom<u8> for AppTheme {
     }
 }
 
+/// A "remind me later" on a notification: a fresh desktop toast at `due`,
+/// whether or not the window is open.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Reminder {
+    pub notification_id: String,
+    pub title: String,
+    pub repo_full_name: String,
+    /// Opened when the toast is clicked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    pub due: chrono::DateTime<chrono::Utc>,
+}
+
 /// Stored account information.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StoredAccount {
     pub username: String,
     pub is_active: bool,
+    /// Optional label shown next to the login (e.g. "Work"). Display only.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// Power mode as last chosen while this account was active. Restored
+    /// when switching to it; `None` keeps the current layout.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub power_mode: Option<bool>,
+    /// Notification ids pinned to the top of this account's list.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pinned: Vec<String>,
+    /// When this account's token expires, as last reported by GitHub.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token_expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// The expiry whose warning banner was dismissed; a new token brings it back.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token_expiry_dismissed: Option<chrono::DateTime<chrono::Utc>>,
+    /// Pending "remind me later" toasts for this account's notifications.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub reminders: Vec<Reminder>,
+    /// Restored from a backup and not signed in to on this machine yet.
+    /// Kept at startup even though there's no token for it.
+    #[serde(default)]
+    pub awaiting_sign_in: bool,
 }
 
 /// Proxy settings (credentials stored securely in keyring)
@@ -112,6 +272,9 @@ pub struct AppSettings {
     pub icon_theme: IconTheme,
     #[serde(default)]
     pub theme: AppTheme,
+    /// Language for translated UI strings
+    #[serde(default)]
+    pub language: Language,
     pub accounts: Vec<StoredAccount>,
     #[serde(default = "default_minimize_to_tray")]
     pub minimize_to_tray: bool,
@@ -121,6 +284,15 @@ pub struct AppSettings {
     pub sidebar_font_scale: f32,
     #[serde(default = "default_sidebar_width")]
     pub sidebar_width: f32,
+    /// Most notifications kept in memory per refresh; the rest stay on GitHub
+    #[serde(default = "default_max_notifications")]
+    pub max_notifications: usize,
+    /// Only fetch notifications updated within this window.
+    #[serde(default)]
+    pub fetch_window: FetchWindow,
+    /// Seconds between automatic refreshes; see `refresh_interval`.
+    #[serde(default = "default_refresh_interval_secs")]
+    pub refresh_interval_secs: u64,
     #[serde(default)]
     pub window_x: Option<i32>,
     #[serde(default)]
@@ -133,11 +305,94 @@ pub struct AppSettings {
     pub power_mode: bool,
     #[serde(default = "default_show_details_panel")]
     pub show_details_panel: bool,
+    /// Compact list-only window.
+    #[serde(default)]
+    pub mini_mode: bool,
+    /// Keep the mini window above other windows.
+    #[serde(default = "default_mini_mode_on_top")]
+    pub mini_mode_on_top: bool,
+    /// Keep the window above other windows in every layout.
+    #[serde(default)]
+    pub always_on_top: bool,
+    /// Mini window size, kept apart from the normal `window_width`/`window_height`.
+    #[serde(default = "default_mini_window_width")]
+    pub mini_window_width: f32,
+    #[serde(default = "default_mini_window_height")]
+    pub mini_window_height: f32,
+    /// Hide read items while in "All" mode.
+    #[serde(default)]
+    pub hide_read: bool,
+    /// Fetch only participating threads (GitHub's `participating=true`).
+    #[serde(default)]
+    pub participating_only: bool,
+    #[serde(default)]
+    pub grouping_mode: GroupingMode,
+    /// Bucket size when grouping by time.
+    #[serde(default)]
+    pub time_buckets: TimeBuckets,
+    /// Skip flashes and transitions even if the OS doesn't ask for it.
+    #[serde(default)]
+    pub reduce_motion: bool,
+    /// Play a sound when a priority item arrives while the window is open.
+    #[serde(default)]
+    pub priority_sound: bool,
+    /// Reasons that never produce a desktop toast (still shown in-app).
+    #[serde(default)]
+    pub muted_desktop_reasons: Vec<NotificationReason>,
+    /// Regular toasts sent one by one before a batch is summarized instead.
+    #[serde(default = "default_toast_individual_limit")]
+    pub toast_individual_limit: usize,
+    /// Titles listed in a summary toast.
+    #[serde(default = "default_toast_summary_titles")]
+    pub toast_summary_titles: usize,
+    /// Global show/hide shortcut, e.g. "Ctrl+Shift+G". Unbound by default.
+    #[serde(default)]
+    pub global_hotkey: Option<String>,
+    /// Command used to open GitHub links, with a `{url}` placeholder.
+    /// Empty means the system default browser.
+    #[serde(default)]
+    pub browser_command: Option<String>,
+    /// Append actions and rule decisions to `logs/activity.jsonl`.
+    #[serde(default)]
+    pub activity_log: bool,
+    /// Serve the local scripting API on 127.0.0.1 (off by default).
+    #[serde(default)]
+    pub local_api_enabled: bool,
+    #[serde(default = "default_local_api_port")]
+    pub local_api_port: u16,
     #[serde(default)]
     pub proxy: ProxySettings,
     /// Check for updates on startup (opt-in, default: false)
     #[serde(default)]
     pub check_for_updates: bool,
+    /// Offer to download and install updates in-app (Windows/macOS)
+    #[serde(default)]
+    pub self_update: bool,
+    /// Release the user chose to skip; no update notice is shown for it
+    #[serde(default)]
+    pub skipped_update_version: Option<String>,
+    /// Last version whose "What's new" panel was dismissed
+    #[serde(default)]
+    pub last_seen_version: Option<String>,
+    /// Last selected Rule Engine tab, by key; unknown keys fall back to Overview
+    #[serde(default)]
+    pub rule_engine_tab: Option<String>,
+    /// Bulk "Open selected" also marks the opened threads as read
+    #[serde(default = "default_bulk_open_marks_read")]
+    pub bulk_open_marks_read: bool,
+    /// What opening a single notification does to it
+    #[serde(default)]
+    pub open_action: OpenAction,
+    /// Ask before "Mark all as read" clears more than a handful of unread
+    #[serde(default = "default_confirm_mark_all_read")]
+    pub confirm_mark_all_read: bool,
+    /// Look up and show who triggered each notification. Costs an extra API
+    /// call per visible item, so it's off by default.
+    #[serde(default)]
+    pub show_actors: bool,
+    /// Fold notifications about the same subject into a single item
+    #[serde(default)]
+    pub compact_threads: bool,
 }
 
 fn default_minimize_to_tray() -> bool {
@@ -152,6 +407,55 @@ fn default_sidebar_width() -> f32 {
     220.0
 }
 
+fn default_max_notifications() -> usize {
+    1000
+}
+
+fn default_refresh_interval_secs() -> u64 {
+    60
+}
+
+/// Allowed auto-refresh intervals, in seconds. Faster than this burns rate
+/// limit for little gain; slower and notifications feel stuck.
+pub const REFRESH_INTERVAL_RANGE: std::ops::RangeInclusive<u64> = 15..=600;
+
+fn default_toast_individual_limit() -> usize {
+    1
+}
+
+fn default_toast_summary_titles() -> usize {
+    3
+}
+
+fn default_local_api_port() -> u16 {
+    crate::local_api::DEFAULT_PORT
+}
+
+fn default_bulk_open_marks_read() -> bool {
+    true
+}
+
+/// Windows reports sizes below this while the window is minimized.
+pub const MIN_WINDOW_SIZE: f32 = 100.0;
+
+/// Windows parks minimized windows far off-screen (around -32000).
+pub const MIN_WINDOW_POSITION: f32 = -10000.0;
+
+/// `width` and `height` if they describe a usable window, `None` for the
+/// tiny or zero sizes reported while minimized.
+pub fn validated_window_size(width: f32, height: f32) -> Option<(f32, f32)> {
+    (width >= MIN_WINDOW_SIZE && height >= MIN_WINDOW_SIZE).then_some((width, height))
+}
+
+/// `x` and `y` unless they are the off-screen spot of a minimized window.
+pub fn validated_window_position(x: f32, y: f32) -> Option<(f32, f32)> {
+    (x > MIN_WINDOW_POSITION && y > MIN_WINDOW_POSITION).then_some((x, y))
+}
+
+fn default_confirm_mark_all_read() -> bool {
+    true
+}
+
 fn default_window_width() -> f32 {
     800.0
 }
@@ -168,24 +472,68 @@ fn default_show_details_panel() -> bool {
     true
 }
 
+fn default_mini_mode_on_top() -> bool {
+    true
+}
+
+fn default_mini_window_width() -> f32 {
+    360.0
+}
+
+fn default_mini_window_height() -> f32 {
+    420.0
+}
+
 impl Default for AppSettings {
     fn default() -> Self {
         Self {
             icon_theme: IconTheme::Svg,
             theme: AppTheme::default(),
+            language: Language::default(),
             accounts: Vec::new(),
             minimize_to_tray: true,
             notification_font_scale: 1.0,
             sidebar_font_scale: 1.0,
             sidebar_width: 220.0,
+            max_notifications: 1000,
+            fetch_window: FetchWindow::default(),
+            refresh_interval_secs: default_refresh_interval_secs(),
             window_x: None,
             window_y: None,
             window_width: 800.0,
             window_height: 640.0,
             power_mode: false,
             show_details_panel: true,
+            mini_mode: false,
+            mini_mode_on_top: true,
+            always_on_top: false,
+            mini_window_width: 360.0,
+            mini_window_height: 420.0,
+            hide_read: false,
+            participating_only: false,
+            grouping_mode: GroupingMode::Time,
+            time_buckets: TimeBuckets::Coarse,
+            reduce_motion: false,
+            priority_sound: false,
+            muted_desktop_reasons: Vec::new(),
+            toast_individual_limit: 1,
+            toast_summary_titles: 3,
+            global_hotkey: None,
+            browser_command: None,
+            activity_log: false,
+            local_api_enabled: false,
+            local_api_port: crate::local_api::DEFAULT_PORT,
             proxy: ProxySettings::default(),
             check_for_updates: false,
+            self_update: false,
+            skipped_update_version: None,
+            last_seen_version: None,
+            rule_engine_tab: None,
+            bulk_open_marks_read: true,
+            open_action: OpenAction::MarkRead,
+            confirm_mark_all_read: true,
+            show_actors: false,
+            compact_threads: false,
         }
     }
 }
@@ -261,32 +609,232 @@ impl AppSettings {
         Ok(())
     }
 
+    /// Mark `username` active and restore the power mode it was last used in.
     pub fn set_active_account(&mut self, username: &str) {
         let mut found = false;
         for acc in &mut self.accounts {
             acc.is_active = acc.username == username;
-            found |= acc.is_active;
+            if acc.is_active {
+                found = true;
+                acc.awaiting_sign_in = false;
+                if let Some(power_mode) = acc.power_mode {
+                    self.power_mode = power_mode;
+                }
+            }
         }
 
         if !found {
             self.accounts.push(StoredAccount {
                 username: username.to_string(),
                 is_active: true,
+                display_name: None,
+                power_mode: None,
+                pinned: Vec::new(),
+                token_expires_at: None,
+                token_expiry_dismissed: None,
+                reminders: Vec::new(),
+                awaiting_sign_in: false,
             });
         }
     }
 
+    /// Turn power mode on or off, remembering the choice for the active account.
+    pub fn set_power_mode(&mut self, enabled: bool) {
+        self.power_mode = enabled;
+        if let Some(acc) = self.accounts.iter_mut().find(|a| a.is_active) {
+            acc.power_mode = Some(enabled);
+        }
+    }
+
+    /// Notification ids `username` has pinned.
+    pub fn pinned(&self, username: &str) -> &[String] {
+        self.accounts
+            .iter()
+            .find(|a| a.username == username)
+            .map_or(&[], |a| a.pinned.as_slice())
+    }
+
+    /// Pin a notification for `username`, or unpin it if any of `ids` is
+    /// pinned. `ids` starts with the notification's own id, followed by those
+    /// compact mode folded into it. Returns whether it's now pinned.
+    pub fn toggle_pinned(&mut self, username: &str, ids: &[String]) -> bool {
+        let Some(acc) = self.accounts.iter_mut().find(|a| a.username == username) else {
+            return false;
+        };
+        if acc.pinned.iter().any(|p| ids.contains(p)) {
+            acc.pinned.retain(|p| !ids.contains(p));
+            false
+        } else {
+            acc.pinned.extend(ids.first().cloned());
+            true
+        }
+    }
+
+    /// Drop `username`'s pins that `keep` rejects. Returns whether any were dropped.
+    pub fn retain_pinned(&mut self, username: &str, keep: impl Fn(&str) -> bool) -> bool {
+        let Some(acc) = self.accounts.iter_mut().find(|a| a.username == username) else {
+            return false;
+        };
+        let before = acc.pinned.len();
+        acc.pinned.retain(|id| keep(id));
+        acc.pinned.len() != before
+    }
+
+    /// Time between automatic refreshes, clamped to `REFRESH_INTERVAL_RANGE`
+    /// in case the file was edited by hand.
+    pub fn refresh_interval(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.refresh_interval_secs.clamp(
+            *REFRESH_INTERVAL_RANGE.start(),
+            *REFRESH_INTERVAL_RANGE.end(),
+        ))
+    }
+
+    /// `username`'s pending reminders.
+    pub fn reminders(&self, username: &str) -> &[Reminder] {
+        self.accounts
+            .iter()
+            .find(|a| a.username == username)
+            .map_or(&[], |a| a.reminders.as_slice())
+    }
+
+    /// Set a reminder for `username`, replacing any already on that notification.
+    pub fn set_reminder(&mut self, username: &str, reminder: Reminder) {
+        let Some(acc) = self.accounts.iter_mut().find(|a| a.username == username) else {
+            return;
+        };
+        acc.reminders
+            .retain(|r| r.notification_id != reminder.notification_id);
+        acc.reminders.push(reminder);
+    }
+
+    /// Drop `username`'s reminder on a notification, if there is one.
+    pub fn cancel_reminder(&mut self, username: &str, notification_id: &str) {
+        if let Some(acc) = self.accounts.iter_mut().find(|a| a.username == username) {
+            acc.reminders
+                .retain(|r| r.notification_id != notification_id);
+        }
+    }
+
+    /// Remove and return every reminder due by `now`, across all accounts,
+    /// paired with the account it belongs to.
+    pub fn take_due_reminders(
+        &mut self,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Vec<(String, Reminder)> {
+        let mut due = Vec::new();
+        for acc in &mut self.accounts {
+            let (fired, pending) = acc.reminders.drain(..).partition(|r| r.due <= now);
+            acc.reminders = pending;
+            due.extend(
+                fired
+                    .into_iter()
+                    .map(|r: Reminder| (acc.username.clone(), r)),
+            );
+        }
+        due
+    }
+
+    /// Remember when `username`'s token expires, as reported at sign-in.
+    pub fn set_token_expiry(
+        &mut self,
+        username: &str,
+        expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    ) {
+        if let Some(acc) = self.accounts.iter_mut().find(|a| a.username == username) {
+            acc.token_expires_at = expires_at;
+        }
+    }
+
+    /// When `username`'s token expires, if known.
+    pub fn token_expiry(&self, username: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.accounts
+            .iter()
+            .find(|a| a.username == username)
+            .and_then(|a| a.token_expires_at)
+    }
+
+    /// Hide the warning for `username`'s token expiring at `expires_at`.
+    pub fn dismiss_token_expiry(
+        &mut self,
+        username: &str,
+        expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    ) {
+        if let Some(acc) = self.accounts.iter_mut().find(|a| a.username == username) {
+            acc.token_expiry_dismissed = expires_at;
+        }
+    }
+
+    /// Whether the warning for a token expiring at `expires_at` was dismissed.
+    pub fn token_expiry_dismissed(
+        &self,
+        username: &str,
+        expires_at: chrono::DateTime<chrono::Utc>,
+    ) -> bool {
+        self.accounts
+            .iter()
+            .find(|a| a.username == username)
+            .is_some_and(|a| a.token_expiry_dismissed == Some(expires_at))
+    }
+
+    /// Accounts restored from a backup that still need a token here.
+    pub fn awaiting_sign_in(&self) -> impl Iterator<Item = &str> {
+        self.accounts
+            .iter()
+            .filter(|a| a.awaiting_sign_in)
+            .map(|a| a.username.as_str())
+    }
+
     /// Remove an account by username.
     pub fn remove_account(&mut self, username: &str) {
         self.accounts.retain(|a| a.username != username);
     }
 
-    /// Apply theme and font scale settings globally.
+    /// Set or clear an account's display name; blank clears it.
+    pub fn set_display_name(&mut self, username: &str, name: &str) {
+        if let Some(acc) = self.accounts.iter_mut().find(|a| a.username == username) {
+            acc.display_name = Some(name.to_string()).filter(|n| !n.trim().is_empty());
+        }
+    }
+
+    /// How an account is shown in switchers: "Work (login)", or the login.
+    pub fn account_label(&self, username: &str) -> String {
+        self.accounts
+            .iter()
+            .find(|a| a.username == username)
+            .and_then(|a| a.display_name.as_deref())
+            .map(str::trim)
+            .map_or_else(
+                || username.to_string(),
+                |name| format!("{name} ({username})"),
+            )
+    }
+
+    /// Port the local API should listen on, or `None` when it's disabled.
+    pub fn local_api_port(&self) -> Option<u16> {
+        self.local_api_enabled.then_some(self.local_api_port)
+    }
+
+    /// Apply theme, font scale and language settings globally.
     /// Call this after loading settings to initialize the UI theme.
     pub fn apply_theme(&self) {
         crate::ui::theme::set_theme(self.theme);
+        crate::i18n::set_language(self.language);
         crate::ui::theme::set_notification_font_scale(self.notification_font_scale);
         crate::ui::theme::set_sidebar_font_scale(self.sidebar_font_scale);
+        crate::ui::theme::set_reduced_motion(
+            self.reduce_motion || crate::platform::prefers_reduced_motion(),
+        );
+    }
+
+    /// Delete the settings file. A missing file is not an error.
+    pub fn delete_file() -> Result<(), std::io::Error> {
+        let path = Self::settings_path().ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::NotFound, "No config directory")
+        })?;
+        match fs::remove_file(&path) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        }
     }
 
     /// Save settings to disk, ignoring any errors.
@@ -294,3 +842,79 @@ impl AppSettings {
         let _ = self.save();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window_size_rejects_minimized_and_empty() {
+        assert_eq!(validated_window_size(800.0, 640.0), Some((800.0, 640.0)));
+        assert_eq!(validated_window_size(100.0, 100.0), Some((100.0, 100.0)));
+        assert_eq!(validated_window_size(0.0, 0.0), None);
+        assert_eq!(validated_window_size(160.0, 28.0), None);
+        assert_eq!(validated_window_size(-800.0, 640.0), None);
+        assert_eq!(validated_window_size(f32::NAN, 640.0), None);
+    }
+
+    #[test]
+    fn test_window_position_rejects_minimized() {
+        assert_eq!(validated_window_position(0.0, 0.0), Some((0.0, 0.0)));
+        // Left of or above the primary monitor is a real position
+        assert_eq!(
+            validated_window_position(-1920.0, -200.0),
+            Some((-1920.0, -200.0))
+        );
+        assert_eq!(validated_window_position(-32000.0, -32000.0), None);
+        assert_eq!(validated_window_position(200.0, -32000.0), None);
+        assert_eq!(validated_window_position(-10000.0, 0.0), None);
+    }
+
+    #[test]
+    fn test_due_reminders_are_taken_once_per_account() {
+        let now = chrono::Utc::now();
+        let reminder = |id: &str, minutes: i64| Reminder {
+            notification_id: id.to_string(),
+            title: format!("Thread {id}"),
+            repo_full_name: "owner/repo".to_string(),
+            url: None,
+            due: now + chrono::TimeDelta::minutes(minutes),
+        };
+        let mut settings = AppSettings::default();
+        settings.set_active_account("alice");
+        settings.set_active_account("bob");
+        settings.set_reminder("alice", reminder("1", -5));
+        settings.set_reminder("alice", reminder("2", 30));
+        settings.set_reminder("bob", reminder("3", 0));
+        // Setting it again moves the existing reminder rather than adding one
+        settings.set_reminder("bob", reminder("3", -1));
+
+        let due = settings.take_due_reminders(now);
+        let fired: Vec<_> = due
+            .iter()
+            .map(|(account, r)| (account.as_str(), r.notification_id.as_str()))
+            .collect();
+        assert_eq!(fired, [("alice", "1"), ("bob", "3")]);
+        assert_eq!(settings.reminders("alice").len(), 1);
+        assert!(settings.reminders("bob").is_empty());
+        assert!(settings.take_due_reminders(now).is_empty());
+    }
+
+    #[test]
+    fn test_pins_toggle_across_merged_ids_and_prune() {
+        let ids = |ids: &[&str]| ids.iter().map(|id| id.to_string()).collect::<Vec<_>>();
+        let mut settings = AppSettings::default();
+        settings.set_active_account("alice");
+
+        assert!(settings.toggle_pinned("alice", &ids(&["2"])));
+        // Compact mode folded "2" under the newer "3": unpinning "3" unpins it
+        assert!(!settings.toggle_pinned("alice", &ids(&["3", "2"])));
+        assert!(settings.pinned("alice").is_empty());
+
+        settings.toggle_pinned("alice", &ids(&["1"]));
+        settings.toggle_pinned("alice", &ids(&["4"]));
+        assert!(settings.retain_pinned("alice", |id| id == "4"));
+        assert_eq!(settings.pinned("alice"), ["4"]);
+        assert!(!settings.retain_pinned("alice", |id| id == "4"));
+    }
+}
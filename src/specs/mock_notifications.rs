@@ -94,6 +94,8 @@ pub fn generate_mock_notifications(count: usize, account: &str) -> Vec<Notificat
             avatar_url: format!("https://github.com/{}.png", owner),
             is_private: i % 10 == 0, // 10% private
             account: account.to_string(),
+            ci_conclusion: None,
+            actor: None,
         });
     }
 
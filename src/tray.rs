@@ -5,11 +5,272 @@
 //! - Linux/FreeBSD: Uses `ksni` (pure-Rust StatusNotifierItem)
 //! - Windows/macOS: Uses `tray-icon` (native platform APIs)
 
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{LazyLock, Mutex, OnceLock};
+
 #[derive(Debug, Clone)]
 pub enum TrayCommand {
     ShowWindow,
+    /// Show the window if hidden, otherwise send it to the tray.
+    ToggleWindow,
+    /// Switch the active account from the tray submenu.
+    SwitchAccount(String),
+    /// Pause refreshes and desktop alerts for this many minutes.
+    PauseFor(u32),
+    /// End a pause early.
+    Resume,
+    /// Switch between the compact mini window and the normal one.
+    ToggleMiniMode,
+    /// Fetch notifications now (from the local API).
+    Refresh,
+    /// Mark a thread read from a desktop notification's "Mark read" action.
+    MarkRead {
+        account: String,
+        thread_id: String,
+    },
     Quit,
 }
 
+/// Pause lengths offered in the tray menu and settings, in minutes.
+pub const PAUSE_OPTIONS: [(u32, &str); 2] = [(30, "30 minutes"), (60, "1 hour")];
+
 // Re-export the platform-specific TrayManager
 pub use crate::platform::tray::TrayManager;
+
+/// Commands raised by desktop notification actions. Each toast waits for
+/// its action on its own thread, so they share one channel.
+static TOAST_COMMANDS: LazyLock<(Sender<TrayCommand>, Mutex<Receiver<TrayCommand>>)> =
+    LazyLock::new(|| {
+        let (tx, rx) = mpsc::channel();
+        (tx, Mutex::new(rx))
+    });
+
+/// Queue a command from a desktop notification action.
+pub fn send_toast_command(cmd: TrayCommand) {
+    let _ = TOAST_COMMANDS.0.send(cmd);
+}
+
+/// Next command raised by a desktop notification action, if any.
+pub fn poll_toast_commands() -> Option<TrayCommand> {
+    TOAST_COMMANDS.1.lock().ok()?.try_recv().ok()
+}
+
+/// Tray icon, and its muted variant shown when nothing is unread.
+pub const ICON_BYTES: &[u8] = include_bytes!("../assets/images/GitTop-256x256.png");
+pub const QUIET_ICON_BYTES: &[u8] = include_bytes!("../assets/images/GitTop-256x256-quiet.png");
+
+/// Unread count the tray icon was last drawn for, capped at `BADGE_CAP + 1`.
+static SYNCED_UNREAD: Mutex<Option<usize>> = Mutex::new(None);
+
+/// Counts above this show as "99+" on the badge.
+const BADGE_CAP: usize = 99;
+
+/// A signed-in account as listed in the tray's switcher.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrayAccount {
+    pub username: String,
+    /// Display name and login, or just the login.
+    pub label: String,
+}
+
+/// Last account list pushed to the tray, to skip needless menu rebuilds.
+static SYNCED_ACCOUNTS: Mutex<Option<(Vec<TrayAccount>, Option<String>)>> = Mutex::new(None);
+
+/// Keep the tray's account submenu in step with the signed-in sessions.
+pub fn sync_accounts(mut accounts: Vec<TrayAccount>, active: Option<String>) {
+    accounts.sort_unstable_by_key(|a| a.label.to_lowercase());
+
+    let Ok(mut synced) = SYNCED_ACCOUNTS.lock() else {
+        return;
+    };
+    if synced
+        .as_ref()
+        .is_some_and(|(a, act)| *a == accounts && *act == active)
+    {
+        return;
+    }
+
+    TrayManager::set_accounts(&accounts, active.as_deref());
+    *synced = Some((accounts, active));
+}
+
+/// Show the muted tray icon when `unread` is zero, the normal one with a
+/// count badge otherwise.
+pub fn sync_unread(unread: usize) {
+    // Everything past the cap draws the same "99+" badge
+    let shown = unread.min(BADGE_CAP + 1);
+    let Ok(mut synced) = SYNCED_UNREAD.lock() else {
+        return;
+    };
+    if *synced == Some(shown) {
+        return;
+    }
+
+    TrayManager::set_unread_count(shown);
+    *synced = Some(shown);
+}
+
+/// The 32x32 tray icon for `unread`: the plain icon when the count isn't
+/// known yet, the muted one at zero, and a badged one otherwise.
+pub fn render_icon(unread: Option<usize>) -> Result<image::RgbaImage, image::ImageError> {
+    let mut img = base_icon(unread == Some(0))?;
+
+    if let Some(n) = unread.filter(|&n| n > 0) {
+        let label = if n > BADGE_CAP {
+            format!("{BADGE_CAP}+")
+        } else {
+            n.to_string()
+        };
+        draw_badge(&mut img, &label);
+    }
+    Ok(img)
+}
+
+/// The plain or muted icon at 32x32. Decoding and resizing the 256px PNG is
+/// slow, so each is done once and copied for every badge redraw.
+fn base_icon(quiet: bool) -> Result<image::RgbaImage, image::ImageError> {
+    use image::ImageReader;
+    use std::io::Cursor;
+
+    static PLAIN: OnceLock<image::RgbaImage> = OnceLock::new();
+    static QUIET: OnceLock<image::RgbaImage> = OnceLock::new();
+
+    let (cache, bytes) = if quiet {
+        (&QUIET, QUIET_ICON_BYTES)
+    } else {
+        (&PLAIN, ICON_BYTES)
+    };
+    if let Some(img) = cache.get() {
+        return Ok(img.clone());
+    }
+    let img = ImageReader::new(Cursor::new(bytes))
+        .with_guessed_format()?
+        .decode()?
+        .resize(32, 32, image::imageops::FilterType::Lanczos3)
+        .into_rgba8();
+    Ok(cache.get_or_init(|| img).clone())
+}
+
+/// 3x5 glyphs for the badge, one row per byte, high bit on the left.
+fn glyph(c: char) -> [u8; 5] {
+    match c {
+        '0' => [0b111, 0b101, 0b101, 0b101, 0b111],
+        '1' => [0b010, 0b110, 0b010, 0b010, 0b111],
+        '2' => [0b111, 0b001, 0b111, 0b100, 0b111],
+        '3' => [0b111, 0b001, 0b111, 0b001, 0b111],
+        '4' => [0b101, 0b101, 0b111, 0b001, 0b001],
+        '5' => [0b111, 0b100, 0b111, 0b001, 0b111],
+        '6' => [0b111, 0b100, 0b111, 0b101, 0b111],
+        '7' => [0b111, 0b001, 0b001, 0b001, 0b001],
+        '8' => [0b111, 0b101, 0b111, 0b101, 0b111],
+        '9' => [0b111, 0b101, 0b111, 0b001, 0b111],
+        '+' => [0b000, 0b010, 0b111, 0b010, 0b000],
+        _ => [0; 5],
+    }
+}
+
+/// Paint `label` in white on a red badge in the bottom-right corner.
+fn draw_badge(img: &mut image::RgbaImage, label: &str) {
+    const SCALE: u32 = 2;
+    const PAD: u32 = 3;
+    const BADGE: image::Rgba<u8> = image::Rgba([0xd7, 0x3a, 0x49, 0xff]);
+    const TEXT: image::Rgba<u8> = image::Rgba([0xff, 0xff, 0xff, 0xff]);
+    const RING: image::Rgba<u8> = TEXT;
+
+    let chars = label.chars().count() as u32;
+    let text_width = chars * 3 * SCALE + chars.saturating_sub(1) * SCALE;
+    let height = 5 * SCALE + 2 * PAD;
+    let width = (text_width + 2 * PAD).max(height).min(img.width());
+    let left = img.width() - width;
+    let top = img.height() - height;
+
+    // White ring so the badge stands out against the red icon
+    fill_rounded(img, left, top, width, height, 4, RING);
+    fill_rounded(img, left + 1, top + 1, width - 2, height - 2, 3, BADGE);
+
+    let mut x0 = left + (width - text_width) / 2;
+    for c in label.chars() {
+        for (row, bits) in glyph(c).into_iter().enumerate() {
+            for col in 0..3 {
+                if bits & (0b100 >> col) == 0 {
+                    continue;
+                }
+                for sy in 0..SCALE {
+                    for sx in 0..SCALE {
+                        img.put_pixel(
+                            x0 + col * SCALE + sx,
+                            top + PAD + row as u32 * SCALE + sy,
+                            TEXT,
+                        );
+                    }
+                }
+            }
+        }
+        x0 += 4 * SCALE;
+    }
+}
+
+/// Fill a `width` x `height` box whose corners are rounded by `radius`.
+fn fill_rounded(
+    img: &mut image::RgbaImage,
+    left: u32,
+    top: u32,
+    width: u32,
+    height: u32,
+    radius: i32,
+    color: image::Rgba<u8>,
+) {
+    let (w, h) = (width as i32, height as i32);
+    for y in 0..h {
+        for x in 0..w {
+            // Distance past the inner box that the corners curve around
+            let dx = (radius - x).max(x - (w - 1 - radius)).max(0);
+            let dy = (radius - y).max(y - (h - 1 - radius)).max(0);
+            if dx * dx + dy * dy <= radius * radius {
+                img.put_pixel(left + x as u32, top + y as u32, color);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RING: image::Rgba<u8> = image::Rgba([0xff, 0xff, 0xff, 0xff]);
+    const BADGE: image::Rgba<u8> = image::Rgba([0xd7, 0x3a, 0x49, 0xff]);
+
+    #[test]
+    fn test_badge_stays_inside_icon() {
+        for label in ["7", "42", "99+"] {
+            let mut img = image::RgbaImage::new(32, 32);
+            draw_badge(&mut img, label);
+
+            // The badge sits in the bottom-right corner without covering the
+            // whole width
+            assert_eq!(img.get_pixel(31, 24)[3], 0xff, "{label}");
+            assert_eq!(img.get_pixel(0, 24)[3], 0, "{label}");
+
+            // Every text row is ring, badge, text..., badge, ring: the
+            // digits never reach the edge of the badge
+            for y in 19..29 {
+                let row: Vec<_> = (0..32)
+                    .map(|x| *img.get_pixel(x, y))
+                    .filter(|p| p[3] > 0)
+                    .collect();
+                let n = row.len();
+                assert_eq!([row[0], row[1]], [RING, BADGE], "{label} row {y}");
+                assert_eq!([row[n - 2], row[n - 1]], [BADGE, RING], "{label} row {y}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_render_icon_badges_the_base_icon() {
+        let plain = render_icon(None).unwrap();
+        assert_eq!(plain.dimensions(), (32, 32));
+        assert_eq!(render_icon(None).unwrap(), plain);
+        assert_ne!(render_icon(Some(3)).unwrap(), plain);
+        assert_eq!(render_icon(Some(0)).unwrap().dimensions(), (32, 32));
+    }
+}
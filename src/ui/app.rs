@@ -6,7 +6,7 @@
 use std::time::Duration;
 
 use iced::window::Id as WindowId;
-use iced::{Element, Event, Subscription, Task, Theme, event, time, window};
+use iced::{Element, Event, Subscription, Task, Theme, event, keyboard, time, window};
 
 use crate::github::{SessionManager, auth};
 use crate::settings::AppSettings;
@@ -17,10 +17,11 @@ use crate::ui::handlers::platform;
 
 use crate::ui::routing::{RuleEngineOrigin, Screen};
 use crate::ui::screens::{
-    login::{LoginMessage, LoginScreen},
+    login::{LoginMessage, LoginMode, LoginScreen},
     notifications::NotificationsScreen,
-    notifications::messages::NotificationMessage,
-    settings::messages::SettingsMessage,
+    notifications::desktop_notify,
+    notifications::messages::{NotificationMessage, SelectionKey},
+    settings::messages::{SettingsMessage, SettingsTab},
     settings::rule_engine::messages::RuleEngineMessage,
 };
 use crate::ui::state;
@@ -42,6 +43,7 @@ pub enum Message {
     RestoreComplete(SessionManager, Option<String>),
     /// Update check completed
     UpdateCheckResult(Option<crate::update_checker::UpdateInfo>),
+    ReleaseNotesLoaded(crate::update_checker::ReleaseNotes),
 
     // -- UI Screens --
     Login(LoginMessage),
@@ -52,64 +54,62 @@ pub enum Message {
     // -- Platform/System --
     Tick,
     TrayPoll,
+    /// Periodic disk cache pruning.
+    CacheMaintenance,
+    /// Periodic check for reminders that have come due.
+    ReminderCheck,
     WindowEvent(WindowId, window::Event),
+    /// Tab / Shift+Tab pressed outside of any widget that consumed it.
+    FocusNext,
+    FocusPrevious,
+    /// Ctrl+1..9 pressed; switches to the account at this index in
+    /// `AppContext::account_choices` unless a text input has focus.
+    AccountShortcut(usize),
+    SwitchToAccount(usize),
+    /// List navigation or triage key; dropped if a text input has focus.
+    SelectionKey(SelectionKey),
 }
 
 impl App {
     pub fn new() -> (Self, Task<Message>) {
-        (
-            App::Loading,
-            Task::perform(
-                async {
-                    use crate::github::session::SessionError;
-
-                    let mut sessions = SessionManager::new();
-                    let mut settings = AppSettings::load();
-                    let mut failed_accounts = Vec::new();
-                    let mut network_error: Option<String> = None;
-
-                    for account in &settings.accounts {
-                        match sessions.restore_account(&account.username).await {
-                            Ok(()) => {}
-                            Err(SessionError::AccountNotFound(_)) => {
-                                failed_accounts.push(account.username.clone());
-                            }
-                            Err(SessionError::NetworkError(msg)) => {
-                                network_error = Some(msg);
-                            }
-                            Err(e) => {
-                                tracing::warn!(
-                                    username = %account.username,
-                                    error = %e,
-                                    "Failed to restore saved session"
-                                );
-                                failed_accounts.push(account.username.clone());
-                            }
-                        }
-                    }
+        let restore_task = Task::perform(
+            async {
+                use crate::github::session::RestoreReport;
 
-                    if !failed_accounts.is_empty() {
-                        for username in failed_accounts {
-                            settings.remove_account(&username);
-                        }
-                        settings.save_silent();
-                    }
+                let mut sessions = SessionManager::new();
+                let mut settings = AppSettings::load();
+                let mut report = RestoreReport::default();
 
-                    let primary = settings
-                        .accounts
-                        .iter()
-                        .find(|a| a.is_active)
-                        .or_else(|| settings.accounts.first())
-                        .map(|a| a.username.clone());
+                for account in &settings.accounts {
+                    let result = sessions.restore_account(&account.username).await;
+                    report.record(&account.username, result);
+                }
 
-                    if let Some(username) = primary {
-                        sessions.set_primary(&username);
-                    }
+                // Only accounts whose token is gone are dropped; a network
+                // outage at startup must not cost the user their accounts.
+                if report.apply(&mut settings) {
+                    settings.save_silent();
+                }
 
-                    (sessions, network_error)
-                },
-                |(sessions, network_error)| Message::RestoreComplete(sessions, network_error),
-            ),
+                let primary = settings
+                    .accounts
+                    .iter()
+                    .find(|a| a.is_active)
+                    .or_else(|| settings.accounts.first())
+                    .map(|a| a.username.clone());
+
+                if let Some(username) = primary {
+                    sessions.set_primary(&username);
+                }
+
+                (sessions, report.transient_error)
+            },
+            |(sessions, network_error)| Message::RestoreComplete(sessions, network_error),
+        );
+
+        (
+            App::Loading,
+            Task::batch([restore_task, platform::run_cache_maintenance()]),
         )
     }
 
@@ -119,13 +119,48 @@ impl App {
         match &message {
             Message::Tick => return self.handle_tick(),
             Message::TrayPoll => return self.handle_tray_poll(),
+            Message::CacheMaintenance => return platform::run_cache_maintenance(),
+            Message::ReminderCheck => return self.handle_reminder_check(),
             Message::WindowEvent(id, event) => return self.handle_window_event(*id, event.clone()),
+            Message::FocusNext => return iced::widget::operation::focus_next(),
+            Message::FocusPrevious => return iced::widget::operation::focus_previous(),
+            Message::AccountShortcut(index) => {
+                // Text inputs let Ctrl+digit through, so check focus ourselves
+                let index = *index;
+                return iced::advanced::widget::operate(
+                    iced::advanced::widget::operation::focusable::count(),
+                )
+                .then(move |count| match count.focused {
+                    Some(_) => Task::none(),
+                    None => Task::done(Message::SwitchToAccount(index)),
+                });
+            }
+            Message::SwitchToAccount(index) => return self.switch_to_account(*index),
+            Message::SelectionKey(key) => {
+                // Enter and Escape get past a text input without on_submit
+                let key = *key;
+                return iced::advanced::widget::operate(
+                    iced::advanced::widget::operation::focusable::count(),
+                )
+                .then(move |count| match count.focused {
+                    Some(_) => Task::none(),
+                    None => Task::done(Message::Notifications(NotificationMessage::SelectionKey(
+                        key,
+                    ))),
+                });
+            }
             Message::UpdateCheckResult(info) => {
                 if let Some(screen) = self.notification_screen_mut() {
                     screen.update_info = info.clone();
                 }
                 return Task::none();
             }
+            Message::ReleaseNotesLoaded(notes) => {
+                if let Some(screen) = self.notification_screen_mut() {
+                    screen.whats_new = Some(notes.clone());
+                }
+                return Task::none();
+            }
             _ => {}
         }
 
@@ -137,11 +172,13 @@ impl App {
                 Screen::Notifications(_) => self.update_notifications(message),
                 Screen::Settings(_) => self.update_settings(message),
                 Screen::RuleEngine(_, _) => self.update_rule_engine(message),
+                Screen::AccountLogin(_) => self.update_account_login(message),
             },
         };
 
         // Apply any effects and combine tasks
         let effect_task = self.apply_effect(effect);
+        self.sync_tray_accounts();
         Task::batch([task, effect_task])
     }
 
@@ -171,17 +208,29 @@ impl App {
             NavigateTo::Notifications => {
                 match navigation::go_to_notifications(current_screen, ctx) {
                     Some(t) => {
+                        // Mini mode may have been switched from the settings screen
+                        let window_task = if state::window_level(&ctx.settings)
+                            != state::window_level(&t.updated_settings)
+                            || ctx.settings.mini_mode != t.updated_settings.mini_mode
+                        {
+                            state::apply_window_mode(&t.updated_settings)
+                        } else {
+                            Task::none()
+                        };
                         *self = App::Authenticated(
                             Box::new(Screen::Notifications(t.screen)),
                             ctx.with_settings(t.updated_settings),
                         );
-                        t.task
+                        Task::batch([t.task, window_task])
                     }
                     None => Task::none(),
                 }
             }
-            NavigateTo::Settings => {
-                let t = navigation::go_to_settings(ctx);
+            NavigateTo::Settings | NavigateTo::ProxySettings => {
+                let mut t = navigation::go_to_settings(ctx);
+                if matches!(to, NavigateTo::ProxySettings) {
+                    t.screen.selected_tab = SettingsTab::NetworkProxy;
+                }
                 *self = App::Authenticated(
                     Box::new(Screen::Settings(t.screen)),
                     ctx.with_settings(t.updated_settings),
@@ -194,13 +243,21 @@ impl App {
                     Screen::Settings(s) => Some(&s.settings),
                     _ => Some(&ctx.settings),
                 };
-                let t = navigation::go_to_rule_engine(settings, origin);
+                let notifications = match &**current_screen {
+                    Screen::Notifications(s) => s.processing.all_notifications.clone(),
+                    _ => navigation::cached_notifications(ctx),
+                };
+                let t = navigation::go_to_rule_engine(settings, origin, notifications);
                 *self = App::Authenticated(
                     Box::new(Screen::RuleEngine(t.screen, t.origin)),
                     ctx.with_settings(t.updated_settings),
                 );
                 Task::none()
             }
+            NavigateTo::Reauthenticate(username) => {
+                self.open_account_login(LoginScreen::for_account(&username))
+            }
+            NavigateTo::AddAccount => self.open_account_login(LoginScreen::add_account()),
             NavigateTo::Login => {
                 let _ = auth::delete_token();
                 *self = App::Login(LoginScreen::new());
@@ -215,6 +272,7 @@ impl App {
                     };
                     self.navigate(target)
                 }
+                Screen::AccountLogin(_) => self.navigate(NavigateTo::Notifications),
                 Screen::Notifications(_) => Task::none(),
             },
         }
@@ -262,6 +320,28 @@ impl App {
                 }
             }
 
+            SessionEffect::ResetAll => {
+                let settings = match &**screen {
+                    Screen::Settings(s) => &s.settings,
+                    _ => &ctx.settings,
+                };
+                let failed = navigation::reset_all_data(&ctx.sessions, settings);
+
+                crate::hotkey::unregister();
+                crate::tray::sync_accounts(Vec::new(), None);
+                AppSettings::default().apply_theme();
+
+                let mut login_screen = LoginScreen::new();
+                if !failed.is_empty() {
+                    login_screen.error_message = Some(format!(
+                        "Reset finished, but these could not be removed: {}",
+                        failed.join(", ")
+                    ));
+                }
+                *self = App::Login(login_screen);
+                crate::platform::trim_memory();
+                Task::none()
+            }
             SessionEffect::RemoveAccount(username) => {
                 let _ = ctx.sessions.remove_account(&username);
                 ctx.settings.remove_account(&username);
@@ -285,19 +365,51 @@ impl App {
     // Screen Update Handlers
     // ========================================================================
 
+    /// Load the "What's new" notes if this version hasn't been seen yet.
+    ///
+    /// A first run has nothing to compare against, so it just records the
+    /// current version.
+    fn whats_new_task(settings: &mut AppSettings) -> Task<Message> {
+        let current = env!("CARGO_PKG_VERSION");
+        match settings.last_seen_version.as_deref() {
+            None => {
+                settings.last_seen_version = Some(current.to_string());
+                Task::none()
+            }
+            Some(seen) if crate::update_checker::is_newer(current, seen) => Task::perform(
+                crate::update_checker::release_notes(
+                    current.to_string(),
+                    settings.check_for_updates,
+                    settings.proxy.clone(),
+                ),
+                Message::ReleaseNotesLoaded,
+            ),
+            Some(_) => Task::none(),
+        }
+    }
+
     fn update_loading(&mut self, message: Message) -> Task<Message> {
         if let Message::RestoreComplete(sessions, network_error) = message {
             if let Some(session) = sessions.primary() {
                 let mut settings = AppSettings::load();
                 settings.set_active_account(&session.username);
+                for username in sessions.usernames() {
+                    if let Some(restored) = sessions.get(username) {
+                        settings.set_token_expiry(username, restored.user.token_expires_at);
+                    }
+                }
+                let whats_new_task = Self::whats_new_task(&mut settings);
                 settings.save_silent();
                 settings.apply_theme();
 
-                let (mut notif_screen, task) =
-                    NotificationsScreen::new(session.client.clone(), session.user.clone());
+                let (mut notif_screen, task) = NotificationsScreen::new(
+                    session.client.clone(),
+                    session.user.clone(),
+                    &settings,
+                );
 
                 if let Some(error) = network_error {
-                    notif_screen.error_message = Some(format!("Network error: {}", error));
+                    notif_screen.error = Some(crate::github::GitHubError::Network(error));
                 }
 
                 let ctx = AppContext::new(settings.clone(), sessions);
@@ -309,14 +421,21 @@ impl App {
                 // Spawn update check if enabled
                 let update_task = if settings.check_for_updates {
                     Task::perform(
-                        crate::update_checker::check_for_update(),
+                        crate::update_checker::check_for_update(
+                            settings.proxy.clone(),
+                            settings.skipped_update_version.clone(),
+                        ),
                         Message::UpdateCheckResult,
                     )
                 } else {
                     Task::none()
                 };
 
-                return Task::batch([task.map(Message::Notifications), update_task]);
+                return Task::batch([
+                    task.map(Message::Notifications),
+                    update_task,
+                    whats_new_task,
+                ]);
             }
 
             let settings = AppSettings::load();
@@ -349,6 +468,7 @@ impl App {
             LoginMessage::LoginSuccess(client, user) => {
                 let mut settings = AppSettings::load();
                 settings.set_active_account(&user.login);
+                settings.set_token_expiry(&user.login, user.token_expires_at);
                 settings.save_silent();
                 settings.apply_theme();
 
@@ -362,7 +482,7 @@ impl App {
                     user: user.clone(),
                 });
 
-                let (notif_screen, task) = NotificationsScreen::new(client, user);
+                let (notif_screen, task) = NotificationsScreen::new(client, user, &settings);
                 let ctx = AppContext::new(settings, sessions);
                 *self = App::Authenticated(
                     Box::new(Screen::Notifications(Box::new(notif_screen))),
@@ -413,8 +533,93 @@ impl App {
         (task.map(Message::Settings), effect)
     }
 
+    /// Show the login screen on top of the signed-in app (add account or
+    /// re-authenticate), keeping pending settings edits.
+    fn open_account_login(&mut self, login_screen: LoginScreen) -> Task<Message> {
+        let App::Authenticated(current_screen, ctx) = self else {
+            return Task::none();
+        };
+        let settings = match &**current_screen {
+            Screen::Settings(s) => s.settings.clone(),
+            _ => ctx.settings.clone(),
+        };
+        *self = App::Authenticated(
+            Box::new(Screen::AccountLogin(Box::new(login_screen))),
+            ctx.with_settings(settings),
+        );
+        Task::none()
+    }
+
+    fn update_account_login(&mut self, message: Message) -> (Task<Message>, AppEffect) {
+        use crate::ui::handlers::navigation;
+
+        let App::Authenticated(boxed_screen, ctx) = self else {
+            return (Task::none(), AppEffect::None);
+        };
+
+        let Screen::AccountLogin(screen) = &mut **boxed_screen else {
+            return (Task::none(), AppEffect::None);
+        };
+
+        let Message::Login(login_msg) = message else {
+            return (Task::none(), AppEffect::None);
+        };
+
+        match login_msg {
+            LoginMessage::LoginSuccess(client, user) => {
+                let result = match screen.mode() {
+                    LoginMode::Reauth(expected) if ctx.sessions.get(expected).is_none() => {
+                        navigation::sign_in_restored_account(
+                            expected,
+                            client,
+                            user,
+                            &mut ctx.sessions,
+                            &mut ctx.settings,
+                        )
+                    }
+                    LoginMode::Reauth(expected) => {
+                        let expires_at = user.token_expires_at;
+                        navigation::reauthenticate_account(
+                            expected,
+                            client,
+                            user,
+                            &mut ctx.sessions,
+                        )
+                        .map(|()| {
+                            ctx.settings.set_token_expiry(expected, expires_at);
+                            ctx.settings.save_silent();
+                            None
+                        })
+                    }
+                    LoginMode::AddAccount | LoginMode::SignIn => {
+                        navigation::add_account(client, user, &mut ctx.sessions, &mut ctx.settings)
+                    }
+                };
+                match result {
+                    Ok(None) => (Task::none(), AppEffect::Navigate(NavigateTo::Notifications)),
+                    Ok(Some(notice)) => {
+                        let task = self.navigate(NavigateTo::Notifications);
+                        if let App::Authenticated(screen, _) = self
+                            && let Screen::Notifications(notif_screen) = &mut **screen
+                        {
+                            notif_screen.set_notice(notice);
+                        }
+                        (task, AppEffect::None)
+                    }
+                    Err(error) => {
+                        tracing::warn!(error = %error, "Account sign-in failed");
+                        let task = screen.update(LoginMessage::LoginFailed(error));
+                        (task.map(Message::Login), AppEffect::None)
+                    }
+                }
+            }
+            LoginMessage::Cancel => (Task::none(), AppEffect::Navigate(NavigateTo::Back)),
+            other => (screen.update(other).map(Message::Login), AppEffect::None),
+        }
+    }
+
     fn update_rule_engine(&mut self, message: Message) -> (Task<Message>, AppEffect) {
-        let App::Authenticated(boxed_screen, _) = self else {
+        let App::Authenticated(boxed_screen, ctx) = self else {
             return (Task::none(), AppEffect::None);
         };
 
@@ -427,7 +632,7 @@ impl App {
         };
 
         // Let the screen handle the message and return effect
-        let (task, effect) = screen.update_with_effect(rule_msg);
+        let (task, effect) = screen.update_with_effect(rule_msg, ctx);
         (task.map(Message::RuleEngine), effect)
     }
 
@@ -436,17 +641,74 @@ impl App {
     // ========================================================================
 
     fn handle_tick(&mut self) -> Task<Message> {
-        let App::Authenticated(boxed_screen, _) = self else {
+        let App::Authenticated(boxed_screen, ctx) = self else {
             return Task::none();
         };
         let Screen::Notifications(screen) = &mut **boxed_screen else {
             return Task::none();
         };
-        platform::handle_tick(screen)
+        platform::handle_tick(screen, ctx.settings.refresh_interval())
+    }
+
+    /// Toast every reminder that has come due, for any account and on any
+    /// screen, so they fire while paused or hidden too.
+    fn handle_reminder_check(&mut self) -> Task<Message> {
+        let App::Authenticated(boxed_screen, ctx) = self else {
+            return Task::none();
+        };
+        let now = chrono::Utc::now();
+        let due = ctx.settings.take_due_reminders(now);
+        if due.is_empty() {
+            return Task::none();
+        }
+        ctx.settings.save_silent();
+        tracing::info!(count = due.len(), "Reminders due");
+        let outcome = desktop_notify::send_reminders(&due);
+        match &mut **boxed_screen {
+            Screen::Notifications(screen) => screen.reminders_fired(&due, outcome),
+            // Its copy of the settings would bring them back when saved
+            Screen::Settings(s) => {
+                s.settings.take_due_reminders(now);
+            }
+            _ => {}
+        }
+        Task::none()
+    }
+
+    /// The active account's throttle ran out since the last poll.
+    fn take_expired_throttle(&self) -> bool {
+        let App::Authenticated(boxed_screen, _) = self else {
+            return false;
+        };
+        match &**boxed_screen {
+            Screen::Notifications(screen) => screen.client.take_expired_throttle(),
+            _ => false,
+        }
     }
 
     fn handle_tray_poll(&mut self) -> Task<Message> {
-        platform::handle_tray_poll(self.notification_screen_mut())
+        // A timed pause or throttle ran out: catch up right away instead of
+        // on the next tick
+        if state::take_expired_pause() | self.take_expired_throttle() {
+            return self.handle_tick();
+        }
+
+        let (task, effect) = match self {
+            App::Authenticated(boxed, ctx) => {
+                let screen = match &mut **boxed {
+                    Screen::Notifications(s) => Some(s.as_mut()),
+                    _ => None,
+                };
+                platform::handle_tray_poll(screen, Some(&ctx.sessions))
+            }
+            _ => platform::handle_tray_poll(None, None),
+        };
+        if matches!(effect, AppEffect::None) {
+            return task;
+        }
+        let effect_task = self.apply_effect(effect);
+        self.sync_tray_accounts();
+        Task::batch([task, effect_task])
     }
 
     fn handle_window_event(&mut self, id: WindowId, event: window::Event) -> Task<Message> {
@@ -463,7 +725,7 @@ impl App {
         let (settings, notification_screen) = match &mut **boxed_screen {
             Screen::Settings(s) => (Some(&mut s.settings), None),
             Screen::Notifications(s) => (Some(&mut ctx.settings), Some(&mut **s)),
-            Screen::RuleEngine(_, _) => (Some(&mut ctx.settings), None),
+            Screen::RuleEngine(_, _) | Screen::AccountLogin(_) => (Some(&mut ctx.settings), None),
         };
 
         platform::handle_window_event(
@@ -481,6 +743,35 @@ impl App {
     // Helper Methods
     // ========================================================================
 
+    /// Push the current account list to the tray's switcher submenu.
+    fn switch_to_account(&mut self, index: usize) -> Task<Message> {
+        let App::Authenticated(_, ctx) = self else {
+            return Task::none();
+        };
+        // Same list the switcher numbers, so only signed-in accounts count
+        let Some(account) = ctx.account_choices().into_iter().nth(index) else {
+            return Task::none();
+        };
+        tracing::info!("Switching account from keyboard shortcut");
+        self.handle_session_effect(SessionEffect::SwitchAccount(account.username))
+    }
+
+    fn sync_tray_accounts(&self) {
+        let App::Authenticated(_, ctx) = self else {
+            return;
+        };
+        let active = ctx.sessions.primary().map(|s| s.username.clone());
+        let accounts = ctx
+            .account_choices()
+            .into_iter()
+            .map(|choice| crate::tray::TrayAccount {
+                username: choice.username,
+                label: choice.label,
+            })
+            .collect();
+        crate::tray::sync_accounts(accounts, active);
+    }
+
     fn notification_screen_mut(&mut self) -> Option<&mut NotificationsScreen> {
         let App::Authenticated(boxed, _) = self else {
             return None;
@@ -501,9 +792,13 @@ impl App {
             App::Login(screen) => screen.view().map(Message::Login),
             App::Authenticated(boxed_screen, ctx) => match &**boxed_screen {
                 Screen::Notifications(notif_screen) => {
-                    let accounts = ctx.account_names();
+                    let accounts = ctx.account_choices();
 
-                    if ctx.settings.power_mode {
+                    if ctx.settings.mini_mode {
+                        notif_screen
+                            .view_mini(ctx.settings.icon_theme)
+                            .map(Message::Notifications)
+                    } else if ctx.settings.power_mode {
                         features::power_mode::view::app_layout(
                             notif_screen,
                             &ctx.settings,
@@ -516,6 +811,7 @@ impl App {
                                 accounts,
                                 ctx.settings.icon_theme,
                                 ctx.settings.sidebar_width,
+                                ctx.settings.window_width,
                                 false,
                             )
                             .map(Message::Notifications)
@@ -523,6 +819,7 @@ impl App {
                 }
                 Screen::Settings(settings_screen) => settings_screen.view().map(Message::Settings),
                 Screen::RuleEngine(rule_screen, _) => rule_screen.view().map(Message::RuleEngine),
+                Screen::AccountLogin(login_screen) => login_screen.view().map(Message::Login),
             },
         }
     }
@@ -553,31 +850,142 @@ impl App {
     }
 
     pub fn subscription(&self) -> Subscription<Message> {
-        let is_hidden = state::is_hidden();
-
-        let tray_interval = if is_hidden {
-            platform::TRAY_POLL_INTERVAL_HIDDEN_MS
-        } else {
-            platform::TRAY_POLL_INTERVAL_ACTIVE_MS
-        };
-
-        let tray_sub = time::every(Duration::from_millis(tray_interval)).map(|_| Message::TrayPoll);
+        let tray_sub = time::every(platform::tray_poll_interval()).map(|_| Message::TrayPoll);
 
         let window_sub = event::listen_with(|event, _status, id| match event {
             Event::Window(e) => Some(Message::WindowEvent(id, e)),
             _ => None,
         });
 
+        // Tab cycles through focusable inputs on every form (login, settings,
+        // rule engine); the widgets themselves don't handle it.
+        let focus_sub = event::listen_with(|event, status, _id| match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Tab),
+                modifiers,
+                ..
+            }) if status == event::Status::Ignored => Some(if modifiers.shift() {
+                Message::FocusPrevious
+            } else {
+                Message::FocusNext
+            }),
+            _ => None,
+        });
+
         let on_notifications = matches!(
             self,
             App::Authenticated(screen, _) if matches!(&**screen, Screen::Notifications(_))
         );
 
-        let tick_sub = on_notifications.then(|| {
-            time::every(Duration::from_secs(platform::REFRESH_INTERVAL_SECS)).map(|_| Message::Tick)
+        // Escape closes the rule engine's inspector, then leaves the screen.
+        // A focused text input captures Escape to unfocus itself, so typing
+        // into a form never navigates away.
+        let on_rule_engine = matches!(
+            self,
+            App::Authenticated(screen, _) if matches!(&**screen, Screen::RuleEngine(_, _))
+        );
+        let escape_sub = on_rule_engine.then(|| {
+            event::listen_with(|event, status, _id| match event {
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key: keyboard::Key::Named(keyboard::key::Named::Escape),
+                    ..
+                }) if status == event::Status::Ignored => {
+                    Some(Message::RuleEngine(RuleEngineMessage::Escape))
+                }
+                _ => None,
+            })
+        });
+
+        // List navigation and triage keys; skipped while a text input has focus
+        let mini_mode = matches!(
+            self,
+            App::Authenticated(_, ctx) if ctx.settings.mini_mode
+        );
+        let triage_sub = (on_notifications && !mini_mode).then(|| {
+            event::listen_with(|event, status, _id| {
+                let Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. }) = event
+                else {
+                    return None;
+                };
+                if status == event::Status::Captured || modifiers.alt() {
+                    return None;
+                }
+                if modifiers.command() && !modifiers.shift() {
+                    return matches!(key.as_ref(), keyboard::Key::Character("a"))
+                        .then_some(Message::SelectionKey(SelectionKey::SelectAll));
+                }
+                if modifiers.control() || modifiers.logo() {
+                    return None;
+                }
+                let key = match key.as_ref() {
+                    keyboard::Key::Named(keyboard::key::Named::Escape) => SelectionKey::Cancel,
+                    keyboard::Key::Named(keyboard::key::Named::ArrowDown)
+                    | keyboard::Key::Character("j") => SelectionKey::Next,
+                    keyboard::Key::Named(keyboard::key::Named::ArrowUp)
+                    | keyboard::Key::Character("k") => SelectionKey::Previous,
+                    keyboard::Key::Named(keyboard::key::Named::Enter)
+                    | keyboard::Key::Character("o") => SelectionKey::Open,
+                    keyboard::Key::Character("e") => SelectionKey::MarkRead,
+                    keyboard::Key::Character("d") => SelectionKey::MarkDone,
+                    keyboard::Key::Character("m") => SelectionKey::Mute,
+                    _ => return None,
+                };
+                Some(Message::SelectionKey(key))
+            })
+        });
+
+        // Ctrl+1..9 (Cmd on macOS) switches accounts
+        let account_sub = on_notifications.then(|| {
+            event::listen_with(|event, status, _id| match event {
+                Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. })
+                    if status == event::Status::Ignored
+                        && modifiers.command()
+                        && !modifiers.shift()
+                        && !modifiers.alt() =>
+                {
+                    let keyboard::Key::Character(c) = key.as_ref() else {
+                        return None;
+                    };
+                    match c.parse::<usize>() {
+                        Ok(n @ 1..=9) => Some(Message::AccountShortcut(n - 1)),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            })
+        });
+
+        // No ticks while notifications are paused; the tray poll resumes them
+        let paused = state::paused_until().is_some();
+        let tick_sub = match self {
+            App::Authenticated(screen, ctx) if !paused => match &**screen {
+                Screen::Notifications(s) => Some(
+                    time::every(s.refresh_tick_period(ctx.settings.refresh_interval()))
+                        .map(|_| Message::Tick),
+                ),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        let cache_sub = time::every(Duration::from_secs(
+            platform::CACHE_MAINTENANCE_INTERVAL_SECS,
+        ))
+        .map(|_| Message::CacheMaintenance);
+
+        let reminder_sub = matches!(self, App::Authenticated(..)).then(|| {
+            time::every(Duration::from_secs(platform::REMINDER_CHECK_INTERVAL_SECS))
+                .map(|_| Message::ReminderCheck)
         });
 
-        let subs: Vec<_> = tick_sub.into_iter().chain([tray_sub, window_sub]).collect();
+        let subs: Vec<_> = tick_sub
+            .into_iter()
+            .chain(triage_sub)
+            .chain(account_sub)
+            .chain(escape_sub)
+            .chain(reminder_sub)
+            .chain([tray_sub, window_sub, focus_sub, cache_sub])
+            .collect();
         Subscription::batch(subs)
     }
 
@@ -27,8 +27,39 @@ impl AppContext {
         }
     }
 
-    /// Get list of account usernames.
-    pub fn account_names(&self) -> Vec<String> {
-        self.sessions.usernames().map(String::from).collect()
+    /// Signed-in accounts for switchers, in `settings.accounts` order and
+    /// labelled with their display names.
+    pub fn account_choices(&self) -> Vec<AccountChoice> {
+        self.settings
+            .accounts
+            .iter()
+            .filter(|account| self.sessions.get(&account.username).is_some())
+            .enumerate()
+            .map(|(index, account)| AccountChoice {
+                username: account.username.clone(),
+                label: self.settings.account_label(&account.username),
+                shortcut: (index < 9).then_some(index + 1),
+            })
+            .collect()
+    }
+}
+
+/// An account in a switcher: keyed by `username`, shown as `label`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountChoice {
+    pub username: String,
+    pub label: String,
+    /// Digit that switches to this account with Ctrl (Cmd on macOS).
+    pub shortcut: Option<usize>,
+}
+
+impl std::fmt::Display for AccountChoice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.label)?;
+        match self.shortcut {
+            Some(n) if cfg!(target_os = "macos") => write!(f, "  ⌘{n}"),
+            Some(n) => write!(f, "  Ctrl+{n}"),
+            None => Ok(()),
+        }
     }
 }
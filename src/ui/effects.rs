@@ -21,8 +21,16 @@ pub enum AppEffect {
 pub enum NavigateTo {
     Notifications,
     Settings,
-    RuleEngine { from_settings: bool },
+    /// Settings, opened on the Network Proxy tab.
+    ProxySettings,
+    RuleEngine {
+        from_settings: bool,
+    },
     Login,
+    /// Login screen scoped to replacing one account's token.
+    Reauthenticate(String),
+    /// Login screen that signs in an additional account.
+    AddAccount,
     Back,
 }
 
@@ -32,4 +40,6 @@ pub enum SessionEffect {
     Logout,
     SwitchAccount(String),
     RemoveAccount(String),
+    /// Wipe cache, tokens, and settings, then return to login.
+    ResetAll,
 }
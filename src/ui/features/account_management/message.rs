@@ -3,5 +3,14 @@ pub enum AccountMessage {
     TokenInputChanged(String),
     SubmitToken,
     TokenValidated(Result<String, String>),
+    /// Ask for confirmation before removing (trash button).
+    RequestRemoveAccount(String),
+    CancelRemoveAccount,
     RemoveAccount(String),
+    /// Edit an account's display name; kept as a draft until saved.
+    DisplayNameChanged(String, String),
+    /// Save the drafted display name for this account.
+    SaveDisplayName(String),
+    /// Replace this account's token (handled by the settings screen).
+    Reauthenticate(String),
 }
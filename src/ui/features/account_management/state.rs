@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 #[derive(Debug, Clone, Default)]
 pub enum SubmissionStatus {
     #[default]
@@ -11,4 +13,8 @@ pub enum SubmissionStatus {
 pub struct AccountManagementState {
     pub token_input: String,
     pub status: SubmissionStatus,
+    /// Account awaiting confirmation in the remove dialog.
+    pub pending_removal: Option<String>,
+    /// Display names being edited, by login. Saved on Enter or "Save".
+    pub display_name_drafts: HashMap<String, String>,
 }
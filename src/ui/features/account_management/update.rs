@@ -47,9 +47,25 @@ pub fn update(
         AccountMessage::TokenValidated(result) => {
             match result {
                 Ok(username) => {
+                    let existing = settings
+                        .accounts
+                        .iter()
+                        .find(|a| a.username.eq_ignore_ascii_case(&username))
+                        .map(|a| a.username.clone());
+                    state.token_input.clear();
+
+                    if let Some(existing) = existing {
+                        // Same login again: the keyring entry was overwritten above
+                        settings.set_active_account(&existing);
+                        let _ = settings.save();
+                        state.status =
+                            SubmissionStatus::Success(format!("Updated token for @{existing}"));
+                        tracing::info!("Existing account token updated");
+                        return Task::none();
+                    }
+
                     settings.set_active_account(&username);
                     let _ = settings.save();
-                    state.token_input.clear();
                     state.status = SubmissionStatus::Success(format!(
                         "Account '{}' added successfully!",
                         username
@@ -64,7 +80,28 @@ pub fn update(
             }
             Task::none()
         }
+        AccountMessage::Reauthenticate(_) => Task::none(),
+        AccountMessage::DisplayNameChanged(username, name) => {
+            state.display_name_drafts.insert(username, name);
+            Task::none()
+        }
+        AccountMessage::SaveDisplayName(username) => {
+            if let Some(name) = state.display_name_drafts.remove(&username) {
+                settings.set_display_name(&username, &name);
+                let _ = settings.save();
+            }
+            Task::none()
+        }
+        AccountMessage::RequestRemoveAccount(username) => {
+            state.pending_removal = Some(username);
+            Task::none()
+        }
+        AccountMessage::CancelRemoveAccount => {
+            state.pending_removal = None;
+            Task::none()
+        }
         AccountMessage::RemoveAccount(username) => {
+            state.pending_removal = None;
             settings.remove_account(&username);
             let _ = settings.save();
             let _ = keyring::delete_token(&username);
@@ -1,5 +1,7 @@
-use iced::widget::{Space, button, column, container, row, text, text_input};
-use iced::{Alignment, Element, Fill};
+use iced::widget::{
+    Space, button, center, column, container, mouse_area, opaque, row, text, text_input,
+};
+use iced::{Alignment, Element, Fill, Length};
 
 use crate::settings::{AppSettings, StoredAccount};
 use crate::ui::screens::settings::components::{setting_card, tab_title};
@@ -23,7 +25,7 @@ pub fn view<'a>(
         Space::new().height(16),
         view_add_account_section(state, settings),
         Space::new().height(16),
-        view_accounts_list(settings),
+        view_accounts_list(state, settings),
     ]
     .spacing(4)
     .padding(24)
@@ -55,6 +57,10 @@ fn view_add_account_section<'a>(
         row![
             text_input("ghp_xxxxxxxxxxxx", &state.token_input)
                 .on_input(AccountMessage::TokenInputChanged)
+                .on_submit_maybe(
+                    (!is_validating && !state.token_input.is_empty())
+                        .then_some(AccountMessage::SubmitToken)
+                )
                 .padding([8, 12])
                 .size(13)
                 .width(Fill)
@@ -93,7 +99,63 @@ fn view_add_account_section<'a>(
     setting_card(content)
 }
 
-fn view_accounts_list(settings: &AppSettings) -> Element<'static, AccountMessage> {
+/// Modal asking to confirm removal of `username`; drawn over the settings screen.
+pub fn view_remove_confirmation(username: &str) -> Element<'static, AccountMessage> {
+    let p = theme::palette();
+
+    let dialog = container(
+        column![
+            text("Remove account?").size(16).color(p.text_primary),
+            Space::new().height(8),
+            text(format!(
+                "'{}' will be signed out and its saved token deleted. \
+                 Adding it again requires a new token.",
+                username
+            ))
+            .size(12)
+            .color(p.text_secondary),
+            Space::new().height(16),
+            row![
+                Space::new().width(Fill),
+                button(text("Cancel").size(12))
+                    .on_press(AccountMessage::CancelRemoveAccount)
+                    .padding([8, 12])
+                    .style(theme::ghost_button),
+                button(text("Remove").size(12).color(iced::Color::WHITE))
+                    .on_press(AccountMessage::RemoveAccount(username.to_string()))
+                    .padding([8, 12])
+                    .style(theme::danger_button),
+            ]
+            .spacing(8),
+        ]
+        .padding(20),
+    )
+    .width(Length::Fixed(360.0))
+    .style(move |_| container::Style {
+        background: Some(iced::Background::Color(p.bg_card)),
+        border: iced::Border {
+            color: p.border,
+            width: 1.0,
+            radius: 8.0.into(),
+        },
+        ..Default::default()
+    });
+
+    // Clicking the backdrop cancels, like pressing Cancel
+    let backdrop = center(opaque(dialog)).style(|_| container::Style {
+        background: Some(iced::Background::Color(iced::Color::from_rgba(
+            0.0, 0.0, 0.0, 0.5,
+        ))),
+        ..Default::default()
+    });
+
+    opaque(mouse_area(backdrop).on_press(AccountMessage::CancelRemoveAccount))
+}
+
+fn view_accounts_list(
+    state: &AccountManagementState,
+    settings: &AppSettings,
+) -> Element<'static, AccountMessage> {
     let p = theme::palette();
 
     if settings.accounts.is_empty() {
@@ -105,7 +167,7 @@ fn view_accounts_list(settings: &AppSettings) -> Element<'static, AccountMessage
     let account_items = settings
         .accounts
         .iter()
-        .map(|account| view_account_item(account, settings));
+        .map(|account| view_account_item(account, state, settings));
 
     column![
         text("Connected Accounts").size(13).color(p.text_secondary),
@@ -118,6 +180,7 @@ fn view_accounts_list(settings: &AppSettings) -> Element<'static, AccountMessage
 
 fn view_account_item(
     account: &StoredAccount,
+    state: &AccountManagementState,
     settings: &AppSettings,
 ) -> Element<'static, AccountMessage> {
     let p = theme::palette();
@@ -126,18 +189,48 @@ fn view_account_item(
     // We need owned strings for both output elements because we are returning Element<'static>
     let username_display = account.username.clone();
     let username_msg = account.username.clone();
+    let username_reauth = account.username.clone();
+    let username_label = account.username.clone();
+    let draft = state.display_name_drafts.get(&account.username);
+    let display_name = draft
+        .or(account.display_name.as_ref())
+        .cloned()
+        .unwrap_or_default();
+    let save = draft
+        .is_some()
+        .then(|| AccountMessage::SaveDisplayName(account.username.clone()));
 
     container(
         row![
             icons::icon_user(14.0, p.text_secondary, icon_theme),
             Space::new().width(8),
             text(username_display).size(13).color(p.text_primary),
-            Space::new().width(8),
+            Space::new().width(12),
+            text_input("Display name (optional)", &display_name)
+                .on_input(move |name| AccountMessage::DisplayNameChanged(
+                    username_label.clone(),
+                    name
+                ))
+                .on_submit_maybe(save.clone())
+                .padding([6, 10])
+                .size(12)
+                .width(Length::Fixed(180.0))
+                .style(theme::text_input_style),
+            Space::new().width(4),
+            button(text("Save").size(12))
+                .style(theme::ghost_button)
+                .padding([6, 10])
+                .on_press_maybe(save),
             Space::new().width(Fill),
+            button(text("Re-authenticate").size(12))
+                .style(theme::ghost_button)
+                .padding([6, 10])
+                .on_press(AccountMessage::Reauthenticate(username_reauth)),
+            Space::new().width(4),
             button(icons::icon_trash(14.0, p.text_muted, icon_theme))
                 .style(theme::ghost_button)
                 .padding(6)
-                .on_press(AccountMessage::RemoveAccount(username_msg)),
+                .on_press(AccountMessage::RequestRemoveAccount(username_msg)),
         ]
         .align_y(Alignment::Center)
         .padding(14),
@@ -9,6 +9,8 @@ use std::collections::HashSet;
 
 use crate::settings::IconTheme;
 use crate::ui::icons;
+use crate::ui::screens::notifications::RuleImpact;
+use crate::ui::screens::settings::rule_engine::components::view_impact_preview;
 use crate::ui::screens::settings::rule_engine::rules::{
     AccountRule, NotificationRuleSet, OutsideScheduleBehavior,
 };
@@ -22,6 +24,7 @@ pub fn view_account_rules_tab<'a>(
     selected_account_id: &Option<String>,
     expanded_time_windows: &HashSet<String>,
     _accounts: &[String], // unused for now, we rely on rules.account_rules
+    impact: Option<RuleImpact>,
 ) -> Element<'a, RuleEngineMessage> {
     let p = theme::palette();
 
@@ -55,7 +58,7 @@ pub fn view_account_rules_tab<'a>(
     // 3. Right Pane: Details
     let details_pane = if let Some(id) = selected_account_id {
         if let Some(rule) = rules.account_rules.iter().find(|r| r.id == *id) {
-            view_account_details(rule, icon_theme)
+            view_account_details(rule, impact, icon_theme)
         } else {
             Space::new().into()
         }
@@ -336,7 +339,8 @@ fn view_schedule_config<'a>(
                         Some(current_end_str.clone())
                     )))
                     .width(Length::Fixed(80.0))
-                    .padding(6),
+                    .padding(6)
+                    .style(theme::text_input_style),
                 Space::new().width(16),
                 text("To:").size(13).color(p.text_muted),
                 text_input("17:00", &end_val)
@@ -346,7 +350,8 @@ fn view_schedule_config<'a>(
                         Some(s)
                     )))
                     .width(Length::Fixed(80.0))
-                    .padding(6),
+                    .padding(6)
+                    .style(theme::text_input_style),
             ]
             .align_y(Alignment::Center),
             Space::new().height(8),
@@ -428,6 +433,7 @@ fn view_schedule_config<'a>(
 
 fn view_account_details<'a>(
     rule: &'a AccountRule,
+    impact: Option<RuleImpact>,
     _icon_theme: IconTheme,
 ) -> Element<'a, RuleEngineMessage> {
     let p = theme::palette();
@@ -492,6 +498,13 @@ fn view_account_details<'a>(
         text("• Notifications will be delivered when account becomes active")
             .size(13)
             .color(p.text_muted),
+        Space::new().height(24),
+        text("Current Impact").size(14).font(iced::Font {
+            weight: iced::font::Weight::Bold,
+            ..Default::default()
+        }),
+        Space::new().height(8),
+        view_impact_preview(impact, "affects"),
     ]
     .padding(24)
     .into()
@@ -0,0 +1,14 @@
+//! Actor rule messages.
+
+use crate::ui::screens::settings::rule_engine::rules::RuleAction;
+
+/// Messages for actor rule operations.
+#[derive(Debug, Clone)]
+pub enum ActorRuleMessage {
+    Toggle(String, bool),
+    Delete(String),
+    FormActorChanged(String),
+    FormPriorityChanged(i32),
+    FormActionChanged(RuleAction),
+    Add,
+}
@@ -0,0 +1,13 @@
+//! Actor Rules feature module for Rule Engine.
+//!
+//! Handles rules keyed on the user who triggered a notification.
+
+mod message;
+mod state;
+mod update;
+mod view;
+
+pub use message::ActorRuleMessage;
+pub use state::ActorRuleFormState;
+pub use update::update_actor_rule;
+pub use view::view_actor_rules_tab;
@@ -0,0 +1,28 @@
+//! Actor rule form state.
+
+use crate::ui::screens::settings::rule_engine::rules::RuleAction;
+
+/// State for the actor rule creation form.
+#[derive(Debug, Clone)]
+pub struct ActorRuleFormState {
+    pub actor: String,
+    pub priority: i32,
+    pub action: RuleAction,
+}
+
+impl Default for ActorRuleFormState {
+    fn default() -> Self {
+        Self {
+            actor: String::new(),
+            priority: 0,
+            action: RuleAction::Show,
+        }
+    }
+}
+
+impl ActorRuleFormState {
+    /// Reset form to defaults after adding a rule.
+    pub fn reset_form(&mut self) {
+        *self = Self::default();
+    }
+}
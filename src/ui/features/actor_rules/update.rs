@@ -0,0 +1,68 @@
+//! Actor rule update logic.
+
+use iced::Task;
+
+use crate::ui::screens::settings::rule_engine::rules::{ActorRule, NotificationRuleSet};
+
+use super::message::ActorRuleMessage;
+use super::state::ActorRuleFormState;
+
+/// Update actor rule state based on message.
+///
+/// Returns Task::none() since all operations are synchronous.
+pub fn update_actor_rule(
+    state: &mut ActorRuleFormState,
+    message: ActorRuleMessage,
+    rules: &mut NotificationRuleSet,
+) -> Task<ActorRuleMessage> {
+    match message {
+        ActorRuleMessage::Toggle(id, enabled) => {
+            if let Some(rule) = rules.actor_rules.iter_mut().find(|r| r.id == id) {
+                rule.enabled = enabled;
+            }
+            let _ = rules.save();
+            tracing::info!(rule_id = %id, enabled, "Actor rule enabled state updated");
+        }
+
+        ActorRuleMessage::Delete(id) => {
+            rules.actor_rules.retain(|r| r.id != id);
+            let _ = rules.save();
+            tracing::info!(rule_id = %id, "Actor rule deleted");
+        }
+
+        ActorRuleMessage::FormActorChanged(s) => {
+            state.actor = s;
+        }
+
+        ActorRuleMessage::FormPriorityChanged(p) => {
+            state.priority = p;
+        }
+
+        ActorRuleMessage::FormActionChanged(a) => {
+            state.action = a;
+        }
+
+        ActorRuleMessage::Add => {
+            let actor = state.actor.trim().trim_start_matches('@');
+            if actor.is_empty() {
+                return Task::none();
+            }
+
+            let mut rule = ActorRule::new(actor, state.priority);
+            rule.action = state.action;
+
+            let rule_id = rule.id.clone();
+            let action = rule.action;
+            let priority = rule.priority;
+
+            rules.actor_rules.push(rule);
+            let _ = rules.save();
+
+            state.reset_form();
+
+            tracing::info!(rule_id = %rule_id, action = ?action, priority, "Actor rule added");
+        }
+    }
+
+    Task::none()
+}
@@ -0,0 +1,203 @@
+use iced::widget::{
+    Space, button, column, container, pick_list, row, slider, text, text_input, toggler,
+};
+use iced::{Alignment, Element, Fill, Length};
+
+use crate::settings::IconTheme;
+use crate::ui::icons;
+use crate::ui::screens::settings::rule_engine::rules::{
+    ActorRule, NotificationRuleSet, RuleAction,
+};
+use crate::ui::theme;
+
+use super::ActorRuleFormState;
+use super::message::ActorRuleMessage;
+use crate::ui::screens::settings::rule_engine::components::{view_empty_state, view_warning_row};
+use crate::ui::screens::settings::rule_engine::messages::{InspectorMessage, RuleEngineMessage};
+
+pub fn view_actor_rules_tab<'a>(
+    rules: &'a NotificationRuleSet,
+    icon_theme: IconTheme,
+    form_state: &ActorRuleFormState,
+) -> Element<'a, RuleEngineMessage> {
+    let p = theme::palette();
+
+    // ========================================================================
+    // Form Section
+    // ========================================================================
+    let can_add = !form_state.actor.trim().trim_start_matches('@').is_empty();
+    let add_msg = can_add.then_some(RuleEngineMessage::Actor(ActorRuleMessage::Add));
+
+    let actor_input = container(
+        column![
+            text("User").size(12).color(p.text_secondary),
+            text_input("e.g. dependabot[bot]", &form_state.actor)
+                .on_input(|s| RuleEngineMessage::Actor(ActorRuleMessage::FormActorChanged(s)))
+                .on_submit_maybe(add_msg.clone())
+                .size(13)
+                .padding(6)
+                .width(Length::Fixed(180.0))
+                .style(theme::text_input_style),
+        ]
+        .spacing(4),
+    );
+
+    let priority_input = container(
+        column![
+            row![
+                text("Priority").size(12).color(p.text_secondary),
+                Space::new().width(8),
+                text(format!("{}", form_state.priority))
+                    .size(12)
+                    .color(p.text_primary),
+            ]
+            .align_y(Alignment::Center),
+            slider(-100..=100, form_state.priority, |p| {
+                RuleEngineMessage::Actor(ActorRuleMessage::FormPriorityChanged(p))
+            })
+            .width(Length::Fixed(150.0)),
+        ]
+        .spacing(4),
+    );
+
+    let action_input = container(
+        column![
+            text("Action").size(12).color(p.text_secondary),
+            pick_list(RuleAction::ALL, Some(form_state.action), |a| {
+                RuleEngineMessage::Actor(ActorRuleMessage::FormActionChanged(a))
+            })
+            .width(Length::Fixed(100.0))
+            .style(theme::pick_list_style)
+            .menu_style(theme::menu_style),
+        ]
+        .spacing(4),
+    );
+
+    let add_btn = button(text("Add Rule").size(13))
+        .style(theme::primary_button)
+        .on_press_maybe(add_msg)
+        .padding([8, 16]);
+
+    let form_row = row![
+        actor_input,
+        priority_input,
+        action_input,
+        Space::new().width(Fill),
+        column![Space::new().height(19), add_btn].spacing(0),
+    ]
+    .spacing(12)
+    .align_y(Alignment::End);
+
+    let form_section = container(form_row)
+        .padding(16)
+        .style(move |_| container::Style {
+            background: Some(iced::Background::Color(p.bg_control)),
+            border: iced::Border {
+                radius: 8.0.into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+
+    let header = column![
+        text("Actor Rules").size(20).color(p.text_primary),
+        text("Prioritize or silence notifications triggered by specific users.")
+            .size(12)
+            .color(p.text_secondary),
+        text("Needs \"Show Who Triggered It\" in General settings; items whose author isn't known yet are left alone.")
+            .size(11)
+            .color(p.text_muted),
+    ]
+    .spacing(4);
+
+    let rules_list: Element<_> = if rules.actor_rules.is_empty() {
+        view_empty_state("No actor rules configured.", icon_theme)
+    } else {
+        column(
+            rules
+                .actor_rules
+                .iter()
+                .map(|rule| view_actor_rule_card(rule, icon_theme)),
+        )
+        .spacing(8)
+        .into()
+    };
+
+    column![
+        header,
+        Space::new().height(16),
+        form_section,
+        Space::new().height(24),
+        rules_list,
+    ]
+    .padding(24)
+    .width(Fill)
+    .into()
+}
+
+// ============================================================================
+// Actor Rule Card
+// ============================================================================
+
+fn view_actor_rule_card(
+    rule: &ActorRule,
+    icon_theme: IconTheme,
+) -> Element<'static, RuleEngineMessage> {
+    let p = theme::palette();
+    let id_toggle = rule.id.clone();
+    let id_delete = rule.id.clone();
+    let id_select = rule.id.clone();
+
+    let mut info_column = column![
+        text(format!("@{}", rule.actor))
+            .size(14)
+            .color(p.text_primary),
+        Space::new().height(4),
+        text(format!(
+            "Priority: {} • Action: {}",
+            rule.priority,
+            rule.action.display_label()
+        ))
+        .size(11)
+        .color(p.text_muted),
+    ]
+    .width(Fill);
+
+    if rule.action == RuleAction::Hide {
+        info_column = info_column.push(Space::new().height(4));
+        info_column = info_column.push(view_warning_row("Hides notifications", icon_theme));
+    }
+
+    let clickable_info = button(info_column)
+        .style(theme::ghost_button)
+        .padding(0)
+        .on_press(RuleEngineMessage::Inspector(InspectorMessage::Select(
+            id_select,
+        )));
+
+    let delete_btn = button(icons::icon_trash(14.0, p.text_muted, icon_theme))
+        .style(theme::ghost_button)
+        .padding(6)
+        .on_press(RuleEngineMessage::Actor(ActorRuleMessage::Delete(
+            id_delete,
+        )));
+
+    container(
+        row![
+            clickable_info,
+            Space::new().width(Fill),
+            delete_btn,
+            Space::new().width(8),
+            toggler(rule.enabled)
+                .on_toggle(move |e| RuleEngineMessage::Actor(ActorRuleMessage::Toggle(
+                    id_toggle.clone(),
+                    e
+                )))
+                .size(18),
+        ]
+        .align_y(Alignment::Center)
+        .padding(14),
+    )
+    .style(|_| theme::rule_card_container())
+    .into()
+}
@@ -0,0 +1,9 @@
+//! Actor lookup messages.
+
+use crate::github::GitHubError;
+
+#[derive(Debug, Clone)]
+pub enum ActorMessage {
+    /// Actor login fetched for a comment or subject URL.
+    Fetched(String, Result<Option<String>, GitHubError>),
+}
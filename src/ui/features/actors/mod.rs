@@ -0,0 +1,7 @@
+mod message;
+mod state;
+mod update;
+
+pub use message::ActorMessage;
+pub use state::ActorState;
+pub use update::{apply_cached_actors, prefetch_actors, update_actors};
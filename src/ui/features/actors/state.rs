@@ -0,0 +1,20 @@
+//! Actor state.
+//!
+//! This feature resolves who triggered each notification:
+//! - Prefetching the comment or subject author for visible items after a refresh
+//! - Caching logins per lookup URL across refreshes
+
+use std::collections::{HashMap, HashSet};
+
+/// Cached actor logins keyed by the API URL they were read from.
+#[derive(Debug, Clone, Default)]
+pub struct ActorState {
+    pub actors: HashMap<String, String>,
+    pub pending: HashSet<String>,
+}
+
+impl ActorState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
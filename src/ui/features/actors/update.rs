@@ -0,0 +1,98 @@
+//! Actor update logic.
+
+use iced::Task;
+
+use crate::github::{GitHubClient, NotificationView};
+
+use super::message::ActorMessage;
+use super::state::ActorState;
+
+/// Upper bound on lookups started per refresh.
+///
+/// Each lookup is one API call, so only the items at the top of the list
+/// (the ones on screen) are resolved eagerly.
+const MAX_PREFETCH: usize = 20;
+
+/// The URL whose author triggered the notification: the latest comment when
+/// there is one, otherwise the subject itself.
+fn lookup_url(notif: &NotificationView) -> Option<&String> {
+    notif.latest_comment_url.as_ref().or(notif.url.as_ref())
+}
+
+/// Start fetching actors for items that aren't cached yet.
+///
+/// `notifications` should be in display order so the visible items go first.
+pub fn prefetch_actors<'a>(
+    state: &mut ActorState,
+    notifications: impl IntoIterator<Item = &'a NotificationView>,
+    client: &GitHubClient,
+) -> Task<ActorMessage> {
+    let mut urls: Vec<String> = Vec::new();
+    for url in notifications.into_iter().filter_map(lookup_url) {
+        if urls.len() == MAX_PREFETCH {
+            break;
+        }
+        if !state.actors.contains_key(url) && !state.pending.contains(url) && !urls.contains(url) {
+            urls.push(url.clone());
+        }
+    }
+
+    let tasks: Vec<_> = urls
+        .into_iter()
+        .map(|url| {
+            state.pending.insert(url.clone());
+            let client = client.clone();
+            let key = url.clone();
+            Task::perform(async move { client.get_actor(&url).await }, move |result| {
+                ActorMessage::Fetched(key.clone(), result)
+            })
+        })
+        .collect();
+
+    Task::batch(tasks)
+}
+
+/// Copy cached actors onto freshly fetched notifications.
+pub fn apply_cached_actors(state: &ActorState, notifications: &mut [NotificationView]) {
+    for notif in notifications {
+        if let Some(actor) = lookup_url(notif).and_then(|url| state.actors.get(url)) {
+            notif.actor = Some(actor.clone());
+        }
+    }
+}
+
+/// Update actor state.
+///
+/// Returns `true` when a notification's actor changed and the groups need
+/// rebuilding.
+pub fn update_actors(
+    state: &mut ActorState,
+    message: ActorMessage,
+    notifications: &mut [NotificationView],
+) -> bool {
+    match message {
+        ActorMessage::Fetched(url, result) => {
+            state.pending.remove(&url);
+            let actor = match result {
+                Ok(Some(actor)) => actor,
+                Ok(None) => return false,
+                Err(e) => {
+                    tracing::debug!(error = %e, "Failed to fetch notification actor");
+                    return false;
+                }
+            };
+
+            state.actors.insert(url.clone(), actor.clone());
+
+            let mut changed = false;
+            for notif in notifications
+                .iter_mut()
+                .filter(|n| lookup_url(n) == Some(&url))
+            {
+                changed |= notif.actor.as_deref() != Some(actor.as_str());
+                notif.actor = Some(actor.clone());
+            }
+            changed
+        }
+    }
+}
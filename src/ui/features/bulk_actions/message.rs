@@ -1,7 +1,5 @@
 //! Bulk action messages.
 
-use crate::github::GitHubError;
-
 #[derive(Debug, Clone)]
 pub enum BulkActionMessage {
     ToggleMode,
@@ -10,5 +8,31 @@ pub enum BulkActionMessage {
     Clear,
     MarkAsRead,
     MarkAsDone,
-    Complete(Result<(), GitHubError>),
+    /// Open every selected thread in the browser (asks first above a threshold).
+    OpenSelected,
+    ConfirmOpenSelected,
+    CancelOpenSelected,
+    /// Mark the listed threads (everything the current filters show) as done.
+    /// Always asks first.
+    MarkAllAsDone(Vec<String>),
+    ConfirmMarkAllAsDone,
+    CancelMarkAllAsDone,
+    /// Mark the given unread notifications of one subject type as read.
+    MarkTypeAsRead(Vec<String>),
+    Complete(BulkOutcome),
+}
+
+/// Which API call a bulk operation makes per thread.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BulkOp {
+    MarkRead,
+    MarkDone,
+}
+
+/// Result of a bulk operation: which threads succeeded and which failed.
+#[derive(Debug, Clone)]
+pub struct BulkOutcome {
+    pub op: BulkOp,
+    pub succeeded: Vec<String>,
+    pub failed: Vec<String>,
 }
@@ -2,7 +2,7 @@ mod message;
 mod state;
 mod update;
 
-pub use message::BulkActionMessage;
+pub use message::{BulkActionMessage, BulkOp};
 pub use state::BulkActionState;
 pub use update::update_bulk_action;
 pub mod view;
@@ -5,6 +5,8 @@
 //! - Select/deselect notifications
 //! - Bulk mark as read
 //! - Bulk mark as done
+//! - Bulk open in browser
+//! - Mark everything visible as done
 
 use std::collections::HashSet;
 
@@ -13,6 +15,14 @@ use std::collections::HashSet;
 pub struct BulkActionState {
     pub selected_ids: HashSet<String>,
     pub bulk_mode: bool,
+    /// Summary of the last bulk operation when some requests failed.
+    pub status: Option<String>,
+    /// Waiting for the user to confirm opening a large selection.
+    pub confirm_open: bool,
+    /// Threads waiting for the user to confirm "Mark all as done".
+    pub confirm_done_all: Option<Vec<String>>,
+    /// Mirrors `AppSettings::bulk_open_marks_read`.
+    pub open_marks_read: bool,
 }
 
 impl BulkActionState {
@@ -35,5 +45,7 @@ impl BulkActionState {
         self.selected_ids.clear();
         self.selected_ids.shrink_to_fit();
         self.bulk_mode = false;
+        self.status = None;
+        self.confirm_open = false;
     }
 }
@@ -1,16 +1,31 @@
 //! Bulk action update logic.
 
+use std::collections::HashSet;
+
 use iced::Task;
+use iced::futures::{StreamExt, stream};
 
-use crate::github::{GitHubClient, GitHubError, NotificationView};
+use crate::github::{GitHubClient, NotificationView};
+use crate::ui::screens::notifications::helper::api_url_to_web_url;
 
-use super::message::BulkActionMessage;
+use super::message::{BulkActionMessage, BulkOp, BulkOutcome};
 use super::state::BulkActionState;
 
+/// Requests in flight at once; enough to be quick without tripping
+/// GitHub's secondary rate limits.
+const BULK_CONCURRENCY: usize = 6;
+
+/// Selections larger than this ask before opening browser tabs.
+pub const OPEN_CONFIRM_THRESHOLD: usize = 10;
+
+/// Hard cap on tabs opened by one "Open selected".
+pub const OPEN_MAX_TABS: usize = 30;
+
 /// Result of a bulk action update.
 pub struct BulkActionResult {
     pub task: Task<BulkActionMessage>,
     pub needs_rebuild: bool,
+    pub needs_refresh: bool,
 }
 
 impl BulkActionResult {
@@ -18,6 +33,7 @@ impl BulkActionResult {
         Self {
             task: Task::none(),
             needs_rebuild: false,
+            needs_refresh: false,
         }
     }
 
@@ -25,10 +41,49 @@ impl BulkActionResult {
         Self {
             task,
             needs_rebuild: true,
+            needs_refresh: false,
         }
     }
 }
 
+/// Run `op` for every thread with bounded concurrency.
+fn run_bulk(client: &GitHubClient, op: BulkOp, ids: Vec<String>) -> Task<BulkActionMessage> {
+    let client = client.clone();
+    Task::perform(
+        async move {
+            let results: Vec<(String, bool)> = stream::iter(ids)
+                .map(|id| {
+                    let client = client.clone();
+                    async move {
+                        let result = match op {
+                            BulkOp::MarkRead => client.mark_as_read(&id).await,
+                            BulkOp::MarkDone => client.mark_thread_as_done(&id).await,
+                        };
+                        if let Err(e) = &result {
+                            tracing::debug!(thread_id = %id, error = %e, "Bulk request failed");
+                        }
+                        (id, result.is_ok())
+                    }
+                })
+                .buffer_unordered(BULK_CONCURRENCY)
+                .collect()
+                .await;
+
+            let (succeeded, failed): (Vec<_>, Vec<_>) =
+                results.into_iter().partition(|(_, ok)| *ok);
+            let ids =
+                |results: Vec<(String, bool)>| results.into_iter().map(|(id, _)| id).collect();
+            let (succeeded, failed) = (ids(succeeded), ids(failed));
+            BulkOutcome {
+                op,
+                succeeded,
+                failed,
+            }
+        },
+        BulkActionMessage::Complete,
+    )
+}
+
 /// Update bulk action state and return any side effects.
 pub fn update_bulk_action(
     state: &mut BulkActionState,
@@ -39,6 +94,7 @@ pub fn update_bulk_action(
     match message {
         BulkActionMessage::ToggleMode => {
             state.bulk_mode = !state.bulk_mode;
+            state.status = None;
             if !state.bulk_mode {
                 state.selected_ids.clear();
                 state.selected_ids.shrink_to_fit();
@@ -64,6 +120,7 @@ pub fn update_bulk_action(
 
         BulkActionMessage::Clear => {
             state.selected_ids.clear();
+            state.status = None;
             BulkActionResult::none()
         }
 
@@ -75,19 +132,26 @@ pub fn update_bulk_action(
                 }
             }
 
-            let client = client.clone();
             let ids: Vec<String> = state.selected_ids.iter().cloned().collect();
             state.clear();
 
-            BulkActionResult::rebuild_with_task(Task::perform(
-                async move {
-                    for id in ids {
-                        let _ = client.mark_as_read(&id).await;
-                    }
-                    Ok::<(), GitHubError>(())
-                },
-                BulkActionMessage::Complete,
-            ))
+            BulkActionResult::rebuild_with_task(run_bulk(client, BulkOp::MarkRead, ids))
+        }
+
+        BulkActionMessage::MarkTypeAsRead(ids) => {
+            if ids.is_empty() {
+                return BulkActionResult::none();
+            }
+            // Optimistic update
+            let marked: HashSet<&str> = ids.iter().map(String::as_str).collect();
+            for notif in notifications
+                .iter_mut()
+                .filter(|n| marked.contains(n.id.as_str()))
+            {
+                notif.unread = false;
+            }
+
+            BulkActionResult::rebuild_with_task(run_bulk(client, BulkOp::MarkRead, ids))
         }
 
         BulkActionMessage::MarkAsDone => {
@@ -95,20 +159,132 @@ pub fn update_bulk_action(
             let ids_to_remove: Vec<String> = state.selected_ids.iter().cloned().collect();
             notifications.retain(|n| !state.selected_ids.contains(&n.id));
 
-            let client = client.clone();
             state.clear();
 
-            BulkActionResult::rebuild_with_task(Task::perform(
-                async move {
-                    for id in ids_to_remove {
-                        let _ = client.mark_thread_as_done(&id).await;
-                    }
-                    Ok::<(), GitHubError>(())
-                },
-                BulkActionMessage::Complete,
-            ))
+            BulkActionResult::rebuild_with_task(run_bulk(client, BulkOp::MarkDone, ids_to_remove))
+        }
+
+        BulkActionMessage::MarkAllAsDone(ids) => {
+            state.confirm_done_all = (!ids.is_empty()).then_some(ids);
+            BulkActionResult::none()
+        }
+
+        BulkActionMessage::ConfirmMarkAllAsDone => {
+            let Some(ids) = state.confirm_done_all.take() else {
+                return BulkActionResult::none();
+            };
+            // Optimistic update - remove from list
+            let done: HashSet<&str> = ids.iter().map(String::as_str).collect();
+            notifications.retain(|n| !done.contains(n.id.as_str()));
+            tracing::info!(
+                count = ids.len(),
+                "Marking all visible notifications as done"
+            );
+
+            BulkActionResult::rebuild_with_task(run_bulk(client, BulkOp::MarkDone, ids))
+        }
+
+        BulkActionMessage::CancelMarkAllAsDone => {
+            state.confirm_done_all = None;
+            BulkActionResult::none()
         }
 
-        BulkActionMessage::Complete(_result) => BulkActionResult::none(),
+        BulkActionMessage::OpenSelected => {
+            if state.selection_count() > OPEN_CONFIRM_THRESHOLD {
+                state.confirm_open = true;
+                return BulkActionResult::none();
+            }
+            open_selected(state, notifications, client)
+        }
+
+        BulkActionMessage::ConfirmOpenSelected => open_selected(state, notifications, client),
+
+        BulkActionMessage::CancelOpenSelected => {
+            state.confirm_open = false;
+            BulkActionResult::none()
+        }
+
+        BulkActionMessage::Complete(outcome) => {
+            let total = outcome.succeeded.len() + outcome.failed.len();
+            tracing::info!(
+                op = ?outcome.op,
+                succeeded = outcome.succeeded.len(),
+                failed = outcome.failed.len(),
+                "Bulk operation finished"
+            );
+            if outcome.failed.is_empty() {
+                return BulkActionResult::none();
+            }
+
+            let verb = match outcome.op {
+                BulkOp::MarkRead => "marked read",
+                BulkOp::MarkDone => "marked done",
+            };
+            state.status = Some(format!(
+                "{} of {} {}; {} failed and are still selected",
+                outcome.succeeded.len(),
+                total,
+                verb,
+                outcome.failed.len()
+            ));
+
+            // Undo the optimistic update for failures and queue them for retry
+            if outcome.op == BulkOp::MarkRead {
+                for notif in notifications
+                    .iter_mut()
+                    .filter(|n| outcome.failed.contains(&n.id))
+                {
+                    notif.unread = true;
+                }
+            }
+            state.bulk_mode = true;
+            state.selected_ids.extend(outcome.failed);
+
+            BulkActionResult {
+                task: Task::none(),
+                needs_rebuild: true,
+                // Threads removed by a failed "done" only come back with a fetch
+                needs_refresh: outcome.op == BulkOp::MarkDone,
+            }
+        }
+    }
+}
+
+/// Open the selected threads in the browser, newest first, up to
+/// [`OPEN_MAX_TABS`]; optionally mark them read like a single `Open`.
+fn open_selected(
+    state: &mut BulkActionState,
+    notifications: &mut [NotificationView],
+    client: &GitHubClient,
+) -> BulkActionResult {
+    let mut selected: Vec<&mut NotificationView> = notifications
+        .iter_mut()
+        .filter(|n| state.selected_ids.contains(&n.id))
+        .collect();
+    selected.sort_by_key(|n| std::cmp::Reverse(n.updated_at));
+
+    let mut opened = Vec::new();
+    for notif in selected.into_iter().take(OPEN_MAX_TABS) {
+        let Some(url) = &notif.url else {
+            continue;
+        };
+        if let Err(e) = crate::browser::open(&api_url_to_web_url(url)) {
+            tracing::warn!(error = %e, "Failed to open notification in browser");
+            continue;
+        }
+        if state.open_marks_read {
+            notif.unread = false;
+        }
+        opened.push(notif.id.clone());
+    }
+    tracing::info!(opened = opened.len(), "Opened bulk selection in browser");
+
+    let marks_read = state.open_marks_read;
+    state.clear();
+
+    if marks_read && !opened.is_empty() {
+        BulkActionResult::rebuild_with_task(run_bulk(client, BulkOp::MarkRead, opened))
+    } else {
+        BulkActionResult::none()
     }
 }
@@ -8,6 +8,7 @@ use crate::ui::{icons, theme};
 
 use super::message::BulkActionMessage;
 use super::state::BulkActionState;
+use super::update::OPEN_MAX_TABS;
 
 pub fn view<'a>(
     state: &BulkActionState,
@@ -21,10 +22,14 @@ pub fn view<'a>(
         return Space::new().height(0).into();
     }
 
-    let selection_text = if selection_count == 0 {
-        "Select items".to_string()
-    } else {
-        format!("{} selected", selection_count)
+    if state.confirm_open {
+        return view_confirm_open(selection_count);
+    }
+
+    let total = filtered_ids.len();
+    let selection_text = match &state.status {
+        Some(status) => status.clone(),
+        None => format!("{} of {} selected", selection_count, total),
     };
 
     let select_all_btn = button(text("Select All").size(12).color(p.text_secondary))
@@ -49,6 +54,18 @@ pub fn view<'a>(
     .padding([6, 10])
     .on_press(BulkActionMessage::ToggleMode);
 
+    let open_btn = button(
+        row![
+            icons::icon_external_link(12.0, p.text_secondary, icon_theme),
+            Space::new().width(6),
+            text("Open").size(12).color(p.text_primary),
+        ]
+        .align_y(Alignment::Center),
+    )
+    .style(theme::ghost_button)
+    .padding([6, 12])
+    .on_press_maybe((selection_count > 0).then_some(BulkActionMessage::OpenSelected));
+
     let mark_read_btn = button(
         row![
             icons::icon_check(12.0, iced::Color::WHITE, icon_theme),
@@ -89,31 +106,63 @@ pub fn view<'a>(
     .padding([6, 12])
     .on_press_maybe((selection_count > 0).then_some(BulkActionMessage::MarkAsDone));
 
-    container(
-        row![
-            text(selection_text).size(13).color(p.text_primary),
-            Space::new().width(16),
-            select_all_btn,
-            clear_btn,
-            Space::new().width(Fill),
-            mark_read_btn,
-            Space::new().width(8),
-            archive_btn,
-            Space::new().width(16),
-            cancel_btn,
-        ]
-        .align_y(Alignment::Center)
-        .padding([8, 16]),
-    )
-    .width(Fill)
-    .style(move |_| container::Style {
-        background: Some(iced::Background::Color(p.accent.scale_alpha(0.1))),
-        border: iced::Border {
-            color: p.accent.scale_alpha(0.3),
-            width: 0.0,
-            radius: 0.0.into(),
-        },
-        ..Default::default()
-    })
-    .into()
+    bar(row![
+        text(selection_text).size(13).color(p.text_primary),
+        Space::new().width(16),
+        select_all_btn,
+        clear_btn,
+        Space::new().width(Fill),
+        open_btn,
+        Space::new().width(8),
+        mark_read_btn,
+        Space::new().width(8),
+        archive_btn,
+        Space::new().width(16),
+        cancel_btn,
+    ])
+}
+
+/// Asks before opening a large selection in browser tabs.
+fn view_confirm_open<'a>(selection_count: usize) -> Element<'a, BulkActionMessage> {
+    let p = theme::palette();
+    let tabs = selection_count.min(OPEN_MAX_TABS);
+    let prompt = if selection_count > OPEN_MAX_TABS {
+        format!(
+            "Open the {} most recent of {} selected in the browser?",
+            tabs, selection_count
+        )
+    } else {
+        format!("Open {} browser tabs?", tabs)
+    };
+
+    bar(row![
+        text(prompt).size(13).color(p.text_primary),
+        Space::new().width(Fill),
+        button(text("Cancel").size(12).color(p.text_secondary))
+            .style(theme::ghost_button)
+            .padding([6, 10])
+            .on_press(BulkActionMessage::CancelOpenSelected),
+        Space::new().width(8),
+        button(text("Open").size(12).color(iced::Color::WHITE))
+            .style(theme::primary_button)
+            .padding([6, 12])
+            .on_press(BulkActionMessage::ConfirmOpenSelected),
+    ])
+}
+
+fn bar<'a>(content: iced::widget::Row<'a, BulkActionMessage>) -> Element<'a, BulkActionMessage> {
+    let p = theme::palette();
+
+    container(content.align_y(Alignment::Center).padding([8, 16]))
+        .width(Fill)
+        .style(move |_| container::Style {
+            background: Some(iced::Background::Color(p.accent.scale_alpha(0.1))),
+            border: iced::Border {
+                color: p.accent.scale_alpha(0.3),
+                width: 0.0,
+                radius: 0.0.into(),
+            },
+            ..Default::default()
+        })
+        .into()
 }
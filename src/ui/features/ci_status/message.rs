@@ -0,0 +1,11 @@
+//! CI status messages.
+
+use crate::github::{CiConclusion, GitHubError};
+
+use super::state::CiKey;
+
+#[derive(Debug, Clone)]
+pub enum CiStatusMessage {
+    /// Conclusion fetched for a subject URL as of an update time.
+    Fetched(CiKey, Result<Option<CiConclusion>, GitHubError>),
+}
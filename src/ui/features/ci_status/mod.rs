@@ -0,0 +1,7 @@
+mod message;
+mod state;
+mod update;
+
+pub use message::CiStatusMessage;
+pub use state::CiStatusState;
+pub use update::{apply_cached_conclusions, prefetch_ci_status, update_ci_status};
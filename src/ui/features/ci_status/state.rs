@@ -0,0 +1,34 @@
+//! CI status state.
+//!
+//! This feature resolves pass/fail for CI activity notifications:
+//! - Prefetching check-run conclusions for CI items after a refresh
+//! - Caching conclusions per subject URL and update time across refreshes
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Utc};
+
+use crate::github::{CiConclusion, NotificationView};
+
+/// A subject API URL and when its notification was last updated. A new run
+/// updates the notification, so its conclusion gets looked up again.
+pub type CiKey = (String, DateTime<Utc>);
+
+/// Cached CI conclusions.
+#[derive(Debug, Clone, Default)]
+pub struct CiStatusState {
+    pub conclusions: HashMap<CiKey, CiConclusion>,
+    pub pending: HashSet<CiKey>,
+}
+
+impl CiStatusState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cache key for a notification; `None` if it has no subject URL.
+    pub fn key(notification: &NotificationView) -> Option<CiKey> {
+        let url = notification.url.clone()?;
+        Some((url, notification.updated_at))
+    }
+}
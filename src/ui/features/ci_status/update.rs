@@ -0,0 +1,98 @@
+//! CI status update logic.
+
+use iced::Task;
+
+use crate::github::{CiConclusion, GitHubClient, NotificationReason, NotificationView};
+
+use super::message::CiStatusMessage;
+use super::state::{CiKey, CiStatusState};
+
+/// Upper bound on lookups started per refresh.
+///
+/// Each lookup costs one or two API calls, so only the items at the top of
+/// the list (the ones on screen) are resolved eagerly.
+const MAX_PREFETCH: usize = 20;
+
+/// Start fetching conclusions for CI items that aren't cached yet.
+///
+/// `notifications` should be in display order so the visible items go first.
+pub fn prefetch_ci_status<'a>(
+    state: &mut CiStatusState,
+    notifications: impl IntoIterator<Item = &'a NotificationView>,
+    client: &GitHubClient,
+) -> Task<CiStatusMessage> {
+    let keys: Vec<CiKey> = notifications
+        .into_iter()
+        .filter(|n| n.reason == NotificationReason::CiActivity)
+        .filter_map(CiStatusState::key)
+        .filter(|key| !state.conclusions.contains_key(key) && !state.pending.contains(key))
+        .take(MAX_PREFETCH)
+        .collect();
+
+    let tasks: Vec<_> = keys
+        .into_iter()
+        .map(|key| {
+            state.pending.insert(key.clone());
+            let client = client.clone();
+            let url = key.0.clone();
+            Task::perform(
+                async move { client.get_ci_conclusion(&url).await },
+                move |result| CiStatusMessage::Fetched(key.clone(), result),
+            )
+        })
+        .collect();
+
+    Task::batch(tasks)
+}
+
+/// Copy cached conclusions onto freshly fetched notifications.
+pub fn apply_cached_conclusions(state: &CiStatusState, notifications: &mut [NotificationView]) {
+    for notif in notifications {
+        if let Some(conclusion) =
+            CiStatusState::key(notif).and_then(|key| state.conclusions.get(&key))
+        {
+            notif.ci_conclusion = Some(*conclusion);
+        }
+    }
+}
+
+/// Update CI status state.
+///
+/// Returns `true` when a notification's conclusion changed and the groups
+/// need rebuilding.
+pub fn update_ci_status(
+    state: &mut CiStatusState,
+    message: CiStatusMessage,
+    notifications: &mut [NotificationView],
+) -> bool {
+    match message {
+        CiStatusMessage::Fetched(key, result) => {
+            state.pending.remove(&key);
+            let conclusion = match result {
+                Ok(Some(conclusion)) => conclusion,
+                Ok(None) => return false,
+                Err(e) => {
+                    tracing::debug!(error = %e, "Failed to fetch CI conclusion");
+                    return false;
+                }
+            };
+
+            // Running checks may still finish, so only settled results stick.
+            // Older runs of the same subject are superseded.
+            if conclusion != CiConclusion::Pending {
+                state.conclusions.retain(|(url, _), _| *url != key.0);
+                state.conclusions.insert(key.clone(), conclusion);
+            }
+
+            let mut changed = false;
+            for notif in notifications
+                .iter_mut()
+                .filter(|n| CiStatusState::key(n).as_ref() == Some(&key))
+            {
+                changed |= notif.ci_conclusion != Some(conclusion);
+                notif.ci_conclusion = Some(conclusion);
+            }
+            changed
+        }
+    }
+}
@@ -1,12 +1,60 @@
 #[derive(Debug, Clone)]
 pub enum GeneralMessage {
     ChangeTheme(crate::settings::AppTheme),
-    ToggleIconTheme(bool),
+    ChangeGroupingMode(crate::settings::GroupingMode),
+    ChangeTimeBuckets(crate::settings::TimeBuckets),
+    ChangeFetchWindow(crate::settings::FetchWindow),
+    ChangeIconTheme(crate::settings::IconTheme),
     ToggleMinimizeToTray(bool),
     ToggleCheckForUpdates(bool),
+    ToggleSelfUpdate(bool),
+    TogglePrioritySound(bool),
+    ToggleReduceMotion(bool),
+    ToggleActivityLog(bool),
+    ToggleBulkOpenMarksRead(bool),
+    ChangeOpenAction(crate::settings::OpenAction),
+    ChangeLanguage(crate::i18n::Language),
+    ToggleAlwaysOnTop(bool),
+    ToggleConfirmMarkAllRead(bool),
+    ToggleShowActors(bool),
+    ToggleCompactThreads(bool),
+    ToggleMiniMode(bool),
+    ToggleMiniModeOnTop(bool),
+    ToggleDesktopReason(crate::github::NotificationReason, bool),
+    SetToastIndividualLimit(f32),
+    SetToastSummaryTitles(f32),
+    PauseFor(u32),
+    ResumeNotifications,
     SetNotificationFontScale(f32),
     SetSidebarFontScale(f32),
     SetSidebarWidth(f32),
+    SetMaxNotifications(f32),
+    SetRefreshInterval(f32),
+    HotkeyInputChanged(String),
+    ApplyHotkey,
+    BrowserInputChanged(String),
+    ApplyBrowserCommand,
+    ToggleLocalApi(bool),
+    LocalApiPortChanged(String),
+    ApplyLocalApiPort,
+    ClearCache,
+    CacheCleared(Result<u64, String>),
+    /// Send a desktop notification to check that they show up.
+    SendTestNotification,
+    CopyDebugReport,
+    /// Save settings, rules and the account list to a file.
+    BackupConfig,
+    BackupSaved(Result<Option<String>, String>),
+    RestoreConfig,
+    /// A backup was picked; applied by the settings screen, which knows who
+    /// is signed in.
+    RestoreLoaded(Result<Option<Box<crate::backup::ConfigBackup>>, String>),
+    /// Sign in to an account listed by a restored backup.
+    SignInRestoredAccount(String),
+    RequestReset,
+    CancelReset,
+    /// Confirmed; handled by the settings screen as an app effect.
+    ConfirmReset,
     ToggleStartOnBoot(bool),
     StartOnBootResult(Result<bool, String>),
 }
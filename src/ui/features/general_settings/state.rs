@@ -1,14 +1,53 @@
+use crate::settings::AppSettings;
+
 #[derive(Debug, Clone)]
 pub struct GeneralSettingsState {
     pub start_on_boot_enabled: bool,
+    /// Draft text for the global shortcut field (applied on submit).
+    pub hotkey_input: String,
+    pub hotkey_error: Option<String>,
+    /// Draft text for the browser command field (applied on submit).
+    pub browser_input: String,
+    pub browser_error: Option<String>,
+    /// Draft text for the local API port field (applied on submit).
+    pub local_api_port_input: String,
+    pub local_api_error: Option<String>,
+    /// Disk cache size in bytes, if the cache could be opened.
+    pub cache_size: Option<u64>,
+    pub cache_error: Option<String>,
+    /// The debug report was copied this visit.
+    pub debug_report_copied: bool,
+    /// Outcome of the last backup or restore this visit.
+    pub backup_status: Option<Result<String, String>>,
+    /// Accounts from a restored backup that still need a token.
+    pub restored_pending: Vec<String>,
+    /// "Reset GitTop" is awaiting confirmation.
+    pub confirm_reset: bool,
+    /// Outcome of the last test notification this visit.
+    pub test_notification: Option<Result<(), String>>,
 }
 
 impl GeneralSettingsState {
-    pub fn new() -> Self {
+    pub fn new(settings: &AppSettings) -> Self {
         // Cache start-on-boot state to avoid querying systemctl on every render
         let start_on_boot_enabled = crate::platform::on_boot::is_enabled();
         Self {
             start_on_boot_enabled,
+            hotkey_input: settings.global_hotkey.clone().unwrap_or_default(),
+            hotkey_error: None,
+            browser_input: settings.browser_command.clone().unwrap_or_default(),
+            browser_error: None,
+            local_api_port_input: settings.local_api_port.to_string(),
+            local_api_error: None,
+            cache_size: crate::cache::DiskCache::open()
+                .ok()
+                .map(|c| c.size_on_disk()),
+            cache_error: None,
+            debug_report_copied: false,
+            backup_status: None,
+            restored_pending: settings.awaiting_sign_in().map(str::to_string).collect(),
+            confirm_reset: false,
+            test_notification: None,
         }
     }
 }
@@ -1,6 +1,7 @@
 use super::message::GeneralMessage;
 use super::state::GeneralSettingsState;
-use crate::settings::{AppSettings, IconTheme};
+use crate::settings::AppSettings;
+use crate::ui::features::sidebar;
 use crate::ui::theme;
 use iced::Task;
 
@@ -17,14 +18,35 @@ pub fn update(
             tracing::info!(theme = %new_theme, "Theme updated");
             Task::none()
         }
-        GeneralMessage::ToggleIconTheme(use_svg) => {
-            settings.icon_theme = if use_svg {
-                IconTheme::Svg
-            } else {
-                IconTheme::Emoji
-            };
+        GeneralMessage::ChangeLanguage(language) => {
+            settings.language = language;
+            crate::i18n::set_language(language);
             persist_settings(settings);
-            tracing::info!(use_svg, "Icon theme updated");
+            tracing::info!(language = %language, "Language updated");
+            Task::none()
+        }
+        GeneralMessage::ChangeGroupingMode(mode) => {
+            settings.grouping_mode = mode;
+            persist_settings(settings);
+            tracing::info!(mode = %mode, "Grouping mode updated");
+            Task::none()
+        }
+        GeneralMessage::ChangeTimeBuckets(buckets) => {
+            settings.time_buckets = buckets;
+            persist_settings(settings);
+            tracing::info!(buckets = %buckets, "Time buckets updated");
+            Task::none()
+        }
+        GeneralMessage::ChangeFetchWindow(window) => {
+            settings.fetch_window = window;
+            persist_settings(settings);
+            tracing::info!(window = %window, "Fetch window updated");
+            Task::none()
+        }
+        GeneralMessage::ChangeIconTheme(icon_theme) => {
+            settings.icon_theme = icon_theme;
+            persist_settings(settings);
+            tracing::info!(icon_theme = %icon_theme, "Icon theme updated");
             Task::none()
         }
         GeneralMessage::ToggleMinimizeToTray(enabled) => {
@@ -50,18 +72,305 @@ pub fn update(
             Task::none()
         }
         GeneralMessage::SetSidebarWidth(width) => {
-            let clamped = width.clamp(180.0, 400.0);
+            let clamped = sidebar::resize::clamp_width(width);
             settings.sidebar_width = clamped;
             persist_settings(settings);
             tracing::debug!(width = clamped, "Sidebar width updated");
             Task::none()
         }
+        GeneralMessage::SetMaxNotifications(max) => {
+            settings.max_notifications = max.clamp(100.0, 5000.0) as usize;
+            persist_settings(settings);
+            tracing::debug!(
+                max = settings.max_notifications,
+                "Max notifications in memory updated"
+            );
+            Task::none()
+        }
+        GeneralMessage::SetRefreshInterval(secs) => {
+            let range = crate::settings::REFRESH_INTERVAL_RANGE;
+            settings.refresh_interval_secs = (secs as u64).clamp(*range.start(), *range.end());
+            persist_settings(settings);
+            tracing::info!(
+                secs = settings.refresh_interval_secs,
+                "Refresh interval updated"
+            );
+            Task::none()
+        }
+        GeneralMessage::TogglePrioritySound(enabled) => {
+            settings.priority_sound = enabled;
+            persist_settings(settings);
+            tracing::info!(enabled, "Priority sound setting updated");
+            Task::none()
+        }
+        GeneralMessage::ToggleReduceMotion(enabled) => {
+            settings.reduce_motion = enabled;
+            theme::set_reduced_motion(enabled || crate::platform::prefers_reduced_motion());
+            persist_settings(settings);
+            tracing::info!(enabled, "Reduce motion setting updated");
+            Task::none()
+        }
+        GeneralMessage::ToggleActivityLog(enabled) => {
+            settings.activity_log = enabled;
+            crate::activity_log::set_enabled(enabled);
+            persist_settings(settings);
+            tracing::info!(enabled, "Activity log setting updated");
+            Task::none()
+        }
+        GeneralMessage::ToggleBulkOpenMarksRead(enabled) => {
+            settings.bulk_open_marks_read = enabled;
+            persist_settings(settings);
+            tracing::info!(enabled, "Bulk open marks-read setting updated");
+            Task::none()
+        }
+        GeneralMessage::ChangeOpenAction(action) => {
+            settings.open_action = action;
+            persist_settings(settings);
+            tracing::info!(action = %action, "Open action updated");
+            Task::none()
+        }
+        GeneralMessage::ToggleConfirmMarkAllRead(enabled) => {
+            settings.confirm_mark_all_read = enabled;
+            persist_settings(settings);
+            tracing::info!(enabled, "Mark all read confirmation updated");
+            Task::none()
+        }
+        GeneralMessage::ToggleShowActors(enabled) => {
+            settings.show_actors = enabled;
+            persist_settings(settings);
+            tracing::info!(enabled, "Show actors setting updated");
+            Task::none()
+        }
+        GeneralMessage::ToggleCompactThreads(enabled) => {
+            settings.compact_threads = enabled;
+            persist_settings(settings);
+            tracing::info!(enabled, "Compact threads setting updated");
+            Task::none()
+        }
+        GeneralMessage::ToggleMiniMode(enabled) => {
+            settings.mini_mode = enabled;
+            persist_settings(settings);
+            tracing::info!(enabled, "Mini mode setting updated");
+            Task::none()
+        }
+        GeneralMessage::ToggleAlwaysOnTop(enabled) => {
+            settings.always_on_top = enabled;
+            persist_settings(settings);
+            tracing::info!(enabled, "Always-on-top setting updated");
+            crate::ui::state::apply_window_level(settings)
+        }
+        GeneralMessage::ToggleMiniModeOnTop(enabled) => {
+            settings.mini_mode_on_top = enabled;
+            persist_settings(settings);
+            tracing::info!(enabled, "Mini mode always-on-top setting updated");
+            Task::none()
+        }
+        GeneralMessage::ToggleDesktopReason(reason, enabled) => {
+            settings.muted_desktop_reasons.retain(|r| *r != reason);
+            if !enabled {
+                settings.muted_desktop_reasons.push(reason);
+            }
+            persist_settings(settings);
+            tracing::info!(reason = %reason, enabled, "Desktop notification reason updated");
+            Task::none()
+        }
+        GeneralMessage::SetToastIndividualLimit(limit) => {
+            settings.toast_individual_limit = clamp_toast_limit(limit);
+            persist_settings(settings);
+            tracing::debug!(
+                limit = settings.toast_individual_limit,
+                "Individual toast limit updated"
+            );
+            Task::none()
+        }
+        GeneralMessage::SetToastSummaryTitles(titles) => {
+            settings.toast_summary_titles = clamp_toast_limit(titles);
+            persist_settings(settings);
+            tracing::debug!(
+                titles = settings.toast_summary_titles,
+                "Summary toast titles updated"
+            );
+            Task::none()
+        }
+        GeneralMessage::PauseFor(minutes) => {
+            crate::ui::state::pause_for(minutes);
+            Task::none()
+        }
+        GeneralMessage::ResumeNotifications => {
+            crate::ui::state::resume();
+            Task::none()
+        }
         GeneralMessage::ToggleCheckForUpdates(enabled) => {
             settings.check_for_updates = enabled;
             persist_settings(settings);
             tracing::info!(enabled, "Check for updates setting updated");
             Task::none()
         }
+        GeneralMessage::ToggleSelfUpdate(enabled) => {
+            settings.self_update = enabled;
+            persist_settings(settings);
+            tracing::info!(enabled, "Self-update setting updated");
+            Task::none()
+        }
+        GeneralMessage::HotkeyInputChanged(value) => {
+            state.hotkey_input = value;
+            state.hotkey_error = None;
+            Task::none()
+        }
+        GeneralMessage::ApplyHotkey => {
+            let spec = state.hotkey_input.trim().to_string();
+            match crate::hotkey::apply(Some(&spec)) {
+                Ok(()) => {
+                    settings.global_hotkey = (!spec.is_empty()).then_some(spec);
+                    state.hotkey_error = None;
+                    persist_settings(settings);
+                    tracing::info!(shortcut = ?settings.global_hotkey, "Global shortcut updated");
+                }
+                Err(e) => {
+                    tracing::warn!(error = %e, "Failed to apply global shortcut");
+                    state.hotkey_error = Some(e);
+                    // Keep the previously saved shortcut working
+                    let _ = crate::hotkey::apply(settings.global_hotkey.as_deref());
+                }
+            }
+            Task::none()
+        }
+        GeneralMessage::BrowserInputChanged(value) => {
+            state.browser_input = value;
+            state.browser_error = None;
+            Task::none()
+        }
+        GeneralMessage::ApplyBrowserCommand => {
+            let template = state.browser_input.trim().to_string();
+            // An invalid template is rejected before replacing the current one
+            match crate::browser::apply(Some(&template)) {
+                Ok(()) => {
+                    settings.browser_command = (!template.is_empty()).then_some(template);
+                    state.browser_error = None;
+                    persist_settings(settings);
+                }
+                Err(e) => {
+                    tracing::warn!(error = %e, "Rejected browser command");
+                    state.browser_error = Some(e);
+                }
+            }
+            Task::none()
+        }
+        GeneralMessage::ToggleLocalApi(enabled) => {
+            settings.local_api_enabled = enabled;
+            apply_local_api(settings, state);
+            persist_settings(settings);
+            Task::none()
+        }
+        GeneralMessage::LocalApiPortChanged(value) => {
+            state.local_api_port_input = value;
+            state.local_api_error = None;
+            Task::none()
+        }
+        GeneralMessage::ApplyLocalApiPort => {
+            match state.local_api_port_input.trim().parse::<u16>() {
+                Ok(port) if port > 0 => {
+                    settings.local_api_port = port;
+                    apply_local_api(settings, state);
+                    persist_settings(settings);
+                }
+                _ => state.local_api_error = Some("Enter a port between 1 and 65535".to_string()),
+            }
+            Task::none()
+        }
+        GeneralMessage::CopyDebugReport => {
+            state.debug_report_copied = true;
+            tracing::info!("Debug report copied to clipboard");
+            let rules = crate::ui::screens::settings::rule_engine::NotificationRuleSet::load();
+            iced::clipboard::write(crate::diagnostics::debug_report(settings, rules.summary()))
+        }
+        GeneralMessage::BackupConfig => {
+            let rules = crate::ui::screens::settings::rule_engine::NotificationRuleSet::load();
+            let backup =
+                crate::backup::ConfigBackup::capture(settings, &rules, state.start_on_boot_enabled);
+            Task::perform(
+                crate::backup::save_with_dialog(backup),
+                GeneralMessage::BackupSaved,
+            )
+        }
+        GeneralMessage::BackupSaved(result) => {
+            match result {
+                Ok(Some(file_name)) => {
+                    tracing::info!("Configuration backed up");
+                    state.backup_status = Some(Ok(format!("Saved to {file_name}")));
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    tracing::warn!(error = %e, "Configuration backup failed");
+                    state.backup_status = Some(Err(e));
+                }
+            }
+            Task::none()
+        }
+        GeneralMessage::RestoreConfig => {
+            Task::perform(crate::backup::open_with_dialog(), |result| {
+                GeneralMessage::RestoreLoaded(result.map(|backup| backup.map(Box::new)))
+            })
+        }
+        GeneralMessage::RestoreLoaded(Err(e)) => {
+            tracing::warn!(error = %e, "Configuration restore failed");
+            state.backup_status = Some(Err(e));
+            Task::none()
+        }
+        // A picked backup is applied by the settings screen; cancelling is a no-op
+        GeneralMessage::RestoreLoaded(Ok(_)) => Task::none(),
+        GeneralMessage::SignInRestoredAccount(_) => Task::none(),
+        GeneralMessage::SendTestNotification => {
+            // Sent inline like real alerts, since some platforms want the
+            // main thread
+            let result = crate::platform::notify(
+                "GitTop",
+                "Test notification",
+                Some("https://github.com/notifications"),
+                None,
+            )
+            .map_err(|e| e.to_string());
+            match &result {
+                Ok(()) => tracing::info!("Test notification sent"),
+                Err(e) => tracing::warn!(error = %e, "Test notification failed"),
+            }
+            state.test_notification = Some(result);
+            Task::none()
+        }
+        GeneralMessage::ClearCache => {
+            tracing::info!("Cache clear requested");
+            Task::perform(
+                async {
+                    tokio::task::spawn_blocking(clear_cache)
+                        .await
+                        .unwrap_or_else(|e| Err(e.to_string()))
+                },
+                GeneralMessage::CacheCleared,
+            )
+        }
+        GeneralMessage::CacheCleared(result) => {
+            match result {
+                Ok(size) => {
+                    state.cache_size = Some(size);
+                    state.cache_error = None;
+                    tracing::info!(size_bytes = size, "Cache cleared");
+                }
+                Err(e) => {
+                    tracing::warn!(error = %e, "Failed to clear cache");
+                    state.cache_error = Some(e);
+                }
+            }
+            Task::none()
+        }
+        GeneralMessage::RequestReset => {
+            state.confirm_reset = true;
+            Task::none()
+        }
+        GeneralMessage::CancelReset => {
+            state.confirm_reset = false;
+            Task::none()
+        }
+        GeneralMessage::ConfirmReset => Task::none(),
         GeneralMessage::ToggleStartOnBoot(enabled) => {
             tracing::info!(enabled, "Start-on-boot toggle requested");
             // Perform the operation asynchronously and report result
@@ -94,7 +403,39 @@ pub fn update(
     }
 }
 
+/// Wipe the disk cache and return its new size.
+fn clear_cache() -> Result<u64, String> {
+    let cache = crate::cache::DiskCache::open().map_err(|e| e.to_string())?;
+    cache.clear().map_err(|e| e.to_string())?;
+    Ok(cache.size_on_disk())
+}
+
+/// Re-apply the settings that live outside the window (global shortcut,
+/// browser, activity log, local API) after they were replaced wholesale.
+pub fn apply_system_settings(settings: &AppSettings, state: &mut GeneralSettingsState) {
+    state.hotkey_error = crate::hotkey::apply(settings.global_hotkey.as_deref())
+        .inspect_err(|e| tracing::warn!(error = %e, "Failed to apply global shortcut"))
+        .err();
+    state.browser_error = crate::browser::apply(settings.browser_command.as_deref())
+        .inspect_err(|e| tracing::warn!(error = %e, "Ignoring invalid browser command"))
+        .err();
+    crate::activity_log::set_enabled(settings.activity_log);
+    apply_local_api(settings, state);
+}
+
+/// Start, move or stop the local API to match `settings`.
+fn apply_local_api(settings: &AppSettings, state: &mut GeneralSettingsState) {
+    state.local_api_error = crate::local_api::apply(settings.local_api_port())
+        .inspect_err(|e| tracing::warn!(error = %e, "Failed to start local API"))
+        .err();
+}
+
 fn persist_settings(settings: &mut AppSettings) {
     let _ = settings.save();
     crate::platform::trim_memory();
 }
+
+fn clamp_toast_limit(value: f32) -> usize {
+    use crate::ui::screens::notifications::desktop_notify::TOAST_LIMIT_RANGE;
+    (value.round() as usize).clamp(*TOAST_LIMIT_RANGE.start(), *TOAST_LIMIT_RANGE.end())
+}
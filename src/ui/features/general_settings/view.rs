@@ -1,10 +1,18 @@
-use iced::widget::{Space, column, pick_list, row, slider, text, toggler};
+use iced::widget::{Space, button, column, pick_list, row, slider, text, text_input, toggler};
 use iced::{Alignment, Element, Fill};
 
-use crate::settings::{AppSettings, AppTheme, IconTheme};
+use crate::github::NotificationReason;
+use crate::settings::{
+    AppSettings, AppTheme, FetchWindow, GroupingMode, IconTheme, OpenAction, TimeBuckets,
+};
 use crate::ui::screens::settings::components::{setting_card, tab_title};
 use crate::ui::theme;
 
+use crate::ui::features::sidebar;
+use crate::ui::screens::notifications::desktop_notify::TOAST_LIMIT_RANGE;
+
+use crate::i18n::{Key, Language, tr};
+
 use super::message::GeneralMessage;
 use super::state::GeneralSettingsState;
 
@@ -22,6 +30,8 @@ pub fn view(
         Space::new().height(16),
         view_theme(settings),
         Space::new().height(8),
+        view_language(settings),
+        Space::new().height(8),
         view_icons(settings),
         Space::new().height(8),
         view_minimize_to_tray(settings),
@@ -29,14 +39,80 @@ pub fn view(
         view_start_on_boot(state.start_on_boot_enabled),
         Space::new().height(8),
         view_check_for_updates(settings),
+        Space::new().height(8),
+        view_self_update(settings),
+        Space::new().height(8),
+        view_global_hotkey(state),
+        Space::new().height(8),
+        view_browser_command(state),
+        Space::new().height(8),
+        view_local_api(settings, state),
+        Space::new().height(8),
+        view_priority_sound(settings),
+        Space::new().height(8),
+        view_open_action(settings),
+        Space::new().height(8),
+        view_bulk_open_marks_read(settings),
+        Space::new().height(8),
+        view_confirm_mark_all_read(settings),
+        Space::new().height(24),
+        text("Desktop Notifications").size(13).color(p.text_muted),
+        Space::new().height(8),
+        view_pause(),
+        Space::new().height(8),
+        view_desktop_reasons(settings),
+        Space::new().height(8),
+        view_toast_individual_limit(settings),
+        Space::new().height(8),
+        view_toast_summary_titles(settings),
+        Space::new().height(8),
+        view_test_notification(state),
         Space::new().height(24),
         text("Display").size(13).color(p.text_muted),
         Space::new().height(8),
+        view_grouping_mode(settings),
+        Space::new().height(8),
+        view_time_buckets(settings),
+        Space::new().height(8),
+        view_show_actors(settings),
+        Space::new().height(8),
+        view_compact_threads(settings),
+        Space::new().height(8),
+        view_mini_mode(settings),
+        Space::new().height(8),
+        view_mini_mode_on_top(settings),
+        Space::new().height(8),
+        view_always_on_top(settings),
+        Space::new().height(8),
+        view_reduce_motion(settings),
+        Space::new().height(8),
         view_notification_scale(settings),
         Space::new().height(8),
         view_sidebar_scale(settings),
         Space::new().height(8),
         view_sidebar_width(settings),
+        Space::new().height(8),
+        view_max_notifications(settings),
+        Space::new().height(8),
+        view_refresh_interval(settings),
+        Space::new().height(8),
+        view_fetch_window(settings),
+        Space::new().height(24),
+        text("Storage").size(13).color(p.text_muted),
+        Space::new().height(8),
+        view_cache(state),
+        Space::new().height(8),
+        view_activity_log(settings),
+        Space::new().height(8),
+        view_backup(state),
+        Space::new().height(8),
+        view_reset(state),
+        Space::new().height(24),
+        text("Support").size(13).color(p.text_muted),
+        Space::new().height(8),
+        view_debug_report(state),
+        Space::new().height(8),
+        view_recent_errors(),
     ]
     .spacing(4)
     .padding(24)
@@ -75,15 +151,146 @@ fn view_theme(settings: &AppSettings) -> Element<'static, GeneralMessage> {
     )
 }
 
+fn view_language(settings: &AppSettings) -> Element<'static, GeneralMessage> {
+    let p = theme::palette();
+
+    setting_card(
+        row![
+            column![
+                text(tr(Key::Language)).size(14).color(p.text_primary),
+                Space::new().height(4),
+                text(tr(Key::LanguageDescription))
+                    .size(11)
+                    .color(p.text_secondary),
+            ]
+            .width(Fill),
+            pick_list(
+                Language::ALL,
+                Some(settings.language),
+                GeneralMessage::ChangeLanguage
+            )
+            .text_size(13)
+            .padding([8, 12])
+            .style(theme::pick_list_style)
+            .menu_style(theme::menu_style),
+        ]
+        .align_y(Alignment::Center),
+    )
+}
+
+fn view_grouping_mode(settings: &AppSettings) -> Element<'static, GeneralMessage> {
+    let p = theme::palette();
+
+    setting_card(
+        row![
+            column![
+                text("Group Notifications By")
+                    .size(14)
+                    .color(p.text_primary),
+                Space::new().height(4),
+                text("Time buckets, repository, or subject type")
+                    .size(11)
+                    .color(p.text_secondary),
+            ]
+            .width(Fill),
+            pick_list(
+                GroupingMode::ALL,
+                Some(settings.grouping_mode),
+                GeneralMessage::ChangeGroupingMode
+            )
+            .text_size(13)
+            .padding([8, 12])
+            .style(theme::pick_list_style)
+            .menu_style(theme::menu_style),
+        ]
+        .align_y(Alignment::Center),
+    )
+}
+
+fn view_time_buckets(settings: &AppSettings) -> Element<'static, GeneralMessage> {
+    let p = theme::palette();
+
+    setting_card(
+        row![
+            column![
+                text("Time Groups").size(14).color(p.text_primary),
+                Space::new().height(4),
+                text("When grouping by time: a few broad groups, or one per day")
+                    .size(11)
+                    .color(p.text_secondary),
+            ]
+            .width(Fill),
+            pick_list(
+                TimeBuckets::ALL,
+                Some(settings.time_buckets),
+                GeneralMessage::ChangeTimeBuckets
+            )
+            .text_size(13)
+            .padding([8, 12])
+            .style(theme::pick_list_style)
+            .menu_style(theme::menu_style),
+        ]
+        .align_y(Alignment::Center),
+    )
+}
+
+fn view_fetch_window(settings: &AppSettings) -> Element<'static, GeneralMessage> {
+    let p = theme::palette();
+
+    setting_card(
+        row![
+            column![
+                text("Load Notifications From the Last")
+                    .size(14)
+                    .color(p.text_primary),
+                Space::new().height(4),
+                text("Older threads are skipped (Default: 30 days)")
+                    .size(11)
+                    .color(p.text_secondary),
+            ]
+            .width(Fill),
+            pick_list(
+                FetchWindow::ALL,
+                Some(settings.fetch_window),
+                GeneralMessage::ChangeFetchWindow
+            )
+            .text_size(13)
+            .padding([8, 12])
+            .style(theme::pick_list_style)
+            .menu_style(theme::menu_style),
+        ]
+        .align_y(Alignment::Center),
+    )
+}
+
 fn view_icons(settings: &AppSettings) -> Element<'static, GeneralMessage> {
-    let use_svg = settings.icon_theme == IconTheme::Svg;
-    let desc = if use_svg {
-        "High quality SVG icons (Default)"
-    } else {
-        "Emoji icons (minimal memory)"
+    let p = theme::palette();
+    let desc = match settings.icon_theme {
+        IconTheme::Svg => "High quality SVG icons (Default)",
+        IconTheme::Emoji => "Emoji icons (minimal memory)",
+        IconTheme::Auto => "SVG while the window is open, emoji while in the tray",
     };
 
-    toggle_card("Icon Style", desc, use_svg, GeneralMessage::ToggleIconTheme)
+    setting_card(
+        row![
+            column![
+                text("Icon Style").size(14).color(p.text_primary),
+                Space::new().height(4),
+                text(desc).size(11).color(p.text_secondary),
+            ]
+            .width(Fill),
+            pick_list(
+                IconTheme::ALL,
+                Some(settings.icon_theme),
+                GeneralMessage::ChangeIconTheme
+            )
+            .text_size(13)
+            .padding([8, 12])
+            .style(theme::pick_list_style)
+            .menu_style(theme::menu_style),
+        ]
+        .align_y(Alignment::Center),
+    )
 }
 
 fn view_minimize_to_tray(settings: &AppSettings) -> Element<'static, GeneralMessage> {
@@ -133,6 +340,655 @@ fn view_check_for_updates(settings: &AppSettings) -> Element<'static, GeneralMes
     )
 }
 
+fn view_self_update(settings: &AppSettings) -> Element<'static, GeneralMessage> {
+    let enabled = settings.self_update;
+    let desc = match crate::self_update::unsupported_reason() {
+        Some(reason) => reason,
+        None if !settings.check_for_updates => "Requires update checks to be enabled",
+        None if enabled => "Offers to download, verify and install new releases",
+        None => "Only link to new releases (Default)",
+    };
+
+    toggle_card(
+        "Install Updates In-App",
+        desc,
+        enabled,
+        GeneralMessage::ToggleSelfUpdate,
+    )
+}
+
+fn view_global_hotkey(state: &GeneralSettingsState) -> Element<'static, GeneralMessage> {
+    let p = theme::palette();
+
+    let (desc, desc_color) = match &state.hotkey_error {
+        Some(err) => (err.clone(), p.accent_danger),
+        None if !crate::hotkey::is_supported() => (
+            "Not available on Wayland; use the tray icon instead".to_string(),
+            p.text_secondary,
+        ),
+        None => (
+            "Show or hide GitTop from anywhere. Leave empty to disable.".to_string(),
+            p.text_secondary,
+        ),
+    };
+
+    setting_card(
+        row![
+            column![
+                text("Global Shortcut").size(14).color(p.text_primary),
+                Space::new().height(4),
+                text(desc).size(11).color(desc_color),
+            ]
+            .width(Fill),
+            text_input("Ctrl+Shift+G", &state.hotkey_input)
+                .on_input(GeneralMessage::HotkeyInputChanged)
+                .on_submit(GeneralMessage::ApplyHotkey)
+                .size(13)
+                .padding([8, 12])
+                .width(160)
+                .style(theme::text_input_style),
+            button(text("Apply").size(12))
+                .on_press(GeneralMessage::ApplyHotkey)
+                .padding([8, 12])
+                .style(theme::ghost_button),
+        ]
+        .spacing(8)
+        .align_y(Alignment::Center),
+    )
+}
+
+fn view_browser_command(state: &GeneralSettingsState) -> Element<'static, GeneralMessage> {
+    let p = theme::palette();
+
+    let (desc, desc_color) = match &state.browser_error {
+        Some(err) => (err.clone(), p.accent_danger),
+        None => (
+            "Command used to open links, with {url} for the link. Leave empty for the default browser."
+                .to_string(),
+            p.text_secondary,
+        ),
+    };
+
+    setting_card(
+        row![
+            column![
+                text("Open Links With").size(14).color(p.text_primary),
+                Space::new().height(4),
+                text(desc).size(11).color(desc_color),
+            ]
+            .width(Fill),
+            text_input("firefox -P work {url}", &state.browser_input)
+                .on_input(GeneralMessage::BrowserInputChanged)
+                .on_submit(GeneralMessage::ApplyBrowserCommand)
+                .size(13)
+                .padding([8, 12])
+                .width(220)
+                .style(theme::text_input_style),
+            button(text("Apply").size(12))
+                .on_press(GeneralMessage::ApplyBrowserCommand)
+                .padding([8, 12])
+                .style(theme::ghost_button),
+        ]
+        .spacing(8)
+        .align_y(Alignment::Center),
+    )
+}
+
+fn view_local_api(
+    settings: &AppSettings,
+    state: &GeneralSettingsState,
+) -> Element<'static, GeneralMessage> {
+    let p = theme::palette();
+    let enabled = settings.local_api_enabled;
+
+    let (desc, desc_color) = match &state.local_api_error {
+        Some(err) => (err.clone(), p.accent_danger),
+        None if enabled => (
+            format!(
+                "Scripts can POST /refresh and /show or GET /unread on 127.0.0.1:{}",
+                settings.local_api_port
+            ),
+            p.text_secondary,
+        ),
+        None => (
+            "Let local scripts refresh, show or query GitTop over HTTP".to_string(),
+            p.text_secondary,
+        ),
+    };
+
+    setting_card(
+        row![
+            column![
+                text("Local API").size(14).color(p.text_primary),
+                Space::new().height(4),
+                text(desc).size(11).color(desc_color),
+            ]
+            .width(Fill),
+            text_input("Port", &state.local_api_port_input)
+                .on_input(GeneralMessage::LocalApiPortChanged)
+                .on_submit(GeneralMessage::ApplyLocalApiPort)
+                .size(13)
+                .padding([8, 12])
+                .width(80)
+                .style(theme::text_input_style),
+            button(text("Apply").size(12))
+                .on_press(GeneralMessage::ApplyLocalApiPort)
+                .padding([8, 12])
+                .style(theme::ghost_button),
+            toggler(enabled)
+                .on_toggle(GeneralMessage::ToggleLocalApi)
+                .size(20),
+        ]
+        .spacing(8)
+        .align_y(Alignment::Center),
+    )
+}
+
+fn view_priority_sound(settings: &AppSettings) -> Element<'static, GeneralMessage> {
+    let enabled = settings.priority_sound;
+    let desc = if enabled {
+        "Plays a sound when a priority item arrives while GitTop is open"
+    } else {
+        "Priority arrivals only flash the Important header (Default)"
+    };
+
+    toggle_card(
+        "Priority Sound",
+        desc,
+        enabled,
+        GeneralMessage::TogglePrioritySound,
+    )
+}
+
+fn view_activity_log(settings: &AppSettings) -> Element<'static, GeneralMessage> {
+    let desc = if settings.activity_log {
+        "Appending actions and rule decisions to logs/activity.jsonl"
+    } else {
+        "Keep a JSON Lines record of mark read, done, mute and rule decisions"
+    };
+
+    toggle_card(
+        "Activity Log",
+        desc,
+        settings.activity_log,
+        GeneralMessage::ToggleActivityLog,
+    )
+}
+
+fn view_reduce_motion(settings: &AppSettings) -> Element<'static, GeneralMessage> {
+    let desc = if settings.reduce_motion {
+        "No header flashes or transitions"
+    } else if crate::platform::prefers_reduced_motion() {
+        "Following your system's reduced motion setting"
+    } else {
+        "Follows your system's reduced motion setting (Default)"
+    };
+
+    toggle_card(
+        "Reduce Motion",
+        desc,
+        settings.reduce_motion,
+        GeneralMessage::ToggleReduceMotion,
+    )
+}
+
+fn view_open_action(settings: &AppSettings) -> Element<'static, GeneralMessage> {
+    let p = theme::palette();
+
+    setting_card(
+        row![
+            column![
+                text("When Opening a Notification")
+                    .size(14)
+                    .color(p.text_primary),
+                Space::new().height(4),
+                text("What happens to it on GitHub (Default: mark as read)")
+                    .size(11)
+                    .color(p.text_secondary),
+            ]
+            .width(Fill),
+            pick_list(
+                OpenAction::ALL,
+                Some(settings.open_action),
+                GeneralMessage::ChangeOpenAction
+            )
+            .text_size(13)
+            .padding([8, 12])
+            .style(theme::pick_list_style)
+            .menu_style(theme::menu_style),
+        ]
+        .align_y(Alignment::Center),
+    )
+}
+
+fn view_bulk_open_marks_read(settings: &AppSettings) -> Element<'static, GeneralMessage> {
+    let enabled = settings.bulk_open_marks_read;
+    let desc = if enabled {
+        "Opening a bulk selection in the browser marks it as read (Default)"
+    } else {
+        "Opening a bulk selection leaves it unread"
+    };
+
+    toggle_card(
+        "Mark Read When Opening Selection",
+        desc,
+        enabled,
+        GeneralMessage::ToggleBulkOpenMarksRead,
+    )
+}
+
+fn view_confirm_mark_all_read(settings: &AppSettings) -> Element<'static, GeneralMessage> {
+    let enabled = settings.confirm_mark_all_read;
+    let desc = if enabled {
+        "Ask before \"Mark all as read\" clears more than a few notifications (Default)"
+    } else {
+        "\"Mark all as read\" clears the inbox straight away"
+    };
+
+    toggle_card(
+        "Confirm Mark All Read",
+        desc,
+        enabled,
+        GeneralMessage::ToggleConfirmMarkAllRead,
+    )
+}
+
+fn view_mini_mode(settings: &AppSettings) -> Element<'static, GeneralMessage> {
+    toggle_card(
+        "Mini Mode",
+        "A small list-only window with just your notifications. Also in the tray menu",
+        settings.mini_mode,
+        GeneralMessage::ToggleMiniMode,
+    )
+}
+
+fn view_mini_mode_on_top(settings: &AppSettings) -> Element<'static, GeneralMessage> {
+    toggle_card(
+        "Keep Mini Window on Top",
+        "The mini window stays above other windows (Default)",
+        settings.mini_mode_on_top,
+        GeneralMessage::ToggleMiniModeOnTop,
+    )
+}
+
+fn view_always_on_top(settings: &AppSettings) -> Element<'static, GeneralMessage> {
+    let desc = if crate::platform::supports_window_level() {
+        "Keep GitTop above other windows in any layout, like a side panel"
+    } else {
+        "Keep GitTop above other windows. Most Wayland compositors ignore this"
+    };
+
+    toggle_card(
+        "Always on Top",
+        desc,
+        settings.always_on_top,
+        GeneralMessage::ToggleAlwaysOnTop,
+    )
+}
+
+fn view_show_actors(settings: &AppSettings) -> Element<'static, GeneralMessage> {
+    let enabled = settings.show_actors;
+    let desc = if enabled {
+        "Shows who triggered each notification (one extra API call per item)"
+    } else {
+        "Skips the author lookup to save API calls (Default)"
+    };
+
+    toggle_card(
+        "Show Who Triggered It",
+        desc,
+        enabled,
+        GeneralMessage::ToggleShowActors,
+    )
+}
+
+fn view_compact_threads(settings: &AppSettings) -> Element<'static, GeneralMessage> {
+    let enabled = settings.compact_threads;
+    let desc = if enabled {
+        "Notifications about the same PR or issue show as one item with its history"
+    } else {
+        "Every notification is its own item (Default)"
+    };
+
+    toggle_card(
+        "Compact Notifications",
+        desc,
+        enabled,
+        GeneralMessage::ToggleCompactThreads,
+    )
+}
+
+fn view_pause() -> Element<'static, GeneralMessage> {
+    let p = theme::palette();
+    let paused_until = crate::ui::state::paused_until();
+
+    let desc = match paused_until {
+        Some(until) => format!("Paused — resuming at {}", until.format("%H:%M")),
+        None => "Stop refreshing and desktop alerts for a while".to_string(),
+    };
+
+    let mut actions = row![].spacing(4);
+    for (minutes, label) in crate::tray::PAUSE_OPTIONS {
+        actions = actions.push(
+            button(text(label).size(12))
+                .on_press(GeneralMessage::PauseFor(minutes))
+                .padding([8, 12])
+                .style(theme::ghost_button),
+        );
+    }
+    if paused_until.is_some() {
+        actions = actions.push(
+            button(text("Resume").size(12))
+                .on_press(GeneralMessage::ResumeNotifications)
+                .padding([8, 12])
+                .style(theme::ghost_button),
+        );
+    }
+
+    setting_card(
+        row![
+            column![
+                text("Pause Notifications").size(14).color(p.text_primary),
+                Space::new().height(4),
+                text(desc).size(11).color(p.text_secondary),
+            ]
+            .width(Fill),
+            actions,
+        ]
+        .spacing(8)
+        .align_y(Alignment::Center),
+    )
+}
+
+fn view_desktop_reasons(settings: &AppSettings) -> Element<'static, GeneralMessage> {
+    let p = theme::palette();
+
+    let rows = NotificationReason::ALL.chunks(2).map(|pair| {
+        pair.iter()
+            .fold(row![].spacing(16), |row, &reason| {
+                let enabled = !settings.muted_desktop_reasons.contains(&reason);
+                row.push(
+                    row![
+                        text(reason.label())
+                            .size(12)
+                            .color(p.text_primary)
+                            .width(Fill),
+                        toggler(enabled)
+                            .on_toggle(move |on| GeneralMessage::ToggleDesktopReason(reason, on))
+                            .size(16),
+                    ]
+                    .align_y(Alignment::Center)
+                    .width(Fill),
+                )
+            })
+            .into()
+    });
+
+    setting_card(column![
+        text("Notify For").size(14).color(p.text_primary),
+        Space::new().height(4),
+        text("Reasons that pop up a desktop notification. Important items always do.")
+            .size(11)
+            .color(p.text_secondary),
+        Space::new().height(12),
+        column(rows).spacing(8),
+    ])
+}
+
+fn view_test_notification(state: &GeneralSettingsState) -> Element<'static, GeneralMessage> {
+    let p = theme::palette();
+
+    let (desc, desc_color) = match &state.test_notification {
+        None => (
+            "Check that desktop notifications reach you. If nothing appears, look at \
+             your notification daemon, system permissions, or Do Not Disturb."
+                .to_string(),
+            p.text_secondary,
+        ),
+        Some(Ok(())) => (
+            "Sent. If it didn't appear, the system is hiding GitTop's notifications.".to_string(),
+            p.accent_success,
+        ),
+        Some(Err(e)) => (format!("Couldn't send: {e}"), p.accent_danger),
+    };
+
+    setting_card(
+        row![
+            column![
+                text("Test Notification").size(14).color(p.text_primary),
+                Space::new().height(4),
+                text(desc).size(11).color(desc_color),
+            ]
+            .width(Fill),
+            button(text("Send").size(12))
+                .on_press(GeneralMessage::SendTestNotification)
+                .padding([8, 12])
+                .style(theme::ghost_button),
+        ]
+        .align_y(Alignment::Center),
+    )
+}
+
+fn view_cache(state: &GeneralSettingsState) -> Element<'static, GeneralMessage> {
+    let p = theme::palette();
+
+    let (desc, desc_color) = match (&state.cache_error, state.cache_size) {
+        (Some(err), _) => (err.clone(), p.accent_danger),
+        (None, Some(size)) => (
+            format!(
+                "{} on disk. Old entries are pruned automatically.",
+                format_bytes(size)
+            ),
+            p.text_secondary,
+        ),
+        (None, None) => ("Cache unavailable".to_string(), p.text_secondary),
+    };
+
+    setting_card(
+        row![
+            column![
+                text("Cache").size(14).color(p.text_primary),
+                Space::new().height(4),
+                text(desc).size(11).color(desc_color),
+            ]
+            .width(Fill),
+            button(text("Clear cache").size(12))
+                .on_press_maybe(state.cache_size.map(|_| GeneralMessage::ClearCache))
+                .padding([8, 12])
+                .style(theme::ghost_button),
+        ]
+        .align_y(Alignment::Center),
+    )
+}
+
+fn view_debug_report(state: &GeneralSettingsState) -> Element<'static, GeneralMessage> {
+    let p = theme::palette();
+
+    setting_card(
+        row![
+            column![
+                text("Debug Report").size(14).color(p.text_primary),
+                Space::new().height(4),
+                text("Version, platform, and settings summary for bug reports. No tokens or account names.")
+                    .size(11)
+                    .color(p.text_secondary),
+            ]
+            .width(Fill),
+            button(
+                text(if state.debug_report_copied {
+                    "Copied"
+                } else {
+                    "Copy"
+                })
+                .size(12)
+            )
+            .on_press(GeneralMessage::CopyDebugReport)
+            .padding([8, 12])
+            .style(theme::ghost_button),
+        ]
+        .align_y(Alignment::Center),
+    )
+}
+
+/// Errors seen this run, newest first, so intermittent failures can be
+/// reported after their banner is gone.
+fn view_recent_errors() -> Element<'static, GeneralMessage> {
+    let p = theme::palette();
+    let errors = crate::diagnostics::recent_errors();
+
+    let list: Element<'static, GeneralMessage> = if errors.is_empty() {
+        text("None since GitTop started.")
+            .size(11)
+            .color(p.text_muted)
+            .into()
+    } else {
+        errors
+            .into_iter()
+            .fold(column![].spacing(6), |col, e| {
+                let at = e.at.with_timezone(&chrono::Local).format("%b %-d %H:%M:%S");
+                col.push(
+                    column![
+                        text(format!("{at} · {}", e.category))
+                            .size(11)
+                            .color(p.text_muted),
+                        text(e.message).size(12).color(p.text_secondary),
+                    ]
+                    .spacing(2),
+                )
+            })
+            .into()
+    };
+
+    setting_card(column![
+        text("Recent Errors").size(14).color(p.text_primary),
+        Space::new().height(4),
+        text("Failures since GitTop started. The debug report includes them.")
+            .size(11)
+            .color(p.text_secondary),
+        Space::new().height(8),
+        list,
+    ])
+}
+
+fn view_backup(state: &GeneralSettingsState) -> Element<'static, GeneralMessage> {
+    let p = theme::palette();
+
+    let (desc, desc_color) = match &state.backup_status {
+        Some(Ok(status)) => (status.clone(), p.accent_success),
+        Some(Err(e)) => (e.clone(), p.accent_danger),
+        None => (
+            "Settings, rules, and the account list in one file. Tokens are not included."
+                .to_string(),
+            p.text_secondary,
+        ),
+    };
+
+    let mut content = column![
+        row![
+            column![
+                text("Backup").size(14).color(p.text_primary),
+                Space::new().height(4),
+                text(desc).size(11).color(desc_color),
+            ]
+            .width(Fill),
+            button(text("Back up config").size(12))
+                .on_press(GeneralMessage::BackupConfig)
+                .padding([8, 12])
+                .style(theme::ghost_button),
+            button(text("Restore config").size(12))
+                .on_press(GeneralMessage::RestoreConfig)
+                .padding([8, 12])
+                .style(theme::ghost_button),
+        ]
+        .spacing(8)
+        .align_y(Alignment::Center),
+    ];
+
+    if !state.restored_pending.is_empty() {
+        content = content.push(Space::new().height(8)).push(
+            text("Sign in to each account here or under Accounts. Until then they keep their settings but don't sync.")
+                .size(11)
+                .color(p.accent_warning),
+        );
+        for username in &state.restored_pending {
+            content = content.push(
+                row![
+                    text(format!("@{username}"))
+                        .size(12)
+                        .color(p.text_primary)
+                        .width(Fill),
+                    button(text("Sign in").size(12))
+                        .on_press(GeneralMessage::SignInRestoredAccount(username.clone()))
+                        .padding([6, 10])
+                        .style(theme::ghost_button),
+                ]
+                .align_y(Alignment::Center),
+            );
+        }
+    }
+
+    setting_card(content.spacing(4))
+}
+
+fn view_reset(state: &GeneralSettingsState) -> Element<'static, GeneralMessage> {
+    let p = theme::palette();
+
+    let actions: Element<'static, GeneralMessage> = if state.confirm_reset {
+        row![
+            button(text("Cancel").size(12))
+                .on_press(GeneralMessage::CancelReset)
+                .padding([8, 12])
+                .style(theme::ghost_button),
+            button(text("Reset").size(12).color(iced::Color::WHITE))
+                .on_press(GeneralMessage::ConfirmReset)
+                .padding([8, 12])
+                .style(theme::danger_button),
+        ]
+        .spacing(8)
+        .into()
+    } else {
+        button(text("Reset GitTop").size(12))
+            .on_press(GeneralMessage::RequestReset)
+            .padding([8, 12])
+            .style(theme::ghost_button)
+            .into()
+    };
+
+    let desc = if state.confirm_reset {
+        "This signs out every account and deletes all settings. Continue?"
+    } else {
+        "Clear the cache, settings, and saved tokens, then return to sign in"
+    };
+
+    setting_card(
+        row![
+            column![
+                text("Reset All Data").size(14).color(p.text_primary),
+                Space::new().height(4),
+                text(desc).size(11).color(if state.confirm_reset {
+                    p.accent_danger
+                } else {
+                    p.text_secondary
+                }),
+            ]
+            .width(Fill),
+            actions,
+        ]
+        .align_y(Alignment::Center),
+    )
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const KB: f64 = 1024.0;
+    const MB: f64 = KB * 1024.0;
+    let bytes = bytes as f64;
+    if bytes >= MB {
+        format!("{:.1} MB", bytes / MB)
+    } else {
+        format!("{:.0} KB", bytes / KB)
+    }
+}
+
 fn view_notification_scale(settings: &AppSettings) -> Element<'static, GeneralMessage> {
     let scale = settings.notification_font_scale;
     slider_card(
@@ -162,7 +1018,7 @@ fn view_sidebar_width(settings: &AppSettings) -> Element<'static, GeneralMessage
     slider_card(
         "Sidebar Width (Default: 220px)",
         format!("{}px", width as i32),
-        180.0..=400.0,
+        sidebar::resize::WIDTH_RANGE,
         width,
         10.0,
         GeneralMessage::SetSidebarWidth,
@@ -195,6 +1051,65 @@ fn toggle_card<'a>(
     )
 }
 
+fn view_max_notifications(settings: &AppSettings) -> Element<'static, GeneralMessage> {
+    let max = settings.max_notifications;
+    slider_card(
+        "Notifications Kept in Memory (Default: 1000)",
+        max.to_string(),
+        100.0..=5000.0,
+        max as f32,
+        100.0,
+        GeneralMessage::SetMaxNotifications,
+    )
+}
+
+fn view_refresh_interval(settings: &AppSettings) -> Element<'static, GeneralMessage> {
+    let secs = settings.refresh_interval().as_secs();
+    let value_text = match (secs / 60, secs % 60) {
+        (0, s) => format!("{s}s"),
+        (m, 0) => format!("{m}m"),
+        (m, s) => format!("{m}m {s}s"),
+    };
+    let range = crate::settings::REFRESH_INTERVAL_RANGE;
+    slider_card(
+        "Refresh Every (Default: 1m)",
+        value_text,
+        *range.start() as f32..=*range.end() as f32,
+        secs as f32,
+        15.0,
+        GeneralMessage::SetRefreshInterval,
+    )
+}
+
+fn view_toast_individual_limit(settings: &AppSettings) -> Element<'static, GeneralMessage> {
+    let limit = settings.toast_individual_limit;
+    slider_card(
+        "Separate Toasts Before Summarizing (Default: 1)",
+        limit.to_string(),
+        toast_limit_range(),
+        limit as f32,
+        1.0,
+        GeneralMessage::SetToastIndividualLimit,
+    )
+}
+
+fn view_toast_summary_titles(settings: &AppSettings) -> Element<'static, GeneralMessage> {
+    let titles = settings.toast_summary_titles;
+    slider_card(
+        "Titles Listed in a Summary (Default: 3)",
+        titles.to_string(),
+        toast_limit_range(),
+        titles as f32,
+        1.0,
+        GeneralMessage::SetToastSummaryTitles,
+    )
+}
+
+fn toast_limit_range() -> std::ops::RangeInclusive<f32> {
+    let range = TOAST_LIMIT_RANGE;
+    *range.start() as f32..=*range.end() as f32
+}
+
 fn slider_card<'a>(
     title: &'static str,
     value_text: String,
@@ -2,7 +2,10 @@
 
 pub mod account_management;
 pub mod account_rules;
+pub mod actor_rules;
+pub mod actors;
 pub mod bulk_actions;
+pub mod ci_status;
 pub mod general_settings;
 pub mod network_proxy;
 pub mod notification_details;
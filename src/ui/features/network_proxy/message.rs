@@ -5,4 +5,6 @@ pub enum ProxyMessage {
     UsernameChanged(String),
     PasswordChanged(String),
     Save,
+    /// Result of reaching GitHub through the saved proxy.
+    Checked(Result<(), String>),
 }
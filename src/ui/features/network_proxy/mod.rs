@@ -4,6 +4,6 @@ pub mod update;
 pub mod view;
 
 pub use message::ProxyMessage;
-pub use state::NetworkProxyState;
+pub use state::{ConnectionCheck, NetworkProxyState};
 pub use update::update;
 pub use view::view;
@@ -1,6 +1,16 @@
 use crate::github::proxy_keyring;
 use crate::settings::AppSettings;
 
+/// Whether GitHub answered through the last saved proxy settings.
+#[derive(Debug, Clone, Default)]
+pub enum ConnectionCheck {
+    #[default]
+    Idle,
+    Checking,
+    Ok,
+    Failed(String),
+}
+
 #[derive(Debug, Clone)]
 pub struct NetworkProxyState {
     pub enabled: bool,
@@ -9,6 +19,7 @@ pub struct NetworkProxyState {
     pub password: String,
     pub creds_dirty: bool,
     pub needs_rebuild: bool,
+    pub check: ConnectionCheck,
 }
 
 impl NetworkProxyState {
@@ -33,6 +44,7 @@ impl NetworkProxyState {
             password,
             creds_dirty: false,
             needs_rebuild: false,
+            check: ConnectionCheck::Idle,
         }
     }
 }
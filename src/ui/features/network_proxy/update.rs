@@ -1,5 +1,5 @@
 use super::message::ProxyMessage;
-use super::state::NetworkProxyState;
+use super::state::{ConnectionCheck, NetworkProxyState};
 use crate::github::proxy_keyring;
 use crate::settings::AppSettings;
 use iced::Task;
@@ -9,6 +9,11 @@ pub fn update(
     message: ProxyMessage,
     settings: &mut AppSettings,
 ) -> Task<ProxyMessage> {
+    if !matches!(message, ProxyMessage::Save | ProxyMessage::Checked(_)) {
+        // The last check was for the saved settings, not these edits
+        state.check = ConnectionCheck::Idle;
+    }
+
     match message {
         ProxyMessage::ToggleEnabled(enabled) => {
             state.enabled = enabled;
@@ -32,6 +37,16 @@ pub fn update(
             update_proxy_credentials(state, settings);
             Task::none()
         }
+        ProxyMessage::Checked(result) => {
+            state.check = match result {
+                Ok(()) => ConnectionCheck::Ok,
+                Err(e) => {
+                    tracing::warn!(error = %e, "GitHub unreachable with new proxy settings");
+                    ConnectionCheck::Failed(e)
+                }
+            };
+            Task::none()
+        }
     }
 }
 
@@ -112,7 +127,7 @@ fn update_proxy_credentials(state: &mut NetworkProxyState, settings: &mut AppSet
         "Proxy settings saved"
     );
 
-    // Signal that clients need rebuild when leaving settings
+    // Signal that clients need rebuild; the settings screen does it right away
     state.needs_rebuild = true;
     // Reset dirty flag since we just saved
     state.creds_dirty = false;
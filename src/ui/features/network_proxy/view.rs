@@ -6,7 +6,7 @@ use crate::ui::screens::settings::components::{setting_card, tab_title};
 use crate::ui::{icons, theme};
 
 use super::message::ProxyMessage;
-use super::state::NetworkProxyState;
+use super::state::{ConnectionCheck, NetworkProxyState};
 
 /// Check if proxy settings have unsaved changes
 fn has_unsaved_changes(state: &NetworkProxyState, settings: &AppSettings) -> bool {
@@ -88,6 +88,7 @@ fn view_proxy_configuration<'a>(
             Space::new().height(12),
             text_input("http://proxy.company.com:8080", &state.url)
                 .on_input(ProxyMessage::UrlChanged)
+                .on_submit(ProxyMessage::Save)
                 .padding([8, 12])
                 .size(13)
                 .width(Fill)
@@ -126,6 +127,7 @@ fn view_proxy_configuration<'a>(
             row![
                 text_input("Username", &state.username)
                     .on_input(ProxyMessage::UsernameChanged)
+                    .on_submit(ProxyMessage::Save)
                     .padding([8, 12])
                     .size(13)
                     .width(Fill)
@@ -134,6 +136,7 @@ fn view_proxy_configuration<'a>(
                 text_input("Password", &state.password)
                     .secure(true)
                     .on_input(ProxyMessage::PasswordChanged)
+                    .on_submit(ProxyMessage::Save)
                     .padding([8, 12])
                     .size(13)
                     .width(Fill)
@@ -155,7 +158,29 @@ fn view_proxy_configuration<'a>(
                     .padding(6),
             ]
             .align_y(Alignment::Center),
+            view_connection_check(&state.check),
         ]
         .spacing(4),
     )
 }
+
+/// Outcome of reaching GitHub after the last save.
+fn view_connection_check(check: &ConnectionCheck) -> Element<'_, ProxyMessage> {
+    let p = theme::palette();
+
+    match check {
+        ConnectionCheck::Idle => Space::new().into(),
+        ConnectionCheck::Checking => text("Checking connection to GitHub…")
+            .size(12)
+            .color(p.text_muted)
+            .into(),
+        ConnectionCheck::Ok => text("Connected to GitHub")
+            .size(12)
+            .color(p.accent_success)
+            .into(),
+        ConnectionCheck::Failed(error) => text(format!("Couldn't reach GitHub: {error}"))
+            .size(12)
+            .color(p.accent_danger)
+            .into(),
+    }
+}
@@ -70,7 +70,9 @@ pub fn update_notification_details(
                 && let Some(ref url) = notif.url
             {
                 let web_url = api_url_to_web_url(url);
-                let _ = open::that(&web_url);
+                if let Err(e) = crate::browser::open(&web_url) {
+                    tracing::warn!(error = %e, "Failed to open notification in browser");
+                }
             }
             Task::none()
         }
@@ -16,6 +16,9 @@ use crate::ui::features::thread_actions::ThreadActionMessage;
 use crate::ui::screens::notifications::messages::NotificationMessage;
 use crate::ui::{icons, theme};
 
+/// Fixed width of the details panel.
+pub const PANEL_WIDTH: f32 = 380.0;
+
 /// View the details panel for a selected notification.
 pub fn view<'a>(
     notification: Option<&'a NotificationView>,
@@ -38,7 +41,7 @@ pub fn view<'a>(
     };
 
     container(content)
-        .width(Length::Fixed(380.0))
+        .width(Length::Fixed(PANEL_WIDTH))
         .height(Fill)
         .style(move |_| container::Style {
             background: Some(iced::Background::Color(p.bg_base)),
@@ -3,5 +3,7 @@ use iced::widget::scrollable::Viewport;
 #[derive(Debug, Clone)]
 pub enum NotificationListMessage {
     ToggleGroup(usize),
+    /// Expand or collapse the "hidden by rules" section.
+    ToggleHidden,
     OnScroll(Viewport),
 }
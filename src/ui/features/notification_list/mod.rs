@@ -7,4 +7,4 @@ mod widgets;
 pub use messages::NotificationListMessage;
 pub use state::NotificationListState;
 pub use update::update;
-pub use view::{ListArgs, view};
+pub use view::{ListArgs, ListLayout, SCROLL_ID, item_bounds, view};
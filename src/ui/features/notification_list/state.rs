@@ -2,6 +2,8 @@
 pub struct NotificationListState {
     pub scroll_offset: f32,
     pub viewport_height: f32,
+    /// Whether the "hidden by rules" section is expanded.
+    pub hidden_expanded: bool,
 }
 
 impl Default for NotificationListState {
@@ -9,6 +11,7 @@ impl Default for NotificationListState {
         Self {
             scroll_offset: 0.0,
             viewport_height: 600.0, // Default fallback
+            hidden_expanded: false,
         }
     }
 }
@@ -27,9 +30,26 @@ impl NotificationListState {
         self.viewport_height = viewport.bounds().height;
     }
 
+    /// Offset that brings a row spanning `top..bottom` into view, or `None`
+    /// if it's already on screen. The tracked offset moves with it.
+    pub fn reveal(&mut self, (top, bottom): (f32, f32)) -> Option<f32> {
+        let offset = if top < self.scroll_offset {
+            top
+        } else if bottom > self.scroll_offset + self.viewport_height {
+            bottom - self.viewport_height
+        } else {
+            return None;
+        };
+        self.scroll_offset = offset.max(0.0);
+        Some(self.scroll_offset)
+    }
+
     /// Calculate the range of items to render for virtual scrolling.
     /// Returns (start_index, end_index) of flattened items.
     ///
+    /// `gap` is extra space of the given height right above one item, such
+    /// as the "new since last check" separator.
+    ///
     /// This is a helper for the view logic.
     pub fn calculate_visible_range(
         &self,
@@ -38,24 +58,39 @@ impl NotificationListState {
         buffer_items: usize,
         items_start_y: f32,
         total_items_count: usize,
+        gap: Option<(usize, f32)>,
     ) -> (usize, usize) {
         let first_visible_px = self.scroll_offset.max(0.0);
         let last_visible_px = self.scroll_offset + self.viewport_height + 100.0;
-        let items_end_y = items_start_y
-            + (total_items_count as f32 * (item_height + column_spacing) - column_spacing);
+        let pitch = item_height + column_spacing;
+        let gap_height = gap.map_or(0.0, |(_, height)| height);
+        let items_end_y =
+            items_start_y + (total_items_count as f32 * pitch - column_spacing) + gap_height;
 
         if items_end_y < first_visible_px || items_start_y > last_visible_px {
             return (0, 0); // Not visible
         }
 
+        // Distance into the items as if the gap weren't there; a point
+        // inside the gap counts as the top of the item below it
+        let offset_at = |px: f32| {
+            let offset = px - items_start_y;
+            match gap {
+                Some((idx, height)) if offset > idx as f32 * pitch => {
+                    (offset - height).max(idx as f32 * pitch)
+                }
+                _ => offset,
+            }
+        };
+
         let first_visible_idx = if first_visible_px > items_start_y {
-            ((first_visible_px - items_start_y) / (item_height + column_spacing)) as usize
+            (offset_at(first_visible_px) / pitch) as usize
         } else {
             0
         };
 
         let last_visible_idx = if last_visible_px < items_end_y {
-            ((last_visible_px - items_start_y) / (item_height + column_spacing)).ceil() as usize + 1
+            (offset_at(last_visible_px) / pitch).ceil() as usize + 1
         } else {
             total_items_count
         };
@@ -66,3 +101,34 @@ impl NotificationListState {
         (render_start, render_end)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scrolled_to(scroll_offset: f32) -> NotificationListState {
+        NotificationListState {
+            scroll_offset,
+            viewport_height: 200.0,
+            ..NotificationListState::default()
+        }
+    }
+
+    #[test]
+    fn test_visible_range_counts_the_separator_gap() {
+        // Rows every 100px from 0; a 50px gap above row 5 pushes row 20
+        // from 2000px down to 2050px
+        let state = scrolled_to(2040.0);
+        let (start, _) = state.calculate_visible_range(90.0, 10.0, 0, 0.0, 40, None);
+        assert_eq!(start, 20);
+        let (start, _) = state.calculate_visible_range(90.0, 10.0, 0, 0.0, 40, Some((5, 50.0)));
+        assert_eq!(start, 19);
+    }
+
+    #[test]
+    fn test_point_inside_the_gap_starts_at_the_row_below() {
+        let state = scrolled_to(520.0);
+        let (start, _) = state.calculate_visible_range(90.0, 10.0, 0, 0.0, 40, Some((5, 50.0)));
+        assert_eq!(start, 5);
+    }
+}
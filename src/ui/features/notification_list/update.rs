@@ -16,6 +16,10 @@ pub fn update(
             }
             Task::none()
         }
+        NotificationListMessage::ToggleHidden => {
+            state.hidden_expanded = !state.hidden_expanded;
+            Task::none()
+        }
         NotificationListMessage::OnScroll(viewport) => {
             state.update_viewport(&viewport);
             Task::none()
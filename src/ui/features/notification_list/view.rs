@@ -1,46 +1,164 @@
-use iced::widget::{Space, button, column, container, row, scrollable};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use iced::widget::{Space, button, column, container, row, scrollable, text};
 use iced::{Alignment, Element, Fill};
+use iced_aw::ContextMenu;
 
 use super::widgets::notification_item;
+use crate::github::GitHubError;
 use crate::settings::IconTheme;
 use crate::ui::features::bulk_actions::{BulkActionMessage, BulkActionState};
 use crate::ui::features::sidebar::SidebarState;
-use crate::ui::screens::notifications::components::group::view_group_header;
+use crate::ui::features::thread_actions::ThreadActionMessage;
+use crate::ui::screens::notifications::components::group::{view_group_header, view_hidden_header};
 use crate::ui::screens::notifications::components::states::{
     EmptyState, view_empty, view_error, view_loading,
 };
-use crate::ui::screens::notifications::helper::{NotificationGroup, ProcessedNotification};
-use crate::ui::screens::notifications::messages::NotificationMessage;
+use crate::ui::screens::notifications::helper::{
+    HiddenNotification, NotificationGroup, ProcessedNotification,
+};
+use crate::ui::screens::notifications::messages::{
+    NotificationMessage, ReminderDelay, SnoozeDelay,
+};
+use crate::ui::screens::settings::rule_engine::components::view_context_menu_item;
 use crate::ui::{icons, theme};
 
 use super::{NotificationListMessage, NotificationListState};
 
 pub struct ListArgs<'a> {
     pub groups: &'a [NotificationGroup],
+    /// Notifications rules hid, listed in a collapsed section at the end.
+    pub hidden: &'a [HiddenNotification],
     pub is_loading: bool,
+    /// Loading after a restore from tray rather than a routine refresh.
+    pub restoring: bool,
     pub has_notifications: bool, // or check groups.is_empty?
-    pub error_message: Option<&'a String>,
+    pub error: Option<&'a GitHubError>,
     pub filters: &'a SidebarState,
     pub bulk_actions: &'a BulkActionState,
 
     pub list_state: &'a NotificationListState,
+    /// Draw a separator below items updated after this.
+    pub new_since: Option<DateTime<Utc>>,
+    /// Highlight the priority group header (new priority item arrived).
+    pub flash_priority: bool,
+    /// GitHub rejected the token, so an empty list isn't "caught up".
+    pub token_invalid: bool,
+    /// Time left on a GitHub throttle, for the error's countdown.
+    pub throttled_for: Option<Duration>,
+    /// Row the keyboard is on; drawn with an accent outline.
+    pub focused_id: Option<&'a str>,
     pub icon_theme: IconTheme,
-    pub power_mode: bool,
+    pub layout: ListLayout,
+}
+
+/// How list rows look and what clicking one does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListLayout {
+    /// Roomy cards that open in the browser.
+    Standard,
+    /// Dense rows that select into the details panel; bulk selection allowed.
+    Power,
+    /// Dense rows that open in the browser, for the mini window.
+    Mini,
+}
+
+impl ListLayout {
+    pub fn is_dense(self) -> bool {
+        matches!(self, Self::Power | Self::Mini)
+    }
+
+    /// Estimated row height for virtual scrolling. Items grow with larger
+    /// text and with High Contrast's bigger hit targets.
+    fn item_height(self) -> f32 {
+        let base: f32 = if self.is_dense() { 56.0 } else { 72.0 };
+        base * theme::notification_font_scale().max(1.0) + 2.0 * theme::palette().hit_padding
+    }
+}
+
+// === HEIGHT ESTIMATES FOR VIRTUAL SCROLLING ===
+const HEADER_HEIGHT: f32 = 32.0;
+const COLUMN_SPACING: f32 = 8.0;
+const CONTENT_PADDING: f32 = 8.0;
+const SEPARATOR_HEIGHT: f32 = 16.0;
+const BUFFER_ITEMS: usize = 10;
+
+/// The list's scrollable, so keyboard selection can scroll it.
+pub const SCROLL_ID: &str = "notification-list";
+
+/// Estimated top and bottom of `id`'s row within the list, using the same
+/// estimates as the virtual scrolling in [`view`].
+pub fn item_bounds(
+    groups: &[NotificationGroup],
+    id: &str,
+    layout: ListLayout,
+    new_since: Option<DateTime<Utc>>,
+) -> Option<(f32, f32)> {
+    let item_height = layout.item_height();
+    let mut y = CONTENT_PADDING;
+    for group in groups.iter().filter(|g| !g.notifications.is_empty()) {
+        y += HEADER_HEIGHT + COLUMN_SPACING;
+        if !group.is_expanded {
+            continue;
+        }
+        let separator_idx = separator_index(group, new_since);
+        if let Some(idx) = group
+            .notifications
+            .iter()
+            .position(|p| p.notification.id == id)
+        {
+            let gap = separator_idx
+                .filter(|&sep| sep <= idx)
+                .map_or(0.0, |_| SEPARATOR_HEIGHT + COLUMN_SPACING);
+            let top = y + idx as f32 * (item_height + COLUMN_SPACING) + gap;
+            return Some((top, top + item_height));
+        }
+        y += group.notifications.len() as f32 * (item_height + COLUMN_SPACING);
+        if separator_idx.is_some() {
+            y += SEPARATOR_HEIGHT + COLUMN_SPACING;
+        }
+    }
+    None
+}
+
+/// First item in `group` the user had already seen, if newer ones sit above
+/// it. The "new since last check" separator goes right above it.
+fn separator_index(group: &NotificationGroup, new_since: Option<DateTime<Utc>>) -> Option<usize> {
+    let since = new_since?;
+    group
+        .notifications
+        .iter()
+        .position(|p| p.notification.updated_at <= since)
+        .filter(|&idx| idx > 0)
 }
 
 pub fn view<'a>(args: ListArgs<'a>) -> Element<'a, NotificationMessage> {
     if args.is_loading && !args.has_notifications {
-        return view_loading();
+        let label = if args.restoring {
+            "Syncing your notifications…"
+        } else {
+            "Loading notifications..."
+        };
+        return view_loading(label);
     }
 
-    if let Some(error) = args.error_message {
-        return view_error(error, args.icon_theme);
+    if let Some(error) = args.error {
+        return view_error(error, args.throttled_for, args.icon_theme);
     }
 
     // Check if there are any notifications to display
+    // The mini window has no room for the hidden section
+    let hidden = if args.layout == ListLayout::Mini {
+        &[][..]
+    } else {
+        args.hidden
+    };
     let has_content = args.groups.iter().any(|g| !g.notifications.is_empty());
-    if !has_content {
-        let empty_state = if args.filters.show_all {
+    if !has_content && hidden.is_empty() {
+        let empty_state = if args.token_invalid {
+            EmptyState::TokenInvalid
+        } else if args.filters.show_all {
             EmptyState::NoNotifications
         } else {
             EmptyState::AllCaughtUp
@@ -48,87 +166,117 @@ pub fn view<'a>(args: ListArgs<'a>) -> Element<'a, NotificationMessage> {
         return view_empty(empty_state, args.icon_theme);
     }
 
-    let in_bulk_mode = args.bulk_actions.bulk_mode && args.power_mode;
+    let in_bulk_mode = args.bulk_actions.bulk_mode && args.layout == ListLayout::Power;
     let pp = theme::palette();
 
-    // === HEIGHT ESTIMATES FOR VIRTUAL SCROLLING ===
-    let item_height: f32 = if args.power_mode { 56.0 } else { 72.0 };
-    let header_height: f32 = 32.0;
-    let column_spacing: f32 = 8.0;
-    let content_padding: f32 = 8.0;
-    let buffer_items: usize = 10;
+    let item_height = args.layout.item_height();
 
     let first_visible_px = args.list_state.scroll_offset.max(0.0);
     let last_visible_px = args.list_state.scroll_offset + args.list_state.viewport_height + 100.0;
 
     let mut content = column![]
-        .spacing(column_spacing)
-        .padding([content_padding, content_padding]);
-    let mut current_y: f32 = content_padding;
+        .spacing(COLUMN_SPACING)
+        .padding([CONTENT_PADDING, CONTENT_PADDING]);
+    let mut current_y: f32 = CONTENT_PADDING;
 
     for (group_idx, group) in args.groups.iter().enumerate() {
         if group.notifications.is_empty() {
             continue;
         }
 
-        let header_end_y = current_y + header_height;
-        let header =
-            container(view_group_header(group, group_idx, args.icon_theme)).height(header_height);
+        let header_end_y = current_y + HEADER_HEIGHT;
+        let header = container(view_group_header(
+            group,
+            group_idx,
+            args.icon_theme,
+            args.flash_priority,
+        ))
+        .height(HEADER_HEIGHT);
         content = content.push(header);
-        current_y = header_end_y + column_spacing;
+        current_y = header_end_y + COLUMN_SPACING;
 
         if group.is_expanded {
             let items_start_y = current_y;
             let items_count = group.notifications.len();
-            let total_items_height =
-                items_count as f32 * (item_height + column_spacing) - column_spacing;
+            // The separator is a row of its own, so it takes spacing too
+            let separator_idx = separator_index(group, args.new_since);
+            let separator_gap = SEPARATOR_HEIGHT + COLUMN_SPACING;
+            let total_items_height = items_count as f32 * (item_height + COLUMN_SPACING)
+                - COLUMN_SPACING
+                + separator_idx.map_or(0.0, |_| separator_gap);
             let items_end_y = items_start_y + total_items_height;
 
             if items_end_y >= first_visible_px && items_start_y <= last_visible_px {
                 let (render_start, render_end) = args.list_state.calculate_visible_range(
                     item_height,
-                    column_spacing,
-                    buffer_items,
+                    COLUMN_SPACING,
+                    BUFFER_ITEMS,
                     items_start_y,
                     items_count,
+                    separator_idx.map(|idx| (idx, separator_gap)),
                 );
 
+                // Rows the spacers stand in for include the separator when
+                // it falls outside the rendered range
                 if render_start > 0 {
-                    let top_spacer_height = render_start as f32 * (item_height + column_spacing);
+                    let mut top_spacer_height =
+                        render_start as f32 * (item_height + COLUMN_SPACING);
+                    if separator_idx.is_some_and(|idx| idx < render_start) {
+                        top_spacer_height += separator_gap;
+                    }
                     content = content.push(Space::new().height(top_spacer_height).width(Fill));
                 }
 
                 let is_priority = group.is_priority;
-                for p in &group.notifications[render_start..render_end] {
-                    let item_element = item_view(
-                        p,
-                        in_bulk_mode,
-                        args.bulk_actions,
-                        args.icon_theme,
-                        args.power_mode,
-                        is_priority,
-                        pp,
-                    );
+                for (idx, p) in group.notifications[render_start..render_end]
+                    .iter()
+                    .enumerate()
+                    .map(|(i, p)| (render_start + i, p))
+                {
+                    if separator_idx == Some(idx) {
+                        content = content.push(new_since_separator(pp));
+                    }
+                    let item_element = item_view(p, &args, in_bulk_mode, is_priority);
                     content = content.push(item_element);
                 }
 
                 if render_end < items_count {
                     let remaining = items_count - render_end;
-                    let bottom_spacer_height = remaining as f32 * (item_height + column_spacing);
+                    let mut bottom_spacer_height =
+                        remaining as f32 * (item_height + COLUMN_SPACING);
+                    if separator_idx.is_some_and(|idx| idx >= render_end) {
+                        bottom_spacer_height += separator_gap;
+                    }
                     content = content.push(Space::new().height(bottom_spacer_height).width(Fill));
                 }
             } else {
                 content = content.push(Space::new().height(total_items_height).width(Fill));
             }
 
-            current_y = items_end_y + column_spacing;
+            current_y = items_end_y + COLUMN_SPACING;
         }
     }
 
-    content = content.push(Space::new().height(content_padding));
+    // Rendered in full after the groups; it starts collapsed and sits below
+    // everything the virtual scrolling above accounts for
+    if !hidden.is_empty() {
+        let expanded = args.list_state.hidden_expanded;
+        content = content.push(
+            container(view_hidden_header(hidden.len(), expanded, args.icon_theme))
+                .height(HEADER_HEIGHT),
+        );
+        if expanded {
+            for h in hidden {
+                content = content.push(hidden_item(h, pp));
+            }
+        }
+    }
+
+    content = content.push(Space::new().height(CONTENT_PADDING));
 
     container(
         scrollable(content)
+            .id(SCROLL_ID)
             .on_scroll(|v| NotificationMessage::List(NotificationListMessage::OnScroll(v)))
             .height(Fill)
             .width(Fill)
@@ -140,20 +288,71 @@ pub fn view<'a>(args: ListArgs<'a>) -> Element<'a, NotificationMessage> {
     .into()
 }
 
+fn new_since_separator<'a>(pp: theme::ThemePalette) -> Element<'a, NotificationMessage> {
+    let line = move || {
+        container(Space::new().height(1))
+            .width(Fill)
+            .style(move |_| container::Style {
+                background: Some(iced::Background::Color(pp.accent)),
+                ..Default::default()
+            })
+    };
+
+    container(
+        row![
+            line(),
+            text("New since last check").size(11).color(pp.accent),
+            line(),
+        ]
+        .spacing(8)
+        .align_y(Alignment::Center),
+    )
+    .height(SEPARATOR_HEIGHT)
+    .align_y(Alignment::Center)
+    .into()
+}
+
+/// A hidden notification: its title, where it's from and the rule that hid it.
+/// Clicking opens it in the browser.
+fn hidden_item<'a>(
+    h: &'a HiddenNotification,
+    pp: theme::ThemePalette,
+) -> Element<'a, NotificationMessage> {
+    let n = &h.notification;
+    button(
+        column![
+            text(&n.title).size(13).color(pp.text_secondary),
+            text(format!("{} · Hidden by {}", n.repo_full_name, h.rule))
+                .size(11)
+                .color(pp.text_muted),
+        ]
+        .spacing(2)
+        .width(Fill),
+    )
+    .style(theme::ghost_button)
+    .padding([6, 8])
+    .on_press(NotificationMessage::Thread(ThreadActionMessage::Open(
+        n.id.clone(),
+    )))
+    .width(Fill)
+    .into()
+}
+
 fn item_view<'a>(
     p: &'a ProcessedNotification,
+    args: &ListArgs<'_>,
     in_bulk_mode: bool,
-    bulk_actions: &'a BulkActionState,
-    icon_theme: IconTheme,
-    power_mode: bool,
     is_priority: bool,
-    pp: theme::ThemePalette,
 ) -> Element<'a, NotificationMessage> {
+    let icon_theme = args.icon_theme;
+    let layout = args.layout;
+    let focused = args.focused_id == Some(p.notification.id.as_str());
     if in_bulk_mode {
+        let pp = theme::palette();
         // Bulk mode: checkbox + notification item
-        let item = notification_item(p, icon_theme, power_mode, is_priority, false);
+        let item = notification_item(p, icon_theme, layout, is_priority, false, focused);
         let id = p.notification.id.clone();
-        let is_selected = bulk_actions.is_selected(&id);
+        let is_selected = args.bulk_actions.is_selected(&id);
 
         let checkbox_icon: Element<'_, NotificationMessage> = if is_selected {
             container(icons::icon_check(12.0, iced::Color::WHITE, icon_theme))
@@ -209,7 +408,48 @@ fn item_view<'a>(
         .width(Fill)
         .into()
     } else {
-        // Normal mode: just the notification item
-        notification_item(p, icon_theme, power_mode, is_priority, true)
+        // Normal mode: the notification item, with pinning, reminders and
+        // snoozing on right-click
+        let id = p.notification.id.clone();
+        let pin_ids: Vec<String> = std::iter::once(id.clone())
+            .chain(p.merged.iter().map(|m| m.id.clone()))
+            .collect();
+        let is_pinned = pin_ids.iter().any(|id| args.filters.pinned.contains(id));
+        let is_reminded = args.filters.reminded.contains(&id);
+        ContextMenu::new(
+            notification_item(p, icon_theme, layout, is_priority, true, focused),
+            move || {
+                let label = if is_pinned { "Unpin" } else { "Pin to top" };
+                let mut menu = column![view_context_menu_item(
+                    label,
+                    NotificationMessage::TogglePin(pin_ids.clone()),
+                )];
+                if is_reminded {
+                    menu = menu.push(view_context_menu_item(
+                        "Cancel reminder",
+                        NotificationMessage::CancelReminder(id.clone()),
+                    ));
+                } else {
+                    for delay in ReminderDelay::ALL {
+                        menu = menu.push(view_context_menu_item(
+                            delay.label(),
+                            NotificationMessage::RemindLater(id.clone(), delay),
+                        ));
+                    }
+                }
+                for delay in SnoozeDelay::ALL {
+                    menu = menu.push(view_context_menu_item(
+                        delay.label(),
+                        NotificationMessage::Snooze(id.clone(), delay),
+                    ));
+                }
+                container(menu)
+                    .style(|_| theme::context_menu_container())
+                    .padding(4)
+                    .width(170)
+                    .into()
+            },
+        )
+        .into()
     }
 }
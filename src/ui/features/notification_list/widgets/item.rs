@@ -2,18 +2,19 @@
 //!
 //! Architecture:
 //! - `NotificationVisualState`: Single source of truth for ALL visual decisions
-//!   - Subject colors (Issue=green, PR=blue, etc.)
+//!   - Subject colors (Issue=green, PR=blue, etc.), overridden by CI pass/fail
 //!   - Card styling (background, border, accent bar)
 //!   - State indicators (priority, silent)
-//! - Widget builders: `account_badge()`, `priority_indicator()`, `silent_indicator()`
+//! - Widget builders: `account_badge()`, `actor_label()`, `priority_indicator()`, `silent_indicator()`
 //! - `notification_item()`: Coordinates layout using the visual state
 
-use iced::widget::{Space, button, column, container, row, text};
+use iced::widget::{Space, button, column, container, row, text, tooltip};
 use iced::{Alignment, Color, Element, Fill};
 
-use crate::github::types::{self, SubjectType};
+use crate::github::types::{self, CiConclusion, NotificationReason, NotificationView, SubjectType};
 use crate::settings::IconTheme;
 use crate::ui::features::notification_details::NotificationDetailsMessage;
+use crate::ui::features::notification_list::ListLayout;
 use crate::ui::features::thread_actions::ThreadActionMessage;
 use crate::ui::screens::notifications::helper::ProcessedNotification;
 use crate::ui::screens::notifications::messages::NotificationMessage;
@@ -54,13 +55,17 @@ impl NotificationVisualState {
     pub fn compute(
         is_unread: bool,
         subject_type: SubjectType,
+        ci_conclusion: Option<CiConclusion>,
         action: RuleAction,
         is_priority_group: bool,
     ) -> Self {
         let p = theme::palette();
 
-        // Subject color is the foundation - used for icons, bars, and tints
-        let subject_color = Self::color_for_subject_type(subject_type);
+        // Subject color is the foundation - used for icons, bars, and tints.
+        // A known CI outcome wins so failing builds stand out.
+        let subject_color = ci_conclusion
+            .map(Self::color_for_ci_conclusion)
+            .unwrap_or_else(|| Self::color_for_subject_type(subject_type));
 
         // Priority styling only applies when in the priority group
         let is_priority = is_priority_group && action == RuleAction::Important;
@@ -136,6 +141,17 @@ impl NotificationVisualState {
         }
     }
 
+    /// Get the color for a CI outcome.
+    #[inline]
+    pub fn color_for_ci_conclusion(conclusion: CiConclusion) -> Color {
+        let p = theme::palette();
+        match conclusion {
+            CiConclusion::Success => p.accent_success,
+            CiConclusion::Failure => p.accent_danger,
+            CiConclusion::Pending => p.accent_warning,
+        }
+    }
+
     /// Get the icon for a subject type with the correct color.
     ///
     /// Uses `color_for_subject_type` internally to guarantee consistency.
@@ -181,6 +197,44 @@ impl NotificationVisualState {
             SubjectType::Unknown => icons::icon_unknown(icon_size, color, icon_theme),
         }
     }
+
+    /// Get the small icon shown next to the reason label.
+    ///
+    /// Lets mentions, review requests and CI runs be told apart without
+    /// reading the label.
+    pub fn icon_for_reason(
+        reason: NotificationReason,
+        color: Color,
+        icon_theme: IconTheme,
+    ) -> Element<'static, NotificationMessage> {
+        let size = theme::notification_scaled(11.0);
+
+        match reason {
+            NotificationReason::Mention => icons::icon_at(size, color, icon_theme),
+            NotificationReason::TeamMention => icons::icon_users(size, color, icon_theme),
+            NotificationReason::ReviewRequested => icons::icon_eye(size, color, icon_theme),
+            NotificationReason::CiActivity => icons::icon_workflow(size, color, icon_theme),
+            NotificationReason::ApprovalRequested => {
+                icons::icon_shield_check(size, color, icon_theme)
+            }
+            NotificationReason::Assign => icons::icon_user_plus(size, color, icon_theme),
+            NotificationReason::Author => icons::icon_pencil(size, color, icon_theme),
+            NotificationReason::Comment => icons::icon_message(size, color, icon_theme),
+            NotificationReason::Invitation => icons::icon_mail(size, color, icon_theme),
+            NotificationReason::MemberFeatureRequested => {
+                icons::icon_sparkles(size, color, icon_theme)
+            }
+            NotificationReason::SecurityAdvisoryCredit => {
+                icons::icon_award(size, color, icon_theme)
+            }
+            NotificationReason::SecurityAlert => icons::icon_security(size, color, icon_theme),
+            NotificationReason::StateChange => icons::icon_git_merge(size, color, icon_theme),
+            NotificationReason::Manual | NotificationReason::Subscribed => {
+                icons::icon_bell(size, color, icon_theme)
+            }
+            NotificationReason::Unknown => icons::icon_unknown(size, color, icon_theme),
+        }
+    }
 }
 
 // ============================================================================
@@ -207,6 +261,63 @@ fn account_badge(account: &str, size: f32) -> Element<'_, NotificationMessage> {
         .into()
 }
 
+/// "by @login" for whoever triggered the notification.
+fn actor_label<'a>(
+    actor: &str,
+    size: f32,
+    p: &theme::ThemePalette,
+) -> Element<'a, NotificationMessage> {
+    text(format!("by @{}", actor))
+        .size(size)
+        .color(p.text_muted)
+        .into()
+}
+
+/// "+N" for notifications folded into this one, listing them on hover.
+fn merged_badge<'a>(
+    merged: &'a [NotificationView],
+    size: f32,
+    p: &theme::ThemePalette,
+) -> Element<'a, NotificationMessage> {
+    let badge = container(
+        text(format!("+{}", merged.len()))
+            .size(size)
+            .color(p.text_muted),
+    )
+    .padding([2, 6]);
+
+    let history = merged.iter().fold(column![].spacing(2), |col, n| {
+        col.push(
+            text(format!(
+                "{} · {}",
+                n.reason.label(),
+                types::format_time_ago(n.updated_at)
+            ))
+            .size(size)
+            .color(p.text_secondary),
+        )
+    });
+    let bg = p.bg_control;
+    let border = p.border_subtle;
+
+    tooltip(
+        badge,
+        container(history)
+            .padding([6, 8])
+            .style(move |_| container::Style {
+                background: Some(iced::Background::Color(bg)),
+                border: iced::Border {
+                    radius: 4.0.into(),
+                    color: border,
+                    width: 1.0,
+                },
+                ..Default::default()
+            }),
+        tooltip::Position::Bottom,
+    )
+    .into()
+}
+
 fn priority_indicator(size: f32) -> Element<'static, NotificationMessage> {
     container(text("⚡").size(size)).padding([0, 4]).into()
 }
@@ -224,17 +335,20 @@ fn silent_indicator(size: f32) -> Element<'static, NotificationMessage> {
 pub fn notification_item(
     processed: &ProcessedNotification,
     icon_theme: IconTheme,
-    dense: bool,
+    layout: ListLayout,
     is_priority_group: bool,
     interactive: bool,
+    focused: bool,
 ) -> Element<'_, NotificationMessage> {
     let notif = &processed.notification;
     let p = theme::palette();
+    let dense = layout.is_dense();
 
     // Compute all visual decisions upfront (single source of truth)
     let visual = NotificationVisualState::compute(
         notif.unread,
         notif.subject_type,
+        notif.ci_conclusion,
         processed.action,
         is_priority_group,
     );
@@ -248,24 +362,32 @@ pub fn notification_item(
         meta_size: theme::notification_scaled(12.0),
         reason_size: theme::notification_scaled(11.0),
         account_size: theme::notification_scaled(10.0),
-        padding_x: if dense { 12.0 } else { 16.0 },
-        padding_y: if dense { 8.0 } else { 14.0 },
+        padding_x: if dense { 12.0 } else { 16.0 } + p.hit_padding,
+        padding_y: if dense { 8.0 } else { 14.0 } + p.hit_padding,
         content_spacing: if dense { 2.0 } else { 6.0 },
         row_spacing: 8.0,
     };
 
     // --- BUILD CONTENT ---
     let content = if dense {
-        build_dense_layout(notif, icon_theme, &visual, &metrics, &p)
+        build_dense_layout(notif, &processed.merged, icon_theme, &visual, &metrics, &p)
     } else {
-        build_standard_layout(notif, subject_icon, &visual, &metrics, &p)
+        build_standard_layout(
+            notif,
+            &processed.merged,
+            subject_icon,
+            icon_theme,
+            &visual,
+            &metrics,
+            &p,
+        )
     };
 
     let content_element: Element<'_, NotificationMessage> = if interactive {
         // Click behavior depends on mode:
-        // - Dense (power mode): Select for details panel view
-        // - Standard: Open in browser
-        let click_message = if dense {
+        // - Power mode: Select for details panel view
+        // - Standard and mini: Open in browser
+        let click_message = if layout == ListLayout::Power {
             NotificationMessage::Details(NotificationDetailsMessage::Select(notif.id.clone()))
         } else {
             NotificationMessage::Thread(ThreadActionMessage::Open(notif.id.clone()))
@@ -283,7 +405,7 @@ pub fn notification_item(
         content.into()
     };
 
-    build_card(content_element, &visual, dense)
+    build_card(content_element, &visual, dense, focused)
 }
 
 // ============================================================================
@@ -303,7 +425,9 @@ struct LayoutMetrics {
 
 fn build_standard_layout<'a>(
     notif: &'a crate::github::types::NotificationView,
+    merged: &'a [NotificationView],
     subject_icon: Element<'static, NotificationMessage>,
+    icon_theme: IconTheme,
     visual: &NotificationVisualState,
     metrics: &LayoutMetrics,
     p: &theme::ThemePalette,
@@ -324,12 +448,24 @@ fn build_standard_layout<'a>(
             .size(metrics.meta_size)
             .color(p.text_secondary),
         Space::new().width(8),
+        NotificationVisualState::icon_for_reason(notif.reason, p.text_muted, icon_theme),
+        Space::new().width(4),
         text(notif.reason.label())
             .size(metrics.reason_size)
             .color(p.text_muted),
     ]
     .align_y(Alignment::Center);
 
+    if let Some(actor) = &notif.actor {
+        meta_row = meta_row.push(Space::new().width(8));
+        meta_row = meta_row.push(actor_label(actor, metrics.reason_size, p));
+    }
+
+    if !merged.is_empty() {
+        meta_row = meta_row.push(Space::new().width(8));
+        meta_row = meta_row.push(merged_badge(merged, metrics.reason_size, p));
+    }
+
     // Add account badge only for priority notifications (they can come from any account)
     if visual.is_priority && !notif.account.is_empty() {
         meta_row = meta_row.push(Space::new().width(8));
@@ -358,6 +494,7 @@ fn build_standard_layout<'a>(
 
 fn build_dense_layout<'a>(
     notif: &'a crate::github::types::NotificationView,
+    merged: &'a [NotificationView],
     icon_theme: IconTheme,
     visual: &NotificationVisualState,
     metrics: &LayoutMetrics,
@@ -387,26 +524,35 @@ fn build_dense_layout<'a>(
         title_row = title_row.push(account_badge(&notif.account, metrics.account_size));
     }
 
+    let mut meta_row = row![
+        text(&notif.repo_full_name)
+            .size(metrics.meta_size)
+            .color(p.text_secondary),
+        Space::new().width(8),
+        NotificationVisualState::icon_for_reason(notif.reason, p.text_muted, icon_theme),
+        Space::new().width(4),
+        text(notif.reason.label())
+            .size(metrics.reason_size)
+            .color(p.text_muted),
+    ]
+    .align_y(Alignment::Center)
+    .padding([0, 20]); // Indent meta slightly
+
+    if let Some(actor) = &notif.actor {
+        meta_row = meta_row.push(Space::new().width(8));
+        meta_row = meta_row.push(actor_label(actor, metrics.reason_size, p));
+    }
+
+    if !merged.is_empty() {
+        meta_row = meta_row.push(Space::new().width(8));
+        meta_row = meta_row.push(merged_badge(merged, metrics.reason_size, p));
+    }
+
     let time_ago = types::format_time_ago(notif.updated_at);
     let time_row = build_time_row(visual, time_ago, metrics.meta_size, p);
 
     row![
-        column![
-            title_row,
-            row![
-                text(&notif.repo_full_name)
-                    .size(metrics.meta_size)
-                    .color(p.text_secondary),
-                Space::new().width(8),
-                text(notif.reason.label())
-                    .size(metrics.reason_size)
-                    .color(p.text_muted),
-            ]
-            .align_y(Alignment::Center)
-            .padding([0, 20]) // Indent meta slightly
-        ]
-        .spacing(2)
-        .width(Fill),
+        column![title_row, meta_row].spacing(2).width(Fill),
         container(time_row).padding([0, 8]),
     ]
     .align_y(Alignment::Center)
@@ -432,11 +578,18 @@ fn build_card<'a>(
     content_element: Element<'a, NotificationMessage>,
     visual: &NotificationVisualState,
     dense: bool,
+    focused: bool,
 ) -> Element<'a, NotificationMessage> {
     let bar_color = visual.bar_color;
     let card_bg = visual.card_bg;
-    let border_color = visual.border_color;
-    let show_border = visual.show_border;
+    // The keyboard selection outlines the card over whatever border it has
+    let (border_color, border_width) = if focused {
+        (theme::palette().accent, 1.5)
+    } else if visual.show_border {
+        (visual.border_color, 1.0)
+    } else {
+        (visual.border_color, 0.0)
+    };
 
     // Use a fixed-size accent bar instead of Fill to avoid layout collapse
     // when nested in rows without explicit height
@@ -456,7 +609,7 @@ fn build_card<'a>(
         border: iced::Border {
             radius: if dense { 0.0.into() } else { 6.0.into() },
             color: border_color,
-            width: if show_border { 1.0 } else { 0.0 },
+            width: border_width,
         },
         ..Default::default()
     })
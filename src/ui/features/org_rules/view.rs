@@ -56,7 +56,7 @@ fn view_org_rule_card(
     let id_delete = id.clone();
     let enabled = rule.enabled;
 
-    let info_column = column![
+    let mut info_column = column![
         text(rule.org.clone()).size(14).color(p.text_primary),
         Space::new().height(4),
         text(format!("Action: {}", rule.action.display_label()))
@@ -65,6 +65,14 @@ fn view_org_rule_card(
     ]
     .width(Fill);
 
+    if let Some(schedule) = &rule.schedule {
+        info_column = info_column.push(
+            text(format!("Active: {}", schedule.summary()))
+                .size(11)
+                .color(p.text_muted),
+        );
+    }
+
     // Visible action buttons
     let dup_btn = button(icons::icon_plus(14.0, p.text_muted, icon_theme))
         .style(theme::ghost_button)
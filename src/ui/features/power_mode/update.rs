@@ -10,7 +10,7 @@ pub fn update(
 ) -> Task<PowerModeMessage> {
     match message {
         PowerModeMessage::Toggle(enabled) => {
-            settings.power_mode = enabled;
+            settings.set_power_mode(enabled);
             let _ = settings.save();
             tracing::info!(enabled, "Power mode updated");
             Task::none()
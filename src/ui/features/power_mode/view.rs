@@ -2,6 +2,7 @@ use iced::widget::{Space, button, column, container, row, text, toggler};
 use iced::{Alignment, Element, Fill};
 
 use crate::settings::AppSettings;
+use crate::ui::context::AccountChoice;
 use crate::ui::screens::settings::components::tab_title;
 use crate::ui::{icons, theme};
 
@@ -15,12 +16,18 @@ use crate::ui::screens::notifications::messages::NotificationMessage;
 pub fn app_layout<'a>(
     screen: &'a NotificationsScreen,
     settings: &AppSettings,
-    accounts: Vec<String>,
+    accounts: Vec<AccountChoice>,
 ) -> Element<'a, NotificationMessage> {
+    let available_width = if settings.show_details_panel {
+        settings.window_width - notification_details::view::PANEL_WIDTH
+    } else {
+        settings.window_width
+    };
     let content = screen.view(
         accounts.clone(),
         settings.icon_theme,
         settings.sidebar_width,
+        available_width,
         true,
     );
 
@@ -40,13 +47,6 @@ pub fn app_layout<'a>(
         row![content].height(Fill).into()
     };
 
-    let account_infos: Vec<top_bar::AccountInfo> = accounts
-        .iter()
-        .map(|username| top_bar::AccountInfo {
-            username: username.clone(),
-        })
-        .collect();
-
     let unread_count = screen
         .processing
         .filtered_notifications
@@ -57,10 +57,10 @@ pub fn app_layout<'a>(
     column![
         top_bar::view_top_bar(
             &screen.user,
-            account_infos,
-            screen.is_loading,
+            accounts,
+            screen.sync_status(),
             unread_count,
-            screen.sidebar_state.show_all,
+            &screen.sidebar_state,
             screen.bulk_actions.bulk_mode,
             settings.icon_theme
         ),
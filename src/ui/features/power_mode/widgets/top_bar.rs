@@ -5,25 +5,22 @@ use iced::{Alignment, Element, Fill};
 
 use crate::github::UserInfo;
 use crate::settings::IconTheme;
+use crate::ui::context::AccountChoice;
 use crate::ui::features::bulk_actions::BulkActionMessage;
+use crate::ui::features::sidebar::SidebarState;
 use crate::ui::features::thread_actions::ThreadActionMessage;
+use crate::ui::screens::notifications::components::header::{self, SyncStatus};
 use crate::ui::screens::notifications::messages::{
     FilterMessage, NavigationMessage, NotificationMessage,
 };
 use crate::ui::{icons, theme};
 
-/// Account info for the account switcher.
-#[derive(Debug, Clone)]
-pub struct AccountInfo {
-    pub username: String,
-}
-
 pub fn view_top_bar<'a>(
     user: &'a UserInfo,
-    accounts: Vec<AccountInfo>,
-    is_loading: bool,
+    accounts: Vec<AccountChoice>,
+    sync: SyncStatus,
     unread_count: usize,
-    show_all_filters: bool,
+    filters: &SidebarState,
     bulk_mode: bool,
     icon_theme: IconTheme,
 ) -> Element<'a, NotificationMessage> {
@@ -32,10 +29,10 @@ pub fn view_top_bar<'a>(
     // Context Switcher (Account / Workspace selector)
     let context_switch: Element<'_, NotificationMessage> = if accounts.len() > 1 {
         // Dropdown for switching
-        let account_names: Vec<String> = accounts.iter().map(|a| a.username.clone()).collect();
+        let selected = accounts.iter().find(|a| a.username == user.login).cloned();
 
-        iced::widget::pick_list(account_names, Some(user.login.clone()), |s| {
-            NotificationMessage::Navigation(NavigationMessage::SwitchAccount(s))
+        iced::widget::pick_list(accounts.clone(), selected, |a| {
+            NotificationMessage::Navigation(NavigationMessage::SwitchAccount(a.username))
         })
         .text_size(13)
         .padding([4, 8])
@@ -75,7 +72,7 @@ pub fn view_top_bar<'a>(
     // --- Middle Section: Notification Controls ---
 
     // 1. Sync Status / Refresh
-    let sync_status: Element<'_, NotificationMessage> = if is_loading {
+    let sync_status: Element<'_, NotificationMessage> = if sync.is_loading {
         row![
             icons::icon_refresh(14.0, p.text_muted, icon_theme),
             Space::new().width(6),
@@ -97,9 +94,15 @@ pub fn view_top_bar<'a>(
         .on_press(NotificationMessage::Refresh)
         .into()
     };
+    let sync_status = row![
+        sync_status,
+        header::view_delta(sync.delta),
+        header::view_rate_limit(sync.rate_limit),
+    ]
+    .align_y(Alignment::Center);
 
     // 2. Filter Toggle (Unread | All)
-    let is_unread_filter = !show_all_filters;
+    let is_unread_filter = !filters.show_all;
 
     let unread_btn = button(text("Unread").size(12))
         .style(theme::segment_button(is_unread_filter))
@@ -114,6 +117,39 @@ pub fn view_top_bar<'a>(
     let filter_segment =
         container(row![unread_btn, all_btn].spacing(0)).style(theme::segment_container);
 
+    let hide_read_toggle: Element<'_, NotificationMessage> = if filters.show_all {
+        container(
+            button(text("Hide read").size(12))
+                .style(theme::segment_button(filters.hide_read))
+                .padding([4, 10])
+                .on_press(NotificationMessage::Filter(FilterMessage::ToggleHideRead)),
+        )
+        .style(theme::segment_container)
+        .into()
+    } else {
+        Space::new().width(0).into()
+    };
+
+    let mentions_chip = container(
+        button(text("@ Mentions").size(12))
+            .style(theme::segment_button(filters.mentions_only))
+            .padding([4, 10])
+            .on_press(NotificationMessage::Filter(
+                FilterMessage::ToggleMentionsOnly,
+            )),
+    )
+    .style(theme::segment_container);
+
+    let participating_chip = container(
+        button(text("Participating").size(12))
+            .style(theme::segment_button(filters.participating_only))
+            .padding([4, 10])
+            .on_press(NotificationMessage::Filter(
+                FilterMessage::ToggleParticipatingOnly,
+            )),
+    )
+    .style(theme::segment_container);
+
     // 3. Mark All Read
     let mark_read: Element<'_, NotificationMessage> = if unread_count > 0 {
         button(
@@ -193,7 +229,15 @@ pub fn view_top_bar<'a>(
 
     // Middle container
     let middle_controls = row![
+        header::view_search(&filters.search_query),
+        Space::new().width(8),
         filter_segment,
+        Space::new().width(8),
+        hide_read_toggle,
+        Space::new().width(8),
+        mentions_chip,
+        Space::new().width(8),
+        participating_chip,
         Space::new().width(16),
         sync_status,
         Space::new().width(16),
@@ -1,5 +1,12 @@
+use super::state::SimulatedThread;
+
 #[derive(Debug, Clone)]
 pub enum OverviewMessage {
     SetTestType(String),
-    // Logic for other overview interactions can go here
+    UrlInputChanged(String),
+    /// Fetch the pasted URL's thread and run it through the rules.
+    SimulateUrl,
+    /// Thread fetched, with the reason of its inbox notification if any.
+    UrlFetched(Result<(SimulatedThread, Option<String>), String>),
+    ClearSimulation,
 }
@@ -1,6 +1,9 @@
 #[derive(Debug, Clone, Default)]
 pub struct RuleOverviewState {
     pub explain_test_type: String,
+    /// Issue or PR URL pasted into the Test Lab.
+    pub url_input: String,
+    pub simulation: UrlSimulation,
 }
 
 impl RuleOverviewState {
@@ -8,6 +11,42 @@ impl RuleOverviewState {
     pub fn new() -> Self {
         Self {
             explain_test_type: "Mentioned".to_string(),
+            ..Default::default()
         }
     }
 }
+
+/// Progress of simulating a pasted URL.
+#[derive(Debug, Clone, Default)]
+pub enum UrlSimulation {
+    #[default]
+    Idle,
+    Loading,
+    Found(SimulatedThread),
+    Failed(String),
+}
+
+/// What the rule engine sees for a pasted issue or PR.
+#[derive(Debug, Clone)]
+pub struct SimulatedThread {
+    pub title: String,
+    pub repo_full_name: String,
+    pub number: u64,
+    pub is_pull_request: bool,
+    pub actor: Option<String>,
+    /// Account the thread's notification arrived on, or the primary account
+    /// when it isn't in the inbox.
+    pub account: Option<String>,
+    /// Whether the URL matched a notification already in the inbox, in which
+    /// case its reason and actor are the real ones.
+    pub in_inbox: bool,
+}
+
+impl SimulatedThread {
+    pub fn repo_owner(&self) -> &str {
+        self.repo_full_name
+            .split('/')
+            .next()
+            .unwrap_or(&self.repo_full_name)
+    }
+}
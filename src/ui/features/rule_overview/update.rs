@@ -1,5 +1,6 @@
 use super::message::OverviewMessage;
-use super::state::RuleOverviewState;
+use super::state::{RuleOverviewState, SimulatedThread, UrlSimulation};
+use crate::github::{NotificationView, SessionManager};
 use iced::Task;
 
 pub fn update(state: &mut RuleOverviewState, message: OverviewMessage) -> Task<OverviewMessage> {
@@ -7,6 +8,194 @@ pub fn update(state: &mut RuleOverviewState, message: OverviewMessage) -> Task<O
         OverviewMessage::SetTestType(test_type) => {
             state.explain_test_type = test_type;
         }
+        OverviewMessage::UrlInputChanged(url) => {
+            state.url_input = url;
+        }
+        // Needs a client, so the screen routes it to `simulate_url`.
+        OverviewMessage::SimulateUrl => {}
+        OverviewMessage::UrlFetched(Ok((thread, reason))) => {
+            if let Some(reason) = reason {
+                state.explain_test_type = reason;
+            }
+            state.simulation = UrlSimulation::Found(thread);
+        }
+        OverviewMessage::UrlFetched(Err(e)) => {
+            state.simulation = UrlSimulation::Failed(e);
+        }
+        OverviewMessage::ClearSimulation => {
+            state.url_input.clear();
+            state.simulation = UrlSimulation::Idle;
+        }
     }
     Task::none()
 }
+
+/// Fetch the thread behind the pasted URL.
+///
+/// When the thread is already in the inbox its notification supplies the
+/// reason, account and actor; otherwise the actor is the thread's author, the
+/// primary account stands in for the account and the selected test type for
+/// the reason.
+pub fn simulate_url(
+    state: &mut RuleOverviewState,
+    notifications: &[NotificationView],
+    sessions: &SessionManager,
+) -> Task<OverviewMessage> {
+    if matches!(state.simulation, UrlSimulation::Loading) {
+        return Task::none();
+    }
+    let Some(target) = ThreadRef::parse(&state.url_input) else {
+        state.simulation =
+            UrlSimulation::Failed("Paste a GitHub issue or pull request URL".to_string());
+        return Task::none();
+    };
+
+    let api_url = target.api_url();
+    let notification = notifications
+        .iter()
+        .find(|n| n.url.as_deref() == Some(api_url.as_str()));
+
+    let session = notification
+        .and_then(|n| sessions.get(&n.account))
+        .or_else(|| sessions.primary());
+    let Some(session) = session else {
+        state.simulation = UrlSimulation::Failed("No signed-in account".to_string());
+        return Task::none();
+    };
+
+    state.simulation = UrlSimulation::Loading;
+
+    let client = session.client.clone();
+    let account =
+        Some(notification.map_or_else(|| session.username.clone(), |n| n.account.clone()));
+    let known_actor = notification.and_then(|n| n.actor.clone());
+    let reason = notification.map(|n| n.reason.label().to_string());
+    let in_inbox = notification.is_some();
+
+    Task::perform(
+        async move {
+            let (title, author) = if target.is_pull_request {
+                let pr = client.get_pull_request(&api_url).await?;
+                (pr.title, pr.user.login)
+            } else {
+                let issue = client.get_issue(&api_url).await?;
+                (issue.title, issue.user.login)
+            };
+            Ok(SimulatedThread {
+                title,
+                repo_full_name: target.repo_full_name,
+                number: target.number,
+                is_pull_request: target.is_pull_request,
+                actor: known_actor.or(Some(author)),
+                account,
+                in_inbox,
+            })
+        },
+        move |result: Result<SimulatedThread, crate::github::GitHubError>| {
+            OverviewMessage::UrlFetched(
+                result
+                    .map(|thread| (thread, reason.clone()))
+                    .map_err(|e| e.to_string()),
+            )
+        },
+    )
+}
+
+/// An issue or pull request named by a web or API URL.
+struct ThreadRef {
+    repo_full_name: String,
+    number: u64,
+    is_pull_request: bool,
+}
+
+impl ThreadRef {
+    /// Accepts `github.com/{owner}/{repo}/(issues|pull)/{n}` and
+    /// `api.github.com/repos/{owner}/{repo}/(issues|pulls)/{n}`, ignoring
+    /// anything after the number (tabs, anchors, query strings).
+    fn parse(url: &str) -> Option<Self> {
+        let url = url.trim();
+        let path = url
+            .split_once("://")
+            .map_or(url, |(_, rest)| rest)
+            .split(['?', '#'])
+            .next()?;
+        let mut parts = path.split('/').skip(1).filter(|s| !s.is_empty());
+
+        let mut owner = parts.next()?;
+        if owner == "repos" {
+            owner = parts.next()?;
+        }
+        let repo = parts.next()?;
+        let is_pull_request = match parts.next()? {
+            "pull" | "pulls" => true,
+            "issues" => false,
+            _ => return None,
+        };
+        let number = parts.next()?.parse().ok()?;
+
+        Some(Self {
+            repo_full_name: format!("{owner}/{repo}"),
+            number,
+            is_pull_request,
+        })
+    }
+
+    /// The subject URL GitHub puts on notifications for this thread.
+    fn api_url(&self) -> String {
+        let kind = if self.is_pull_request {
+            "pulls"
+        } else {
+            "issues"
+        };
+        format!(
+            "https://api.github.com/repos/{}/{}/{}",
+            self.repo_full_name, kind, self.number
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parsed(url: &str) -> Option<(String, u64, bool)> {
+        ThreadRef::parse(url).map(|t| (t.repo_full_name, t.number, t.is_pull_request))
+    }
+
+    #[test]
+    fn test_thread_ref_parses_web_and_api_urls() {
+        let pr = Some(("owner/repo".to_string(), 42, true));
+        assert_eq!(parsed("https://github.com/owner/repo/pull/42"), pr);
+        assert_eq!(parsed("  github.com/owner/repo/pull/42/files#diff  "), pr);
+        assert_eq!(
+            parsed("https://api.github.com/repos/owner/repo/pulls/42"),
+            pr
+        );
+        assert_eq!(
+            parsed("https://github.com/owner/repo/issues/7?q=1"),
+            Some(("owner/repo".to_string(), 7, false))
+        );
+    }
+
+    #[test]
+    fn test_thread_ref_rejects_other_urls() {
+        assert_eq!(parsed(""), None);
+        assert_eq!(parsed("https://github.com/owner/repo"), None);
+        assert_eq!(parsed("https://github.com/owner/repo/discussions/3"), None);
+        assert_eq!(parsed("https://github.com/owner/repo/pull/abc"), None);
+    }
+
+    #[test]
+    fn test_thread_ref_api_url_matches_notification_subject() {
+        let issue = ThreadRef::parse("https://github.com/owner/repo/issues/7").unwrap();
+        assert_eq!(
+            issue.api_url(),
+            "https://api.github.com/repos/owner/repo/issues/7"
+        );
+        let pr = ThreadRef::parse("https://github.com/owner/repo/pull/42").unwrap();
+        assert_eq!(
+            pr.api_url(),
+            "https://api.github.com/repos/owner/repo/pulls/42"
+        );
+    }
+}
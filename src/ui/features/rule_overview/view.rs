@@ -1,14 +1,14 @@
-use iced::widget::{Space, button, column, container, row, text};
+use iced::widget::{Space, button, column, container, row, text, text_input};
 use iced::{Element, Fill, Length};
 
 use crate::github::types::NotificationReason;
 use crate::settings::IconTheme;
 use crate::ui::icons;
-use crate::ui::screens::settings::rule_engine::rules::NotificationRuleSet;
+use crate::ui::screens::settings::rule_engine::rules::{NotificationRuleSet, RuleSubject};
 use crate::ui::theme;
 
 use super::message::OverviewMessage;
-use super::state::RuleOverviewState;
+use super::state::{RuleOverviewState, UrlSimulation};
 
 pub fn view(
     rules: &NotificationRuleSet,
@@ -146,6 +146,10 @@ pub fn view(
         dist_divider(),
         Space::new().width(12),
         dist_item("Type", rules.type_rules.len()),
+        Space::new().width(12),
+        dist_divider(),
+        Space::new().width(12),
+        dist_item("Actor", rules.actor_rules.len()),
     ]
     .align_y(iced::Alignment::Center);
 
@@ -187,12 +191,89 @@ pub fn view(
         type_buttons = type_buttons.push(btn);
     }
 
-    // Explain panel
+    // Pasted URL
+    let simulated = match &state.simulation {
+        UrlSimulation::Found(thread) => Some(thread),
+        _ => None,
+    };
+    let is_loading = matches!(state.simulation, UrlSimulation::Loading);
+    let simulate =
+        (!is_loading && !state.url_input.trim().is_empty()).then_some(OverviewMessage::SimulateUrl);
+
+    let mut url_row = row![
+        text_input("Paste an issue or PR URL to test it", &state.url_input)
+            .on_input(OverviewMessage::UrlInputChanged)
+            .on_submit_maybe(simulate.clone())
+            .size(13)
+            .padding([6, 10])
+            .width(Fill)
+            .style(theme::text_input_style),
+        Space::new().width(8),
+        button(
+            text(if is_loading {
+                "Fetching..."
+            } else {
+                "Simulate"
+            })
+            .size(12)
+        )
+        .style(theme::primary_button)
+        .padding([6, 12])
+        .on_press_maybe(simulate),
+    ]
+    .align_y(iced::Alignment::Center);
+
+    if !matches!(state.simulation, UrlSimulation::Idle) {
+        url_row = url_row.push(Space::new().width(4)).push(
+            button(text("Clear").size(12).color(p.text_secondary))
+                .style(theme::ghost_button)
+                .padding([6, 10])
+                .on_press(OverviewMessage::ClearSimulation),
+        );
+    }
+
+    let url_status: Element<'static, OverviewMessage> = match &state.simulation {
+        UrlSimulation::Found(thread) => {
+            let kind = if thread.is_pull_request {
+                "Pull request"
+            } else {
+                "Issue"
+            };
+            let mut detail = kind.to_string();
+            if let Some(actor) = &thread.actor {
+                detail.push_str(&format!(" by @{actor}"));
+            }
+            detail.push_str(if thread.in_inbox {
+                " · in your inbox, using its reason"
+            } else {
+                " · not in your inbox, using the selected type"
+            });
+            column![
+                text(format!(
+                    "{}#{} · {}",
+                    thread.repo_full_name, thread.number, thread.title
+                ))
+                .size(12)
+                .color(p.text_primary),
+                text(detail).size(11).color(p.text_secondary),
+            ]
+            .spacing(2)
+            .into()
+        }
+        UrlSimulation::Failed(e) => text(e.clone()).size(12).color(p.accent_warning).into(),
+        UrlSimulation::Idle | UrlSimulation::Loading => Space::new().into(),
+    };
+
     // Explain panel
     let explain_panel = super::widgets::explain_panel::view_explain_panel::<OverviewMessage>(
         rules,
-        &type_owned,
-        None,
+        RuleSubject {
+            notification_type: &type_owned,
+            repo_owner: simulated.map(|t| t.repo_owner()),
+            account: simulated.and_then(|t| t.account.as_deref()),
+            actor: simulated.and_then(|t| t.actor.as_deref()),
+            title: simulated.map(|t| t.title.as_str()),
+        },
         icon_theme,
     );
 
@@ -216,6 +297,10 @@ pub fn view(
                 .color(p.text_secondary),
             Space::new().height(20),
             type_buttons,
+            Space::new().height(12),
+            url_row,
+            Space::new().height(6),
+            url_status,
             Space::new().height(24),
             explain_panel,
         ]
@@ -5,7 +5,9 @@ use iced::{Alignment, Element, Fill};
 
 use crate::settings::IconTheme;
 use crate::ui::icons;
-use crate::ui::screens::settings::rule_engine::rules::{NotificationRuleSet, RuleAction};
+use crate::ui::screens::settings::rule_engine::rules::{
+    NotificationRuleSet, RuleAction, RuleSubject,
+};
 use crate::ui::theme;
 
 use chrono::Local;
@@ -13,8 +15,7 @@ use chrono::Local;
 /// View the explanation panel.
 pub fn view_explain_panel<'a, Message>(
     rules: &NotificationRuleSet,
-    test_type: &str,
-    test_account: Option<&str>,
+    subject: RuleSubject<'_>,
     icon_theme: IconTheme,
 ) -> Element<'a, Message>
 where
@@ -34,7 +35,7 @@ where
         .color(p.text_secondary);
 
     // Simulate matching using the actual engine logic
-    let matches = rules.trace(test_type, None, test_account, &Local::now(), true);
+    let matches = rules.trace(&subject, &Local::now(), true);
 
     let result_content = if matches.is_empty() {
         column![
@@ -148,7 +149,19 @@ where
         col
     };
 
-    let test_type_owned = test_type.to_string();
+    // Only the parts actually being simulated, e.g. "Mentioned · rust-lang · @bors"
+    let mut testing = subject.notification_type.to_string();
+    for part in [
+        subject.repo_owner.map(str::to_string),
+        subject.actor.map(|a| format!("@{a}")),
+        subject.account.map(str::to_string),
+    ]
+    .into_iter()
+    .flatten()
+    {
+        testing.push_str(" · ");
+        testing.push_str(&part);
+    }
 
     container(
         column![
@@ -156,7 +169,7 @@ where
             Space::new().height(4),
             description,
             Space::new().height(12),
-            text(format!("Testing: {}", test_type_owned))
+            text(format!("Testing: {}", testing))
                 .size(12)
                 .color(p.text_secondary),
             result_content,
@@ -4,7 +4,12 @@ use crate::github::SubjectType;
 pub enum SidebarMessage {
     SelectType(Option<SubjectType>),
     SelectRepo(Option<String>),
+    /// Text typed into the repository filter.
+    RepoQueryChanged(String),
+    MarkRepoRead(String),
+    MarkTypeRead(SubjectType),
     SwitchAccount(String),
+    AddAccount,
     OpenSettings,
     Logout,
 }
@@ -1,9 +1,11 @@
 pub mod messages;
+pub mod resize;
 pub mod state;
 pub mod update;
 pub mod view;
 
 pub use messages::SidebarMessage;
+pub use resize::SidebarResize;
 pub use state::{SidebarState, SidebarViewArgs};
 pub use update::{SidebarAction, update};
 pub use view::view_sidebar as view;
@@ -0,0 +1,95 @@
+//! Drag-to-resize for sidebars.
+//!
+//! Screens put a `handle` between their sidebar and content, and wrap the
+//! row in `track`, which follows the cursor while a drag is in progress.
+//! Sidebars sit at the left edge, so the cursor x is the new width.
+
+use iced::widget::{Space, container, mouse_area};
+use iced::{Element, Fill, mouse};
+
+use crate::ui::theme;
+
+/// Allowed range for `sidebar_width`.
+pub const WIDTH_RANGE: std::ops::RangeInclusive<f32> = 180.0..=400.0;
+
+/// Below this much room the sidebar folds away behind a menu button.
+pub const COLLAPSE_BELOW: f32 = 720.0;
+
+/// Room always left for the content next to the sidebar.
+const MIN_CONTENT_WIDTH: f32 = 360.0;
+
+/// Steps of a resize drag.
+#[derive(Debug, Clone, Copy)]
+pub enum SidebarResize {
+    Start,
+    /// Cursor x relative to the row holding the sidebar.
+    Drag(f32),
+    End,
+}
+
+pub fn clamp_width(width: f32) -> f32 {
+    width.clamp(*WIDTH_RANGE.start(), *WIDTH_RANGE.end())
+}
+
+/// Sidebar width for a row `available` wide: the stored width, shrunk so the
+/// content keeps `MIN_CONTENT_WIDTH`, but never below the range minimum.
+pub fn fit_width(width: f32, available: f32) -> f32 {
+    clamp_width(width).min((available - MIN_CONTENT_WIDTH).max(*WIDTH_RANGE.start()))
+}
+
+/// Thin divider that starts a resize when pressed.
+pub fn handle<'a, M: Clone + 'a>(
+    resizing: bool,
+    on_resize: fn(SidebarResize) -> M,
+) -> Element<'a, M> {
+    mouse_area(
+        container(Space::new())
+            .width(4)
+            .height(Fill)
+            .style(theme::resize_handle(resizing)),
+    )
+    .on_press(on_resize(SidebarResize::Start))
+    .interaction(mouse::Interaction::ResizingHorizontally)
+    .into()
+}
+
+/// Follow the cursor over `content` while `resizing`. The row is always
+/// wrapped so starting a drag doesn't reset widget state inside it.
+pub fn track<'a, M: Clone + 'a>(
+    content: impl Into<Element<'a, M>>,
+    resizing: bool,
+    on_resize: fn(SidebarResize) -> M,
+) -> Element<'a, M> {
+    let area = mouse_area(content);
+    if !resizing {
+        return area.into();
+    }
+
+    // Leaving the window counts as a release; we'd never see the real one
+    area.on_move(move |point| on_resize(SidebarResize::Drag(point.x)))
+        .on_release(on_resize(SidebarResize::End))
+        .on_exit(on_resize(SidebarResize::End))
+        .interaction(mouse::Interaction::ResizingHorizontally)
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fit_width_keeps_stored_width_when_there_is_room() {
+        assert_eq!(fit_width(220.0, 1200.0), 220.0);
+        assert_eq!(fit_width(900.0, 1200.0), 400.0);
+    }
+
+    #[test]
+    fn test_fit_width_shrinks_to_leave_room_for_content() {
+        assert_eq!(fit_width(400.0, 640.0), 280.0);
+    }
+
+    #[test]
+    fn test_fit_width_never_goes_below_minimum() {
+        assert_eq!(fit_width(400.0, 300.0), 180.0);
+    }
+}
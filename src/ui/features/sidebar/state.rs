@@ -1,26 +1,55 @@
 //! Sidebar state structure for view rendering.
 
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Utc};
+
 use crate::github::{SubjectType, UserInfo};
-use crate::settings::IconTheme;
+use crate::settings::{GroupingMode, IconTheme, TimeBuckets};
+use crate::ui::context::AccountChoice;
+use crate::ui::screens::notifications::helper::TypeCount;
 
 /// Persistent state for the sidebar (filters, selections).
 #[derive(Debug, Clone, Default)]
 pub struct SidebarState {
     pub show_all: bool,
+    /// Drop read items even when `show_all` is set.
+    pub hide_read: bool,
+    /// Only show direct and team mentions, on top of the other filters.
+    pub mentions_only: bool,
+    /// Fetch only threads the user participates in, not everything watched.
+    pub participating_only: bool,
     /// None means "All Types"
     pub selected_type: Option<SubjectType>,
     /// None means "All Repos"
     pub selected_repo: Option<String>,
+    /// Narrows the repository list in the sidebar; doesn't filter notifications.
+    pub repo_query: String,
+    /// Text search over notification titles and repo names.
+    pub search_query: String,
+    /// How the filtered list is grouped.
+    pub grouping: GroupingMode,
+    /// Bucket size when `grouping` is by time.
+    pub time_buckets: TimeBuckets,
+    /// Fold notifications sharing a subject into one item.
+    pub compact_threads: bool,
+    /// Notification ids the user pinned to the top.
+    pub pinned: HashSet<String>,
+    /// Notification ids with a pending "remind me later".
+    pub reminded: HashSet<String>,
+    /// Notification ids hidden until their wake time.
+    pub snoozed: HashMap<String, DateTime<Utc>>,
 }
 
 /// View arguments for rendering the sidebar.
 pub struct SidebarViewArgs<'a> {
     pub user: &'a UserInfo,
-    pub accounts: Vec<String>,
-    pub type_counts: &'a [(SubjectType, usize)],
+    pub accounts: Vec<AccountChoice>,
+    pub type_counts: &'a [(SubjectType, TypeCount)],
     pub repo_counts: &'a [(String, usize)],
     pub selected_type: Option<SubjectType>,
     pub selected_repo: Option<&'a str>,
+    pub repo_query: &'a str,
     pub total_count: usize,
     pub total_repo_count: usize,
     pub icon_theme: IconTheme,
@@ -1,12 +1,17 @@
 use iced::Task;
 
+use crate::github::SubjectType;
+
 use super::messages::SidebarMessage;
 use super::state::SidebarState;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum SidebarAction {
     FilterChanged,
+    MarkRepoRead(String),
+    MarkTypeRead(SubjectType),
     SwitchAccount(String),
+    AddAccount,
     OpenSettings,
     Logout,
 }
@@ -21,7 +26,14 @@ pub fn update(state: &mut SidebarState, message: SidebarMessage) -> Task<Sidebar
             state.selected_repo = r;
             Task::done(SidebarAction::FilterChanged)
         }
+        SidebarMessage::RepoQueryChanged(query) => {
+            state.repo_query = query;
+            Task::none()
+        }
+        SidebarMessage::MarkRepoRead(r) => Task::done(SidebarAction::MarkRepoRead(r)),
+        SidebarMessage::MarkTypeRead(t) => Task::done(SidebarAction::MarkTypeRead(t)),
         SidebarMessage::SwitchAccount(u) => Task::done(SidebarAction::SwitchAccount(u)),
+        SidebarMessage::AddAccount => Task::done(SidebarAction::AddAccount),
         SidebarMessage::OpenSettings => Task::done(SidebarAction::OpenSettings),
         SidebarMessage::Logout => Task::done(SidebarAction::Logout),
     }
@@ -1,15 +1,21 @@
 //! Sidebar component - navigation and filtering.
 
-use iced::widget::{Space, button, column, container, row, scrollable, text};
+use iced::widget::{Space, button, column, container, hover, row, scrollable, text, text_input};
 use iced::{Alignment, Element, Fill, Length, Padding};
 
 use crate::github::{SubjectType, UserInfo};
 use crate::settings::IconTheme;
+use crate::ui::context::AccountChoice;
+use crate::ui::screens::notifications::helper::TypeCount;
 use crate::ui::{icons, theme};
 
 use super::messages::SidebarMessage;
+use super::resize::clamp_width;
 use super::state::SidebarViewArgs;
 
+/// Repositories listed at once; past this the filter input shows up.
+const MAX_VISIBLE_REPOS: usize = 10;
+
 pub fn view_sidebar<'a>(args: SidebarViewArgs<'a>) -> Element<'a, SidebarMessage> {
     if args.power_mode {
         view_power_sidebar(args)
@@ -30,6 +36,7 @@ fn view_standard_sidebar<'a>(args: SidebarViewArgs<'a>) -> Element<'a, SidebarMe
         .push(view_repos_section(
             args.repo_counts,
             args.selected_repo,
+            args.repo_query,
             args.total_repo_count,
             args.icon_theme,
         ))
@@ -55,7 +62,7 @@ fn view_standard_sidebar<'a>(args: SidebarViewArgs<'a>) -> Element<'a, SidebarMe
         ]
         .height(Fill),
     )
-    .width(Length::Fixed(args.width.clamp(180.0, 400.0)))
+    .width(Length::Fixed(clamp_width(args.width)))
     .height(Fill)
     .style(theme::sidebar)
     .into()
@@ -75,6 +82,7 @@ fn view_power_sidebar<'a>(args: SidebarViewArgs<'a>) -> Element<'a, SidebarMessa
         view_repos_section(
             args.repo_counts,
             args.selected_repo,
+            args.repo_query,
             args.total_repo_count,
             args.icon_theme,
         )
@@ -87,7 +95,7 @@ fn view_power_sidebar<'a>(args: SidebarViewArgs<'a>) -> Element<'a, SidebarMessa
             .height(Fill)
             .style(theme::scrollbar),
     )
-    .width(Length::Fixed(args.width.clamp(180.0, 400.0)))
+    .width(Length::Fixed(clamp_width(args.width)))
     .height(Fill)
     .style(theme::sidebar)
     .into()
@@ -101,7 +109,7 @@ fn view_branding<'a>() -> Element<'a, SidebarMessage> {
 }
 
 fn view_types_section(
-    type_counts: &[(SubjectType, usize)],
+    type_counts: &[(SubjectType, TypeCount)],
     selected_type: Option<SubjectType>,
     total_count: usize,
     icon_theme: IconTheme,
@@ -118,17 +126,27 @@ fn view_types_section(
 
     let types_items = type_counts.iter().map(|(subject_type, count)| {
         let is_selected = selected_type == Some(*subject_type);
+        // Nothing unread means nothing to act on; let those fade back
+        let dimmed = count.unread == 0 && !is_selected;
         let icon_color = if is_selected {
             p.accent
+        } else if dimmed {
+            p.text_muted
         } else {
             p.text_primary
         };
-        sidebar_item(
+        let item = sidebar_item_with_count(
             subject_type_icon(*subject_type, icon_color, icon_theme),
             subject_type_label(*subject_type).to_owned(),
-            *count,
+            format!("{}/{}", count.unread, count.total),
+            dimmed,
             is_selected,
             SidebarMessage::SelectType(Some(*subject_type)),
+        );
+
+        hover(
+            item,
+            mark_read_action(SidebarMessage::MarkTypeRead(*subject_type), icon_theme),
         )
     });
 
@@ -147,6 +165,7 @@ fn view_types_section(
 fn view_repos_section(
     repo_counts: &[(String, usize)],
     selected_repo: Option<&str>,
+    repo_query: &str,
     total_repo_count: usize,
     icon_theme: IconTheme,
 ) -> Element<'static, SidebarMessage> {
@@ -160,34 +179,60 @@ fn view_repos_section(
         SidebarMessage::SelectRepo(None),
     );
 
-    let repo_items = repo_counts.iter().take(10).map(|(repo, count)| {
-        let is_selected = selected_repo == Some(repo.as_str());
-        let icon_color = if is_selected {
-            p.accent
-        } else {
-            p.text_primary
-        };
+    let mut matching: Vec<_> = repo_counts
+        .iter()
+        .filter_map(|(repo, count)| fuzzy_score(repo_query, repo).map(|score| (score, repo, count)))
+        .collect();
+    // Stable, so equally good matches keep their count order
+    matching.sort_by_key(|(score, _, _)| *score);
 
-        let short_name = format_repo_short_name(repo);
+    let repo_items = matching
+        .into_iter()
+        .take(MAX_VISIBLE_REPOS)
+        .map(|(_, repo, count)| {
+            let is_selected = selected_repo == Some(repo.as_str());
+            let icon_color = if is_selected {
+                p.accent
+            } else {
+                p.text_primary
+            };
 
-        sidebar_item(
-            icons::icon_folder(14.0, icon_color, icon_theme),
-            short_name,
-            *count,
-            is_selected,
-            SidebarMessage::SelectRepo(Some(repo.clone())),
-        )
-    });
+            let short_name = format_repo_short_name(repo);
+
+            let item = sidebar_item(
+                icons::icon_folder(14.0, icon_color, icon_theme),
+                short_name,
+                *count,
+                is_selected,
+                SidebarMessage::SelectRepo(Some(repo.clone())),
+            );
+
+            hover(
+                item,
+                mark_read_action(SidebarMessage::MarkRepoRead(repo.clone()), icon_theme),
+            )
+        });
 
     let mut col = column![
         text("Repositories")
             .size(theme::sidebar_scaled(11.0))
             .color(p.text_secondary),
         Space::new().height(8),
-        all_item
     ]
-    .spacing(2)
-    .extend(repo_items);
+    .spacing(2);
+
+    if repo_counts.len() > MAX_VISIBLE_REPOS || !repo_query.is_empty() {
+        col = col.push(
+            text_input("Filter repositories", repo_query)
+                .on_input(SidebarMessage::RepoQueryChanged)
+                .size(theme::sidebar_scaled(12.0))
+                .padding([4, 8])
+                .style(theme::text_input_style),
+        );
+        col = col.push(Space::new().height(4));
+    }
+
+    col = col.push(all_item).extend(repo_items);
 
     if repo_counts.is_empty() && total_repo_count == 0 {
         col = col.push(text("No repositories").size(11).color(p.text_muted));
@@ -198,22 +243,24 @@ fn view_repos_section(
 
 fn view_user_section<'a>(
     user: &'a UserInfo,
-    accounts: &[String],
+    accounts: &[AccountChoice],
     icon_theme: IconTheme,
 ) -> Element<'a, SidebarMessage> {
     let p = theme::palette();
+    let current = accounts.iter().find(|a| a.username == user.login).cloned();
 
     // Account selector or just label
     let account_control: Element<'_, _, _, iced::Renderer> = if accounts.len() > 1 {
-        iced::widget::pick_list(accounts.to_vec(), Some(user.login.clone()), |s| {
-            SidebarMessage::SwitchAccount(s)
+        iced::widget::pick_list(accounts.to_vec(), current, |a| {
+            SidebarMessage::SwitchAccount(a.username)
         })
         .text_size(13)
         .padding([4, 8])
         .style(theme::pick_list_style)
         .into()
     } else {
-        text(&user.login).size(13).color(p.text_primary).into()
+        let label = current.map_or_else(|| user.login.clone(), |a| a.label);
+        text(label).size(13).color(p.text_primary).into()
     };
 
     column![
@@ -229,6 +276,10 @@ fn view_user_section<'a>(
             Space::new().width(8),
             account_control,
             Space::new().width(Fill), // Push buttons to the right
+            button(icons::icon_user_plus(14.0, p.text_muted, icon_theme))
+                .style(theme::ghost_button)
+                .padding([6, 8])
+                .on_press(SidebarMessage::AddAccount),
             button(icons::icon_settings(14.0, p.text_muted, icon_theme))
                 .style(theme::ghost_button)
                 .padding([6, 8])
@@ -268,10 +319,26 @@ fn sidebar_item<'a>(
     count: usize,
     is_selected: bool,
     on_press: SidebarMessage,
+) -> Element<'a, SidebarMessage> {
+    sidebar_item_with_count(icon, label, count.to_string(), false, is_selected, on_press)
+}
+
+/// `sidebar_item` with a preformatted count, optionally de-emphasized.
+fn sidebar_item_with_count<'a>(
+    icon: Element<'a, SidebarMessage>,
+    label: String,
+    count: String,
+    dimmed: bool,
+    is_selected: bool,
+    on_press: SidebarMessage,
 ) -> Element<'a, SidebarMessage> {
     let p = theme::palette();
     // Use primary text for all items - much more readable
-    let text_color = p.text_primary;
+    let (text_color, count_color) = if dimmed {
+        (p.text_muted, p.text_muted)
+    } else {
+        (p.text_primary, p.text_secondary)
+    };
 
     // Use scaled font sizes (f32 for iced Pixels)
     let label_size = theme::sidebar_scaled(13.0);
@@ -282,9 +349,7 @@ fn sidebar_item<'a>(
         Space::new().width(8),
         text(label).size(label_size).color(text_color),
         Space::new().width(Fill),
-        text(format!("{}", count))
-            .size(count_size)
-            .color(p.text_secondary),
+        text(count).size(count_size).color(count_color),
     ]
     .align_y(Alignment::Center)
     .padding([8, 10]);
@@ -296,6 +361,24 @@ fn sidebar_item<'a>(
         .into()
 }
 
+/// Hover action shown over a sidebar item's count to mark its group as read.
+fn mark_read_action<'a>(
+    on_press: SidebarMessage,
+    icon_theme: IconTheme,
+) -> Element<'a, SidebarMessage> {
+    let p = theme::palette();
+    container(
+        button(icons::icon_check(12.0, p.text_secondary, icon_theme))
+            .style(theme::sidebar_button(true))
+            .padding([4, 6])
+            .on_press(on_press),
+    )
+    .align_right(Fill)
+    .center_y(Fill)
+    .padding([0, 4])
+    .into()
+}
+
 fn subject_type_label(t: SubjectType) -> &'static str {
     match t {
         SubjectType::PullRequest => "Pull requests",
@@ -309,6 +392,29 @@ fn subject_type_label(t: SubjectType) -> &'static str {
     }
 }
 
+/// How well `query` fuzzy-matches `candidate`, lower is better; `None` if
+/// the query's characters don't all appear in order. Substring matches
+/// beat scattered ones.
+fn fuzzy_score(query: &str, candidate: &str) -> Option<usize> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return Some(0);
+    }
+    let candidate = candidate.to_lowercase();
+    if let Some(pos) = candidate.find(&query) {
+        return Some(pos);
+    }
+
+    // Characters skipped between matches, after every substring match
+    let mut chars = candidate.chars();
+    let mut gaps = 0;
+    for wanted in query.chars() {
+        let skipped = chars.by_ref().position(|c| c == wanted)?;
+        gaps += skipped;
+    }
+    Some(candidate.len() + gaps)
+}
+
 /// Helper to format repo name short (e.g. "params/GitTop" -> "GitTop").
 fn format_repo_short_name(full_name: &str) -> String {
     full_name
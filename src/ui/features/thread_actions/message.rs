@@ -9,6 +9,17 @@ pub enum ThreadActionMessage {
     MarkAsReadComplete(String, Result<(), GitHubError>),
     MarkAsDone(String),
     MarkAsDoneComplete(String, Result<(), GitHubError>),
+    /// Mute a thread and clear it from the inbox.
+    Mute(String),
+    MuteComplete(String, Result<(), GitHubError>),
     MarkAllAsRead,
-    MarkAllAsReadComplete(Result<(), GitHubError>),
+    /// Go ahead with a "Mark all as read" that was waiting for confirmation.
+    ConfirmMarkAllAsRead,
+    CancelMarkAllAsRead,
+    /// Ids of the threads that were unread when it was sent.
+    MarkAllAsReadComplete(Vec<String>, Result<(), GitHubError>),
+    /// Mark every notification in a repository (`owner/name`) as read.
+    MarkRepoAsRead(String),
+    /// The repository and the ids of its threads that were unread.
+    MarkRepoAsReadComplete(String, Vec<String>, Result<(), GitHubError>),
 }
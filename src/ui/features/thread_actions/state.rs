@@ -4,9 +4,13 @@
 //! - Opening notifications in browser
 //! - Marking individual threads as read
 //! - Marking individual threads as done
+//! - Muting individual threads
+//! - Marking a whole repository as read
 
 use std::collections::HashSet;
 
+use crate::settings::OpenAction;
+
 /// State for pending thread operations.
 ///
 /// Tracks in-flight operations to prevent duplicate requests
@@ -15,7 +19,15 @@ use std::collections::HashSet;
 pub struct ThreadActionState {
     pub pending_mark_read: HashSet<String>,
     pub pending_mark_done: HashSet<String>,
+    pub pending_mute: HashSet<String>,
     pub pending_mark_all: bool,
+    pub pending_mark_repo: HashSet<String>,
+    /// Mirrors `AppSettings::open_action`.
+    pub on_open: OpenAction,
+    /// Mirrors `AppSettings::confirm_mark_all_read`.
+    pub confirm_mark_all: bool,
+    /// Unread count shown while "Mark all as read" awaits confirmation.
+    pub confirming_mark_all: Option<usize>,
 }
 
 impl ThreadActionState {
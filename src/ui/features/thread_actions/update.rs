@@ -3,11 +3,15 @@
 use iced::Task;
 
 use crate::github::{GitHubClient, NotificationView};
+use crate::settings::OpenAction;
 use crate::ui::screens::notifications::helper::api_url_to_web_url;
 
 use super::message::ThreadActionMessage;
 use super::state::ThreadActionState;
 
+/// "Mark all as read" asks first when it would clear more than this many.
+pub const MARK_ALL_CONFIRM_THRESHOLD: usize = 10;
+
 /// Result of a thread action update.
 pub struct ThreadActionResult {
     pub task: Task<ThreadActionMessage>,
@@ -66,17 +70,17 @@ pub fn update_thread_action(
                 && let Some(ref url) = notif.url
             {
                 let web_url = api_url_to_web_url(url);
-                let _ = open::that(&web_url);
+                if let Err(e) = crate::browser::open(&web_url) {
+                    tracing::warn!(error = %e, "Failed to open notification in browser");
+                }
             }
 
-            // Mark as read
-            state.pending_mark_read.insert(id.clone());
-            let client = client.clone();
-            let notif_id = id.clone();
-            ThreadActionResult::task(Task::perform(
-                async move { client.mark_as_read(&notif_id).await },
-                move |result| ThreadActionMessage::MarkAsReadComplete(id.clone(), result),
-            ))
+            let follow_up = match state.on_open {
+                OpenAction::MarkRead => ThreadActionMessage::MarkAsRead(id),
+                OpenAction::MarkDone => ThreadActionMessage::MarkAsDone(id),
+                OpenAction::Nothing => return ThreadActionResult::none(),
+            };
+            update_thread_action(state, follow_up, notifications, client)
         }
 
         ThreadActionMessage::MarkAsRead(id) => {
@@ -102,22 +106,58 @@ pub fn update_thread_action(
         }
 
         ThreadActionMessage::MarkAllAsRead => {
-            state.pending_mark_all = true;
-            // Optimistic update
-            for notif in notifications.iter_mut() {
-                notif.unread = false;
+            let unread = notifications.iter().filter(|n| n.unread).count();
+            if state.confirm_mark_all && unread > MARK_ALL_CONFIRM_THRESHOLD {
+                state.confirming_mark_all = Some(unread);
+                return ThreadActionResult::none();
+            }
+            mark_all_as_read(state, notifications, client)
+        }
+
+        ThreadActionMessage::ConfirmMarkAllAsRead => mark_all_as_read(state, notifications, client),
+
+        ThreadActionMessage::CancelMarkAllAsRead => {
+            state.confirming_mark_all = None;
+            ThreadActionResult::none()
+        }
+
+        ThreadActionMessage::MarkAllAsReadComplete(..) => {
+            state.pending_mark_all = false;
+            // Trigger a full refresh to sync with server
+            ThreadActionResult {
+                task: Task::none(),
+                needs_rebuild: false,
+                needs_refresh: true,
+            }
+        }
+
+        ThreadActionMessage::MarkRepoAsRead(repo) => {
+            if !state.pending_mark_repo.insert(repo.clone()) {
+                return ThreadActionResult::none();
             }
+            // Optimistic update
+            let ids = mark_unread_as_read(
+                notifications
+                    .iter_mut()
+                    .filter(|n| n.repo_full_name == repo),
+            );
 
             let client = client.clone();
+            let repo_name = repo.clone();
             ThreadActionResult::rebuild_with_task(Task::perform(
-                async move { client.mark_all_as_read().await },
-                ThreadActionMessage::MarkAllAsReadComplete,
+                async move { client.mark_repo_as_read(&repo_name).await },
+                move |result| {
+                    ThreadActionMessage::MarkRepoAsReadComplete(repo.clone(), ids.clone(), result)
+                },
             ))
         }
 
-        ThreadActionMessage::MarkAllAsReadComplete(_result) => {
-            state.pending_mark_all = false;
-            // Trigger a full refresh to sync with server
+        ThreadActionMessage::MarkRepoAsReadComplete(repo, _, result) => {
+            state.pending_mark_repo.remove(&repo);
+            if let Err(e) = &result {
+                tracing::warn!(repo = %repo, error = %e, "Failed to mark repository as read");
+            }
+            // Sync with server either way; a failure restores the unread state
             ThreadActionResult {
                 task: Task::none(),
                 needs_rebuild: false,
@@ -144,5 +184,64 @@ pub fn update_thread_action(
                 ThreadActionResult::none()
             }
         }
+
+        ThreadActionMessage::Mute(id) => {
+            if !state.pending_mute.insert(id.clone()) {
+                return ThreadActionResult::none();
+            }
+            let client = client.clone();
+            let notif_id = id.clone();
+            ThreadActionResult::task(Task::perform(
+                async move {
+                    client.mute_thread(&notif_id).await?;
+                    client.mark_thread_as_done(&notif_id).await
+                },
+                move |result| ThreadActionMessage::MuteComplete(id.clone(), result),
+            ))
+        }
+
+        ThreadActionMessage::MuteComplete(id, result) => {
+            state.pending_mute.remove(&id);
+            match result {
+                Ok(()) => {
+                    notifications.retain(|n| n.id != id);
+                    ThreadActionResult::rebuild()
+                }
+                Err(e) => {
+                    tracing::warn!(error = %e, "Failed to mute notification thread");
+                    ThreadActionResult::none()
+                }
+            }
+        }
     }
 }
+
+fn mark_all_as_read(
+    state: &mut ThreadActionState,
+    notifications: &mut [NotificationView],
+    client: &GitHubClient,
+) -> ThreadActionResult {
+    state.confirming_mark_all = None;
+    state.pending_mark_all = true;
+    // Optimistic update
+    let ids = mark_unread_as_read(notifications.iter_mut());
+
+    let client = client.clone();
+    ThreadActionResult::rebuild_with_task(Task::perform(
+        async move { client.mark_all_as_read().await },
+        move |result| ThreadActionMessage::MarkAllAsReadComplete(ids.clone(), result),
+    ))
+}
+
+/// Mark `notifications` read, returning the ids of those that were unread.
+fn mark_unread_as_read<'a>(
+    notifications: impl Iterator<Item = &'a mut NotificationView>,
+) -> Vec<String> {
+    notifications
+        .filter(|n| n.unread)
+        .map(|n| {
+            n.unread = false;
+            n.id.clone()
+        })
+        .collect()
+}
@@ -14,5 +14,6 @@ pub enum TypeRuleMessage {
     FormAccountChanged(String),
     FormPriorityChanged(i32),
     FormActionChanged(RuleAction),
+    FormTitlePatternChanged(String),
     Add,
 }
@@ -3,7 +3,7 @@
 use std::collections::HashSet;
 
 use crate::github::types::NotificationReason;
-use crate::ui::screens::settings::rule_engine::rules::RuleAction;
+use crate::ui::screens::settings::rule_engine::rules::{RuleAction, TypeRule};
 
 /// State for the type rule creation form and grouping.
 #[derive(Debug, Clone)]
@@ -12,6 +12,8 @@ pub struct TypeRuleFormState {
     pub account: Option<String>,
     pub priority: i32,
     pub action: RuleAction,
+    /// Text the title must contain; blank for any title.
+    pub title_pattern: String,
     pub expanded_groups: HashSet<String>,
 }
 
@@ -22,6 +24,7 @@ impl Default for TypeRuleFormState {
             account: None,
             priority: 0,
             action: RuleAction::Show,
+            title_pattern: String::new(),
             expanded_groups: HashSet::new(),
         }
     }
@@ -33,10 +36,24 @@ impl TypeRuleFormState {
         Self::default()
     }
 
+    /// The rule the form currently describes.
+    pub fn draft_rule(&self) -> TypeRule {
+        let mut rule = TypeRule::new(
+            self.notification_type.label(),
+            self.account.clone(),
+            self.priority,
+        );
+        rule.action = self.action;
+        let pattern = self.title_pattern.trim();
+        rule.title_pattern = (!pattern.is_empty()).then(|| pattern.to_string());
+        rule
+    }
+
     /// Reset form to defaults after adding a rule.
     pub fn reset_form(&mut self) {
         self.account = None;
         self.priority = 0;
         self.action = RuleAction::Show;
+        self.title_pattern.clear();
     }
 }
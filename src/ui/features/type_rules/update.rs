@@ -2,7 +2,7 @@
 
 use iced::Task;
 
-use crate::ui::screens::settings::rule_engine::rules::{NotificationRuleSet, TypeRule};
+use crate::ui::screens::settings::rule_engine::rules::NotificationRuleSet;
 
 use super::message::TypeRuleMessage;
 use super::state::TypeRuleFormState;
@@ -77,15 +77,15 @@ pub fn update_type_rule(
             state.action = a;
         }
 
+        TypeRuleMessage::FormTitlePatternChanged(pattern) => {
+            state.title_pattern = pattern;
+        }
+
         TypeRuleMessage::Add => {
-            let mut rule = TypeRule::new(
-                state.notification_type.label(),
-                state.account.clone(),
-                state.priority,
-            );
-            rule.action = state.action;
+            let rule = state.draft_rule();
 
             let account_scoped = rule.account.is_some();
+            let title_scoped = rule.title_pattern.is_some();
             let rule_id = rule.id.clone();
             let action = rule.action;
             let priority = rule.priority;
@@ -103,6 +103,7 @@ pub fn update_type_rule(
                 action = ?action,
                 priority,
                 account_scoped,
+                title_scoped,
                 "Type rule added"
             );
         }
@@ -1,17 +1,20 @@
 use std::collections::HashSet;
 
-use iced::widget::{Space, button, column, container, pick_list, row, slider, text, toggler};
+use iced::widget::{
+    Space, button, column, container, pick_list, row, slider, text, text_input, toggler,
+};
 use iced::{Alignment, Element, Fill, Length};
 use iced_aw::ContextMenu;
 
 use crate::settings::IconTheme;
 use crate::ui::icons;
+use crate::ui::screens::notifications::RuleImpact;
 use crate::ui::screens::settings::rule_engine::rules::{NotificationRuleSet, RuleAction, TypeRule};
 use crate::ui::theme;
 
 use super::TypeRuleFormState;
 use crate::ui::screens::settings::rule_engine::components::{
-    view_context_menu_item, view_empty_state, view_warning_row,
+    view_context_menu_item, view_empty_state, view_impact_preview, view_warning_row,
 };
 use crate::ui::screens::settings::rule_engine::messages::{
     InspectorMessage, RuleEngineMessage, TypeMessage,
@@ -102,6 +105,7 @@ pub fn view_type_rules_tab<'a>(
     form_state: &TypeRuleFormState,
     available_accounts: &[String],
     expanded_groups: &HashSet<String>,
+    impact: Option<RuleImpact>,
 ) -> Element<'a, RuleEngineMessage> {
     let p = theme::palette();
 
@@ -192,6 +196,19 @@ pub fn view_type_rules_tab<'a>(
         .spacing(4),
     );
 
+    let title_input = container(
+        column![
+            text("Title contains").size(12).color(p.text_secondary),
+            text_input("Any title", &form_state.title_pattern)
+                .on_input(|s| RuleEngineMessage::Type(TypeMessage::FormTitlePatternChanged(s)))
+                .size(13)
+                .padding([6, 8])
+                .width(Length::Fixed(140.0))
+                .style(theme::text_input_style),
+        ]
+        .spacing(4),
+    );
+
     let add_btn = button(text("Add Rule").size(13))
         .style(theme::primary_button)
         .on_press(RuleEngineMessage::Type(TypeMessage::Add))
@@ -202,26 +219,28 @@ pub fn view_type_rules_tab<'a>(
         account_input,
         priority_input,
         action_input,
+        title_input,
         Space::new().width(Fill),
         column![Space::new().height(19), add_btn].spacing(0),
     ]
     .spacing(12)
     .align_y(Alignment::End);
 
-    let form_section = container(form_row)
-        .padding(16)
-        .style(move |_| container::Style {
-            background: Some(iced::Background::Color(p.bg_control)),
-            border: iced::Border {
-                radius: 8.0.into(),
+    let form_section =
+        container(column![form_row, view_impact_preview(impact, "would affect")].spacing(12))
+            .padding(16)
+            .style(move |_| container::Style {
+                background: Some(iced::Background::Color(p.bg_control)),
+                border: iced::Border {
+                    radius: 8.0.into(),
+                    ..Default::default()
+                },
                 ..Default::default()
-            },
-            ..Default::default()
-        });
+            });
 
     let header = column![
         text("Type Rules").size(20).color(p.text_primary),
-        text("Filter notifications by type, account, and priority.")
+        text("Filter notifications by type, account, title, and priority.")
             .size(12)
             .color(p.text_secondary),
     ]
@@ -276,6 +295,22 @@ pub fn view_type_rule_card(
     ]
     .width(Fill);
 
+    if let Some(pattern) = rule.title_pattern() {
+        info_column = info_column.push(
+            text(format!("Title contains \"{pattern}\""))
+                .size(11)
+                .color(p.text_muted),
+        );
+    }
+
+    if let Some(schedule) = &rule.schedule {
+        info_column = info_column.push(
+            text(format!("Active: {}", schedule.summary()))
+                .size(11)
+                .color(p.text_muted),
+        );
+    }
+
     if rule.priority > 100 || rule.priority < -100 {
         info_column = info_column.push(Space::new().height(4));
         info_column = info_column.push(view_warning_row("Non-standard priority", icon_theme));
@@ -2,7 +2,9 @@
 
 use iced::Task;
 
-use crate::github::SessionManager;
+use crate::github::session::Session;
+use crate::github::types::NotificationView;
+use crate::github::{GitHubClient, SessionManager, UserInfo, auth, keyring, proxy_keyring};
 use crate::settings::AppSettings;
 use crate::ui::context::AppContext;
 use crate::ui::routing::{RuleEngineOrigin, Screen};
@@ -10,6 +12,7 @@ use crate::ui::screens::notifications::NotificationsScreen;
 use crate::ui::screens::settings::SettingsScreen;
 use crate::ui::screens::settings::rule_engine::RuleEngineScreen;
 use crate::ui::screens::settings::rule_engine::rules::NotificationRuleSet;
+use crate::ui::state;
 
 use super::super::app::Message;
 
@@ -82,7 +85,7 @@ pub fn go_to_notifications(
     let session = ctx.sessions.primary()?;
 
     let (notif_screen, task) =
-        NotificationsScreen::new(session.client.clone(), session.user.clone());
+        NotificationsScreen::new(session.client.clone(), session.user.clone(), &settings);
 
     Some(NotificationsTransition {
         screen: Box::new(notif_screen),
@@ -106,11 +109,12 @@ pub fn go_to_settings(ctx: &AppContext) -> SettingsTransition {
 pub fn go_to_rule_engine(
     current_settings: Option<&AppSettings>,
     origin: RuleEngineOrigin,
+    notifications: Vec<NotificationView>,
 ) -> RuleEngineTransition {
     let settings = current_settings.cloned().unwrap_or_else(AppSettings::load);
 
     let rules = NotificationRuleSet::load();
-    let rule_engine_screen = RuleEngineScreen::new(rules, settings.clone());
+    let rule_engine_screen = RuleEngineScreen::new(rules, settings.clone(), notifications);
 
     RuleEngineTransition {
         screen: Box::new(rule_engine_screen),
@@ -119,6 +123,23 @@ pub fn go_to_rule_engine(
     }
 }
 
+/// The active account's notifications as of the last sync, for screens opened
+/// while the notifications screen isn't around to ask.
+pub fn cached_notifications(ctx: &AppContext) -> Vec<NotificationView> {
+    let Some(session) = ctx.sessions.primary() else {
+        return Vec::new();
+    };
+    crate::cache::DiskCache::open()
+        .and_then(|cache| {
+            cache.load_notifications(&session.username, false, ctx.settings.participating_only)
+        })
+        .unwrap_or_else(|e| {
+            tracing::debug!(error = %e, "Failed to read cached notifications");
+            None
+        })
+        .unwrap_or_default()
+}
+
 // ============================================================================
 // Account Switching
 // ============================================================================
@@ -132,8 +153,11 @@ pub fn switch_account(
     sessions: &mut SessionManager,
     settings: &mut AppSettings,
 ) -> Option<(Box<NotificationsScreen>, Task<Message>)> {
-    // Skip if already on this account
-    if sessions.primary().is_some_and(|s| s.username == username) {
+    // Skip if already on this account, or if it has no session (listed in
+    // settings, e.g. restored from a backup, but not signed in yet)
+    if sessions.primary().is_some_and(|s| s.username == username)
+        || sessions.get(username).is_none()
+    {
         return None;
     }
 
@@ -141,17 +165,168 @@ pub fn switch_account(
     let cross_account_priority = current_screen.get_cross_account_priority();
     sessions.set_primary(username);
 
-    // Persist the active account preference
+    // Persist the active account preference; this may also restore the
+    // account's own power mode choice
+    let was_power_mode = settings.power_mode;
     settings.set_active_account(username);
     settings.save_silent();
 
     let session = sessions.primary()?;
 
     let (mut notif_screen, task) =
-        NotificationsScreen::new(session.client.clone(), session.user.clone());
+        NotificationsScreen::new(session.client.clone(), session.user.clone(), settings);
     notif_screen.set_cross_account_priority(cross_account_priority);
 
-    Some((Box::new(notif_screen), task.map(Message::Notifications)))
+    let resize_task = if settings.power_mode && !was_power_mode {
+        state::resize_for_power_mode()
+    } else {
+        Task::none()
+    };
+
+    Some((
+        Box::new(notif_screen),
+        Task::batch([task.map(Message::Notifications), resize_task]),
+    ))
+}
+
+/// Apply a new token from the re-authentication screen.
+///
+/// The token must belong to the account being re-authenticated; otherwise
+/// the user would silently end up with a different account in that slot.
+pub fn reauthenticate_account(
+    expected: &str,
+    client: GitHubClient,
+    user: UserInfo,
+    sessions: &mut SessionManager,
+) -> Result<(), String> {
+    check_token_owner(expected, &user)?;
+
+    sessions
+        .replace_session(Session {
+            username: expected.to_string(),
+            client,
+            user,
+        })
+        .map_err(|e| e.to_string())
+}
+
+/// Sign in an account that's listed in settings but has no token here yet,
+/// e.g. one from a restored backup.
+pub fn sign_in_restored_account(
+    expected: &str,
+    client: GitHubClient,
+    user: UserInfo,
+    sessions: &mut SessionManager,
+    settings: &mut AppSettings,
+) -> Result<Option<String>, String> {
+    check_token_owner(expected, &user)?;
+    add_account(client, user, sessions, settings)
+}
+
+fn check_token_owner(expected: &str, user: &UserInfo) -> Result<(), String> {
+    if user.login.eq_ignore_ascii_case(expected) {
+        Ok(())
+    } else {
+        Err(format!(
+            "This token belongs to {}, not {}",
+            user.login, expected
+        ))
+    }
+}
+
+/// Sign in an additional account from the add-account screen and make it
+/// the active one. Existing sessions are left alone.
+///
+/// Signing in to an account that's already there replaces its token instead
+/// of adding a duplicate; the returned notice says so.
+pub fn add_account(
+    client: GitHubClient,
+    user: UserInfo,
+    sessions: &mut SessionManager,
+    settings: &mut AppSettings,
+) -> Result<Option<String>, String> {
+    let username = user.login.clone();
+    let token_expires_at = user.token_expires_at;
+    let existing = sessions
+        .usernames()
+        .find(|u| u.eq_ignore_ascii_case(&username))
+        .map(str::to_string);
+    if let Some(existing) = existing {
+        sessions
+            .replace_session(Session {
+                username: existing.clone(),
+                client,
+                user,
+            })
+            .map_err(|e| e.to_string())?;
+        sessions.set_primary(&existing);
+
+        settings.set_active_account(&existing);
+        settings.set_token_expiry(&existing, token_expires_at);
+        settings.save_silent();
+
+        tracing::info!("Existing account signed in again, token updated");
+        return Ok(Some(format!("Updated token for @{existing}")));
+    }
+
+    keyring::save_token(&username, client.token()).map_err(|e| e.to_string())?;
+    sessions.add_session(Session {
+        username: username.clone(),
+        client,
+        user,
+    });
+    sessions.set_primary(&username);
+
+    settings.set_active_account(&username);
+    settings.set_token_expiry(&username, token_expires_at);
+    settings.save_silent();
+
+    tracing::info!(account_count = settings.accounts.len(), "Account added");
+    Ok(None)
+}
+
+/// Wipe the disk cache, every stored token, and the settings file.
+///
+/// Each step is best-effort; returns a description of each one that failed.
+pub fn reset_all_data(sessions: &SessionManager, settings: &AppSettings) -> Vec<String> {
+    let mut failed = Vec::new();
+
+    if let Err(e) = crate::cache::DiskCache::open().and_then(|cache| cache.clear()) {
+        failed.push(format!("cache ({e})"));
+    }
+
+    let mut usernames: Vec<String> = settings
+        .accounts
+        .iter()
+        .map(|a| a.username.clone())
+        .chain(sessions.usernames().map(String::from))
+        .collect();
+    usernames.sort_unstable();
+    usernames.dedup();
+    for username in &usernames {
+        if let Err(e) = keyring::delete_token(username) {
+            failed.push(format!("token for {username} ({e})"));
+        }
+    }
+    // Legacy single-account entry; usually absent
+    let _ = auth::delete_token();
+
+    if settings.proxy.has_credentials
+        && let Err(e) = proxy_keyring::delete_proxy_credentials(&settings.proxy.url)
+    {
+        failed.push(format!("proxy credentials ({e})"));
+    }
+
+    if let Err(e) = AppSettings::delete_file() {
+        failed.push(format!("settings file ({e})"));
+    }
+
+    tracing::info!(
+        accounts = usernames.len(),
+        failed_steps = failed.len(),
+        "Reset all data"
+    );
+    failed
 }
 
 /// Handle logout - remove current account and switch or go to login.
@@ -180,7 +355,7 @@ pub fn handle_logout(
     settings.save_silent();
 
     let (notif_screen, task) =
-        NotificationsScreen::new(session.client.clone(), session.user.clone());
+        NotificationsScreen::new(session.client.clone(), session.user.clone(), settings);
 
     Some((Box::new(notif_screen), task.map(Message::Notifications)))
 }
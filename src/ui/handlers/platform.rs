@@ -1,12 +1,18 @@
 //! Platform event handlers - tick, tray, window events.
 
+use std::time::Duration;
+
 use iced::window::Id as WindowId;
 use iced::{Task, exit, window};
 
-use crate::settings::AppSettings;
+use crate::activity_log;
+use crate::github::SessionManager;
+use crate::settings::{AppSettings, validated_window_position, validated_window_size};
 use crate::tray::{TrayCommand, TrayManager};
+use crate::ui::effects::{AppEffect, SessionEffect};
+use crate::ui::features::thread_actions::ThreadActionMessage;
 use crate::ui::screens::notifications::NotificationsScreen;
-use crate::ui::screens::notifications::messages::NotificationMessage;
+use crate::ui::screens::notifications::messages::{NavigationMessage, NotificationMessage};
 use crate::ui::state;
 
 use super::super::app::Message;
@@ -15,80 +21,208 @@ use super::super::app::Message;
 // Constants
 // ============================================================================
 
-/// Windows reports these values when window is minimized.
-pub const MINIMIZED_POSITION_THRESHOLD: f32 = -10000.0;
-pub const MINIMIZED_SIZE_THRESHOLD: f32 = 100.0;
-
-/// Polling intervals in milliseconds.
-pub const TRAY_POLL_INTERVAL_HIDDEN_MS: u64 = 500;
+/// Polling intervals in milliseconds. The fast rate is only used for a
+/// while after a focus change or tray command, so an idle app wakes less.
+pub const TRAY_POLL_INTERVAL_IDLE_MS: u64 = 500;
 pub const TRAY_POLL_INTERVAL_ACTIVE_MS: u64 = 100;
 
-/// Auto-refresh interval for notifications.
-pub const REFRESH_INTERVAL_SECS: u64 = 60;
+/// How long after the last interaction the tray keeps polling fast.
+pub const TRAY_POLL_ACTIVE_WINDOW_SECS: u64 = 30;
+
+/// How often the disk cache is pruned (also runs once at startup).
+pub const CACHE_MAINTENANCE_INTERVAL_SECS: u64 = 6 * 60 * 60;
+
+/// How often reminders are checked, whatever the refresh interval or pause.
+pub const REMINDER_CHECK_INTERVAL_SECS: u64 = 30;
 
 // ============================================================================
 // Tick Handler
 // ============================================================================
 
+/// Current tray poll interval: fast right after user activity, slow when
+/// hidden or idle.
+pub fn tray_poll_interval() -> Duration {
+    let recently_active =
+        state::since_activity() < Duration::from_secs(TRAY_POLL_ACTIVE_WINDOW_SECS);
+    let ms = if recently_active && !state::is_hidden() {
+        TRAY_POLL_INTERVAL_ACTIVE_MS
+    } else {
+        TRAY_POLL_INTERVAL_IDLE_MS
+    };
+    Duration::from_millis(ms)
+}
+
 /// Handle periodic refresh tick.
-pub fn handle_tick(screen: &mut NotificationsScreen) -> Task<Message> {
-    if screen.is_loading {
+pub fn handle_tick(screen: &mut NotificationsScreen, base: Duration) -> Task<Message> {
+    if refresh_blocked(screen) || !screen.refresh_due(base) {
+        return Task::none();
+    }
+    refresh(screen)
+}
+
+/// Refresh right away, as asked from the tray.
+fn refresh_now(screen: &mut NotificationsScreen) -> Task<Message> {
+    if refresh_blocked(screen) {
         return Task::none();
     }
+    refresh(screen)
+}
+
+/// Don't keep polling with a token GitHub has already rejected, or while
+/// GitHub has asked us to back off.
+fn refresh_blocked(screen: &NotificationsScreen) -> bool {
+    screen.is_loading || screen.is_token_invalid() || screen.client.throttled_for().is_some()
+}
+
+fn refresh(screen: &mut NotificationsScreen) -> Task<Message> {
     screen
         .update(NotificationMessage::Refresh)
         .map(Message::Notifications)
 }
 
+/// Prune the disk cache off the UI thread.
+pub fn run_cache_maintenance() -> Task<Message> {
+    Task::future(async {
+        let _ = tokio::task::spawn_blocking(crate::cache::run_maintenance).await;
+    })
+    .discard()
+}
+
 // ============================================================================
 // Tray Handler
 // ============================================================================
 
-/// Handle tray icon events.
-pub fn handle_tray_poll(notification_screen: Option<&mut NotificationsScreen>) -> Task<Message> {
-    let Some(cmd) = TrayManager::poll_global_events() else {
+/// Handle tray icon events, the global show/hide shortcut, desktop
+/// notification actions and local API calls.
+///
+/// Account switching is returned as an effect so it goes through the same
+/// path as switching from the sidebar.
+pub fn handle_tray_poll(
+    notification_screen: Option<&mut NotificationsScreen>,
+    sessions: Option<&SessionManager>,
+) -> (Task<Message>, AppEffect) {
+    let cmd = TrayManager::poll_global_events()
+        .or_else(|| crate::hotkey::poll_pressed().then_some(TrayCommand::ToggleWindow))
+        .or_else(crate::tray::poll_toast_commands)
+        .or_else(crate::local_api::poll);
+    let Some(cmd) = cmd else {
+        return (Task::none(), AppEffect::None);
+    };
+    state::mark_activity();
+
+    let task = match cmd {
+        TrayCommand::ShowWindow => show_window(notification_screen),
+        TrayCommand::ToggleWindow => match state::get_window_id() {
+            Some(id) if !state::is_hidden() => enter_tray_mode(id, notification_screen),
+            _ => show_window(notification_screen),
+        },
+        TrayCommand::SwitchAccount(username) => {
+            tracing::info!("Switching account from tray");
+            return (
+                Task::none(),
+                AppEffect::Session(SessionEffect::SwitchAccount(username)),
+            );
+        }
+        TrayCommand::PauseFor(minutes) => {
+            state::pause_for(minutes);
+            Task::none()
+        }
+        TrayCommand::Resume => {
+            state::resume();
+            notification_screen.map_or_else(Task::none, refresh_now)
+        }
+        TrayCommand::Refresh => notification_screen.map_or_else(Task::none, refresh_now),
+        TrayCommand::MarkRead { account, thread_id } => {
+            mark_read_from_toast(notification_screen, sessions, account, thread_id)
+        }
+        TrayCommand::ToggleMiniMode => Task::batch([
+            show_window(notification_screen),
+            Task::done(Message::Notifications(NotificationMessage::Navigation(
+                NavigationMessage::ToggleMiniMode,
+            ))),
+        ]),
+        TrayCommand::Quit => {
+            crate::hotkey::unregister();
+            exit()
+        }
+    };
+    (task, AppEffect::None)
+}
+
+/// Mark a thread read from its toast with the client of the account it
+/// belongs to, which may not be the one on screen.
+fn mark_read_from_toast(
+    notification_screen: Option<&mut NotificationsScreen>,
+    sessions: Option<&SessionManager>,
+    account: String,
+    thread_id: String,
+) -> Task<Message> {
+    // The screen's own path also updates the list and the activity log
+    if let Some(screen) = notification_screen.filter(|s| s.user.login == account) {
+        return screen
+            .update(NotificationMessage::Thread(
+                ThreadActionMessage::MarkAsRead(thread_id),
+            ))
+            .map(Message::Notifications);
+    }
+    let Some(client) = sessions
+        .and_then(|s| s.get(&account))
+        .map(|s| s.client.clone())
+    else {
+        tracing::debug!(%account, "Ignoring mark read for signed-out account");
         return Task::none();
     };
+    Task::future(async move {
+        match client.mark_as_read(&thread_id).await {
+            Ok(()) => activity_log::record(activity_log::Action::MarkRead, &account, &thread_id),
+            Err(e) => tracing::warn!(%account, error = %e, "Failed to mark thread read from toast"),
+        }
+    })
+    .discard()
+}
 
-    match cmd {
-        TrayCommand::ShowWindow => {
-            let was_hidden = state::restore_from_hidden();
+/// Restore the window from tray (or bring it to front) and refresh if it was hidden.
+fn show_window(notification_screen: Option<&mut NotificationsScreen>) -> Task<Message> {
+    let was_hidden = state::restore_from_hidden();
 
-            #[cfg(target_os = "linux")]
-            let window_task = if was_hidden {
-                let (id, open_task) = crate::platform::linux::build_initial_window_settings();
-                state::set_window_id(id);
-                open_task
-            } else {
-                state::get_window_id()
-                    .map(window::gain_focus)
-                    .unwrap_or_else(Task::none)
-            };
-
-            #[cfg(not(target_os = "linux"))]
-            let window_task = state::get_window_id()
-                .map(|id| {
-                    Task::batch([
-                        window::set_mode(id, window::Mode::Windowed),
-                        window::gain_focus(id),
-                    ])
-                })
-                .unwrap_or_else(Task::none);
-
-            let refresh_task = was_hidden
-                .then_some(notification_screen)
-                .flatten()
-                .map(|screen| {
-                    screen
-                        .update(NotificationMessage::Refresh)
-                        .map(Message::Notifications)
-                })
-                .unwrap_or_else(Task::none);
-
-            Task::batch([window_task, refresh_task])
+    // A window that was only hidden comes back as it was; one that was
+    // closed (Wayland) is reopened at its saved size and position
+    #[cfg(target_os = "linux")]
+    let window_task = match state::get_window_id() {
+        Some(id) if was_hidden => Task::batch([
+            window::set_mode(id, window::Mode::Windowed),
+            window::gain_focus(id),
+        ]),
+        Some(id) => window::gain_focus(id),
+        None => {
+            let (id, open_task) = crate::platform::linux::build_initial_window_settings();
+            state::set_window_id(id);
+            open_task
         }
-        TrayCommand::Quit => exit(),
-    }
+    };
+
+    #[cfg(not(target_os = "linux"))]
+    let window_task = state::get_window_id()
+        .map(|id| {
+            Task::batch([
+                window::set_mode(id, window::Mode::Windowed),
+                window::gain_focus(id),
+            ])
+        })
+        .unwrap_or_else(Task::none);
+
+    let refresh_task = was_hidden
+        .then_some(notification_screen)
+        .flatten()
+        .map(|screen| {
+            screen.begin_restore();
+            screen
+                .update(NotificationMessage::Refresh)
+                .map(Message::Notifications)
+        })
+        .unwrap_or_else(Task::none);
+
+    Task::batch([window_task, refresh_task])
 }
 
 // ============================================================================
@@ -120,24 +254,27 @@ pub fn handle_window_event(
         }
 
         window::Event::Moved(position) => {
-            let valid = position.x > MINIMIZED_POSITION_THRESHOLD
-                && position.y > MINIMIZED_POSITION_THRESHOLD;
+            let valid = validated_window_position(position.x, position.y);
 
-            if let Some(s) = valid.then_some(ctx.settings).flatten() {
-                s.window_x = Some(position.x as i32);
-                s.window_y = Some(position.y as i32);
+            if let (Some((x, y)), Some(s)) = (valid, ctx.settings) {
+                s.window_x = Some(x as i32);
+                s.window_y = Some(y as i32);
                 s.save_silent();
             }
             Task::none()
         }
 
         window::Event::Resized(size) => {
-            let valid =
-                size.width > MINIMIZED_SIZE_THRESHOLD && size.height > MINIMIZED_SIZE_THRESHOLD;
-
-            if let Some(s) = valid.then_some(ctx.settings).flatten() {
-                s.window_width = size.width;
-                s.window_height = size.height;
+            let valid = validated_window_size(size.width, size.height);
+
+            if let (Some((width, height)), Some(s)) = (valid, ctx.settings) {
+                if s.mini_mode {
+                    s.mini_window_width = width;
+                    s.mini_window_height = height;
+                } else {
+                    s.window_width = width;
+                    s.window_height = height;
+                }
                 s.save_silent();
             }
             Task::none()
@@ -145,6 +282,7 @@ pub fn handle_window_event(
 
         #[cfg(target_os = "linux")]
         window::Event::Closed => {
+            state::clear_window_id();
             if ctx.minimize_to_tray {
                 state::set_hidden(true);
                 if let Some(screen) = ctx.notification_screen {
@@ -159,11 +297,17 @@ pub fn handle_window_event(
 
         window::Event::Focused => {
             state::set_focused(true);
+            if let Some(screen) = ctx.notification_screen {
+                screen.mark_viewed();
+            }
             Task::none()
         }
 
         window::Event::Unfocused => {
             state::set_focused(false);
+            if let Some(screen) = ctx.notification_screen {
+                screen.mark_left();
+            }
             Task::none()
         }
 
@@ -188,9 +332,15 @@ pub fn enter_tray_mode(
 
     crate::platform::trim_memory();
 
+    // Keep the window (and its GPU surface) around where it can be hidden,
+    // so showing it again doesn't rebuild everything
     #[cfg(target_os = "linux")]
     {
-        window::close(window_id)
+        if crate::platform::linux::can_hide_window() {
+            window::set_mode(window_id, window::Mode::Hidden)
+        } else {
+            window::close(window_id)
+        }
     }
 
     #[cfg(not(target_os = "linux"))]
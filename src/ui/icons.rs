@@ -1,3 +1,5 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::fmt::Write;
 
 use iced::widget::{Svg, Text, svg, text};
@@ -61,16 +63,48 @@ fn emoji_icon(emoji: &'static str, size: f32, color: Color) -> Text<'static> {
     text(emoji).size(size.round() as u32).color(color)
 }
 
+thread_local! {
+    /// Whether each icon's SVG parses, keyed by the icon's static address.
+    static SVG_USABLE: RefCell<HashMap<usize, bool>> = RefCell::new(HashMap::new());
+}
+
+/// Check (once per icon) that the SVG parses and draws something, so a
+/// broken one shows its emoji instead of a blank square.
+fn svg_usable(data: &'static IconData, name: &'static str) -> bool {
+    let key = std::ptr::from_ref(data) as usize;
+    SVG_USABLE.with(|cache| {
+        *cache.borrow_mut().entry(key).or_insert_with(|| {
+            let usable = usvg::Tree::from_data(&icon_to_svg_bytes(data), &usvg::Options::default())
+                .is_ok_and(|tree| tree.root().has_children());
+            if !usable {
+                tracing::warn!(icon = name, "SVG icon failed to load, using emoji fallback");
+            }
+            usable
+        })
+    })
+}
+
+/// Resolve [`IconTheme::Auto`] for the current window state: emoji while
+/// hidden in the tray, so no SVGs are rasterized until the window is back.
+pub fn effective_theme(theme: IconTheme) -> IconTheme {
+    match theme {
+        IconTheme::Auto if crate::ui::state::is_hidden() => IconTheme::Emoji,
+        IconTheme::Auto => IconTheme::Svg,
+        theme => theme,
+    }
+}
+
 fn themed_icon<M: 'static>(
     theme: IconTheme,
+    name: &'static str,
     svg_data: &'static IconData,
     emoji: &'static str,
     size: f32,
     color: Color,
 ) -> Element<'static, M> {
-    match theme {
-        IconTheme::Svg => icon_colored(svg_data, size, color).into(),
-        IconTheme::Emoji => emoji_icon(emoji, size, color).into(),
+    match effective_theme(theme) {
+        IconTheme::Svg if svg_usable(svg_data, name) => icon_colored(svg_data, size, color).into(),
+        _ => emoji_icon(emoji, size, color).into(),
     }
 }
 
@@ -83,7 +117,7 @@ macro_rules! impl_icons {
     ($(fn $name:ident($icon:path, $emoji:literal);)+) => {
         $(
             pub fn $name<M: 'static>(size: f32, color: Color, theme: IconTheme) -> Element<'static, M> {
-                themed_icon(theme, $icon, $emoji, size, color)
+                themed_icon(theme, stringify!($name), $icon, $emoji, size, color)
             }
         )+
     }
@@ -121,4 +155,17 @@ impl_icons! {
     fn icon_at(icondata_lu::LuAtSign, "@");
     fn icon_chart(icondata_lu::LuLayoutDashboard, "📊");
     fn icon_inbox_empty(icondata_lu::LuArchive, "📭");
+    fn icon_eye(icondata_lu::LuEye, "👀");
+    fn icon_workflow(icondata_lu::LuWorkflow, "⚙");
+    fn icon_users(icondata_lu::LuUsers, "👥");
+    fn icon_user_plus(icondata_lu::LuUserPlus, "👤");
+    fn icon_message(icondata_lu::LuMessageSquare, "💬");
+    fn icon_bell(icondata_lu::LuBell, "🔔");
+    fn icon_pencil(icondata_lu::LuPencil, "✎");
+    fn icon_git_merge(icondata_lu::LuGitMerge, "⑂");
+    fn icon_mail(icondata_lu::LuMail, "✉");
+    fn icon_sparkles(icondata_lu::LuSparkles, "✨");
+    fn icon_shield_check(icondata_lu::LuShieldCheck, "🛡");
+    fn icon_award(icondata_lu::LuAward, "🏅");
+    fn icon_menu(icondata_lu::LuMenu, "☰");
 }
@@ -1,5 +1,6 @@
 //! Screen routing and state management.
 
+use crate::ui::screens::login::{LoginMode, LoginScreen};
 use crate::ui::screens::notifications::NotificationsScreen;
 use crate::ui::screens::settings::SettingsScreen;
 use crate::ui::screens::settings::rule_engine::RuleEngineScreen;
@@ -12,6 +13,9 @@ pub enum Screen {
     Settings(Box<SettingsScreen>),
     /// Rule Engine screen with back navigation context.
     RuleEngine(Box<RuleEngineScreen>, RuleEngineOrigin),
+    /// Token entry while signed in (add an account or re-authenticate one),
+    /// keeping every other session.
+    AccountLogin(Box<LoginScreen>),
 }
 
 impl Screen {
@@ -33,6 +37,10 @@ impl Screen {
             }
             Screen::Settings(_) => "GitTop - Settings".into(),
             Screen::RuleEngine(_, _) => "GitTop - Rule Engine".into(),
+            Screen::AccountLogin(login) => match login.mode() {
+                LoginMode::Reauth(_) => "GitTop - Re-authenticate".into(),
+                LoginMode::AddAccount | LoginMode::SignIn => "GitTop - Add Account".into(),
+            },
         }
     }
 }
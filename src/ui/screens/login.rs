@@ -5,9 +5,22 @@ use iced::{Alignment, Element, Fill, Length, Task};
 
 use crate::diagnostics::CrashNotice;
 use crate::github::{GitHubClient, UserInfo, auth, proxy_keyring};
+use crate::i18n::{Key, tr, trf};
 use crate::settings::AppSettings;
 use crate::ui::theme;
 
+/// What a successful sign-in is used for.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum LoginMode {
+    /// No account yet; the token becomes the app's session.
+    #[default]
+    SignIn,
+    /// Another account alongside the ones already signed in.
+    AddAccount,
+    /// New token for an account that's already signed in.
+    Reauth(String),
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct LoginScreen {
     token_input: String,
@@ -19,6 +32,7 @@ pub struct LoginScreen {
     proxy_username: String,
     proxy_password: String,
     crash_notice: Option<CrashNotice>,
+    mode: LoginMode,
 }
 
 #[derive(Debug, Clone)]
@@ -36,6 +50,8 @@ pub enum LoginMessage {
     ProxyPasswordChanged(String),
     SubmitProxySettings,
     DismissCrashNotice,
+    /// Leave add-account/re-authentication without changing anything (handled by parent).
+    Cancel,
 }
 
 impl LoginScreen {
@@ -62,9 +78,32 @@ impl LoginScreen {
             proxy_username,
             proxy_password,
             crash_notice: crate::diagnostics::load_crash_notice(),
+            mode: LoginMode::SignIn,
         }
     }
 
+    /// Login screen that only replaces the token for `username`.
+    pub fn for_account(username: &str) -> Self {
+        Self {
+            mode: LoginMode::Reauth(username.to_string()),
+            crash_notice: None,
+            ..Self::new()
+        }
+    }
+
+    /// Login screen that adds an account next to the signed-in ones.
+    pub fn add_account() -> Self {
+        Self {
+            mode: LoginMode::AddAccount,
+            crash_notice: None,
+            ..Self::new()
+        }
+    }
+
+    pub fn mode(&self) -> &LoginMode {
+        &self.mode
+    }
+
     /// Build ProxySettings from current UI state
     fn build_proxy_settings(&self) -> AppSettings {
         let mut settings = AppSettings::load();
@@ -87,7 +126,7 @@ impl LoginScreen {
             }
             LoginMessage::Submit => {
                 if self.token_input.trim().is_empty() {
-                    self.error_message = Some("Please enter your token".to_string());
+                    self.error_message = Some(tr(Key::EnterToken).to_string());
                     return Task::none();
                 }
 
@@ -175,6 +214,7 @@ impl LoginScreen {
                 self.crash_notice = None;
                 Task::none()
             }
+            LoginMessage::Cancel => Task::none(),
         }
     }
 
@@ -223,11 +263,15 @@ impl LoginScreen {
         let crash_notice = self.view_crash_notice();
         let logo = text("GitTop").size(32).color(p.text_primary);
 
-        let tagline = text("Runs lighter than your IDE's status bar.")
-            .size(14)
-            .style(theme::secondary_text);
+        let tagline = match &self.mode {
+            LoginMode::Reauth(username) => text(trf(Key::LoginReauthTagline, username)),
+            LoginMode::AddAccount => text(tr(Key::LoginAddAccountTagline)),
+            LoginMode::SignIn => text(tr(Key::LoginTagline)),
+        }
+        .size(14)
+        .style(theme::secondary_text);
 
-        let token_label = text("GitHub Personal Access Token")
+        let token_label = text(tr(Key::TokenLabel))
             .size(12)
             .style(theme::secondary_text);
 
@@ -241,7 +285,7 @@ impl LoginScreen {
 
         let submit_button = if self.is_loading {
             button(
-                text("Authenticating...")
+                text(tr(Key::Authenticating))
                     .size(14)
                     .width(Fill)
                     .align_x(Alignment::Center),
@@ -251,10 +295,14 @@ impl LoginScreen {
             .padding(12)
         } else {
             button(
-                text("Sign In")
-                    .size(14)
-                    .width(Fill)
-                    .align_x(Alignment::Center),
+                text(match self.mode {
+                    LoginMode::SignIn => tr(Key::SignIn),
+                    LoginMode::AddAccount => tr(Key::AddAccount),
+                    LoginMode::Reauth(_) => tr(Key::UpdateToken),
+                })
+                .size(14)
+                .width(Fill)
+                .align_x(Alignment::Center),
             )
             .style(theme::primary_button)
             .on_press(LoginMessage::Submit)
@@ -268,15 +316,15 @@ impl LoginScreen {
             Space::new().width(0).height(0).into()
         };
 
-        let help_text = column![
-            button(text("Generate New Token").size(12))
+        let mut help_text = column![
+            button(text(tr(Key::GenerateNewToken)).size(12))
                 .style(theme::ghost_button)
                 .on_press(LoginMessage::OpenTokenUrl)
                 .padding(4),
-            text("Required scopes: notifications, repo")
+            text(tr(Key::RequiredScopes))
                 .size(11)
                 .style(theme::muted_text),
-            button(text("Proxy Settings").size(12))
+            button(text(tr(Key::ProxySettings)).size(12))
                 .style(theme::ghost_button)
                 .on_press(LoginMessage::ToggleProxySettings)
                 .padding(4),
@@ -284,6 +332,15 @@ impl LoginScreen {
         .spacing(4)
         .align_x(Alignment::Center);
 
+        if self.mode != LoginMode::SignIn {
+            help_text = help_text.push(
+                button(text(tr(Key::Cancel)).size(12))
+                    .style(theme::ghost_button)
+                    .on_press(LoginMessage::Cancel)
+                    .padding(4),
+            );
+        }
+
         let form = column![
             token_label,
             Space::new().height(8),
@@ -338,6 +395,7 @@ impl LoginScreen {
 
         let url_input = text_input("http://proxy.company.com:8080", &self.proxy_url)
             .on_input(LoginMessage::ProxyUrlChanged)
+            .on_submit(LoginMessage::SubmitProxySettings)
             .padding(12)
             .size(14)
             .style(theme::text_input_style)
@@ -349,6 +407,7 @@ impl LoginScreen {
 
         let username_input = text_input("", &self.proxy_username)
             .on_input(LoginMessage::ProxyUsernameChanged)
+            .on_submit(LoginMessage::SubmitProxySettings)
             .padding(12)
             .size(14)
             .style(theme::text_input_style)
@@ -361,6 +420,7 @@ impl LoginScreen {
         let password_input = text_input("", &self.proxy_password)
             .secure(true)
             .on_input(LoginMessage::ProxyPasswordChanged)
+            .on_submit(LoginMessage::SubmitProxySettings)
             .padding(12)
             .size(14)
             .style(theme::text_input_style)
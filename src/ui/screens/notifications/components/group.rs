@@ -1,4 +1,4 @@
-//! Notification group component - collapsible time-based groups.
+//! Notification group component - collapsible notification groups.
 
 use iced::widget::{Space, button, container, row, text};
 use iced::{Alignment, Element, Fill};
@@ -13,6 +13,7 @@ pub fn view_group_header<'a>(
     group: &'a NotificationGroup,
     group_index: usize,
     icon_theme: IconTheme,
+    flash: bool,
 ) -> Element<'a, NotificationMessage> {
     let p = theme::palette();
 
@@ -56,9 +57,46 @@ pub fn view_group_header<'a>(
     // Wrap priority headers with subtle background from theme
     if group.is_priority {
         container(header_btn)
-            .style(theme::priority_header_container)
+            .style(if flash {
+                theme::priority_header_flash_container
+            } else {
+                theme::priority_header_container
+            })
             .into()
     } else {
         header_btn.into()
     }
 }
+
+/// Header of the collapsed "hidden by rules" section below the groups.
+pub fn view_hidden_header<'a>(
+    count: usize,
+    expanded: bool,
+    icon_theme: IconTheme,
+) -> Element<'a, NotificationMessage> {
+    let p = theme::palette();
+
+    let chevron = if expanded {
+        icons::icon_chevron_down(12.0, p.text_muted, icon_theme)
+    } else {
+        icons::icon_chevron_right(12.0, p.text_muted, icon_theme)
+    };
+
+    button(
+        row![
+            chevron,
+            Space::new().width(8),
+            text("Hidden by rules").size(13).color(p.text_muted),
+            Space::new().width(6),
+            text(format!("({count})")).size(12).color(p.text_muted),
+        ]
+        .align_y(Alignment::Center),
+    )
+    .style(theme::ghost_button)
+    .padding([6, 8])
+    .on_press(NotificationMessage::List(
+        NotificationListMessage::ToggleHidden,
+    ))
+    .width(Fill)
+    .into()
+}
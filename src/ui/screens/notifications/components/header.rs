@@ -1,32 +1,76 @@
 //! Content header view - title, sync status, filters, actions.
 
-use iced::widget::{Space, button, container, row, text};
+use iced::widget::{Space, button, container, row, text, text_input};
 use iced::{Alignment, Color, Element, Fill};
 
+use crate::i18n::{Key, tr, trf};
 use crate::settings::IconTheme;
 use crate::ui::{icons, theme};
 
-use crate::github::NotificationView;
+use chrono::{DateTime, Local, Utc};
+
+use crate::github::{NotificationView, RateLimit, format_time_ago};
 use crate::ui::features::sidebar::SidebarState;
 use crate::ui::features::thread_actions::ThreadActionMessage;
+use crate::ui::screens::notifications::helper::RefreshDelta;
 use crate::ui::screens::notifications::messages::{FilterMessage, NotificationMessage};
 
+/// Refresh state shown next to the title.
+pub struct SyncStatus {
+    pub is_loading: bool,
+    pub last_synced_at: Option<DateTime<Utc>>,
+    /// Refreshes have been failing or haven't succeeded in a while.
+    pub is_stale: bool,
+    /// Refreshes and alerts are paused until this time.
+    pub paused_until: Option<DateTime<Local>>,
+    /// What the last refresh changed, while it's still fresh.
+    pub delta: Option<RefreshDelta>,
+    /// GitHub API quota from the last refresh.
+    pub rate_limit: Option<RateLimit>,
+}
+
 pub fn view<'a>(
     filtered_notifications: &[NotificationView],
-    is_loading: bool,
+    sync: SyncStatus,
     filters: &SidebarState,
     icon_theme: IconTheme,
 ) -> Element<'a, NotificationMessage> {
     let p = theme::palette();
     let unread_count = filtered_notifications.iter().filter(|n| n.unread).count();
 
-    let title = text("Notifications").size(18).color(p.text_primary);
+    let title = text(tr(Key::Notifications)).size(18).color(p.text_primary);
+
+    let synced_label = sync
+        .last_synced_at
+        .map(format_synced)
+        .unwrap_or_else(|| tr(Key::NotSynced).to_string());
 
-    let sync_status: Element<'_, NotificationMessage> = if is_loading {
+    let sync_status: Element<'_, NotificationMessage> = if sync.is_loading {
         row![
             icons::icon_refresh(11.0, p.text_muted, icon_theme),
             Space::new().width(4),
-            text("Syncing...").size(11).color(p.text_muted),
+            text(tr(Key::Syncing)).size(11).color(p.text_muted),
+        ]
+        .align_y(Alignment::Center)
+        .into()
+    } else if let Some(until) = sync.paused_until {
+        row![
+            text(trf(Key::PausedUntil, until.format("%H:%M")))
+                .size(11)
+                .color(p.text_muted),
+            Space::new().width(6),
+            button(text(tr(Key::Resume)).size(11))
+                .style(theme::ghost_button)
+                .padding([2, 6])
+                .on_press(NotificationMessage::ResumeNotifications),
+        ]
+        .align_y(Alignment::Center)
+        .into()
+    } else if sync.is_stale {
+        row![
+            icons::icon_alert(11.0, p.accent_warning, icon_theme),
+            Space::new().width(4),
+            text(synced_label).size(11).color(p.accent_warning),
         ]
         .align_y(Alignment::Center)
         .into()
@@ -34,7 +78,8 @@ pub fn view<'a>(
         row![
             icons::icon_check(11.0, p.accent_success, icon_theme),
             Space::new().width(4),
-            text("Synced").size(11).color(p.accent_success),
+            text(synced_label).size(11).color(p.accent_success),
+            view_delta(sync.delta),
         ]
         .align_y(Alignment::Center)
         .into()
@@ -43,12 +88,50 @@ pub fn view<'a>(
     // Segmented control for filter selection (Unread | All)
     let is_unread_filter = !filters.show_all;
 
-    let unread_btn = view_filter_pill("Unread", is_unread_filter, FilterMessage::ToggleShowAll);
-    let all_btn = view_filter_pill("All", !is_unread_filter, FilterMessage::ToggleShowAll);
+    let unread_btn = view_filter_pill(
+        tr(Key::Unread),
+        is_unread_filter,
+        FilterMessage::ToggleShowAll,
+    );
+    let all_btn = view_filter_pill(
+        tr(Key::All),
+        !is_unread_filter,
+        FilterMessage::ToggleShowAll,
+    );
 
     let filter_segment =
         container(row![unread_btn, all_btn].spacing(0)).style(theme::segment_container);
 
+    // "Hide read" only makes sense on top of the All view
+    let hide_read_toggle: Element<'_, NotificationMessage> = if filters.show_all {
+        row![
+            Space::new().width(8),
+            container(view_filter_pill(
+                tr(Key::HideRead),
+                filters.hide_read,
+                FilterMessage::ToggleHideRead
+            ))
+            .style(theme::segment_container),
+        ]
+        .into()
+    } else {
+        Space::new().width(0).into()
+    };
+
+    let mentions_chip = container(view_filter_pill(
+        tr(Key::Mentions),
+        filters.mentions_only,
+        FilterMessage::ToggleMentionsOnly,
+    ))
+    .style(theme::segment_container);
+
+    let participating_chip = container(view_filter_pill(
+        tr(Key::Participating),
+        filters.participating_only,
+        FilterMessage::ToggleParticipatingOnly,
+    ))
+    .style(theme::segment_container);
+
     let has_unread = unread_count > 0;
     let mark_all_btn = button(
         row![
@@ -58,7 +141,7 @@ pub fn view<'a>(
                 icon_theme
             ),
             Space::new().width(6),
-            text("Mark all read").size(12).color(if has_unread {
+            text(tr(Key::MarkAllRead)).size(12).color(if has_unread {
                 p.text_primary
             } else {
                 p.text_muted
@@ -86,6 +169,28 @@ pub fn view<'a>(
         ThreadActionMessage::MarkAllAsRead,
     )));
 
+    // Clears exactly what's listed, so it follows the active filters
+    let has_visible = !filtered_notifications.is_empty();
+    let mark_all_done_btn = button(
+        row![
+            icons::icon_inbox_empty(
+                12.0,
+                if has_visible { p.accent } else { p.text_muted },
+                icon_theme
+            ),
+            Space::new().width(6),
+            text(tr(Key::MarkAllDone)).size(12).color(if has_visible {
+                p.text_primary
+            } else {
+                p.text_muted
+            }),
+        ]
+        .align_y(Alignment::Center),
+    )
+    .style(theme::ghost_button)
+    .padding([6, 10])
+    .on_press_maybe(has_visible.then_some(NotificationMessage::MarkAllAsDone));
+
     let refresh_btn = button(icons::icon_refresh(14.0, p.text_secondary, icon_theme))
         .style(theme::ghost_button)
         .padding(8)
@@ -95,11 +200,21 @@ pub fn view<'a>(
         title,
         Space::new().width(12),
         sync_status,
+        view_rate_limit(sync.rate_limit),
         Space::new().width(Fill),
+        view_search(&filters.search_query),
+        Space::new().width(8),
         filter_segment,
+        hide_read_toggle,
+        Space::new().width(8),
+        mentions_chip,
+        Space::new().width(8),
+        participating_chip,
         Space::new().width(12),
         mark_all_btn,
         Space::new().width(4),
+        mark_all_done_btn,
+        Space::new().width(4),
         refresh_btn,
     ]
     .align_y(Alignment::Center)
@@ -111,6 +226,46 @@ pub fn view<'a>(
         .into()
 }
 
+fn format_synced(at: DateTime<Utc>) -> String {
+    let age = Utc::now().signed_duration_since(at);
+    if age.num_minutes() < 1 {
+        tr(Key::SyncedJustNow).to_string()
+    } else if age.num_days() < 7 {
+        trf(Key::SyncedAgo, format_time_ago(at))
+    } else {
+        trf(Key::SyncedOn, format_time_ago(at))
+    }
+}
+
+/// "  ·  API: 4821/5000"; warning-coloured once refreshes are being slowed.
+pub fn view_rate_limit<'a>(rate_limit: Option<RateLimit>) -> Element<'a, NotificationMessage> {
+    let Some(limit) = rate_limit else {
+        return Space::new().width(0).into();
+    };
+    let p = theme::palette();
+    let color = if limit.is_low(Utc::now()) {
+        p.accent_warning
+    } else {
+        p.text_muted
+    };
+    let quota = format!("{}/{}", limit.remaining, limit.limit);
+    text(format!("  ·  {}", trf(Key::ApiQuota, quota)))
+        .size(11)
+        .color(color)
+        .into()
+}
+
+/// Search box over titles and repo names; filters the loaded list locally.
+pub fn view_search<'a>(query: &str) -> Element<'a, NotificationMessage> {
+    text_input(tr(Key::SearchNotifications), query)
+        .on_input(|q| NotificationMessage::Filter(FilterMessage::SearchChanged(q)))
+        .size(12)
+        .padding([6, 10])
+        .width(180)
+        .style(theme::text_input_style)
+        .into()
+}
+
 fn view_filter_pill<'a>(
     label: &'a str,
     is_active: bool,
@@ -122,3 +277,14 @@ fn view_filter_pill<'a>(
         .on_press(NotificationMessage::Filter(msg))
         .into()
 }
+
+/// "+3 new, −5 read" after a refresh; empty once it has faded.
+pub fn view_delta<'a>(delta: Option<RefreshDelta>) -> Element<'a, NotificationMessage> {
+    match delta {
+        Some(delta) => text(format!("  ·  {delta}"))
+            .size(11)
+            .color(theme::palette().text_secondary)
+            .into(),
+        None => Space::new().width(0).into(),
+    }
+}
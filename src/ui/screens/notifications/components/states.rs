@@ -1,42 +1,102 @@
 //! Notification screen state views (loading, error, empty).
 
-use iced::widget::{Space, button, column, container, text};
+use std::time::Duration;
+
+use iced::widget::{Space, button, column, container, row, text};
 use iced::{Alignment, Element, Fill};
 
+use crate::github::{ErrorKind, GitHubError};
 use crate::settings::IconTheme;
-use crate::ui::screens::notifications::messages::NotificationMessage;
+use crate::ui::screens::notifications::messages::{NavigationMessage, NotificationMessage};
 use crate::ui::{icons, theme};
 
-pub fn view_loading<'a>() -> Element<'a, NotificationMessage> {
+pub fn view_loading<'a>(label: &'a str) -> Element<'a, NotificationMessage> {
     let p = theme::palette();
-    container(
-        text("Loading notifications...")
-            .size(14)
-            .color(p.text_secondary),
-    )
-    .width(Fill)
-    .height(Fill)
-    .align_x(Alignment::Center)
-    .align_y(Alignment::Center)
-    .style(theme::app_container)
-    .into()
+    container(text(label).size(14).color(p.text_secondary))
+        .width(Fill)
+        .height(Fill)
+        .align_x(Alignment::Center)
+        .align_y(Alignment::Center)
+        .style(theme::app_container)
+        .into()
 }
 
-pub fn view_error<'a>(error: &'a str, icon_theme: IconTheme) -> Element<'a, NotificationMessage> {
+/// GitHub pages the empty and error states point people to.
+const NOTIFICATION_SETTINGS_URL: &str = "https://github.com/settings/notifications";
+const WATCHING_URL: &str = "https://github.com/watching";
+const GITHUB_STATUS_URL: &str = "https://www.githubstatus.com";
+
+pub fn view_error<'a>(
+    error: &GitHubError,
+    throttled_for: Option<Duration>,
+    icon_theme: IconTheme,
+) -> Element<'a, NotificationMessage> {
     let p = theme::palette();
+
+    let retry = button(text("Retry").size(14))
+        .style(theme::primary_button)
+        .padding([10, 24])
+        .on_press(NotificationMessage::Refresh);
+
+    let (title, detail, actions) = match error.kind() {
+        ErrorKind::Network => (
+            "Can't reach GitHub",
+            "Check your connection. If you're behind a proxy, check its settings.",
+            row![
+                retry,
+                secondary_button(
+                    "Proxy Settings",
+                    NotificationMessage::Navigation(NavigationMessage::OpenProxySettings),
+                ),
+            ],
+        ),
+        ErrorKind::Auth => (
+            "GitHub rejected the token",
+            "It may have been revoked, expired, or be missing the notifications scope.",
+            row![
+                button(text("Re-authenticate").size(14))
+                    .style(theme::primary_button)
+                    .padding([10, 24])
+                    .on_press(NotificationMessage::Navigation(
+                        NavigationMessage::Reauthenticate,
+                    )),
+            ],
+        ),
+        // Retrying early only extends it, so there's nothing to press
+        ErrorKind::RateLimited if matches!(error, GitHubError::Throttled { .. }) => (
+            "Temporarily throttled",
+            "GitHub asked GitTop to slow down. Polling is paused until it's safe to resume.",
+            row![],
+        ),
+        ErrorKind::RateLimited => (
+            "Rate limit reached",
+            "GitHub limits API calls per hour. GitTop will try again on the next refresh.",
+            row![secondary_button("Retry Now", NotificationMessage::Refresh)],
+        ),
+        ErrorKind::Server => (
+            "GitHub is having trouble",
+            "This is usually temporary. Try again in a few minutes.",
+            row![retry, link_button("GitHub Status", GITHUB_STATUS_URL)],
+        ),
+        ErrorKind::Other => (
+            "Failed to load notifications",
+            "Something unexpected came back from GitHub.",
+            row![retry],
+        ),
+    };
+
     let content = column![
         icons::icon_alert(32.0, p.accent_warning, icon_theme),
         Space::new().height(16),
-        text("Failed to load notifications")
-            .size(16)
-            .color(p.text_primary),
+        text(title).size(16).color(p.text_primary),
         Space::new().height(8),
-        text(error).size(12).color(p.text_secondary),
+        text(detail).size(12).color(p.text_secondary),
+        Space::new().height(4),
+        text(error_status(error, throttled_for))
+            .size(11)
+            .color(p.text_muted),
         Space::new().height(24),
-        button(text("Retry").size(14))
-            .style(theme::primary_button)
-            .padding([10, 24])
-            .on_press(NotificationMessage::Refresh),
+        actions.spacing(8).align_y(Alignment::Center),
     ]
     .align_x(Alignment::Center);
 
@@ -49,9 +109,21 @@ pub fn view_error<'a>(error: &'a str, icon_theme: IconTheme) -> Element<'a, Noti
         .into()
 }
 
+/// The error itself, or a live countdown while throttled.
+fn error_status(error: &GitHubError, throttled_for: Option<Duration>) -> String {
+    match (error, throttled_for) {
+        (GitHubError::Throttled { .. }, Some(left)) => {
+            format!("Resuming in {}s", left.as_secs() + 1)
+        }
+        _ => error.to_string(),
+    }
+}
+
 pub enum EmptyState {
     NoNotifications,
     AllCaughtUp,
+    /// Nothing to show because GitHub rejected the token.
+    TokenInvalid,
 }
 
 pub fn view_empty<'a>(
@@ -59,19 +131,56 @@ pub fn view_empty<'a>(
     icon_theme: IconTheme,
 ) -> Element<'a, NotificationMessage> {
     let p = theme::palette();
-    let message = match state {
-        EmptyState::NoNotifications => "No notifications yet",
-        EmptyState::AllCaughtUp => "All caught up!",
+
+    let (icon, title, detail) = match state {
+        EmptyState::NoNotifications => (
+            icons::icon_circle_check(48.0, p.accent_success, icon_theme),
+            "No notifications yet",
+            "GitHub only notifies you about repositories you watch and threads you take part in",
+        ),
+        EmptyState::AllCaughtUp => (
+            icons::icon_circle_check(48.0, p.accent_success, icon_theme),
+            "All caught up!",
+            "You have no unread notifications",
+        ),
+        EmptyState::TokenInvalid => (
+            icons::icon_alert(48.0, p.accent_warning, icon_theme),
+            "Can't load notifications",
+            "GitHub rejected this account's token, so this list may be incomplete",
+        ),
+    };
+
+    let actions: Element<'a, NotificationMessage> = match state {
+        EmptyState::TokenInvalid => button(text("Re-authenticate").size(14))
+            .style(theme::primary_button)
+            .padding([10, 24])
+            .on_press(NotificationMessage::Navigation(
+                NavigationMessage::Reauthenticate,
+            ))
+            .into(),
+        EmptyState::NoNotifications | EmptyState::AllCaughtUp => column![
+            text("Expecting something? Check what you watch on GitHub.")
+                .size(11)
+                .color(p.text_muted),
+            Space::new().height(8),
+            row![
+                link_button("Notification Settings", NOTIFICATION_SETTINGS_URL),
+                link_button("Watched Repositories", WATCHING_URL),
+            ]
+            .spacing(8),
+        ]
+        .align_x(Alignment::Center)
+        .into(),
     };
 
     let content = column![
-        icons::icon_circle_check(48.0, p.accent_success, icon_theme),
+        icon,
         Space::new().height(16),
-        text(message).size(16).color(p.text_primary),
+        text(title).size(16).color(p.text_primary),
         Space::new().height(8),
-        text("You have no unread notifications")
-            .size(12)
-            .color(p.text_secondary),
+        text(detail).size(12).color(p.text_secondary),
+        Space::new().height(24),
+        actions,
     ]
     .align_x(Alignment::Center);
 
@@ -83,3 +192,23 @@ pub fn view_empty<'a>(
         .style(theme::app_container)
         .into()
 }
+
+fn secondary_button<'a>(
+    label: &'a str,
+    message: NotificationMessage,
+) -> Element<'a, NotificationMessage> {
+    button(text(label).size(14))
+        .style(theme::ghost_button)
+        .padding([10, 16])
+        .on_press(message)
+        .into()
+}
+
+fn link_button<'a>(label: &'a str, url: &'static str) -> Element<'a, NotificationMessage> {
+    let p = theme::palette();
+    button(text(label).size(12).color(p.accent))
+        .style(theme::ghost_button)
+        .padding([8, 12])
+        .on_press(NotificationMessage::OpenUrl(url))
+        .into()
+}
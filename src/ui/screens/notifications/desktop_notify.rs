@@ -1,17 +1,60 @@
+use crate::github::NotificationReason;
+use crate::platform::ToastThread;
+use crate::settings::{AppSettings, Reminder};
 use crate::ui::screens::notifications::engine::DesktopNotificationBatch;
 use crate::ui::screens::notifications::helper::{ProcessedNotification, api_url_to_web_url};
 use std::collections::HashMap;
 
+/// How long without a successful sync before the next batch is collapsed
+/// into a single "while you were away" digest.
+pub const CATCH_UP_GAP: chrono::TimeDelta = chrono::TimeDelta::minutes(30);
+
+/// Allowed range for both toast limits.
+pub const TOAST_LIMIT_RANGE: std::ops::RangeInclusive<usize> = 1..=10;
+
+/// When a batch of regular notifications is summarized, and how much of it
+/// the summary lists.
+#[derive(Debug, Clone, Copy)]
+pub struct ToastLimits {
+    /// Batches up to this size are sent as individual toasts.
+    pub individual: usize,
+    /// Titles listed in a summary before "...and N more".
+    pub summary_titles: usize,
+}
+
+impl ToastLimits {
+    pub fn from_settings(settings: &AppSettings) -> Self {
+        let clamp = |n: usize| n.clamp(*TOAST_LIMIT_RANGE.start(), *TOAST_LIMIT_RANGE.end());
+        Self {
+            individual: clamp(settings.toast_individual_limit),
+            summary_titles: clamp(settings.toast_summary_titles),
+        }
+    }
+}
+
 /// Send desktop notifications for a batch of processed notifications.
+///
+/// With `catch_up` set (first sync after a long gap), anything beyond a
+/// single item is sent as one digest instead of individual toasts.
+///
+/// Reports `Failed` only when toasts were due and none of them could be
+/// shown, which usually means nothing on the system is listening for them.
 pub fn send_desktop_notifications(
     processed: &[ProcessedNotification],
     seen_timestamps: &HashMap<String, chrono::DateTime<chrono::Utc>>,
-) {
-    let batch = DesktopNotificationBatch::from_processed(processed, seen_timestamps);
+    muted_reasons: &[NotificationReason],
+    limits: ToastLimits,
+    catch_up: bool,
+) -> ToastOutcome {
+    let batch = DesktopNotificationBatch::from_processed(processed, seen_timestamps, muted_reasons);
 
     if batch.is_empty() {
         tracing::debug!("No desktop notifications to send");
-        return;
+        return ToastOutcome::Idle;
+    }
+
+    if catch_up && batch.len() > 1 {
+        return send_catch_up_digest(&batch);
     }
 
     tracing::debug!(
@@ -20,6 +63,8 @@ pub fn send_desktop_notifications(
         "Sending desktop notifications"
     );
 
+    let mut delivery = Delivery::default();
+
     // Send priority notifications individually
     for p in &batch.priority {
         let notif = &p.notification;
@@ -29,45 +74,150 @@ pub fn send_desktop_notifications(
         );
         let url = notif.url.as_ref().map(|u| api_url_to_web_url(u));
         let body = format!("{}\n{}", notif.title, notif.reason.label());
-        if let Err(e) = crate::platform::notify(&title, &body, url.as_deref()) {
-            tracing::warn!(error = %e, "Failed to send desktop notification");
-        }
+        delivery.send(&title, &body, url.as_deref(), Some(toast_thread(p)));
     }
 
     if batch.regular.is_empty() {
-        return;
+        return delivery.outcome();
     }
 
     // Send regular notifications
-    if batch.regular.len() == 1 {
-        let notif = &batch.regular[0].notification;
-        let title = format!("{} - {}", notif.repo_full_name, notif.subject_type);
-        let url = notif.url.as_ref().map(|u| api_url_to_web_url(u));
-        let body = format!("{}\n{}", notif.title, notif.reason.label());
+    if batch.regular.len() <= limits.individual {
+        for p in &batch.regular {
+            let notif = &p.notification;
+            let title = format!("{} - {}", notif.repo_full_name, notif.subject_type);
+            let url = notif.url.as_ref().map(|u| api_url_to_web_url(u));
+            let body = format!("{}\n{}", notif.title, notif.reason.label());
 
-        if let Err(e) = crate::platform::notify(&title, &body, url.as_deref()) {
-            tracing::warn!(error = %e, "Failed to send desktop notification");
+            delivery.send(&title, &body, url.as_deref(), Some(toast_thread(p)));
         }
     } else {
         let title = format!("{} new GitHub notifications", batch.regular.len());
-        let body = batch
-            .regular
-            .iter()
-            .take(3)
-            .map(|p| format!("• {}", p.notification.title))
-            .collect::<Vec<_>>()
-            .join("\n");
-
-        let body = if batch.regular.len() > 3 {
-            format!("{}\n...and {} more", body, batch.regular.len() - 3)
-        } else {
-            body
-        };
+        let titles = batch.regular.iter().map(|p| p.notification.title.as_str());
+        let body = summary_body(titles, limits.summary_titles);
+
+        delivery.send(&title, &body, None, None);
+    }
+
+    crate::platform::trim_memory();
+    delivery.outcome()
+}
 
-        if let Err(e) = crate::platform::notify(&title, &body, None) {
-            tracing::warn!(error = %e, "Failed to send desktop notification");
+/// Send a toast for each reminder that came due, tagged with its account.
+pub fn send_reminders(due: &[(String, Reminder)]) -> ToastOutcome {
+    let mut delivery = Delivery::default();
+    for (account, reminder) in due {
+        let title = format!("Reminder: {}", reminder.repo_full_name);
+        let body = format!("{}\n@{}", reminder.title, account);
+        delivery.send(&title, &body, reminder.url.as_deref(), None);
+    }
+    delivery.outcome()
+}
+
+/// What happened to the toasts for one refresh.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ToastOutcome {
+    /// Nothing new to show.
+    Idle,
+    Delivered,
+    /// Toasts were due and none could be shown; holds the last error.
+    Failed(String),
+}
+
+/// Toasts sent for one batch: whether any got through, and the last error.
+#[derive(Default)]
+struct Delivery {
+    delivered: bool,
+    error: Option<String>,
+}
+
+impl Delivery {
+    fn send(&mut self, title: &str, body: &str, url: Option<&str>, thread: Option<ToastThread>) {
+        match crate::platform::notify(title, body, url, thread) {
+            Ok(()) => self.delivered = true,
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to send desktop notification");
+                self.error = Some(e.to_string());
+            }
         }
     }
 
+    fn outcome(self) -> ToastOutcome {
+        match self.error {
+            Some(e) if !self.delivered => ToastOutcome::Failed(e),
+            _ => ToastOutcome::Delivered,
+        }
+    }
+}
+
+/// Thread reference behind a toast's "Mark read" action.
+fn toast_thread(p: &ProcessedNotification) -> ToastThread {
+    ToastThread {
+        account: p.notification.account.clone(),
+        id: p.notification.id.clone(),
+    }
+}
+
+/// Bullet list of the first `shown` titles, one per line, followed by an
+/// "...and N more" line when some were left out.
+fn summary_body<'a>(titles: impl ExactSizeIterator<Item = &'a str>, shown: usize) -> String {
+    let total = titles.len();
+    let mut lines: Vec<String> = titles.take(shown).map(|t| format!("• {t}")).collect();
+    if total > shown {
+        lines.push(format!("...and {} more", total - shown));
+    }
+    lines.join("\n")
+}
+
+fn send_catch_up_digest(batch: &DesktopNotificationBatch<'_>) -> ToastOutcome {
+    let repos = batch.repo_count();
+    let title = format!(
+        "{} new notifications across {} {} while you were away",
+        batch.len(),
+        repos,
+        if repos == 1 { "repo" } else { "repos" }
+    );
+    let body = if batch.priority.is_empty() {
+        "Open GitTop to catch up.".to_string()
+    } else {
+        format!("{} marked important.", batch.priority.len())
+    };
+
+    tracing::debug!(count = batch.len(), repos, "Sending catch-up digest");
+    let mut delivery = Delivery::default();
+    delivery.send(&title, &body, None, None);
+
     crate::platform::trim_memory();
+    delivery.outcome()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summary_body_lists_titles_on_separate_lines() {
+        let body = summary_body(["Fix crash", "Add docs"].into_iter(), 3);
+        assert_eq!(body, "• Fix crash\n• Add docs");
+    }
+
+    #[test]
+    fn test_summary_body_counts_remaining_titles() {
+        let body = summary_body(["a", "b", "c", "d", "e"].into_iter(), 2);
+        assert_eq!(body, "• a\n• b\n...and 3 more");
+        assert!(!body.contains("\\n"));
+    }
+
+    #[test]
+    fn test_delivery_fails_only_when_nothing_got_through() {
+        let failed = |delivered| Delivery {
+            delivered,
+            error: Some("no daemon".to_string()),
+        };
+        assert_eq!(
+            failed(false).outcome(),
+            ToastOutcome::Failed("no daemon".to_string())
+        );
+        assert_eq!(failed(true).outcome(), ToastOutcome::Delivered);
+    }
 }
@@ -10,15 +10,26 @@
 use chrono::{DateTime, Local, Utc};
 use std::collections::HashMap;
 
-use crate::github::types::NotificationView;
-use crate::ui::screens::settings::rule_engine::{NotificationRuleSet, RuleAction, RuleEngine};
+use crate::activity_log;
+use crate::github::types::{NotificationReason, NotificationView};
+use crate::ui::screens::settings::rule_engine::{
+    NotificationRuleSet, RuleAction, RuleEngine, RuleSubject,
+};
 
-use super::helper::ProcessedNotification;
+use super::helper::{HiddenNotification, ProcessedNotification};
 
 // ============================================================================
 // Notification Engine
 // ============================================================================
 
+/// How many notifications a rule decides, from `NotificationEngine::impact_of`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RuleImpact {
+    pub affected: usize,
+    pub hidden: usize,
+    pub prioritized: usize,
+}
+
 /// Encapsulates all rule evaluation logic, ensuring notifications are
 /// processed exactly once per refresh cycle.
 pub struct NotificationEngine {
@@ -38,34 +49,94 @@ impl NotificationEngine {
 
     /// Primary entry point. Call this ONCE per refresh cycle.
     pub fn process_all(&self, notifications: &[NotificationView]) -> Vec<ProcessedNotification> {
-        notifications
-            .iter()
-            .filter_map(|n| self.evaluate_single(n))
-            .collect()
+        self.process_with_hidden(notifications).0
     }
 
-    fn evaluate_single(&self, notification: &NotificationView) -> Option<ProcessedNotification> {
-        // This extraction is subtle we must use the exact same label as the rules expected.
-        let reason_label = Self::extract_reason_label(notification);
+    /// Like `process_all`, but also returns what rules hid and why.
+    pub fn process_with_hidden(
+        &self,
+        notifications: &[NotificationView],
+    ) -> (Vec<ProcessedNotification>, Vec<HiddenNotification>) {
+        let mut shown = Vec::new();
+        let mut hidden = Vec::new();
+        for notification in notifications {
+            match self.evaluate_single(notification) {
+                Ok(processed) => shown.push(processed),
+                Err(rule) => hidden.push(HiddenNotification {
+                    notification: notification.clone(),
+                    rule,
+                }),
+            }
+        }
+        (shown, hidden)
+    }
 
-        let (action, _decision) = self.engine.evaluate_detailed(
-            reason_label,
-            Some(notification.repo_owner()),
-            Some(&notification.account),
-            &self.evaluation_time,
-        );
+    /// Dry run: how many notifications `rule_id` ends up deciding, and how.
+    pub fn impact_of(&self, rule_id: &str, notifications: &[NotificationView]) -> RuleImpact {
+        let mut impact = RuleImpact::default();
+        for notification in notifications {
+            let (action, decision) = self
+                .engine
+                .evaluate_detailed(&Self::subject(notification), &self.evaluation_time);
+            if decision.is_some_and(|d| d.applied_rule_id == rule_id) {
+                impact.affected += 1;
+                match action {
+                    RuleAction::Hide => impact.hidden += 1,
+                    RuleAction::Important => impact.prioritized += 1,
+                    RuleAction::Show | RuleAction::Silent => {}
+                }
+            }
+        }
+        impact
+    }
+
+    /// The processed notification, or the rule that hid it.
+    fn evaluate_single(
+        &self,
+        notification: &NotificationView,
+    ) -> Result<ProcessedNotification, String> {
+        let (action, decision) = self
+            .engine
+            .evaluate_detailed(&Self::subject(notification), &self.evaluation_time);
+
+        let logged_action = match action {
+            RuleAction::Hide => Some(activity_log::Action::RuleHide),
+            RuleAction::Important => Some(activity_log::Action::RulePriority),
+            RuleAction::Show | RuleAction::Silent => None,
+        };
+        if let (Some(logged), Some(decision)) = (logged_action, &decision) {
+            activity_log::record_rule(
+                logged,
+                &notification.account,
+                &notification.id,
+                &decision.applied_rule_id,
+            );
+        }
 
-        // Filter out hidden notifications entirely from the UI view model
+        // Hidden notifications stay out of the main view model
         if action == RuleAction::Hide {
-            None
+            Err(decision.map_or_else(|| "Rule".to_string(), |d| d.reason.describe()))
         } else {
-            Some(ProcessedNotification {
+            Ok(ProcessedNotification {
                 notification: notification.clone(),
                 action,
+                merged: Vec::new(),
             })
         }
     }
 
+    /// Everything the rules match on, taken from `notification`.
+    fn subject(notification: &NotificationView) -> RuleSubject<'_> {
+        RuleSubject {
+            // This extraction is subtle we must use the exact same label as the rules expected.
+            notification_type: Self::extract_reason_label(notification),
+            repo_owner: Some(notification.repo_owner()),
+            account: Some(&notification.account),
+            actor: notification.actor.as_deref(),
+            title: Some(&notification.title),
+        }
+    }
+
     /// Single source of truth for notification reason -> string conversion.
     #[inline]
     pub fn extract_reason_label(notification: &NotificationView) -> &str {
@@ -99,13 +170,17 @@ pub struct DesktopNotificationBatch<'a> {
 }
 
 impl<'a> DesktopNotificationBatch<'a> {
+    /// Muted reasons only drop regular items; Important ones come from an
+    /// explicit rule and always get through.
     pub fn from_processed(
         processed: &'a [ProcessedNotification],
         seen_timestamps: &HashMap<String, DateTime<Utc>>,
+        muted_reasons: &[NotificationReason],
     ) -> Self {
         let (priority, regular) = processed
             .iter()
             .filter(|p| NotificationEngine::should_notify_desktop(p, seen_timestamps))
+            .filter(|p| p.is_priority() || !muted_reasons.contains(&p.notification.reason))
             .partition(|p| (*p).is_priority());
 
         Self { priority, regular }
@@ -114,12 +189,34 @@ impl<'a> DesktopNotificationBatch<'a> {
     pub fn is_empty(&self) -> bool {
         self.priority.is_empty() && self.regular.is_empty()
     }
+
+    pub fn len(&self) -> usize {
+        self.priority.len() + self.regular.len()
+    }
+
+    /// Number of distinct repositories in the batch.
+    pub fn repo_count(&self) -> usize {
+        self.priority
+            .iter()
+            .chain(&self.regular)
+            .map(|p| p.notification.repo_full_name.as_str())
+            .collect::<std::collections::HashSet<_>>()
+            .len()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::github::types::{NotificationReason, SubjectType};
+    use crate::settings::{GroupingMode, TimeBuckets};
+    use crate::ui::screens::notifications::helper::{
+        compact_by_subject, float_pinned, group_processed_notifications, refresh_delta,
+        unread_snapshot,
+    };
+    use crate::ui::screens::settings::rule_engine::rules::{
+        AccountRule, OrgRule, OutsideScheduleBehavior, TypeRule,
+    };
 
     fn make_notification(id: &str, unread: bool, reason: NotificationReason) -> NotificationView {
         NotificationView {
@@ -136,6 +233,8 @@ mod tests {
             is_private: false,
             subject_type: SubjectType::Issue,
             account: "testuser".to_string(),
+            ci_conclusion: None,
+            actor: None,
         }
     }
 
@@ -205,4 +304,319 @@ mod tests {
             &seen
         ));
     }
+
+    #[test]
+    fn test_batch_repo_count() {
+        let engine = NotificationEngine::new(NotificationRuleSet::default());
+        let mut other_repo = make_notification("3", true, NotificationReason::Mention);
+        other_repo.repo_full_name = "owner/other".to_string();
+
+        let processed = engine.process_all(&[
+            make_notification("1", true, NotificationReason::Mention),
+            make_notification("2", true, NotificationReason::Subscribed),
+            other_repo,
+        ]);
+        let batch = DesktopNotificationBatch::from_processed(&processed, &HashMap::new(), &[]);
+
+        assert_eq!(batch.len(), 3);
+        assert_eq!(batch.repo_count(), 2);
+    }
+
+    fn enabled_rules() -> NotificationRuleSet {
+        NotificationRuleSet {
+            enabled: true,
+            ..NotificationRuleSet::default()
+        }
+    }
+
+    fn type_rule(
+        reason: NotificationReason,
+        account: Option<&str>,
+        action: RuleAction,
+    ) -> TypeRule {
+        let mut rule = TypeRule::new(reason.label(), account.map(str::to_string), 0);
+        rule.action = action;
+        rule
+    }
+
+    fn ids(processed: &[ProcessedNotification]) -> Vec<&str> {
+        processed
+            .iter()
+            .map(|p| p.notification.id.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_disabled_ruleset_passes_everything_through() {
+        let mut rules = NotificationRuleSet::default();
+        rules.type_rules.push(type_rule(
+            NotificationReason::Subscribed,
+            None,
+            RuleAction::Hide,
+        ));
+        let engine = NotificationEngine::new(rules);
+
+        let processed = engine.process_all(&[
+            make_notification("1", true, NotificationReason::Mention),
+            make_notification("2", true, NotificationReason::Subscribed),
+        ]);
+
+        assert_eq!(ids(&processed), ["1", "2"]);
+        assert!(processed.iter().all(|p| p.action == RuleAction::Show));
+    }
+
+    #[test]
+    fn test_hide_rule_suppresses_matching_type() {
+        let mut rules = enabled_rules();
+        rules.type_rules.push(type_rule(
+            NotificationReason::Subscribed,
+            None,
+            RuleAction::Hide,
+        ));
+        let engine = NotificationEngine::new(rules);
+
+        let processed = engine.process_all(&[
+            make_notification("1", true, NotificationReason::Mention),
+            make_notification("2", true, NotificationReason::Subscribed),
+            make_notification("3", true, NotificationReason::Mention),
+        ]);
+
+        assert_eq!(ids(&processed), ["1", "3"]);
+    }
+
+    #[test]
+    fn test_hidden_notifications_keep_their_rule() {
+        let mut rules = enabled_rules();
+        rules.type_rules.push(type_rule(
+            NotificationReason::Subscribed,
+            None,
+            RuleAction::Hide,
+        ));
+        let engine = NotificationEngine::new(rules);
+
+        let (shown, hidden) = engine.process_with_hidden(&[
+            make_notification("1", true, NotificationReason::Mention),
+            make_notification("2", true, NotificationReason::Subscribed),
+        ]);
+
+        assert_eq!(ids(&shown), ["1"]);
+        assert_eq!(hidden.len(), 1);
+        assert_eq!(hidden[0].notification.id, "2");
+        assert!(hidden[0].rule.starts_with("Type rule"));
+    }
+
+    #[test]
+    fn test_impact_counts_only_notifications_the_rule_decides() {
+        let mut rules = enabled_rules();
+        let draft = type_rule(NotificationReason::Subscribed, None, RuleAction::Hide);
+        let draft_id = draft.id.clone();
+        rules.type_rules.push(draft);
+        let engine = NotificationEngine::new(rules);
+
+        let impact = engine.impact_of(
+            &draft_id,
+            &[
+                make_notification("1", true, NotificationReason::Mention),
+                make_notification("2", true, NotificationReason::Subscribed),
+                make_notification("3", false, NotificationReason::Subscribed),
+            ],
+        );
+
+        assert_eq!(
+            impact,
+            RuleImpact {
+                affected: 2,
+                hidden: 2,
+                prioritized: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_type_rule_matches_reason_only() {
+        let mut rules = enabled_rules();
+        rules.type_rules.push(type_rule(
+            NotificationReason::ReviewRequested,
+            None,
+            RuleAction::Silent,
+        ));
+        let engine = NotificationEngine::new(rules);
+
+        let processed = engine.process_all(&[
+            make_notification("1", true, NotificationReason::ReviewRequested),
+            make_notification("2", true, NotificationReason::Mention),
+        ]);
+
+        assert_eq!(processed[0].action, RuleAction::Silent);
+        assert_eq!(processed[1].action, RuleAction::Show);
+    }
+
+    #[test]
+    fn test_important_overrides_hide_and_leads_groups() {
+        let mut rules = enabled_rules();
+        rules.type_rules.push(type_rule(
+            NotificationReason::Mention,
+            None,
+            RuleAction::Hide,
+        ));
+        let mut org = OrgRule::new("owner", 0);
+        org.action = RuleAction::Important;
+        rules.org_rules.push(org);
+        let engine = NotificationEngine::new(rules);
+
+        let mut other_org = make_notification("1", true, NotificationReason::Subscribed);
+        other_org.repo_full_name = "other/repo".to_string();
+        let processed = engine.process_all(&[
+            other_org,
+            make_notification("2", true, NotificationReason::Mention),
+        ]);
+
+        assert_eq!(ids(&processed), ["1", "2"]);
+        assert_eq!(processed[1].action, RuleAction::Important);
+
+        let groups = group_processed_notifications(
+            &processed,
+            true,
+            GroupingMode::Time,
+            TimeBuckets::Coarse,
+        );
+        assert!(groups[0].is_priority);
+        assert_eq!(ids(&groups[0].notifications), ["2"]);
+        assert!(groups[1..].iter().all(|g| !g.is_priority));
+    }
+
+    #[test]
+    fn test_daily_time_buckets_split_by_day() {
+        let engine = NotificationEngine::new(NotificationRuleSet::default());
+        let mut older = make_notification("2", true, NotificationReason::Mention);
+        older.updated_at = Utc::now() - chrono::Duration::days(3);
+        let processed = engine.process_all(&[
+            make_notification("1", true, NotificationReason::Mention),
+            older,
+        ]);
+
+        let daily =
+            group_processed_notifications(&processed, true, GroupingMode::Time, TimeBuckets::Daily);
+        assert_eq!(daily.len(), 2);
+        assert_eq!(daily[0].title, "Today");
+        assert_eq!(ids(&daily[1].notifications), ["2"]);
+
+        let coarse = group_processed_notifications(
+            &processed,
+            true,
+            GroupingMode::Time,
+            TimeBuckets::Coarse,
+        );
+        let titles: Vec<_> = coarse.iter().map(|g| g.title.as_str()).collect();
+        assert_eq!(titles, ["Today", "Yesterday", "This Week", "Older"]);
+    }
+
+    #[test]
+    fn test_compact_folds_same_subject_into_newest() {
+        let engine = NotificationEngine::new(NotificationRuleSet::default());
+        let mut first = make_notification("1", true, NotificationReason::ReviewRequested);
+        first.url = Some("https://api.github.com/repos/o/r/pulls/7".into());
+        first.updated_at = Utc::now() - chrono::Duration::hours(2);
+        let mut latest = make_notification("2", true, NotificationReason::Comment);
+        latest.url = first.url.clone();
+        let other = make_notification("3", true, NotificationReason::Mention);
+
+        let compacted = compact_by_subject(engine.process_all(&[first, other, latest]));
+        assert_eq!(ids(&compacted), ["2", "3"]);
+        assert_eq!(
+            compacted[0].notification.reason,
+            NotificationReason::Comment
+        );
+        let merged: Vec<_> = compacted[0].merged.iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(merged, ["1"]);
+        assert!(compacted[1].merged.is_empty());
+    }
+
+    #[test]
+    fn test_pinned_float_into_their_own_group() {
+        let engine = NotificationEngine::new(NotificationRuleSet::default());
+        let mut older = make_notification("2", true, NotificationReason::Mention);
+        older.updated_at = Utc::now() - chrono::Duration::days(30);
+        let processed = engine.process_all(&[
+            make_notification("1", true, NotificationReason::Mention),
+            older,
+        ]);
+
+        let mut groups = group_processed_notifications(
+            &processed,
+            true,
+            GroupingMode::Time,
+            TimeBuckets::Coarse,
+        );
+        float_pinned(&mut groups, &["2".to_string()].into_iter().collect());
+        assert_eq!(groups[0].title, "Pinned");
+        assert_eq!(ids(&groups[0].notifications), ["2"]);
+        let older_group = groups.iter().find(|g| g.title == "Older").unwrap();
+        assert!(older_group.notifications.is_empty());
+    }
+
+    #[test]
+    fn test_refresh_delta_counts_new_mentions_and_read() {
+        let engine = NotificationEngine::new(NotificationRuleSet::default());
+        let mut updated = make_notification("2", true, NotificationReason::Comment);
+        updated.updated_at = Utc::now() - chrono::Duration::hours(1);
+        let before = unread_snapshot(&engine.process_all(&[
+            make_notification("1", true, NotificationReason::Comment),
+            updated.clone(),
+            make_notification("3", true, NotificationReason::Comment),
+        ]));
+
+        updated.updated_at = Utc::now();
+        let after = engine.process_all(&[
+            make_notification("1", false, NotificationReason::Comment),
+            updated,
+            make_notification("4", true, NotificationReason::Mention),
+        ]);
+
+        let delta = refresh_delta(&before, &after);
+        assert_eq!((delta.new, delta.mentions, delta.read), (1, 1, 2));
+        assert_eq!(delta.to_string(), "+1 new, +1 mention, −2 read");
+        assert!(refresh_delta(&unread_snapshot(&after), &after).is_empty());
+    }
+
+    #[test]
+    fn test_account_scoped_type_rule() {
+        let mut rules = enabled_rules();
+        rules.type_rules.push(type_rule(
+            NotificationReason::Subscribed,
+            Some("work"),
+            RuleAction::Hide,
+        ));
+        let engine = NotificationEngine::new(rules);
+
+        let mut work = make_notification("1", true, NotificationReason::Subscribed);
+        work.account = "work".to_string();
+        let personal = make_notification("2", true, NotificationReason::Subscribed);
+
+        assert_eq!(ids(&engine.process_all(&[work, personal])), ["2"]);
+    }
+
+    #[test]
+    fn test_account_rule_outside_schedule() {
+        let mut rules = enabled_rules();
+        let mut off_duty = AccountRule::new("testuser");
+        off_duty.active_days.clear();
+        rules.account_rules.push(off_duty);
+        let mut deferred = AccountRule::new("work");
+        deferred.active_days.clear();
+        deferred.outside_behavior = OutsideScheduleBehavior::Defer;
+        rules.account_rules.push(deferred);
+        let engine = NotificationEngine::new(rules);
+
+        let mut work = make_notification("2", true, NotificationReason::Mention);
+        work.account = "work".to_string();
+        let processed = engine.process_all(&[
+            make_notification("1", true, NotificationReason::Mention),
+            work,
+        ]);
+
+        // Suppressed account drops out; deferred one stays but goes quiet
+        assert_eq!(ids(&processed), ["2"]);
+        assert_eq!(processed[0].action, RuleAction::Silent);
+    }
 }
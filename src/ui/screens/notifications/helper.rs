@@ -1,20 +1,24 @@
 //! Architecture Notes:
 //! - `ProcessedNotification` and `NotificationGroup` hold our view data.
-//! - `group_processed_notifications` handles the presentation logic (time buckets).
+//! - `group_processed_notifications` handles the presentation logic (time, repo or type buckets).
 //! - `apply_filters`, `count_by_type`, `count_by_repo` are just pure data transformations.
 //!
 //! Note: For rule evaluation, check `engine.rs` instead.
 
-use crate::github::{NotificationView, SubjectType};
+use crate::github::{NotificationReason, NotificationView, SubjectType};
+use crate::settings::{GroupingMode, TimeBuckets};
 use crate::ui::features::sidebar::SidebarState;
 use crate::ui::screens::settings::rule_engine::RuleAction;
-use chrono::Local;
-use std::collections::HashMap;
+use chrono::{DateTime, Datelike, Local, NaiveDate, Utc};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 #[derive(Debug, Clone)]
 pub struct ProcessedNotification {
     pub notification: NotificationView,
     pub action: RuleAction,
+    /// Older notifications on the same subject, newest first. Only filled
+    /// when compact mode folds them into this one.
+    pub merged: Vec<NotificationView>,
 }
 
 impl ProcessedNotification {
@@ -23,6 +27,13 @@ impl ProcessedNotification {
     }
 }
 
+/// A notification a rule hid, and which rule did it.
+#[derive(Debug, Clone)]
+pub struct HiddenNotification {
+    pub notification: NotificationView,
+    pub rule: String,
+}
+
 #[derive(Debug, Clone)]
 pub struct NotificationGroup {
     pub title: String,
@@ -35,37 +46,54 @@ pub struct NotificationGroup {
 pub fn group_processed_notifications(
     processed: &[ProcessedNotification],
     show_priority_group: bool,
+    mode: GroupingMode,
+    buckets: TimeBuckets,
+) -> Vec<NotificationGroup> {
+    match (mode, buckets) {
+        (GroupingMode::Time, TimeBuckets::Coarse) => group_by_time(processed, show_priority_group),
+        (GroupingMode::Time, TimeBuckets::Daily) => group_by_day(processed, show_priority_group),
+        (GroupingMode::Repository, _) => {
+            group_by_key(processed, show_priority_group, |n| n.repo_full_name.clone())
+        }
+        (GroupingMode::Type, _) => group_by_key(processed, show_priority_group, |n| {
+            n.subject_type.to_string()
+        }),
+    }
+}
+
+fn group_by_time(
+    processed: &[ProcessedNotification],
+    show_priority_group: bool,
 ) -> Vec<NotificationGroup> {
     let now_date = Local::now().date_naive();
+    let yesterday = now_date - chrono::Duration::days(1);
     let one_week_ago = now_date - chrono::Duration::days(7);
 
     // We do a single pass fold here instead of multiple filters so we don't have to
-    // iterate over the list 4 times.
-    let (priority, today, this_week, older) = processed.iter().fold(
-        (Vec::new(), Vec::new(), Vec::new(), Vec::new()),
-        |(mut p, mut t, mut w, mut o), notif| {
+    // iterate over the list 5 times.
+    let (priority, today, yesterday_items, this_week, older) = processed.iter().fold(
+        (Vec::new(), Vec::new(), Vec::new(), Vec::new(), Vec::new()),
+        |(mut p, mut t, mut y, mut w, mut o), notif| {
             if show_priority_group && notif.action == RuleAction::Important {
                 p.push(notif.clone());
             } else {
-                let notif_date = notif
-                    .notification
-                    .updated_at
-                    .with_timezone(&Local)
-                    .date_naive();
+                let notif_date = local_date(notif);
 
                 if notif_date >= now_date {
                     t.push(notif.clone());
+                } else if notif_date >= yesterday {
+                    y.push(notif.clone());
                 } else if notif_date >= one_week_ago {
                     w.push(notif.clone());
                 } else {
                     o.push(notif.clone());
                 }
             }
-            (p, t, w, o)
+            (p, t, y, w, o)
         },
     );
 
-    let mut groups = Vec::with_capacity(4);
+    let mut groups = Vec::with_capacity(5);
 
     if show_priority_group && !priority.is_empty() {
         groups.push(NotificationGroup {
@@ -83,6 +111,13 @@ pub fn group_processed_notifications(
         is_priority: false,
     });
 
+    groups.push(NotificationGroup {
+        title: "Yesterday".to_string(),
+        notifications: yesterday_items,
+        is_expanded: true,
+        is_priority: false,
+    });
+
     groups.push(NotificationGroup {
         title: "This Week".to_string(),
         notifications: this_week,
@@ -100,14 +135,167 @@ pub fn group_processed_notifications(
     groups
 }
 
+/// One group per local day, newest first. Days older than a week start
+/// collapsed, like "Older" does in coarse grouping.
+fn group_by_day(
+    processed: &[ProcessedNotification],
+    show_priority_group: bool,
+) -> Vec<NotificationGroup> {
+    let now_date = Local::now().date_naive();
+    let one_week_ago = now_date - chrono::Duration::days(7);
+
+    let mut priority = Vec::new();
+    let mut days: BTreeMap<NaiveDate, Vec<ProcessedNotification>> = BTreeMap::new();
+    for notif in processed {
+        if show_priority_group && notif.action == RuleAction::Important {
+            priority.push(notif.clone());
+        } else {
+            days.entry(local_date(notif))
+                .or_default()
+                .push(notif.clone());
+        }
+    }
+
+    let mut groups = Vec::with_capacity(days.len() + 1);
+
+    if show_priority_group && !priority.is_empty() {
+        groups.push(NotificationGroup {
+            title: "Important".to_string(),
+            notifications: priority,
+            is_expanded: true,
+            is_priority: true,
+        });
+    }
+
+    groups.extend(
+        days.into_iter()
+            .rev()
+            .map(|(date, notifications)| NotificationGroup {
+                title: day_title(date, now_date),
+                notifications,
+                is_expanded: date >= one_week_ago,
+                is_priority: false,
+            }),
+    );
+
+    groups
+}
+
+fn local_date(notif: &ProcessedNotification) -> NaiveDate {
+    notif
+        .notification
+        .updated_at
+        .with_timezone(&Local)
+        .date_naive()
+}
+
+/// "Today", "Yesterday", "Mon, Jan 5", or with the year once it's not this one.
+fn day_title(date: NaiveDate, today: NaiveDate) -> String {
+    let days_ago = (today - date).num_days();
+    if days_ago <= 0 {
+        "Today".to_string()
+    } else if days_ago == 1 {
+        "Yesterday".to_string()
+    } else if date.year() == today.year() {
+        date.format("%a, %b %-d").to_string()
+    } else {
+        date.format("%a, %b %-d, %Y").to_string()
+    }
+}
+
+/// Buckets by an arbitrary key, largest bucket first (ties broken by name).
+///
+/// The "Important" group still leads when enabled, same as time grouping.
+fn group_by_key(
+    processed: &[ProcessedNotification],
+    show_priority_group: bool,
+    key: impl Fn(&NotificationView) -> String,
+) -> Vec<NotificationGroup> {
+    let mut priority = Vec::new();
+    let mut buckets: HashMap<String, Vec<ProcessedNotification>> = HashMap::new();
+
+    for notif in processed {
+        if show_priority_group && notif.action == RuleAction::Important {
+            priority.push(notif.clone());
+        } else {
+            buckets
+                .entry(key(&notif.notification))
+                .or_default()
+                .push(notif.clone());
+        }
+    }
+
+    let mut keyed: Vec<_> = buckets.into_iter().collect();
+    keyed.sort_by(|a, b| b.1.len().cmp(&a.1.len()).then_with(|| a.0.cmp(&b.0)));
+
+    let mut groups = Vec::with_capacity(keyed.len() + 1);
+
+    if !priority.is_empty() {
+        groups.push(NotificationGroup {
+            title: "Important".to_string(),
+            notifications: priority,
+            is_expanded: true,
+            is_priority: true,
+        });
+    }
+
+    groups.extend(
+        keyed
+            .into_iter()
+            .map(|(title, notifications)| NotificationGroup {
+                title,
+                notifications,
+                is_expanded: true,
+                is_priority: false,
+            }),
+    );
+
+    groups
+}
+
+/// Pull pinned notifications out of their groups into a "Pinned" group on top.
+pub fn float_pinned(groups: &mut Vec<NotificationGroup>, pinned: &HashSet<String>) {
+    if pinned.is_empty() {
+        return;
+    }
+
+    let is_pinned = |p: &ProcessedNotification| {
+        pinned.contains(&p.notification.id) || p.merged.iter().any(|m| pinned.contains(&m.id))
+    };
+    let mut floated = Vec::new();
+    for group in groups.iter_mut() {
+        let (taken, kept) = std::mem::take(&mut group.notifications)
+            .into_iter()
+            .partition(|p| is_pinned(p));
+        group.notifications = kept;
+        floated.extend::<Vec<_>>(taken);
+    }
+    if floated.is_empty() {
+        return;
+    }
+
+    groups.insert(
+        0,
+        NotificationGroup {
+            title: "Pinned".to_string(),
+            notifications: floated,
+            is_expanded: true,
+            is_priority: false,
+        },
+    );
+}
+
 pub fn apply_filters(
     notifications: &[NotificationView],
     filters: &SidebarState,
 ) -> Vec<NotificationView> {
+    let query = filters.search_query.trim().to_lowercase();
+    let now = Utc::now();
     notifications
         .iter()
         .filter(|n| {
             let passes_read = filters.show_all || n.unread;
+            let passes_hide_read = !filters.hide_read || n.unread;
             let passes_type = filters
                 .selected_type
                 .as_ref()
@@ -116,12 +304,65 @@ pub fn apply_filters(
                 .selected_repo
                 .as_ref()
                 .is_none_or(|r| &n.repo_full_name == r);
-            passes_read && passes_type && passes_repo
+            let passes_mentions = !filters.mentions_only
+                || matches!(
+                    n.reason,
+                    NotificationReason::Mention | NotificationReason::TeamMention
+                );
+            let passes_snooze = filters.snoozed.get(&n.id).is_none_or(|wake| *wake <= now);
+            let passes_search = query.is_empty()
+                || n.title.to_lowercase().contains(&query)
+                || n.repo_full_name.to_lowercase().contains(&query);
+            passes_read
+                && passes_hide_read
+                && passes_type
+                && passes_repo
+                && passes_mentions
+                && passes_search
+                && passes_snooze
         })
         .cloned()
         .collect()
 }
 
+/// Fold notifications that share a subject URL into the newest of them.
+///
+/// The survivor keeps its place in the list, carries the others in `merged`,
+/// and is Important if any of them was.
+pub fn compact_by_subject(processed: Vec<ProcessedNotification>) -> Vec<ProcessedNotification> {
+    let mut compacted: Vec<ProcessedNotification> = Vec::with_capacity(processed.len());
+    let mut by_url: HashMap<String, usize> = HashMap::new();
+
+    for item in processed {
+        let Some(url) = item.notification.url.clone() else {
+            compacted.push(item);
+            continue;
+        };
+        let Some(&index) = by_url.get(&url) else {
+            by_url.insert(url, compacted.len());
+            compacted.push(item);
+            continue;
+        };
+
+        let kept = &mut compacted[index];
+        if item.action == RuleAction::Important {
+            kept.action = RuleAction::Important;
+        }
+        let older = if item.notification.updated_at > kept.notification.updated_at {
+            std::mem::replace(&mut kept.notification, item.notification)
+        } else {
+            item.notification
+        };
+        kept.merged.push(older);
+        kept.merged.extend(item.merged);
+    }
+
+    for item in &mut compacted {
+        item.merged.sort_by_key(|n| std::cmp::Reverse(n.updated_at));
+    }
+    compacted
+}
+
 const SUBJECT_TYPE_ORDER: &[SubjectType] = &[
     SubjectType::PullRequest,
     SubjectType::Issue,
@@ -132,9 +373,18 @@ const SUBJECT_TYPE_ORDER: &[SubjectType] = &[
     SubjectType::RepositoryVulnerabilityAlert,
 ];
 
-pub fn count_by_type(notifications: &[NotificationView]) -> Vec<(SubjectType, usize)> {
+/// Unread and total notifications of one subject type.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TypeCount {
+    pub unread: usize,
+    pub total: usize,
+}
+
+pub fn count_by_type(notifications: &[NotificationView]) -> Vec<(SubjectType, TypeCount)> {
     let counts = notifications.iter().fold(HashMap::new(), |mut acc, n| {
-        *acc.entry(n.subject_type).or_insert(0) += 1;
+        let count: &mut TypeCount = acc.entry(n.subject_type).or_default();
+        count.total += 1;
+        count.unread += usize::from(n.unread);
         acc
     });
 
@@ -156,6 +406,79 @@ pub fn count_by_repo(notifications: &[NotificationView]) -> Vec<(String, usize)>
     result
 }
 
+/// How the visible unread set changed across one refresh.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RefreshDelta {
+    /// New or updated unread items, not counting mentions.
+    pub new: usize,
+    pub mentions: usize,
+    /// Unread before, now read or gone.
+    pub read: usize,
+}
+
+impl RefreshDelta {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl std::fmt::Display for RefreshDelta {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut parts = Vec::new();
+        if self.new > 0 {
+            parts.push(format!("+{} new", self.new));
+        }
+        if self.mentions > 0 {
+            let noun = if self.mentions == 1 {
+                "mention"
+            } else {
+                "mentions"
+            };
+            parts.push(format!("+{} {noun}", self.mentions));
+        }
+        if self.read > 0 {
+            parts.push(format!("−{} read", self.read));
+        }
+        f.write_str(&parts.join(", "))
+    }
+}
+
+/// Unread items by id and update time, to diff the next refresh against.
+pub fn unread_snapshot(processed: &[ProcessedNotification]) -> HashMap<String, DateTime<Utc>> {
+    processed
+        .iter()
+        .map(|p| &p.notification)
+        .filter(|n| n.unread)
+        .map(|n| (n.id.clone(), n.updated_at))
+        .collect()
+}
+
+pub fn refresh_delta(
+    before: &HashMap<String, DateTime<Utc>>,
+    after: &[ProcessedNotification],
+) -> RefreshDelta {
+    let mut delta = RefreshDelta::default();
+    let mut still_unread = 0;
+    for n in after.iter().map(|p| &p.notification).filter(|n| n.unread) {
+        match before.get(&n.id) {
+            Some(&at) if at >= n.updated_at => still_unread += 1,
+            seen => {
+                still_unread += usize::from(seen.is_some());
+                if matches!(
+                    n.reason,
+                    NotificationReason::Mention | NotificationReason::TeamMention
+                ) {
+                    delta.mentions += 1;
+                } else {
+                    delta.new += 1;
+                }
+            }
+        }
+    }
+    delta.read = before.len().saturating_sub(still_unread);
+    delta
+}
+
 pub fn api_url_to_web_url(api_url: &str) -> String {
     api_url
         .replace("api.github.com/repos", "github.com")
@@ -3,11 +3,15 @@
 //! This module defines the top-level message enum for the notifications screen.
 //! Screen-level messages are routing wrappers only - actual behavior is handled by features.
 
+use chrono::{DateTime, Local, TimeDelta, Utc};
+
 use crate::github::{GitHubError, NotificationView};
+use crate::ui::features::actors::ActorMessage;
 use crate::ui::features::bulk_actions::BulkActionMessage;
+use crate::ui::features::ci_status::CiStatusMessage;
 use crate::ui::features::notification_details::NotificationDetailsMessage;
 use crate::ui::features::notification_list::NotificationListMessage;
-use crate::ui::features::sidebar::{SidebarAction, SidebarMessage};
+use crate::ui::features::sidebar::{SidebarAction, SidebarMessage, SidebarResize};
 use crate::ui::features::thread_actions::ThreadActionMessage;
 
 /// Top-level message for the notifications screen.
@@ -30,10 +34,18 @@ pub enum NotificationMessage {
     Bulk(BulkActionMessage),
     /// Notification details (selection, details loading).
     Details(NotificationDetailsMessage),
+    /// CI status lookups for CI activity items.
+    CiStatus(CiStatusMessage),
+    /// Actor lookups for the "by @user" line.
+    Actor(ActorMessage),
     /// Sidebar messages (navigation, filters).
     Sidebar(SidebarMessage),
     /// Sidebar actions (results of updates).
     SidebarAction(SidebarAction),
+    /// Dragging the divider next to the sidebar.
+    SidebarResize(SidebarResize),
+    /// Show or hide the sidebar when it's folded away on a narrow window.
+    ToggleSidebar,
 
     // === UI State Messages ===
     /// Filter messages for type/repo selection.
@@ -44,15 +56,147 @@ pub enum NotificationMessage {
     Navigation(NavigationMessage),
     /// Dismiss crash report notice banner.
     DismissCrashNotice,
+    /// Dismiss the one-off notice banner.
+    DismissNotice,
+    /// Hide the token expiry warning for this session.
+    DismissTokenExpiry,
+    /// Hide the "desktop notifications aren't working" banner this session.
+    DismissToastWarning,
     /// Dismiss update available banner for this session.
     DismissUpdateBanner,
     /// Open the GitHub release page for the new version.
     OpenReleasePage,
+    /// Open the download for the new version.
+    DownloadUpdate,
+    /// Ask to install the new version in-app.
+    InstallUpdate,
+    /// Install confirmed; download and verify the installer.
+    ConfirmInstallUpdate,
+    /// Back out of the install confirmation.
+    CancelInstallUpdate,
+    /// Installer download finished (path of the verified file).
+    UpdateDownloaded(Result<std::path::PathBuf, String>),
+    /// Stop showing update notices for the new version.
+    SkipUpdateVersion,
+    /// Dismiss the "What's new" panel and remember this version as seen.
+    DismissWhatsNew,
+    /// Open the release page for the running version.
+    OpenWhatsNewNotes,
+    /// Stop highlighting the priority group header.
+    PriorityFlashEnded,
+    /// Hide the refresh summary next to the sync status.
+    RefreshDeltaEnded,
+    /// End a notification pause early and refresh.
+    ResumeNotifications,
+    /// Open a fixed GitHub page in the browser.
+    OpenUrl(&'static str),
+    /// Pin a notification to the top of the list, or unpin it. Holds its id
+    /// followed by the ids compact mode folded into it.
+    TogglePin(Vec<String>),
+    /// Send a desktop toast about a notification after a while.
+    RemindLater(String, ReminderDelay),
+    CancelReminder(String),
+    /// Hide a notification for a while; it comes back on the first refresh
+    /// after waking.
+    Snooze(String, SnoozeDelay),
+    /// Mark everything the current filters show as done, after confirming.
+    MarkAllAsDone,
+    /// Power mode keyboard triage on the selected notification.
+    SelectionKey(SelectionKey),
+}
+
+/// When a "remind me later" fires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReminderDelay {
+    OneHour,
+    ThreeHours,
+    /// 9:00 tomorrow, local time.
+    Tomorrow,
+}
+
+impl ReminderDelay {
+    pub const ALL: [Self; 3] = [Self::OneHour, Self::ThreeHours, Self::Tomorrow];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::OneHour => "Remind me in 1 hour",
+            Self::ThreeHours => "Remind me in 3 hours",
+            Self::Tomorrow => "Remind me tomorrow",
+        }
+    }
+
+    pub fn due_from(self, now: DateTime<Local>) -> DateTime<Utc> {
+        let due = match self {
+            Self::OneHour => now + TimeDelta::hours(1),
+            Self::ThreeHours => now + TimeDelta::hours(3),
+            Self::Tomorrow => tomorrow_morning(now),
+        };
+        due.with_timezone(&Utc)
+    }
+}
+
+/// How long a snoozed notification stays hidden.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnoozeDelay {
+    OneHour,
+    FourHours,
+    /// 9:00 tomorrow, local time.
+    Tomorrow,
+}
+
+impl SnoozeDelay {
+    pub const ALL: [Self; 3] = [Self::OneHour, Self::FourHours, Self::Tomorrow];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::OneHour => "Snooze for 1 hour",
+            Self::FourHours => "Snooze for 4 hours",
+            Self::Tomorrow => "Snooze until tomorrow",
+        }
+    }
+
+    pub fn wake_from(self, now: DateTime<Local>) -> DateTime<Utc> {
+        let wake = match self {
+            Self::OneHour => now + TimeDelta::hours(1),
+            Self::FourHours => now + TimeDelta::hours(4),
+            Self::Tomorrow => tomorrow_morning(now),
+        };
+        wake.with_timezone(&Utc)
+    }
+}
+
+/// 9:00 local time on the day after `now`.
+fn tomorrow_morning(now: DateTime<Local>) -> DateTime<Local> {
+    (now.date_naive() + TimeDelta::days(1))
+        .and_hms_opt(9, 0, 0)
+        .and_then(|t| t.and_local_timezone(Local).earliest())
+        .unwrap_or(now + TimeDelta::days(1))
+}
+
+/// Keys that act on the selected notification in power mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionKey {
+    Next,
+    Previous,
+    MarkRead,
+    MarkDone,
+    Mute,
+    Open,
+    /// Ctrl+A: bulk-select everything the filters show.
+    SelectAll,
+    /// Escape: clear the bulk selection, or leave bulk mode if it's empty.
+    Cancel,
 }
 
 #[derive(Debug, Clone)]
+#[allow(clippy::enum_variant_names)]
 pub enum FilterMessage {
     ToggleShowAll,
+    ToggleHideRead,
+    ToggleMentionsOnly,
+    ToggleParticipatingOnly,
+    /// The header search box changed; filters locally, no refetch.
+    SearchChanged(String),
 }
 
 #[derive(Debug, Clone)]
@@ -61,5 +205,12 @@ pub enum NavigationMessage {
     OpenSettings,
     OpenRuleEngine,
     SwitchAccount(String),
+    AddAccount,
     TogglePowerMode,
+    /// Switch between the compact mini window and the normal one.
+    ToggleMiniMode,
+    /// The active account's token was rejected; go enter a new one.
+    Reauthenticate,
+    /// Jump straight to the Network Proxy settings tab.
+    OpenProxySettings,
 }
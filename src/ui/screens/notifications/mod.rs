@@ -4,9 +4,10 @@ mod engine;
 pub mod helper;
 pub mod messages;
 pub mod processing;
+mod refresh;
 mod screen;
 
 // Public API exports for external consumers
 #[allow(unused_imports)]
-pub use engine::{DesktopNotificationBatch, NotificationEngine};
+pub use engine::{DesktopNotificationBatch, NotificationEngine, RuleImpact};
 pub use screen::NotificationsScreen;
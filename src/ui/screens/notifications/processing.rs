@@ -3,22 +3,24 @@ use crate::ui::screens::settings::rule_engine::{NotificationRuleSet, RuleAction}
 
 use super::engine::NotificationEngine;
 use super::helper::{
-    NotificationGroup, ProcessedNotification, apply_filters, count_by_repo, count_by_type,
-    group_processed_notifications,
+    HiddenNotification, NotificationGroup, ProcessedNotification, TypeCount, apply_filters,
+    compact_by_subject, count_by_repo, count_by_type, float_pinned, group_processed_notifications,
 };
 use crate::ui::features::sidebar::SidebarState;
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 #[derive(Debug, Clone)]
 pub struct ProcessingState {
     pub all_notifications: Vec<NotificationView>,
     pub filtered_notifications: Vec<NotificationView>,
     pub processed_notifications: Vec<ProcessedNotification>,
+    /// Filtered notifications a rule hid, shown in the "hidden by rules" section.
+    pub hidden_notifications: Vec<HiddenNotification>,
     pub groups: Vec<NotificationGroup>,
     pub rules: NotificationRuleSet,
     pub cross_account_priority: Vec<ProcessedNotification>,
-    pub type_counts: Vec<(SubjectType, usize)>,
+    pub type_counts: Vec<(SubjectType, TypeCount)>,
     pub repo_counts: Vec<(String, usize)>,
 }
 
@@ -28,6 +30,7 @@ impl ProcessingState {
             all_notifications: Vec::new(),
             filtered_notifications: Vec::new(),
             processed_notifications: Vec::new(),
+            hidden_notifications: Vec::new(),
             groups: Vec::new(),
             rules: NotificationRuleSet::load(),
             cross_account_priority: Vec::new(),
@@ -40,13 +43,49 @@ impl ProcessingState {
         self.all_notifications = Vec::new();
         self.filtered_notifications = Vec::new();
         self.processed_notifications = Vec::new();
+        self.hidden_notifications = Vec::new();
         self.groups = Vec::new();
         self.type_counts = Vec::new();
         self.repo_counts = Vec::new();
         self.cross_account_priority = Vec::new();
     }
 
+    /// Keep at most `cap` notifications in memory, preferring pinned and
+    /// priority items and then the most recently updated. The rest stay on GitHub.
+    ///
+    /// Returns the number fetched if anything was dropped.
+    pub fn apply_cap(&mut self, cap: usize, pinned: &HashSet<String>) -> Option<usize> {
+        let total = self.all_notifications.len();
+        if total <= cap {
+            return None;
+        }
+
+        let priority: HashSet<String> = NotificationEngine::new(self.rules.clone())
+            .process_all(&self.all_notifications)
+            .into_iter()
+            .filter(|p| p.is_priority() || pinned.contains(&p.notification.id))
+            .map(|p| p.notification.id)
+            .collect();
+
+        self.all_notifications.sort_by(|a, b| {
+            priority
+                .contains(&b.id)
+                .cmp(&priority.contains(&a.id))
+                .then(b.updated_at.cmp(&a.updated_at))
+        });
+        self.all_notifications.truncate(cap);
+        self.all_notifications
+            .sort_by_key(|n| std::cmp::Reverse(n.updated_at));
+        self.all_notifications.shrink_to_fit();
+
+        Some(total)
+    }
+
     pub fn rebuild_groups(&mut self, filters: &mut SidebarState, current_account: &str) {
+        let unread = self.all_notifications.iter().filter(|n| n.unread).count();
+        crate::local_api::set_unread(unread);
+        crate::tray::sync_unread(unread);
+
         let notifications_for_types: Vec<_> = if let Some(ref repo) = filters.selected_repo {
             self.all_notifications
                 .iter()
@@ -75,7 +114,7 @@ impl ProcessingState {
             let type_valid = self
                 .type_counts
                 .iter()
-                .any(|(t, c)| t == selected_type && *c > 0);
+                .any(|(t, c)| t == selected_type && c.total > 0);
             if !type_valid {
                 filters.selected_type = None;
             }
@@ -122,7 +161,13 @@ impl ProcessingState {
             .collect();
 
         let show_priority_group = !filters.show_all;
-        self.groups = group_processed_notifications(&all_processed, show_priority_group);
+        self.groups = group_processed_notifications(
+            &all_processed,
+            show_priority_group,
+            filters.grouping,
+            filters.time_buckets,
+        );
+        float_pinned(&mut self.groups, &filters.pinned);
 
         for group in &mut self.groups {
             if let Some(&was_expanded) = previous_expansion.get(&group.title) {
@@ -131,10 +176,36 @@ impl ProcessingState {
         }
     }
 
+    /// Ids of the unread notifications of `subject_type` that the current
+    /// filters and rules leave visible, whichever type is selected.
+    pub fn visible_unread_of_type(
+        &self,
+        filters: &SidebarState,
+        subject_type: SubjectType,
+    ) -> Vec<String> {
+        let filters = SidebarState {
+            selected_type: Some(subject_type),
+            ..filters.clone()
+        };
+        let matching = apply_filters(&self.all_notifications, &filters);
+        let (processed, _) =
+            NotificationEngine::new(self.rules.clone()).process_with_hidden(&matching);
+        processed
+            .into_iter()
+            .filter(|p| p.notification.unread)
+            .map(|p| p.notification.id)
+            .collect()
+    }
+
     fn process_notifications(&mut self, filters: &SidebarState) {
         let engine = NotificationEngine::new(self.rules.clone());
         self.filtered_notifications = apply_filters(&self.all_notifications, filters);
-        self.processed_notifications = engine.process_all(&self.filtered_notifications);
+        (self.processed_notifications, self.hidden_notifications) =
+            engine.process_with_hidden(&self.filtered_notifications);
+        if filters.compact_threads {
+            self.processed_notifications =
+                compact_by_subject(std::mem::take(&mut self.processed_notifications));
+        }
     }
 
     fn update_cross_account_priority(&mut self, current_account: &str) {
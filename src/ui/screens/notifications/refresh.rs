@@ -0,0 +1,136 @@
+//! Automatic refresh timing.
+//!
+//! The refresh tick runs at the fixed base interval from the settings, and
+//! pacing decides which ticks actually refresh. iced restarts a
+//! `time::every` whenever its period changes and rebuilds subscriptions
+//! every few hundred milliseconds, so a paced period that drifts with the
+//! clock would never get to fire.
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+
+use crate::github::RateLimit;
+
+/// Ticks can land a little early; don't skip one over that.
+const TICK_SLACK: Duration = Duration::from_secs(1);
+
+/// A successful refresh older than this means background sync has stopped,
+/// however short the refresh interval is.
+const MIN_STALE_AGE: Duration = Duration::from_secs(5 * 60);
+
+#[derive(Debug, Clone, Copy)]
+pub struct RefreshSchedule {
+    /// Gap between refreshes as of the last tick, stretched while the API
+    /// quota is low.
+    interval: Duration,
+    /// When the next automatic refresh is due; `None` until the first tick.
+    next_at: Option<DateTime<Utc>>,
+}
+
+impl RefreshSchedule {
+    pub fn new(base: Duration) -> Self {
+        Self {
+            interval: base,
+            next_at: None,
+        }
+    }
+
+    /// Period for the tick subscription. Always `base`, so the timer isn't
+    /// torn down while the quota changes.
+    pub fn tick_period(&self, base: Duration) -> Duration {
+        base
+    }
+
+    /// How old the last successful refresh can get before sync counts as
+    /// stalled: two missed refreshes at the current pace.
+    pub fn stale_after(&self) -> Duration {
+        (self.interval * 2).max(MIN_STALE_AGE)
+    }
+
+    /// Whether the tick at `now` should refresh. If it should, the refresh
+    /// after it is scheduled one paced interval later.
+    pub fn tick(
+        &mut self,
+        base: Duration,
+        rate_limit: Option<RateLimit>,
+        now: DateTime<Utc>,
+    ) -> bool {
+        self.interval = rate_limit.map_or(base, |limit| limit.paced_interval(base, now));
+        if self.next_at.is_some_and(|at| now < at) {
+            return false;
+        }
+        self.next_at = Some(now + self.interval.saturating_sub(TICK_SLACK));
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BASE: Duration = Duration::from_secs(60);
+
+    fn low_quota(now: DateTime<Utc>) -> RateLimit {
+        // 10 calls left for the next hour: one refresh every 6 minutes
+        RateLimit {
+            remaining: 10,
+            limit: 5000,
+            reset: now + chrono::TimeDelta::hours(1),
+        }
+    }
+
+    fn after(now: DateTime<Utc>, secs: u64) -> DateTime<Utc> {
+        now + Duration::from_secs(secs)
+    }
+
+    #[test]
+    fn test_tick_period_is_stable_while_paced() {
+        let now = Utc::now();
+        let mut schedule = RefreshSchedule::new(BASE);
+        assert!(schedule.tick(BASE, Some(low_quota(now)), now));
+        let first = schedule.tick_period(BASE);
+        schedule.tick(BASE, Some(low_quota(now)), after(now, 60));
+        assert_eq!(schedule.tick_period(BASE), first);
+        assert_eq!(first, BASE);
+    }
+
+    #[test]
+    fn test_tick_refreshes_every_base_interval_with_healthy_quota() {
+        let now = Utc::now();
+        let mut schedule = RefreshSchedule::new(BASE);
+        assert!(schedule.tick(BASE, None, now));
+        assert!(schedule.tick(BASE, None, after(now, 60)));
+        assert!(schedule.tick(BASE, None, after(now, 120)));
+    }
+
+    #[test]
+    fn test_stale_after_follows_a_long_interval() {
+        let ten_minutes = Duration::from_secs(600);
+        assert_eq!(
+            RefreshSchedule::new(ten_minutes).stale_after(),
+            Duration::from_secs(1200)
+        );
+        assert_eq!(RefreshSchedule::new(BASE).stale_after(), MIN_STALE_AGE);
+    }
+
+    #[test]
+    fn test_stale_after_follows_pacing() {
+        let now = Utc::now();
+        let mut schedule = RefreshSchedule::new(BASE);
+        schedule.tick(BASE, Some(low_quota(now)), now);
+        assert_eq!(schedule.stale_after(), Duration::from_secs(720));
+    }
+
+    #[test]
+    fn test_tick_skips_until_the_paced_deadline() {
+        let now = Utc::now();
+        let limit = Some(low_quota(now));
+        let mut schedule = RefreshSchedule::new(BASE);
+        assert!(schedule.tick(BASE, limit, now));
+        for minute in 1..6 {
+            assert!(!schedule.tick(BASE, limit, after(now, minute * 60)));
+        }
+        assert!(schedule.tick(BASE, limit, after(now, 360)));
+    }
+}
@@ -12,29 +12,79 @@
 //! - `handle_refresh_complete()` - refresh result processing
 //!   These are documented as technical debt and should be extracted when the patterns stabilize.
 
+use iced::widget::scrollable::AbsoluteOffset;
 use iced::widget::{Space, button, column, container, row, text};
 use iced::{Element, Fill, Task};
 
+use super::components::header::SyncStatus;
 use super::desktop_notify;
-use super::helper::ProcessedNotification;
-use super::messages::{FilterMessage, NavigationMessage, NotificationMessage};
+use super::helper::{
+    ProcessedNotification, RefreshDelta, api_url_to_web_url, refresh_delta, unread_snapshot,
+};
+use super::messages::{FilterMessage, NavigationMessage, NotificationMessage, SelectionKey};
 use super::processing::ProcessingState;
-use crate::github::{GitHubClient, GitHubError, NotificationView, UserInfo};
-use crate::settings::IconTheme;
-use crate::ui::context::AppContext;
+use super::refresh::RefreshSchedule;
+use crate::activity_log;
+use crate::cache::{DiskCache, SyncMetadata};
+use crate::github::{
+    GitHubClient, GitHubError, NotificationReason, NotificationView, RateLimit, UserInfo,
+};
+use crate::settings::{AppSettings, FetchWindow, IconTheme, Reminder};
+use crate::ui::context::{AccountChoice, AppContext};
 use crate::ui::effects::{AppEffect, NavigateTo, SessionEffect};
-use crate::ui::features::bulk_actions::{BulkActionState, update_bulk_action};
+use crate::ui::features::actors::{
+    ActorState, apply_cached_actors, prefetch_actors, update_actors,
+};
+use crate::ui::features::bulk_actions::{
+    BulkActionMessage, BulkActionState, BulkOp, update_bulk_action,
+};
+use crate::ui::features::ci_status::{
+    CiStatusState, apply_cached_conclusions, prefetch_ci_status, update_ci_status,
+};
 use crate::ui::features::notification_details::{
-    NotificationDetailsState, update_notification_details,
+    NotificationDetailsMessage, NotificationDetailsState, update_notification_details,
+};
+use crate::ui::features::notification_list::{self, ListArgs, ListLayout, NotificationListMessage};
+use crate::ui::features::sidebar::{
+    self, SidebarResize, SidebarState, SidebarViewArgs, view as view_sidebar,
+};
+use crate::ui::features::thread_actions::{
+    ThreadActionMessage, ThreadActionState, update_thread_action,
 };
-use crate::ui::features::notification_list::{self, ListArgs, NotificationListMessage};
-use crate::ui::features::sidebar::{self, SidebarState, SidebarViewArgs, view as view_sidebar};
-use crate::ui::features::thread_actions::{ThreadActionState, update_thread_action};
 use crate::ui::screens::settings::rule_engine::RuleAction;
-use crate::ui::state;
+use crate::ui::{state, theme};
 use crate::{diagnostics, diagnostics::CrashNotice};
 
 use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// How long the priority group header stays highlighted.
+const PRIORITY_FLASH_DURATION: Duration = Duration::from_secs(3);
+
+/// Refreshes in a row whose toasts all failed before we stop counting on
+/// them and fall back to in-app alerts.
+const TOAST_FAILURES_BEFORE_FALLBACK: u32 = 3;
+
+/// How long the "+3 new, −5 read" summary stays up after a refresh.
+const REFRESH_DELTA_DURATION: Duration = Duration::from_secs(6);
+
+/// The "Synced" label turns into a warning after this many failed refreshes
+/// in a row, or once the last success is older than
+/// `RefreshSchedule::stale_after`.
+const STALE_FAILURE_STREAK: u32 = 2;
+
+/// Warn about an expiring token this long before it stops working.
+const TOKEN_EXPIRY_WARNING: chrono::TimeDelta = chrono::TimeDelta::days(7);
+
+/// Progress of an in-app update started from the update banner.
+#[derive(Debug, Clone, Default, PartialEq)]
+enum UpdateInstall {
+    #[default]
+    Idle,
+    Confirming,
+    Downloading,
+    Failed(String),
+}
 
 /// Notifications screen state.
 ///
@@ -48,50 +98,256 @@ pub struct NotificationsScreen {
     pub processing: ProcessingState,
     pub sidebar_state: SidebarState,
     pub is_loading: bool,
-    pub error_message: Option<String>,
+    /// Window came back from tray; data was dropped and is being re-fetched.
+    restoring: bool,
+    pub error: Option<GitHubError>,
     crash_notice: Option<CrashNotice>,
     pub update_info: Option<crate::update_checker::UpdateInfo>,
     update_banner_dismissed: bool,
+    /// One-off message shown above the list until dismissed.
+    notice: Option<String>,
+    /// The sidebar divider is being dragged.
+    sidebar_resizing: bool,
+    /// Sidebar opened from the menu button while the window is too narrow.
+    sidebar_revealed: bool,
+    self_update: bool,
+    update_install: UpdateInstall,
+    /// Notes for a freshly installed version, shown until dismissed.
+    pub whats_new: Option<crate::update_checker::ReleaseNotes>,
+    /// Priority header is highlighted until this instant.
+    priority_flash_until: Option<Instant>,
+    /// What the last refresh changed, shown until the instant passes.
+    refresh_delta: Option<(RefreshDelta, Instant)>,
+    /// Refreshes in a row where no desktop notification could be shown.
+    toast_failures: u32,
+    toast_warning_dismissed: bool,
+    priority_sound: bool,
+    muted_desktop_reasons: Vec<NotificationReason>,
+    toast_limits: desktop_notify::ToastLimits,
+    max_notifications: usize,
+    fetch_window: FetchWindow,
+    /// Look up who triggered each item (costs extra API calls).
+    show_actors: bool,
+    /// Fetched count when the last refresh was cut down to `max_notifications`.
+    capped_from: Option<usize>,
 
     // === Feature States ===
     pub thread_actions: ThreadActionState,
     pub bulk_actions: BulkActionState,
     pub notification_details: NotificationDetailsState,
+    pub ci_status: CiStatusState,
+    pub actors: ActorState,
 
     // === Internal State ===
     seen_notification_timestamps: HashMap<String, chrono::DateTime<chrono::Utc>>,
+    /// When the user last had the window in front of them.
+    last_viewed_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Items updated after this get a "new" separator in the list.
+    new_since: Option<chrono::DateTime<chrono::Utc>>,
+    /// Time of the last successful refresh.
+    last_synced_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// GitHub API quota as of the last refresh.
+    rate_limit: Option<RateLimit>,
+    /// Which refresh ticks actually refresh.
+    refresh_schedule: RefreshSchedule,
+    /// Consecutive failed refreshes since the last success.
+    failed_refreshes: u32,
+    /// GitHub rejected this account's token; polling is paused.
+    token_invalid: bool,
+    /// Row picked with j/k in the standard layout. Power mode uses the
+    /// details panel's selection instead.
+    focused_id: Option<String>,
+    /// When this account's token expires, if GitHub told us.
+    token_expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    token_expiry_dismissed: bool,
 
     pub(crate) list_state: notification_list::NotificationListState,
 }
 
 impl NotificationsScreen {
-    pub fn new(client: GitHubClient, user: UserInfo) -> (Self, Task<NotificationMessage>) {
-        let screen = Self {
+    pub fn new(
+        client: GitHubClient,
+        user: UserInfo,
+        settings: &AppSettings,
+    ) -> (Self, Task<NotificationMessage>) {
+        let pinned = settings.pinned(&user.login).iter().cloned().collect();
+        let reminded = settings
+            .reminders(&user.login)
+            .iter()
+            .map(|r| r.notification_id.clone())
+            .collect();
+        let token_expires_at = user
+            .token_expires_at
+            .or_else(|| settings.token_expiry(&user.login));
+        let token_expiry_dismissed =
+            token_expires_at.is_some_and(|at| settings.token_expiry_dismissed(&user.login, at));
+        let mut screen = Self {
             client,
             user,
             processing: ProcessingState::new(),
-            sidebar_state: SidebarState::default(),
+            sidebar_state: SidebarState {
+                hide_read: settings.hide_read,
+                participating_only: settings.participating_only,
+                grouping: settings.grouping_mode,
+                time_buckets: settings.time_buckets,
+                compact_threads: settings.compact_threads,
+                pinned,
+                reminded,
+                ..SidebarState::default()
+            },
             is_loading: true,
-            error_message: None,
-            thread_actions: ThreadActionState::new(),
-            bulk_actions: BulkActionState::new(),
+            restoring: false,
+            error: None,
+            thread_actions: ThreadActionState {
+                on_open: settings.open_action,
+                confirm_mark_all: settings.confirm_mark_all_read,
+                ..ThreadActionState::new()
+            },
+            bulk_actions: BulkActionState {
+                open_marks_read: settings.bulk_open_marks_read,
+                ..BulkActionState::new()
+            },
             notification_details: NotificationDetailsState::new(),
+            ci_status: CiStatusState::new(),
+            actors: ActorState::new(),
             seen_notification_timestamps: HashMap::new(),
+            last_viewed_at: None,
+            new_since: None,
+            last_synced_at: None,
+            rate_limit: None,
+            refresh_schedule: RefreshSchedule::new(settings.refresh_interval()),
+            failed_refreshes: 0,
+            token_invalid: false,
+            focused_id: None,
+            token_expires_at,
+            token_expiry_dismissed,
             list_state: notification_list::NotificationListState::new(),
             crash_notice: diagnostics::load_crash_notice(),
             update_info: None,
             update_banner_dismissed: false,
+            notice: None,
+            sidebar_resizing: false,
+            sidebar_revealed: false,
+            self_update: settings.self_update && settings.check_for_updates,
+            update_install: UpdateInstall::Idle,
+            whats_new: None,
+            priority_flash_until: None,
+            refresh_delta: None,
+            toast_failures: 0,
+            toast_warning_dismissed: false,
+            priority_sound: settings.priority_sound,
+            muted_desktop_reasons: settings.muted_desktop_reasons.clone(),
+            toast_limits: desktop_notify::ToastLimits::from_settings(settings),
+            max_notifications: settings.max_notifications,
+            fetch_window: settings.fetch_window,
+            show_actors: settings.show_actors,
+            capped_from: None,
         };
+        screen.load_cached();
         let task = screen.fetch_notifications();
         (screen, task)
     }
 
+    /// Render the last session's list right away; the network refresh that
+    /// follows replaces it.
+    fn load_cached(&mut self) {
+        let cache = match DiskCache::open() {
+            Ok(cache) => cache,
+            Err(e) => {
+                tracing::debug!(error = %e, "Disk cache unavailable");
+                return;
+            }
+        };
+        let account = &self.user.login;
+
+        if let Ok(Some(meta)) = cache.load_sync_metadata(account) {
+            self.last_synced_at = Some(meta.last_sync);
+        }
+
+        // Without this, every unread item would alert again after a restart
+        match cache.load_seen(account) {
+            Ok(seen) => self.seen_notification_timestamps = seen,
+            Err(e) => tracing::warn!(error = %e, "Failed to read seen notifications"),
+        }
+
+        match cache.load_snoozed(account) {
+            Ok(mut snoozed) => {
+                let now = chrono::Utc::now();
+                snoozed.retain(|id, wake| {
+                    let asleep = *wake > now;
+                    if !asleep {
+                        let _ = cache.remove_snooze(account, id);
+                    }
+                    asleep
+                });
+                self.sidebar_state.snoozed = snoozed;
+            }
+            Err(e) => tracing::warn!(error = %e, "Failed to read snoozed notifications"),
+        }
+
+        match cache.load_notifications(
+            account,
+            self.sidebar_state.show_all,
+            self.sidebar_state.participating_only,
+        ) {
+            Ok(Some(mut cached)) => {
+                tracing::debug!(count = cached.len(), "Loaded cached notifications");
+                if !self.show_actors {
+                    cached.iter_mut().for_each(|n| n.actor = None);
+                }
+                self.processing.all_notifications = cached;
+                self.processing
+                    .rebuild_groups(&mut self.sidebar_state, &self.user.login);
+            }
+            Ok(None) => {}
+            Err(e) => tracing::warn!(error = %e, "Failed to read cached notifications"),
+        }
+    }
+
+    /// Write the current list to the disk cache off the UI thread.
+    fn save_to_cache(&self) -> Task<NotificationMessage> {
+        let account = self.user.login.clone();
+        let show_all = self.sidebar_state.show_all;
+        let participating = self.sidebar_state.participating_only;
+        let notifications = self.processing.all_notifications.clone();
+
+        Task::future(async move {
+            let result = tokio::task::spawn_blocking(move || {
+                let cache = DiskCache::open()?;
+                cache.save_notifications(&account, show_all, participating, &notifications)?;
+                cache.save_seen(
+                    &account,
+                    notifications.iter().map(|n| (n.id.as_str(), n.updated_at)),
+                )?;
+                cache.save_sync_metadata(
+                    &account,
+                    &SyncMetadata {
+                        last_sync: chrono::Utc::now(),
+                        etag: None,
+                        notification_count: notifications.len(),
+                    },
+                )
+            })
+            .await;
+            if let Ok(Err(e)) = result {
+                tracing::warn!(error = %e, "Failed to cache notifications");
+            }
+        })
+        .discard()
+    }
+
     fn fetch_notifications(&self) -> Task<NotificationMessage> {
         let client = self.client.clone();
         let show_all = self.sidebar_state.show_all;
+        let participating = self.sidebar_state.participating_only;
+        let since = self.fetch_window.since();
         let account = self.user.login.clone();
         Task::perform(
-            async move { client.get_notification_views(show_all, &account).await },
+            async move {
+                client
+                    .get_notification_views(show_all, participating, since, &account)
+                    .await
+            },
             NotificationMessage::RefreshComplete,
         )
     }
@@ -104,8 +360,7 @@ impl NotificationsScreen {
 
     pub fn enter_low_memory_mode(&mut self) {
         self.processing.enter_low_memory_mode();
-        self.error_message = None;
-        self.error_message = None;
+        self.error = None;
         self.list_state.reset();
 
         if self.seen_notification_timestamps.len() > 500 {
@@ -113,6 +368,103 @@ impl NotificationsScreen {
         }
     }
 
+    /// Window is being restored from tray. Show the syncing state until the
+    /// refresh lands instead of flashing an empty list.
+    pub fn begin_restore(&mut self) {
+        self.is_loading = true;
+        self.restoring = true;
+    }
+
+    /// Window gained focus: whatever changed since the user last looked is new.
+    pub fn mark_viewed(&mut self) {
+        self.new_since = self.last_viewed_at;
+        self.last_viewed_at = Some(chrono::Utc::now());
+    }
+
+    /// Window lost focus: the user has seen everything up to now.
+    pub fn mark_left(&mut self) {
+        self.last_viewed_at = Some(chrono::Utc::now());
+    }
+
+    /// Whether GitHub rejected the token on the last refresh.
+    pub fn is_token_invalid(&self) -> bool {
+        self.token_invalid
+    }
+
+    /// Refresh state for the header and the power mode top bar.
+    pub fn sync_status(&self) -> SyncStatus {
+        SyncStatus {
+            is_loading: self.is_loading,
+            last_synced_at: self.last_synced_at,
+            is_stale: self.is_sync_stale(),
+            paused_until: state::paused_until(),
+            delta: self.refresh_delta.map(|(delta, _)| delta),
+            rate_limit: self.rate_limit,
+        }
+    }
+
+    /// Period of the refresh tick subscription.
+    pub fn refresh_tick_period(&self, base: Duration) -> Duration {
+        self.refresh_schedule.tick_period(base)
+    }
+
+    /// Whether this refresh tick should refresh, pacing refreshes out while
+    /// the API quota is running low.
+    pub fn refresh_due(&mut self, base: Duration) -> bool {
+        self.refresh_schedule
+            .tick(base, self.rate_limit, chrono::Utc::now())
+    }
+
+    fn record_toast_outcome(&mut self, outcome: desktop_notify::ToastOutcome) {
+        match outcome {
+            desktop_notify::ToastOutcome::Idle => {}
+            desktop_notify::ToastOutcome::Delivered => self.toast_failures = 0,
+            desktop_notify::ToastOutcome::Failed(e) => {
+                diagnostics::record_error("DesktopNotification", &e);
+                self.toast_failures = self.toast_failures.saturating_add(1);
+                if self.toast_failures == TOAST_FAILURES_BEFORE_FALLBACK {
+                    tracing::warn!(
+                        error = %e,
+                        "Desktop notifications keep failing, falling back to in-app alerts"
+                    );
+                }
+            }
+        }
+    }
+
+    /// Clear the reminder marks for reminders that just fired and note how
+    /// their toasts went.
+    pub fn reminders_fired(
+        &mut self,
+        due: &[(String, Reminder)],
+        outcome: desktop_notify::ToastOutcome,
+    ) {
+        for (account, reminder) in due {
+            if *account == self.user.login {
+                self.sidebar_state
+                    .reminded
+                    .remove(&reminder.notification_id);
+            }
+        }
+        self.record_toast_outcome(outcome);
+    }
+
+    /// Desktop notifications have failed often enough that they're probably
+    /// not set up on this system.
+    fn toasts_unavailable(&self) -> bool {
+        self.toast_failures >= TOAST_FAILURES_BEFORE_FALLBACK
+    }
+
+    /// Whether background refresh looks like it has quietly stopped working.
+    fn is_sync_stale(&self) -> bool {
+        self.failed_refreshes >= STALE_FAILURE_STREAK
+            || self.last_synced_at.is_some_and(|at| {
+                (chrono::Utc::now() - at)
+                    .to_std()
+                    .is_ok_and(|age| age > self.refresh_schedule.stale_after())
+            })
+    }
+
     pub fn get_cross_account_priority(&self) -> Vec<ProcessedNotification> {
         self.processing.cross_account_priority.clone()
     }
@@ -128,9 +480,11 @@ impl NotificationsScreen {
     pub fn update(&mut self, message: NotificationMessage) -> Task<NotificationMessage> {
         match message {
             // Lifecycle
+            // Retrying before Retry-After is up only extends the throttle
+            NotificationMessage::Refresh if self.client.throttled_for().is_some() => Task::none(),
             NotificationMessage::Refresh => {
                 self.is_loading = true;
-                self.error_message = None;
+                self.error = None;
                 tracing::debug!(
                     show_all = self.sidebar_state.show_all,
                     "Refreshing notifications"
@@ -141,6 +495,29 @@ impl NotificationsScreen {
 
             // Feature routing
             NotificationMessage::Thread(msg) => {
+                let logged: Option<(activity_log::Action, &[String])> = match &msg {
+                    ThreadActionMessage::MarkAsReadComplete(id, Ok(())) => {
+                        Some((activity_log::Action::MarkRead, std::slice::from_ref(id)))
+                    }
+                    ThreadActionMessage::MarkAsDoneComplete(id, Ok(())) => {
+                        Some((activity_log::Action::MarkDone, std::slice::from_ref(id)))
+                    }
+                    ThreadActionMessage::MuteComplete(id, Ok(())) => {
+                        Some((activity_log::Action::Mute, std::slice::from_ref(id)))
+                    }
+                    ThreadActionMessage::MarkAllAsReadComplete(ids, Ok(()))
+                    | ThreadActionMessage::MarkRepoAsReadComplete(_, ids, Ok(())) => {
+                        Some((activity_log::Action::MarkRead, ids))
+                    }
+                    _ => None,
+                };
+                for (action, id) in logged
+                    .into_iter()
+                    .flat_map(|(action, ids)| ids.iter().map(move |id| (action, id)))
+                {
+                    activity_log::record(action, &self.user.login, id);
+                }
+
                 let result = update_thread_action(
                     &mut self.thread_actions,
                     msg,
@@ -159,6 +536,15 @@ impl NotificationsScreen {
             }
 
             NotificationMessage::Bulk(msg) => {
+                if let BulkActionMessage::Complete(outcome) = &msg {
+                    let action = match outcome.op {
+                        BulkOp::MarkRead => activity_log::Action::MarkRead,
+                        BulkOp::MarkDone => activity_log::Action::MarkDone,
+                    };
+                    for id in &outcome.succeeded {
+                        activity_log::record(action, &self.user.login, id);
+                    }
+                }
                 let result = update_bulk_action(
                     &mut self.bulk_actions,
                     msg,
@@ -169,6 +555,13 @@ impl NotificationsScreen {
                     self.processing
                         .rebuild_groups(&mut self.sidebar_state, &self.user.login);
                 }
+                if result.needs_refresh {
+                    self.is_loading = true;
+                    return Task::batch([
+                        self.fetch_notifications(),
+                        result.task.map(NotificationMessage::Bulk),
+                    ]);
+                }
                 result.task.map(NotificationMessage::Bulk)
             }
 
@@ -182,17 +575,57 @@ impl NotificationsScreen {
                 task.map(NotificationMessage::Details)
             }
 
+            NotificationMessage::CiStatus(msg) => {
+                let needs_rebuild = update_ci_status(
+                    &mut self.ci_status,
+                    msg,
+                    &mut self.processing.all_notifications,
+                );
+                if needs_rebuild {
+                    self.processing
+                        .rebuild_groups(&mut self.sidebar_state, &self.user.login);
+                }
+                Task::none()
+            }
+
+            NotificationMessage::Actor(msg) => {
+                let needs_rebuild = update_actors(
+                    &mut self.actors,
+                    msg,
+                    &mut self.processing.all_notifications,
+                );
+                if needs_rebuild {
+                    self.processing
+                        .rebuild_groups(&mut self.sidebar_state, &self.user.login);
+                }
+                Task::none()
+            }
+
             // UI state
             NotificationMessage::Filter(msg) => self.update_filter(msg),
             NotificationMessage::List(msg) => self.update_view(msg),
             NotificationMessage::Sidebar(msg) => self.update_sidebar(msg),
             NotificationMessage::SidebarAction(action) => self.handle_sidebar_action(action),
+            // Persisted, so handled in `update_with_effect`.
+            NotificationMessage::SidebarResize(_) => Task::none(),
+            NotificationMessage::ToggleSidebar => {
+                self.sidebar_revealed = !self.sidebar_revealed;
+                Task::none()
+            }
             NotificationMessage::Navigation(_msg) => Task::none(),
             NotificationMessage::DismissCrashNotice => {
                 diagnostics::clear_crash_notice();
                 self.crash_notice = None;
                 Task::none()
             }
+            NotificationMessage::DismissNotice => {
+                self.notice = None;
+                Task::none()
+            }
+            NotificationMessage::DismissToastWarning => {
+                self.toast_warning_dismissed = true;
+                Task::none()
+            }
             NotificationMessage::DismissUpdateBanner => {
                 self.update_banner_dismissed = true;
                 Task::none()
@@ -203,6 +636,259 @@ impl NotificationsScreen {
                 }
                 Task::none()
             }
+            // Persisted in update_with_effect; this only hides the panel
+            NotificationMessage::DismissWhatsNew => {
+                self.whats_new = None;
+                Task::none()
+            }
+            NotificationMessage::DownloadUpdate => {
+                if let Some(ref info) = self.update_info {
+                    let url = info.download_url.as_deref().unwrap_or(&info.release_url);
+                    let _ = open::that(url);
+                }
+                Task::none()
+            }
+            NotificationMessage::Snooze(id, delay) => {
+                let wake = delay.wake_from(chrono::Local::now());
+                if let Err(e) = DiskCache::open()
+                    .and_then(|cache| cache.save_snooze(&self.user.login, &id, wake))
+                {
+                    tracing::warn!(error = %e, "Failed to save snooze");
+                }
+                tracing::info!(%wake, "Notification snoozed");
+                self.sidebar_state.snoozed.insert(id, wake);
+                self.processing
+                    .rebuild_groups(&mut self.sidebar_state, &self.user.login);
+                Task::none()
+            }
+            NotificationMessage::InstallUpdate => {
+                self.update_install = UpdateInstall::Confirming;
+                Task::none()
+            }
+            NotificationMessage::CancelInstallUpdate => {
+                self.update_install = UpdateInstall::Idle;
+                Task::none()
+            }
+            // Need the settings; handled in update_with_effect
+            NotificationMessage::ConfirmInstallUpdate
+            | NotificationMessage::DismissTokenExpiry
+            | NotificationMessage::TogglePin(_)
+            | NotificationMessage::RemindLater(..)
+            | NotificationMessage::CancelReminder(_) => Task::none(),
+            NotificationMessage::UpdateDownloaded(result) => {
+                let launched = result.and_then(|path| crate::self_update::launch_installer(&path));
+                match launched {
+                    Ok(()) => {
+                        tracing::info!("Installer started, exiting");
+                        crate::hotkey::unregister();
+                        iced::exit()
+                    }
+                    Err(e) => {
+                        tracing::warn!(error = %e, "Self-update failed");
+                        self.update_install = UpdateInstall::Failed(e);
+                        Task::none()
+                    }
+                }
+            }
+            // Persisted in update_with_effect; this only hides the banner
+            NotificationMessage::SkipUpdateVersion => {
+                self.update_info = None;
+                Task::none()
+            }
+            NotificationMessage::OpenWhatsNewNotes => {
+                if let Some(ref notes) = self.whats_new {
+                    let _ = open::that(&notes.release_url);
+                }
+                Task::none()
+            }
+            NotificationMessage::PriorityFlashEnded => {
+                // A newer flash may have extended the deadline
+                if self
+                    .priority_flash_until
+                    .is_some_and(|until| Instant::now() >= until)
+                {
+                    self.priority_flash_until = None;
+                }
+                Task::none()
+            }
+            NotificationMessage::RefreshDeltaEnded => {
+                if self
+                    .refresh_delta
+                    .is_some_and(|(_, until)| Instant::now() >= until)
+                {
+                    self.refresh_delta = None;
+                }
+                Task::none()
+            }
+            NotificationMessage::OpenUrl(url) => {
+                if let Err(e) = crate::browser::open(url) {
+                    tracing::warn!(error = %e, url, "Failed to open link");
+                }
+                Task::none()
+            }
+            NotificationMessage::MarkAllAsDone => {
+                // Only what the filters show; rows folded by compact mode count
+                let ids = self
+                    .processing
+                    .processed_notifications
+                    .iter()
+                    .flat_map(|p| std::iter::once(&p.notification).chain(&p.merged))
+                    .map(|n| n.id.clone())
+                    .collect();
+                self.update(NotificationMessage::Bulk(BulkActionMessage::MarkAllAsDone(
+                    ids,
+                )))
+            }
+
+            NotificationMessage::ResumeNotifications => {
+                state::resume();
+                self.update(NotificationMessage::Refresh)
+            }
+            // Depends on the layout; handled in update_with_effect
+            NotificationMessage::SelectionKey(_) => Task::none(),
+        }
+    }
+
+    /// Keyboard triage: act on the selected item or move the selection. In
+    /// power mode the selection is the details panel's, so moving it fetches
+    /// details; the standard layout only highlights the row.
+    fn handle_selection_key(
+        &mut self,
+        key: SelectionKey,
+        power_mode: bool,
+    ) -> Task<NotificationMessage> {
+        let selected = if power_mode {
+            self.notification_details.selected_id.clone()
+        } else {
+            self.focused_id.clone()
+        };
+        match key {
+            SelectionKey::Next | SelectionKey::Previous => {
+                match self.adjacent_notification(selected.as_deref(), key == SelectionKey::Next) {
+                    Some(id) => self.select_notification(id, power_mode),
+                    None => Task::none(),
+                }
+            }
+            SelectionKey::Open => match selected {
+                Some(id) => self.update(NotificationMessage::Thread(ThreadActionMessage::Open(id))),
+                None => Task::none(),
+            },
+            SelectionKey::MarkRead => match selected {
+                Some(id) => self.update(NotificationMessage::Thread(
+                    ThreadActionMessage::MarkAsRead(id),
+                )),
+                None => Task::none(),
+            },
+            SelectionKey::MarkDone | SelectionKey::Mute => {
+                let Some(id) = selected else {
+                    return Task::none();
+                };
+                // Move on before the item drops out of the list
+                let next = self
+                    .adjacent_notification(Some(&id), true)
+                    .or_else(|| self.adjacent_notification(Some(&id), false));
+                let action = if key == SelectionKey::MarkDone {
+                    ThreadActionMessage::MarkAsDone(id)
+                } else {
+                    ThreadActionMessage::Mute(id)
+                };
+                let task = self.update(NotificationMessage::Thread(action));
+                match next {
+                    Some(next) => Task::batch([task, self.select_notification(next, power_mode)]),
+                    None => task,
+                }
+            }
+            // Bulk selection only exists in power mode
+            SelectionKey::SelectAll if !power_mode => Task::none(),
+            SelectionKey::Cancel if !power_mode => {
+                self.focused_id = None;
+                Task::none()
+            }
+            SelectionKey::SelectAll => {
+                if !self.bulk_actions.bulk_mode {
+                    self.bulk_actions.bulk_mode = true;
+                    self.bulk_actions.status = None;
+                }
+                let ids = self
+                    .processing
+                    .filtered_notifications
+                    .iter()
+                    .map(|n| n.id.clone())
+                    .collect();
+                self.update(NotificationMessage::Bulk(BulkActionMessage::SelectAll(ids)))
+            }
+            SelectionKey::Cancel if !self.bulk_actions.bulk_mode => Task::none(),
+            SelectionKey::Cancel if self.bulk_actions.confirm_open => self.update(
+                NotificationMessage::Bulk(BulkActionMessage::CancelOpenSelected),
+            ),
+            SelectionKey::Cancel => {
+                let msg = if self.bulk_actions.selection_count() > 0 {
+                    BulkActionMessage::Clear
+                } else {
+                    BulkActionMessage::ToggleMode
+                };
+                self.update(NotificationMessage::Bulk(msg))
+            }
+        }
+    }
+
+    /// The notification after (or before) `current` in list order. With
+    /// nothing selected this is the first one.
+    fn adjacent_notification(&self, current: Option<&str>, forward: bool) -> Option<String> {
+        let ids: Vec<&str> = self
+            .processing
+            .groups
+            .iter()
+            .flat_map(|g| &g.notifications)
+            .map(|p| p.notification.id.as_str())
+            .collect();
+
+        let target = match current.and_then(|id| ids.iter().position(|i| *i == id)) {
+            None => 0,
+            Some(i) if forward => i + 1,
+            Some(i) => i.checked_sub(1)?,
+        };
+        ids.get(target).map(|id| id.to_string())
+    }
+
+    /// Move the keyboard selection to a notification, expanding its group
+    /// and scrolling so it stays visible. Power mode also loads its details.
+    fn select_notification(&mut self, id: String, power_mode: bool) -> Task<NotificationMessage> {
+        if let Some(group) = self
+            .processing
+            .groups
+            .iter_mut()
+            .find(|g| g.notifications.iter().any(|p| p.notification.id == id))
+        {
+            group.is_expanded = true;
+        }
+
+        let layout = if power_mode {
+            ListLayout::Power
+        } else {
+            ListLayout::Standard
+        };
+        let scroll =
+            notification_list::item_bounds(&self.processing.groups, &id, layout, self.new_since)
+                .and_then(|bounds| self.list_state.reveal(bounds))
+                .map_or_else(Task::none, |y| {
+                    iced::widget::operation::scroll_to(
+                        notification_list::SCROLL_ID,
+                        AbsoluteOffset {
+                            x: None,
+                            y: Some(y),
+                        },
+                    )
+                });
+
+        if power_mode {
+            let details = self.update(NotificationMessage::Details(
+                NotificationDetailsMessage::Select(id),
+            ));
+            Task::batch([details, scroll])
+        } else {
+            self.focused_id = Some(id);
+            scroll
         }
     }
 
@@ -218,9 +904,23 @@ impl NotificationsScreen {
                     .rebuild_groups(&mut self.sidebar_state, &self.user.login);
                 Task::none()
             }
+            SidebarAction::MarkRepoRead(repo) => Task::done(NotificationMessage::Thread(
+                ThreadActionMessage::MarkRepoAsRead(repo),
+            )),
+            SidebarAction::MarkTypeRead(t) => {
+                let ids = self
+                    .processing
+                    .visible_unread_of_type(&self.sidebar_state, t);
+                Task::done(NotificationMessage::Bulk(
+                    BulkActionMessage::MarkTypeAsRead(ids),
+                ))
+            }
             SidebarAction::SwitchAccount(u) => Task::done(NotificationMessage::Navigation(
                 NavigationMessage::SwitchAccount(u),
             )),
+            SidebarAction::AddAccount => Task::done(NotificationMessage::Navigation(
+                NavigationMessage::AddAccount,
+            )),
             SidebarAction::OpenSettings => Task::done(NotificationMessage::Navigation(
                 NavigationMessage::OpenSettings,
             )),
@@ -245,6 +945,9 @@ impl NotificationsScreen {
                 NavigationMessage::OpenSettings => {
                     (Task::none(), AppEffect::Navigate(NavigateTo::Settings))
                 }
+                NavigationMessage::OpenProxySettings => {
+                    (Task::none(), AppEffect::Navigate(NavigateTo::ProxySettings))
+                }
                 NavigationMessage::OpenRuleEngine => (
                     Task::none(),
                     AppEffect::Navigate(NavigateTo::RuleEngine {
@@ -255,8 +958,15 @@ impl NotificationsScreen {
                     Task::none(),
                     AppEffect::Session(SessionEffect::SwitchAccount(username)),
                 ),
+                NavigationMessage::AddAccount => {
+                    (Task::none(), AppEffect::Navigate(NavigateTo::AddAccount))
+                }
+                NavigationMessage::Reauthenticate => (
+                    Task::none(),
+                    AppEffect::Navigate(NavigateTo::Reauthenticate(self.user.login.clone())),
+                ),
                 NavigationMessage::TogglePowerMode => {
-                    ctx.settings.power_mode = !ctx.settings.power_mode;
+                    ctx.settings.set_power_mode(!ctx.settings.power_mode);
                     ctx.settings.save_silent();
                     self.collapse_all_groups();
 
@@ -267,8 +977,148 @@ impl NotificationsScreen {
                     };
                     (task, AppEffect::None)
                 }
+                NavigationMessage::ToggleMiniMode => {
+                    ctx.settings.mini_mode = !ctx.settings.mini_mode;
+                    ctx.settings.save_silent();
+                    tracing::info!(enabled = ctx.settings.mini_mode, "Mini mode toggled");
+                    (state::apply_window_mode(&ctx.settings), AppEffect::None)
+                }
             },
 
+            NotificationMessage::ConfirmInstallUpdate => {
+                let Some(info) = self.update_info.clone() else {
+                    return (Task::none(), AppEffect::None);
+                };
+                self.update_install = UpdateInstall::Downloading;
+                let task = Task::perform(
+                    crate::self_update::download(info, ctx.settings.proxy.clone()),
+                    NotificationMessage::UpdateDownloaded,
+                );
+                (task, AppEffect::None)
+            }
+
+            NotificationMessage::SkipUpdateVersion => {
+                if let Some(info) = self.update_info.take() {
+                    ctx.settings.skipped_update_version = Some(info.latest);
+                    ctx.settings.save_silent();
+                }
+                (Task::none(), AppEffect::None)
+            }
+
+            NotificationMessage::DismissWhatsNew => {
+                if let Some(notes) = self.whats_new.take() {
+                    ctx.settings.last_seen_version = Some(notes.version);
+                    ctx.settings.save_silent();
+                }
+                (Task::none(), AppEffect::None)
+            }
+
+            NotificationMessage::Filter(FilterMessage::ToggleHideRead) => {
+                self.sidebar_state.hide_read = !self.sidebar_state.hide_read;
+                ctx.settings.hide_read = self.sidebar_state.hide_read;
+                ctx.settings.save_silent();
+                self.list_state.reset();
+                self.processing
+                    .rebuild_groups(&mut self.sidebar_state, &self.user.login);
+                (Task::none(), AppEffect::None)
+            }
+
+            NotificationMessage::Filter(FilterMessage::ToggleParticipatingOnly) => {
+                self.sidebar_state.participating_only = !self.sidebar_state.participating_only;
+                ctx.settings.participating_only = self.sidebar_state.participating_only;
+                ctx.settings.save_silent();
+                self.list_state.reset();
+                self.is_loading = true;
+                (self.fetch_notifications(), AppEffect::None)
+            }
+
+            NotificationMessage::DismissTokenExpiry => {
+                self.token_expiry_dismissed = true;
+                ctx.settings
+                    .dismiss_token_expiry(&self.user.login, self.token_expires_at);
+                ctx.settings.save_silent();
+                (Task::none(), AppEffect::None)
+            }
+
+            NotificationMessage::TogglePin(ids) => {
+                ctx.settings.toggle_pinned(&self.user.login, &ids);
+                self.sidebar_state.pinned = ctx
+                    .settings
+                    .pinned(&self.user.login)
+                    .iter()
+                    .cloned()
+                    .collect();
+                ctx.settings.save_silent();
+                self.processing
+                    .rebuild_groups(&mut self.sidebar_state, &self.user.login);
+                (Task::none(), AppEffect::None)
+            }
+
+            NotificationMessage::RemindLater(id, delay) => {
+                let Some(notif) = self
+                    .processing
+                    .all_notifications
+                    .iter()
+                    .find(|n| n.id == id)
+                else {
+                    return (Task::none(), AppEffect::None);
+                };
+                let reminder = Reminder {
+                    notification_id: id.clone(),
+                    title: notif.title.clone(),
+                    repo_full_name: notif.repo_full_name.clone(),
+                    url: notif.url.as_deref().map(api_url_to_web_url),
+                    due: delay.due_from(chrono::Local::now()),
+                };
+                tracing::info!(due = %reminder.due, "Reminder set");
+                ctx.settings.set_reminder(&self.user.login, reminder);
+                ctx.settings.save_silent();
+                self.sidebar_state.reminded.insert(id);
+                (Task::none(), AppEffect::None)
+            }
+
+            NotificationMessage::CancelReminder(id) => {
+                ctx.settings.cancel_reminder(&self.user.login, &id);
+                ctx.settings.save_silent();
+                self.sidebar_state.reminded.remove(&id);
+                tracing::info!("Reminder cancelled");
+                (Task::none(), AppEffect::None)
+            }
+
+            NotificationMessage::SidebarResize(step) => {
+                match step {
+                    SidebarResize::Start => self.sidebar_resizing = true,
+                    SidebarResize::Drag(x) if self.sidebar_resizing => {
+                        ctx.settings.sidebar_width = sidebar::resize::clamp_width(x);
+                    }
+                    SidebarResize::Drag(_) => {}
+                    SidebarResize::End if self.sidebar_resizing => {
+                        self.sidebar_resizing = false;
+                        ctx.settings.save_silent();
+                        tracing::debug!(width = ctx.settings.sidebar_width, "Sidebar resized");
+                    }
+                    SidebarResize::End => {}
+                }
+                (Task::none(), AppEffect::None)
+            }
+
+            NotificationMessage::SelectionKey(key) => (
+                self.handle_selection_key(key, ctx.settings.power_mode),
+                AppEffect::None,
+            ),
+
+            // These can drop notifications, taking their pins with them
+            msg @ (NotificationMessage::RefreshComplete(Ok(_))
+            | NotificationMessage::Thread(ThreadActionMessage::MarkAsDoneComplete(
+                _,
+                Ok(()),
+            ))
+            | NotificationMessage::Bulk(BulkActionMessage::Complete(_))) => {
+                let task = self.update(msg);
+                self.prune_pinned(ctx);
+                (task, AppEffect::None)
+            }
+
             // Other messages handled normally
             other => (self.update(other), AppEffect::None),
         }
@@ -282,6 +1132,22 @@ impl NotificationsScreen {
                 self.is_loading = true;
                 self.fetch_notifications()
             }
+            // Persisted, so handled in `update_with_effect`.
+            FilterMessage::ToggleHideRead | FilterMessage::ToggleParticipatingOnly => Task::none(),
+            FilterMessage::ToggleMentionsOnly => {
+                self.sidebar_state.mentions_only = !self.sidebar_state.mentions_only;
+                self.list_state.reset();
+                self.processing
+                    .rebuild_groups(&mut self.sidebar_state, &self.user.login);
+                Task::none()
+            }
+            FilterMessage::SearchChanged(query) => {
+                self.sidebar_state.search_query = query;
+                self.list_state.reset();
+                self.processing
+                    .rebuild_groups(&mut self.sidebar_state, &self.user.login);
+                Task::none()
+            }
         }
     }
 
@@ -293,9 +1159,10 @@ impl NotificationsScreen {
 
     pub fn view<'a>(
         &'a self,
-        accounts: Vec<String>,
+        accounts: Vec<AccountChoice>,
         icon_theme: IconTheme,
         sidebar_width: f32,
+        available_width: f32,
         power_mode: bool,
     ) -> Element<'a, NotificationMessage> {
         let total_count = if let Some(ref repo) = self.sidebar_state.selected_repo {
@@ -318,28 +1185,136 @@ impl NotificationsScreen {
             self.processing.all_notifications.len()
         };
 
-        row![
-            view_sidebar(SidebarViewArgs {
-                user: &self.user,
-                accounts: accounts.clone(),
-                type_counts: &self.processing.type_counts,
-                repo_counts: &self.processing.repo_counts,
-                selected_type: self.sidebar_state.selected_type,
-                selected_repo: self.sidebar_state.selected_repo.as_deref(),
-                total_count,
-                total_repo_count,
-                icon_theme,
-                width: sidebar_width,
-                power_mode,
-            })
-            .map(NotificationMessage::Sidebar),
-            self.view_main_content(icon_theme, power_mode)
-        ]
-        .height(Fill)
-        .into()
+        // Narrow windows fold the sidebar away behind a menu button
+        let collapsed = available_width < sidebar::resize::COLLAPSE_BELOW;
+        let mut content = row![].height(Fill);
+        if !collapsed || self.sidebar_revealed {
+            content = content
+                .push(
+                    view_sidebar(SidebarViewArgs {
+                        user: &self.user,
+                        accounts: accounts.clone(),
+                        type_counts: &self.processing.type_counts,
+                        repo_counts: &self.processing.repo_counts,
+                        selected_type: self.sidebar_state.selected_type,
+                        selected_repo: self.sidebar_state.selected_repo.as_deref(),
+                        repo_query: &self.sidebar_state.repo_query,
+                        total_count,
+                        total_repo_count,
+                        icon_theme,
+                        width: sidebar::resize::fit_width(sidebar_width, available_width),
+                        power_mode,
+                    })
+                    .map(NotificationMessage::Sidebar),
+                )
+                .push(sidebar::resize::handle(
+                    self.sidebar_resizing,
+                    NotificationMessage::SidebarResize,
+                ));
+        }
+        // After the sidebar, so the sidebar stays at x = 0 for resizing
+        if collapsed {
+            content = content.push(self.view_sidebar_toggle(icon_theme));
+        }
+        let content = content.push(self.view_main_content(icon_theme, power_mode));
+
+        sidebar::resize::track(
+            content,
+            self.sidebar_resizing,
+            NotificationMessage::SidebarResize,
+        )
     }
 
-    fn view_main_content(
+    /// Compact list-only layout for the mini window: an unread count and a
+    /// dense list, without the sidebar and header.
+    pub fn view_mini(&self, icon_theme: IconTheme) -> Element<'_, NotificationMessage> {
+        let p = crate::ui::theme::palette();
+        let unread = self
+            .processing
+            .filtered_notifications
+            .iter()
+            .filter(|n| n.unread)
+            .count();
+
+        let bar = row![
+            text(format!("{unread} unread"))
+                .size(12)
+                .color(p.text_secondary),
+            Space::new().width(Fill),
+            button(crate::ui::icons::icon_refresh(
+                14.0,
+                p.text_muted,
+                icon_theme
+            ))
+            .style(crate::ui::theme::ghost_button)
+            .padding(4)
+            .on_press(NotificationMessage::Refresh),
+            button(crate::ui::icons::icon_external_link(
+                14.0,
+                p.text_muted,
+                icon_theme
+            ))
+            .style(crate::ui::theme::ghost_button)
+            .padding(4)
+            .on_press(NotificationMessage::Navigation(
+                NavigationMessage::ToggleMiniMode
+            )),
+        ]
+        .spacing(4)
+        .padding([4, 8])
+        .align_y(iced::Alignment::Center);
+
+        let list = notification_list::view(ListArgs {
+            groups: &self.processing.groups,
+            hidden: &self.processing.hidden_notifications,
+            is_loading: self.is_loading,
+            restoring: self.restoring,
+            has_notifications: self
+                .processing
+                .groups
+                .iter()
+                .any(|g| !g.notifications.is_empty()),
+            error: self.error.as_ref(),
+            filters: &self.sidebar_state,
+            bulk_actions: &self.bulk_actions,
+            list_state: &self.list_state,
+            new_since: self.new_since,
+            flash_priority: self.priority_flash_until.is_some(),
+            token_invalid: self.token_invalid,
+            throttled_for: self.client.throttled_for(),
+            focused_id: None,
+            icon_theme,
+            layout: ListLayout::Mini,
+        });
+
+        column![
+            container(bar).width(Fill).style(crate::ui::theme::header),
+            list
+        ]
+        .height(Fill)
+        .into()
+    }
+
+    /// Menu button standing in for the sidebar on narrow windows.
+    fn view_sidebar_toggle(&self, icon_theme: IconTheme) -> Element<'_, NotificationMessage> {
+        let p = crate::ui::theme::palette();
+        container(
+            button(crate::ui::icons::icon_menu(
+                16.0,
+                p.text_secondary,
+                icon_theme,
+            ))
+            .style(crate::ui::theme::ghost_button)
+            .padding(6)
+            .on_press(NotificationMessage::ToggleSidebar),
+        )
+        .padding([12, 4])
+        .height(Fill)
+        .style(crate::ui::theme::sidebar)
+        .into()
+    }
+
+    fn view_main_content(
         &self,
         icon_theme: IconTheme,
         power_mode: bool,
@@ -358,52 +1333,85 @@ impl NotificationsScreen {
                 .map(NotificationMessage::Bulk),
                 notification_list::view(ListArgs {
                     groups: &self.processing.groups,
+                    hidden: &self.processing.hidden_notifications,
                     is_loading: self.is_loading,
+                    restoring: self.restoring,
                     has_notifications: self
                         .processing
                         .groups
                         .iter()
                         .any(|g| !g.notifications.is_empty()),
-                    error_message: self.error_message.as_ref(),
+                    error: self.error.as_ref(),
                     filters: &self.sidebar_state,
                     bulk_actions: &self.bulk_actions,
                     list_state: &self.list_state,
+                    new_since: self.new_since,
+                    flash_priority: self.priority_flash_until.is_some(),
+                    token_invalid: self.token_invalid,
+                    throttled_for: self.client.throttled_for(),
+                    focused_id: self.notification_details.selected_id.as_deref(),
                     icon_theme,
-                    power_mode,
+                    layout: ListLayout::Power,
                 })
             ]
         } else {
             column![
                 super::components::header::view(
                     &self.processing.filtered_notifications,
-                    self.is_loading,
+                    self.sync_status(),
                     &self.sidebar_state,
                     icon_theme
                 ),
                 notification_list::view(ListArgs {
                     groups: &self.processing.groups,
+                    hidden: &self.processing.hidden_notifications,
                     is_loading: self.is_loading,
+                    restoring: self.restoring,
                     has_notifications: self
                         .processing
                         .groups
                         .iter()
                         .any(|g| !g.notifications.is_empty()),
-                    error_message: self.error_message.as_ref(),
+                    error: self.error.as_ref(),
                     filters: &self.sidebar_state,
                     bulk_actions: &self.bulk_actions,
                     list_state: &self.list_state,
+                    new_since: self.new_since,
+                    flash_priority: self.priority_flash_until.is_some(),
+                    token_invalid: self.token_invalid,
+                    throttled_for: self.client.throttled_for(),
+                    focused_id: self.focused_id.as_deref(),
                     icon_theme,
-                    power_mode,
+                    layout: ListLayout::Standard,
                 })
             ]
         };
 
+        if let Some(note) = self.view_cap_note() {
+            content = content.push(note);
+        }
+
         // Add banners at top if present
-        let crash_banner = self.view_crash_notice();
+        let notice = self
+            .view_mark_all_confirmation()
+            .or_else(|| self.view_mark_all_done_confirmation())
+            .or_else(|| self.view_notice());
+        let token_banner = self
+            .view_token_banner()
+            .or_else(|| self.view_token_expiry_banner());
+        let crash_banner = self
+            .view_crash_notice()
+            .or_else(|| self.view_toast_warning());
         let update_banner = self.view_update_banner();
+        let whats_new = self.view_whats_new();
 
-        if crash_banner.is_some() || update_banner.is_some() {
-            let banners: Vec<_> = [crash_banner, update_banner]
+        if notice.is_some()
+            || token_banner.is_some()
+            || crash_banner.is_some()
+            || update_banner.is_some()
+            || whats_new.is_some()
+        {
+            let banners: Vec<_> = [notice, token_banner, crash_banner, update_banner, whats_new]
                 .into_iter()
                 .flatten()
                 .collect();
@@ -434,11 +1442,32 @@ impl NotificationsScreen {
 
     // === Extracted Logic ===
 
+    /// Forget pins on notifications that are no longer in the inbox.
+    fn prune_pinned(&mut self, ctx: &mut AppContext) {
+        let present: std::collections::HashSet<&str> = self
+            .processing
+            .all_notifications
+            .iter()
+            .map(|n| n.id.as_str())
+            .collect();
+        self.sidebar_state
+            .pinned
+            .retain(|id| present.contains(id.as_str()));
+        if ctx
+            .settings
+            .retain_pinned(&self.user.login, |id| present.contains(id))
+        {
+            ctx.settings.save_silent();
+        }
+    }
+
     fn handle_refresh_complete(
         &mut self,
         result: Result<Vec<NotificationView>, GitHubError>,
     ) -> Task<NotificationMessage> {
         self.is_loading = false;
+        self.restoring = false;
+        self.rate_limit = self.client.rate_limit().or(self.rate_limit);
         match result {
             Ok(mut notifications) => {
                 let mock_count =
@@ -453,11 +1482,35 @@ impl NotificationsScreen {
                 // We create a temporary engine just for desktop notification check if needed,
                 // or we update state and then check.
                 // Updating state:
+                apply_cached_conclusions(&self.ci_status, &mut notifications);
+                if self.show_actors {
+                    apply_cached_actors(&self.actors, &mut notifications);
+                }
+                // The first sync has nothing meaningful to compare against
+                let before = self
+                    .last_synced_at
+                    .map(|_| unread_snapshot(&self.processing.processed_notifications));
                 self.processing.all_notifications = notifications;
+                self.capped_from = self
+                    .processing
+                    .apply_cap(self.max_notifications, &self.sidebar_state.pinned);
                 // Rebuild groups will process notifications
                 self.processing
                     .rebuild_groups(&mut self.sidebar_state, &self.user.login);
 
+                let delta = before
+                    .map(|before| refresh_delta(&before, &self.processing.processed_notifications))
+                    .filter(|delta| !delta.is_empty());
+                let delta_task = match delta {
+                    Some(delta) => {
+                        self.refresh_delta = Some((delta, Instant::now() + REFRESH_DELTA_DURATION));
+                        Task::perform(tokio::time::sleep(REFRESH_DELTA_DURATION), |_| {
+                            NotificationMessage::RefreshDeltaEnded
+                        })
+                    }
+                    None => Task::none(),
+                };
+
                 let mut show_count = 0usize;
                 let mut silent_count = 0usize;
                 let mut important_count = 0usize;
@@ -474,7 +1527,10 @@ impl NotificationsScreen {
                 let hidden_count = filtered_count.saturating_sub(processed_count);
 
                 tracing::info!(
-                    fetched = self.processing.all_notifications.len(),
+                    fetched = self
+                        .capped_from
+                        .unwrap_or(self.processing.all_notifications.len()),
+                    kept = self.processing.all_notifications.len(),
                     filtered = filtered_count,
                     processed = processed_count,
                     hidden = hidden_count,
@@ -490,12 +1546,40 @@ impl NotificationsScreen {
                 let is_hidden = state::is_hidden();
                 let should_notify = is_hidden || !state::is_focused();
 
-                if should_notify {
+                let mut flash_task = Task::none();
+                let mut cue_in_app = !should_notify;
+                if state::paused_until().is_some() {
+                    tracing::debug!("Notifications paused; skipping alerts");
+                    cue_in_app = false;
+                } else if should_notify {
+                    let catch_up = self
+                        .last_synced_at
+                        .is_some_and(|at| chrono::Utc::now() - at > desktop_notify::CATCH_UP_GAP);
                     // Send desktop notifications using processed data
-                    desktop_notify::send_desktop_notifications(
+                    let outcome = desktop_notify::send_desktop_notifications(
                         &self.processing.processed_notifications,
                         &self.seen_notification_timestamps,
+                        &self.muted_desktop_reasons,
+                        self.toast_limits,
+                        catch_up,
                     );
+                    self.record_toast_outcome(outcome);
+                    // Nobody sees the toasts, so cue in-app as well
+                    cue_in_app = self.toasts_unavailable();
+                }
+                if cue_in_app && self.has_new_priority() {
+                    // Window is in use, so cue in-app instead of a desktop popup
+                    tracing::debug!("New priority notification cued in-app");
+                    if self.priority_sound {
+                        crate::platform::play_alert_sound();
+                    }
+                    if !theme::reduced_motion() {
+                        self.priority_flash_until = Some(Instant::now() + PRIORITY_FLASH_DURATION);
+                        flash_task =
+                            Task::perform(tokio::time::sleep(PRIORITY_FLASH_DURATION), |_| {
+                                NotificationMessage::PriorityFlashEnded
+                            });
+                    }
                 }
 
                 for n in &self.processing.all_notifications {
@@ -514,16 +1598,88 @@ impl NotificationsScreen {
                 }
 
                 crate::platform::trim_memory();
-                self.error_message = None;
+                self.error = None;
+                self.last_synced_at = Some(chrono::Utc::now());
+                self.failed_refreshes = 0;
+                self.token_invalid = false;
+
+                let ci_task = prefetch_ci_status(
+                    &mut self.ci_status,
+                    self.processing
+                        .processed_notifications
+                        .iter()
+                        .map(|p| &p.notification),
+                    &self.client,
+                )
+                .map(NotificationMessage::CiStatus);
+
+                let actor_task = if self.show_actors {
+                    prefetch_actors(
+                        &mut self.actors,
+                        self.processing
+                            .processed_notifications
+                            .iter()
+                            .map(|p| &p.notification),
+                        &self.client,
+                    )
+                    .map(NotificationMessage::Actor)
+                } else {
+                    Task::none()
+                };
+
+                // Mock data would otherwise show up on the next launch
+                let cache_task = if mock_count == 0 {
+                    self.save_to_cache()
+                } else {
+                    Task::none()
+                };
+                return Task::batch([ci_task, actor_task, flash_task, delta_task, cache_task]);
+            }
+            Err(e) if e.is_auth_error() => {
+                // Keep showing what we have; the banner explains what to do
+                diagnostics::record_error(&format!("{:?}", e.kind()), &e.to_string());
+                self.token_invalid = true;
+                self.failed_refreshes = self.failed_refreshes.saturating_add(1);
+                tracing::warn!(
+                    account = %self.user.login,
+                    error = %e,
+                    "Token rejected by GitHub, pausing background refresh"
+                );
             }
             Err(e) => {
-                self.error_message = Some(e.to_string());
-                tracing::error!(error = %e, "Failed to refresh notifications");
+                diagnostics::record_error(&format!("{:?}", e.kind()), &e.to_string());
+                self.failed_refreshes = self.failed_refreshes.saturating_add(1);
+                tracing::error!(
+                    error = %e,
+                    kind = ?e.kind(),
+                    failed_refreshes = self.failed_refreshes,
+                    "Failed to refresh notifications"
+                );
+                self.error = Some(e);
             }
         }
         Task::none()
     }
 
+    /// Whether the latest refresh brought an unread priority item that wasn't
+    /// there (or was older) before it.
+    fn has_new_priority(&self) -> bool {
+        // Nothing to compare against on the first load
+        if self.seen_notification_timestamps.is_empty() {
+            return false;
+        }
+
+        self.processing
+            .processed_notifications
+            .iter()
+            .filter(|p| p.is_priority() && p.notification.unread)
+            .any(|p| {
+                self.seen_notification_timestamps
+                    .get(&p.notification.id)
+                    .is_none_or(|seen| p.notification.updated_at > *seen)
+            })
+    }
+
     fn update_sidebar(
         &mut self,
         message: crate::ui::features::sidebar::SidebarMessage,
@@ -531,6 +1687,273 @@ impl NotificationsScreen {
         sidebar::update(&mut self.sidebar_state, message).map(NotificationMessage::SidebarAction)
     }
 
+    fn view_token_banner(&self) -> Option<Element<'_, NotificationMessage>> {
+        if !self.token_invalid {
+            return None;
+        }
+        let p = crate::ui::theme::palette();
+
+        let content = row![
+            text(format!(
+                "Token for {} is no longer valid — re-authenticate",
+                self.user.login
+            ))
+            .size(13)
+            .color(p.text_primary),
+            Space::new().width(Fill),
+            button(text("Re-authenticate").size(12))
+                .style(crate::ui::theme::primary_button)
+                .on_press(NotificationMessage::Navigation(
+                    NavigationMessage::Reauthenticate
+                ))
+                .padding([4, 12]),
+        ]
+        .align_y(iced::Alignment::Center);
+
+        Some(
+            container(content)
+                .padding(12)
+                .width(Fill)
+                .style(move |_| container::Style {
+                    background: Some(iced::Background::Color(p.bg_control)),
+                    border: iced::Border {
+                        radius: 6.0.into(),
+                        width: 1.0,
+                        color: p.accent_danger,
+                    },
+                    ..Default::default()
+                })
+                .into(),
+        )
+    }
+
+    fn view_token_expiry_banner(&self) -> Option<Element<'_, NotificationMessage>> {
+        if self.token_expiry_dismissed {
+            return None;
+        }
+        let expires_at = self.token_expires_at?;
+        let left = expires_at - chrono::Utc::now();
+        if left > TOKEN_EXPIRY_WARNING {
+            return None;
+        }
+        let p = crate::ui::theme::palette();
+
+        let when = if left <= chrono::TimeDelta::zero() {
+            "has expired".to_string()
+        } else if left < chrono::TimeDelta::days(1) {
+            "expires today".to_string()
+        } else {
+            format!(
+                "expires {}",
+                expires_at.with_timezone(&chrono::Local).format("%b %-d")
+            )
+        };
+
+        let content = row![
+            text(format!("Token for {} {when}", self.user.login))
+                .size(13)
+                .color(p.text_primary),
+            Space::new().width(Fill),
+            button(text("Regenerate").size(12))
+                .style(crate::ui::theme::primary_button)
+                .on_press(NotificationMessage::OpenUrl(
+                    "https://github.com/settings/personal-access-tokens"
+                ))
+                .padding([4, 12]),
+            button(text("Dismiss").size(12))
+                .style(crate::ui::theme::ghost_button)
+                .on_press(NotificationMessage::DismissTokenExpiry)
+                .padding([4, 12]),
+        ]
+        .spacing(8)
+        .align_y(iced::Alignment::Center);
+
+        Some(
+            container(content)
+                .padding(12)
+                .width(Fill)
+                .style(move |_| container::Style {
+                    background: Some(iced::Background::Color(p.bg_control)),
+                    border: iced::Border {
+                        radius: 6.0.into(),
+                        width: 1.0,
+                        color: p.accent_warning,
+                    },
+                    ..Default::default()
+                })
+                .into(),
+        )
+    }
+
+    fn view_toast_warning(&self) -> Option<Element<'_, NotificationMessage>> {
+        if !self.toasts_unavailable() || self.toast_warning_dismissed {
+            return None;
+        }
+        let p = crate::ui::theme::palette();
+
+        let reason = if cfg!(any(target_os = "linux", target_os = "freebsd")) {
+            "No notification daemon was found, so desktop notifications can't be shown."
+        } else {
+            "Desktop notifications aren't getting through; check the system's notification settings."
+        };
+
+        let content = row![
+            text(format!("{reason} GitTop will use in-app alerts instead."))
+                .size(13)
+                .color(p.text_primary),
+            Space::new().width(Fill),
+            button(text("Dismiss").size(12))
+                .style(crate::ui::theme::ghost_button)
+                .on_press(NotificationMessage::DismissToastWarning)
+                .padding([4, 12]),
+        ]
+        .spacing(8)
+        .align_y(iced::Alignment::Center);
+
+        Some(
+            container(content)
+                .padding(12)
+                .width(Fill)
+                .style(move |_| container::Style {
+                    background: Some(iced::Background::Color(p.bg_control)),
+                    border: iced::Border {
+                        radius: 6.0.into(),
+                        width: 1.0,
+                        color: p.accent_warning,
+                    },
+                    ..Default::default()
+                })
+                .into(),
+        )
+    }
+
+    /// Asks before "Mark all as read" clears a large inbox; there's no undo.
+    fn view_mark_all_confirmation(&self) -> Option<Element<'_, NotificationMessage>> {
+        let unread = self.thread_actions.confirming_mark_all?;
+        let p = crate::ui::theme::palette();
+
+        let content = row![
+            text(format!(
+                "Mark all {unread} unread notifications for {} as read? This can't be undone.",
+                self.user.login
+            ))
+            .size(13)
+            .color(p.text_primary),
+            Space::new().width(Fill),
+            button(text("Mark all read").size(12))
+                .style(crate::ui::theme::primary_button)
+                .on_press(NotificationMessage::Thread(
+                    ThreadActionMessage::ConfirmMarkAllAsRead
+                ))
+                .padding([4, 12]),
+            button(text("Cancel").size(12))
+                .style(crate::ui::theme::ghost_button)
+                .on_press(NotificationMessage::Thread(
+                    ThreadActionMessage::CancelMarkAllAsRead
+                ))
+                .padding([4, 12]),
+        ]
+        .spacing(8)
+        .align_y(iced::Alignment::Center);
+
+        Some(
+            container(content)
+                .padding(12)
+                .width(Fill)
+                .style(move |_| container::Style {
+                    background: Some(iced::Background::Color(p.bg_control)),
+                    border: iced::Border {
+                        radius: 6.0.into(),
+                        width: 1.0,
+                        color: p.accent_warning,
+                    },
+                    ..Default::default()
+                })
+                .into(),
+        )
+    }
+
+    /// Asks before "Mark all done" clears what's listed; there's no undo.
+    fn view_mark_all_done_confirmation(&self) -> Option<Element<'_, NotificationMessage>> {
+        let count = self.bulk_actions.confirm_done_all.as_ref()?.len();
+        let p = crate::ui::theme::palette();
+
+        let content = row![
+            text(format!(
+                "Mark the {count} notifications shown for {} as done? They leave the inbox and this can't be undone.",
+                self.user.login
+            ))
+            .size(13)
+            .color(p.text_primary),
+            Space::new().width(Fill),
+            button(text("Mark all done").size(12))
+                .style(crate::ui::theme::primary_button)
+                .on_press(NotificationMessage::Bulk(
+                    BulkActionMessage::ConfirmMarkAllAsDone
+                ))
+                .padding([4, 12]),
+            button(text("Cancel").size(12))
+                .style(crate::ui::theme::ghost_button)
+                .on_press(NotificationMessage::Bulk(
+                    BulkActionMessage::CancelMarkAllAsDone
+                ))
+                .padding([4, 12]),
+        ]
+        .spacing(8)
+        .align_y(iced::Alignment::Center);
+
+        Some(
+            container(content)
+                .padding(12)
+                .width(Fill)
+                .style(move |_| container::Style {
+                    background: Some(iced::Background::Color(p.bg_control)),
+                    border: iced::Border {
+                        radius: 6.0.into(),
+                        width: 1.0,
+                        color: p.accent_warning,
+                    },
+                    ..Default::default()
+                })
+                .into(),
+        )
+    }
+
+    pub fn set_notice(&mut self, notice: String) {
+        self.notice = Some(notice);
+    }
+
+    fn view_notice(&self) -> Option<Element<'_, NotificationMessage>> {
+        let notice = self.notice.as_ref()?;
+        let p = crate::ui::theme::palette();
+
+        let content = row![
+            text(notice).size(13).color(p.text_primary),
+            Space::new().width(Fill),
+            button(text("Dismiss").size(12))
+                .style(crate::ui::theme::ghost_button)
+                .on_press(NotificationMessage::DismissNotice)
+                .padding([4, 12]),
+        ]
+        .align_y(iced::Alignment::Center);
+
+        Some(
+            container(content)
+                .padding(12)
+                .width(Fill)
+                .style(move |_| container::Style {
+                    background: Some(iced::Background::Color(p.bg_control)),
+                    border: iced::Border {
+                        radius: 6.0.into(),
+                        width: 1.0,
+                        color: p.border_subtle,
+                    },
+                    ..Default::default()
+                })
+                .into(),
+        )
+    }
+
     fn view_crash_notice(&self) -> Option<Element<'_, NotificationMessage>> {
         let notice = self.crash_notice.as_ref()?;
         let p = crate::ui::theme::palette();
@@ -590,42 +2013,139 @@ impl NotificationsScreen {
         let info = self.update_info.as_ref()?;
         let p = crate::ui::theme::palette();
 
-        let content = row![
-            text(format!(
-                "🎉 v{} available (you have {}) — update via package manager or releases",
-                info.latest, info.current
-            ))
-            .size(13)
-            .color(p.text_primary),
+        let action = |label, message| {
+            button(text(label).size(12))
+                .style(crate::ui::theme::ghost_button)
+                .on_press(message)
+                .padding([4, 12])
+        };
+
+        let content = match &self.update_install {
+            UpdateInstall::Confirming => row![
+                text(format!(
+                    "Install v{}? GitTop will close while the installer runs.",
+                    info.latest
+                ))
+                .size(13)
+                .color(p.text_primary),
+                Space::new().width(Fill),
+                action("Install", NotificationMessage::ConfirmInstallUpdate),
+                action("Cancel", NotificationMessage::CancelInstallUpdate),
+            ],
+            UpdateInstall::Downloading => row![
+                text(format!("Downloading v{}…", info.latest))
+                    .size(13)
+                    .color(p.text_primary),
+                Space::new().width(Fill),
+            ],
+            state => {
+                let label = match state {
+                    UpdateInstall::Failed(e) => format!("Update failed: {e}"),
+                    _ => format!("🎉 v{} available (you have {})", info.latest, info.current),
+                };
+                let download = if self.self_update && crate::self_update::can_install(info) {
+                    action("Install", NotificationMessage::InstallUpdate)
+                } else {
+                    action("Download", NotificationMessage::DownloadUpdate)
+                };
+
+                row![
+                    text(label).size(13).color(p.text_primary),
+                    Space::new().width(Fill),
+                    action("Release Notes", NotificationMessage::OpenReleasePage),
+                    download,
+                    action("Skip Version", NotificationMessage::SkipUpdateVersion),
+                    Space::new().width(8),
+                    button(text("✕").size(12))
+                        .style(crate::ui::theme::ghost_button)
+                        .on_press(NotificationMessage::DismissUpdateBanner)
+                        .padding([4, 8]),
+                ]
+            }
+        }
+        .align_y(iced::Alignment::Center);
+
+        Some(
+            container(content)
+                .padding(12)
+                .width(Fill)
+                .style(move |_| container::Style {
+                    background: Some(iced::Background::Color(iced::Color::from_rgb(
+                        0.15, 0.28, 0.18,
+                    ))),
+                    border: iced::Border {
+                        radius: 6.0.into(),
+                        width: 1.0,
+                        color: iced::Color::from_rgb(0.25, 0.40, 0.28),
+                    },
+                    ..Default::default()
+                })
+                .into(),
+        )
+    }
+
+    fn view_whats_new(&self) -> Option<Element<'_, NotificationMessage>> {
+        let notes = self.whats_new.as_ref()?;
+        let p = crate::ui::theme::palette();
+
+        let header = row![
+            text(format!("What's new in v{}", notes.version))
+                .size(14)
+                .color(p.text_primary),
             Space::new().width(Fill),
-            button(text("View Release").size(12))
+            button(text("Release Notes").size(12))
                 .style(crate::ui::theme::ghost_button)
-                .on_press(NotificationMessage::OpenReleasePage)
+                .on_press(NotificationMessage::OpenWhatsNewNotes)
                 .padding([4, 12]),
             Space::new().width(8),
             button(text("✕").size(12))
                 .style(crate::ui::theme::ghost_button)
-                .on_press(NotificationMessage::DismissUpdateBanner)
+                .on_press(NotificationMessage::DismissWhatsNew)
                 .padding([4, 8]),
         ]
         .align_y(iced::Alignment::Center);
 
+        let summary = notes
+            .summary
+            .as_deref()
+            .unwrap_or("GitTop was updated. Open the release notes to see what changed.");
+
+        let content = column![header, text(summary).size(12).color(p.text_secondary)].spacing(6);
+
         Some(
             container(content)
                 .padding(12)
                 .width(Fill)
                 .style(move |_| container::Style {
-                    background: Some(iced::Background::Color(iced::Color::from_rgb(
-                        0.15, 0.28, 0.18,
-                    ))),
+                    background: Some(iced::Background::Color(p.bg_control)),
                     border: iced::Border {
                         radius: 6.0.into(),
                         width: 1.0,
-                        color: iced::Color::from_rgb(0.25, 0.40, 0.28),
+                        color: p.accent,
                     },
                     ..Default::default()
                 })
                 .into(),
         )
     }
+
+    fn view_cap_note(&self) -> Option<Element<'_, NotificationMessage>> {
+        let total = self.capped_from?;
+        let p = crate::ui::theme::palette();
+        Some(
+            container(
+                text(format!(
+                    "Showing the most recent {} of {} notifications",
+                    self.processing.all_notifications.len(),
+                    total
+                ))
+                .size(11)
+                .color(p.text_muted),
+            )
+            .padding([4, 12])
+            .width(Fill)
+            .center_x(Fill)
+            .into(),
+        )
+    }
 }
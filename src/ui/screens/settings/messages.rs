@@ -2,6 +2,7 @@ use crate::ui::features::account_management::AccountMessage;
 use crate::ui::features::general_settings::GeneralMessage;
 use crate::ui::features::network_proxy::ProxyMessage;
 use crate::ui::features::power_mode::PowerModeMessage;
+use crate::ui::features::sidebar::SidebarResize;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum SettingsTab {
@@ -21,4 +22,5 @@ pub enum SettingsMessage {
     General(GeneralMessage),
     Proxy(ProxyMessage),
     PowerMode(PowerModeMessage),
+    SidebarResize(SidebarResize),
 }
@@ -4,6 +4,7 @@ use iced::widget::{Space, button, column, container, row, text};
 use iced::{Alignment, Element, Fill};
 
 use crate::settings::IconTheme;
+use crate::ui::screens::notifications::RuleImpact;
 use crate::ui::{icons, theme};
 
 // ============================================================================
@@ -91,3 +92,39 @@ where
     .align_y(Alignment::Center)
     .into()
 }
+
+// ============================================================================
+// Impact Preview
+// ============================================================================
+
+/// "This rule would affect N of your current notifications (X hidden, Y
+/// prioritized)". `None` means there's nothing loaded to preview against.
+pub fn view_impact_preview<'a, Message>(
+    impact: Option<RuleImpact>,
+    verb: &'static str,
+) -> Element<'a, Message>
+where
+    Message: 'a + Clone + 'static,
+{
+    let p = theme::palette();
+
+    let Some(impact) = impact else {
+        return text("No notifications loaded to preview against")
+            .size(12)
+            .color(p.text_muted)
+            .into();
+    };
+
+    let color = if impact.hidden > 0 {
+        p.accent_warning
+    } else {
+        p.text_secondary
+    };
+    text(format!(
+        "This rule {verb} {} of your current notifications ({} hidden, {} prioritized)",
+        impact.affected, impact.hidden, impact.prioritized
+    ))
+    .size(12)
+    .color(color)
+    .into()
+}
@@ -1,17 +1,18 @@
 //! Rule Inspector component - shows detailed rule information in a side panel.
 
-use iced::widget::{Space, button, column, container, row, text};
+use iced::widget::{Space, button, column, container, pick_list, row, text, toggler};
 use iced::{Alignment, Element, Fill, Length};
 
 use crate::settings::IconTheme;
 use crate::ui::icons;
 use crate::ui::screens::settings::rule_engine::rules::{
-    AccountRule, NotificationRuleSet, OrgRule, OutsideScheduleBehavior, RuleAction, TypeRule,
+    AccountRule, ActorRule, NotificationRuleSet, OrgRule, OutsideScheduleBehavior, RuleAction,
+    RuleSchedule, TypeRule,
 };
 use crate::ui::theme;
-use chrono::Local;
+use chrono::{Local, Timelike, Weekday};
 
-use super::messages::{InspectorMessage, RuleEngineMessage};
+use super::messages::{InspectorMessage, RuleEngineMessage, ScheduleHour};
 
 /// Result of finding a rule by ID across all rule types.
 #[derive(Debug, Clone)]
@@ -19,6 +20,7 @@ pub enum FoundRule {
     Account(AccountRule),
     Org(OrgRule),
     Type(TypeRule),
+    Actor(ActorRule),
 }
 
 impl FoundRule {
@@ -28,6 +30,7 @@ impl FoundRule {
             FoundRule::Account(r) => r.enabled,
             FoundRule::Org(r) => r.enabled,
             FoundRule::Type(r) => r.enabled,
+            FoundRule::Actor(r) => r.enabled,
         }
     }
 
@@ -46,6 +49,7 @@ impl FoundRule {
             }
             FoundRule::Org(r) => r.action,
             FoundRule::Type(r) => r.action,
+            FoundRule::Actor(r) => r.action,
         }
     }
 
@@ -55,6 +59,7 @@ impl FoundRule {
             FoundRule::Account(_) => "Account Rule",
             FoundRule::Org(_) => "Org Rule",
             FoundRule::Type(_) => "Type Rule",
+            FoundRule::Actor(_) => "Actor Rule",
         }
     }
 }
@@ -70,9 +75,165 @@ pub fn find_rule_by_id(rules: &NotificationRuleSet, id: &str) -> Option<FoundRul
     if let Some(r) = rules.type_rules.iter().find(|r| r.id == id) {
         return Some(FoundRule::Type(r.clone()));
     }
+    if let Some(r) = rules.actor_rules.iter().find(|r| r.id == id) {
+        return Some(FoundRule::Actor(r.clone()));
+    }
     None
 }
 
+/// Schedule slot of an org or type rule (account rules have their own).
+fn schedule_mut<'a>(
+    rules: &'a mut NotificationRuleSet,
+    id: &str,
+) -> Option<&'a mut Option<RuleSchedule>> {
+    if let Some(r) = rules.org_rules.iter_mut().find(|r| r.id == id) {
+        return Some(&mut r.schedule);
+    }
+    rules
+        .type_rules
+        .iter_mut()
+        .find(|r| r.id == id)
+        .map(|r| &mut r.schedule)
+}
+
+/// Apply a schedule edit from the inspector and persist the rules.
+pub fn update_schedule(rules: &mut NotificationRuleSet, message: InspectorMessage) {
+    let id = match &message {
+        InspectorMessage::SetScheduled(id, _)
+        | InspectorMessage::ToggleScheduleDay(id, _)
+        | InspectorMessage::SetScheduleStart(id, _)
+        | InspectorMessage::SetScheduleEnd(id, _) => id.clone(),
+        InspectorMessage::Select(_) | InspectorMessage::Close => return,
+    };
+    let Some(schedule) = schedule_mut(rules, &id) else {
+        return;
+    };
+
+    match message {
+        InspectorMessage::SetScheduled(_, enabled) => {
+            *schedule = enabled.then(RuleSchedule::default);
+        }
+        InspectorMessage::ToggleScheduleDay(_, day) => {
+            if let Some(s) = schedule
+                && !s.active_days.remove(&day)
+            {
+                s.active_days.insert(day);
+            }
+        }
+        InspectorMessage::SetScheduleStart(_, hour) => {
+            if let Some(s) = schedule {
+                s.start_time = hour.time();
+            }
+        }
+        InspectorMessage::SetScheduleEnd(_, hour) => {
+            if let Some(s) = schedule {
+                s.end_time = hour.time();
+            }
+        }
+        InspectorMessage::Select(_) | InspectorMessage::Close => {}
+    }
+
+    let _ = rules.save();
+    tracing::debug!(rule_id = %id, "Rule schedule updated");
+}
+
+/// Schedule editor for org and type rules.
+fn view_schedule_section(
+    id: &str,
+    schedule: Option<&RuleSchedule>,
+) -> Element<'static, RuleEngineMessage> {
+    let p = theme::palette();
+    let id_toggle = id.to_string();
+
+    let header = row![
+        column![
+            text("Schedule").size(11).color(p.text_muted),
+            text(schedule.map_or_else(|| "Always applies".to_string(), RuleSchedule::summary))
+                .size(13)
+                .color(p.text_primary),
+        ]
+        .width(Fill),
+        toggler(schedule.is_some())
+            .on_toggle(
+                move |on| RuleEngineMessage::Inspector(InspectorMessage::SetScheduled(
+                    id_toggle.clone(),
+                    on
+                ))
+            )
+            .size(16),
+    ]
+    .align_y(Alignment::Center);
+
+    let Some(schedule) = schedule else {
+        return header.into();
+    };
+
+    const DAYS: [(Weekday, &str); 7] = [
+        (Weekday::Mon, "M"),
+        (Weekday::Tue, "T"),
+        (Weekday::Wed, "W"),
+        (Weekday::Thu, "T"),
+        (Weekday::Fri, "F"),
+        (Weekday::Sat, "S"),
+        (Weekday::Sun, "S"),
+    ];
+
+    let days = row(DAYS.iter().map(|(day, label)| {
+        button(text(*label).size(11))
+            .style(theme::segment_button(schedule.active_days.contains(day)))
+            .padding([4, 8])
+            .on_press(RuleEngineMessage::Inspector(
+                InspectorMessage::ToggleScheduleDay(id.to_string(), *day),
+            ))
+            .into()
+    }))
+    .spacing(2);
+
+    let hour_of = |t: Option<chrono::NaiveTime>| t.map(|t| ScheduleHour(t.hour()));
+    let id_start = id.to_string();
+    let id_end = id.to_string();
+
+    let hours = row![
+        pick_list(
+            ScheduleHour::all(),
+            hour_of(schedule.start_time),
+            move |h| {
+                RuleEngineMessage::Inspector(InspectorMessage::SetScheduleStart(
+                    id_start.clone(),
+                    h,
+                ))
+            }
+        )
+        .placeholder("From")
+        .text_size(12)
+        .style(theme::pick_list_style)
+        .menu_style(theme::menu_style),
+        text("to").size(12).color(p.text_muted),
+        pick_list(ScheduleHour::all(), hour_of(schedule.end_time), move |h| {
+            RuleEngineMessage::Inspector(InspectorMessage::SetScheduleEnd(id_end.clone(), h))
+        })
+        .placeholder("To")
+        .text_size(12)
+        .style(theme::pick_list_style)
+        .menu_style(theme::menu_style),
+    ]
+    .spacing(6)
+    .align_y(Alignment::Center);
+
+    column![
+        header,
+        Space::new().height(8),
+        days,
+        Space::new().height(8),
+        hours,
+        Space::new().height(4),
+        text("Outside this window the rule is skipped.")
+            .size(11)
+            .color(p.text_secondary),
+    ]
+    .into()
+}
+
 /// View the rule inspector panel.
 pub fn view_inspector(
     rules: &NotificationRuleSet,
@@ -233,6 +394,7 @@ pub fn view_inspector(
             let notification_type = r.notification_type.clone();
             let account_text = r.account.clone().unwrap_or_else(|| "Global".to_string());
             let priority = r.priority;
+            let title_text = r.title_pattern().unwrap_or("Any").to_string();
             column![
                 text("Notification Type").size(11).color(p.text_muted),
                 text(notification_type).size(13).color(p.text_primary),
@@ -240,6 +402,20 @@ pub fn view_inspector(
                 text("Scope").size(11).color(p.text_muted),
                 text(account_text).size(13).color(p.text_primary),
                 Space::new().height(8),
+                text("Title Contains").size(11).color(p.text_muted),
+                text(title_text).size(13).color(p.text_primary),
+                Space::new().height(8),
+                text("Priority").size(11).color(p.text_muted),
+                text(format!("{}", priority)).size(13).color(p.text_primary),
+            ]
+        }
+        FoundRule::Actor(r) => {
+            let actor = format!("@{}", r.actor);
+            let priority = r.priority;
+            column![
+                text("Triggered By").size(11).color(p.text_muted),
+                text(actor).size(13).color(p.text_primary),
+                Space::new().height(8),
                 text("Priority").size(11).color(p.text_muted),
                 text(format!("{}", priority)).size(13).color(p.text_primary),
             ]
@@ -266,6 +442,16 @@ pub fn view_inspector(
 
     content = content.push(details_section);
 
+    let schedule = match &rule {
+        FoundRule::Account(_) | FoundRule::Actor(_) => None,
+        FoundRule::Org(r) => Some((r.id.as_str(), r.schedule.as_ref())),
+        FoundRule::Type(r) => Some((r.id.as_str(), r.schedule.as_ref())),
+    };
+    if let Some((id, schedule)) = schedule {
+        content = content.push(Space::new().height(16));
+        content = content.push(view_schedule_section(id, schedule));
+    }
+
     container(content.padding(16))
         .width(Length::Fixed(280.0))
         .height(Fill)
@@ -1,16 +1,31 @@
+use chrono::Weekday;
+
+use super::rules::NotificationRuleSet;
+
 use crate::ui::features::account_rules::AccountRuleMessage;
+use crate::ui::features::actor_rules::ActorRuleMessage;
 use crate::ui::features::type_rules::TypeRuleMessage;
 
 #[derive(Debug, Clone)]
 pub enum RuleEngineMessage {
     Back,
+    /// Escape: close the inspector if it's open, otherwise go back.
+    Escape,
     SelectTab(RuleTab),
     ToggleEnabled(bool),
     Account(AccountRuleMessage),
     Org(OrgMessage),
     Type(TypeRuleMessage),
+    Actor(ActorRuleMessage),
     Inspector(InspectorMessage),
     Overview(OverviewMessage),
+    /// Save the rule set to a file picked in a dialog.
+    ExportRules,
+    /// File name written, or `None` if the dialog was cancelled.
+    RulesExported(Result<Option<String>, String>),
+    /// Merge rules from a file picked in a dialog.
+    ImportRules,
+    RulesImported(Result<Option<NotificationRuleSet>, String>),
 }
 
 // Re-export feature messages for convenience
@@ -23,6 +38,31 @@ pub use crate::ui::features::type_rules::TypeRuleMessage as TypeMessage;
 pub enum InspectorMessage {
     Select(String),
     Close,
+    /// Attach (true) or drop (false) a schedule on an org/type rule.
+    SetScheduled(String, bool),
+    ToggleScheduleDay(String, Weekday),
+    SetScheduleStart(String, ScheduleHour),
+    SetScheduleEnd(String, ScheduleHour),
+}
+
+/// Whole-hour choice for schedule pickers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScheduleHour(pub u32);
+
+impl ScheduleHour {
+    pub fn all() -> Vec<Self> {
+        (0..24).map(Self).collect()
+    }
+
+    pub fn time(self) -> Option<chrono::NaiveTime> {
+        chrono::NaiveTime::from_hms_opt(self.0, 0, 0)
+    }
+}
+
+impl std::fmt::Display for ScheduleHour {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:02}:00", self.0)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -32,4 +72,32 @@ pub enum RuleTab {
     AccountRules,
     OrgRules,
     TypeRules,
+    ActorRules,
+}
+
+impl RuleTab {
+    /// Stable key used to persist the selected tab in settings.
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::Overview => "overview",
+            Self::AccountRules => "account_rules",
+            Self::OrgRules => "org_rules",
+            Self::TypeRules => "type_rules",
+            Self::ActorRules => "actor_rules",
+        }
+    }
+
+    /// Inverse of [`RuleTab::key`]; tabs that no longer exist map to the default.
+    pub fn from_key(key: &str) -> Self {
+        [
+            Self::Overview,
+            Self::AccountRules,
+            Self::OrgRules,
+            Self::TypeRules,
+            Self::ActorRules,
+        ]
+        .into_iter()
+        .find(|tab| tab.key() == key)
+        .unwrap_or_default()
+    }
 }
@@ -6,5 +6,5 @@ mod screen;
 
 pub mod rules;
 
-pub use rules::{NotificationRuleSet, RuleAction, RuleEngine};
+pub use rules::{NotificationRuleSet, RuleAction, RuleEngine, RuleSubject};
 pub use screen::RuleEngineScreen;
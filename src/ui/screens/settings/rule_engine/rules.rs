@@ -8,7 +8,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 use std::fs;
 use std::io;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use uuid::Uuid;
 
 // ============================================================================
@@ -160,35 +160,114 @@ impl AccountRule {
             return false;
         }
 
-        let day = now.weekday();
+        in_schedule(&self.active_days, self.start_time, self.end_time, now)
+    }
+}
 
-        // Check active days
-        if !self.active_days.contains(&day) {
-            return false;
-        }
+/// Shared day/time window check for account rules and rule schedules.
+fn in_schedule(
+    active_days: &HashSet<Weekday>,
+    start_time: Option<NaiveTime>,
+    end_time: Option<NaiveTime>,
+    now: &chrono::DateTime<Local>,
+) -> bool {
+    // Check active days
+    if !active_days.contains(&now.weekday()) {
+        return false;
+    }
 
-        // Check time range if specified
-        if let (Some(start), Some(end)) = (self.start_time, self.end_time) {
-            let current_time = now.time();
+    // Check time range if specified
+    if let (Some(start), Some(end)) = (start_time, end_time) {
+        let current_time = now.time();
 
-            if start <= end {
-                // Standard range (e.g. 09:00 to 17:00)
-                if current_time < start || current_time >= end {
-                    return false;
-                }
-            } else {
-                // Crossing midnight (e.g. 22:00 to 07:00)
-                // Active if time >= 22:00 OR time < 07:00
-                if current_time < start && current_time >= end {
-                    return false;
-                }
+        if start <= end {
+            // Standard range (e.g. 09:00 to 17:00)
+            if current_time < start || current_time >= end {
+                return false;
+            }
+        } else {
+            // Crossing midnight (e.g. 22:00 to 07:00)
+            // Active if time >= 22:00 OR time < 07:00
+            if current_time < start && current_time >= end {
+                return false;
             }
         }
+    }
+
+    true
+}
+
+/// When an org or type rule applies. Outside the window the rule simply
+/// doesn't match, so other rules (or the default) decide.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RuleSchedule {
+    #[serde(default = "default_active_days")]
+    pub active_days: HashSet<Weekday>,
+    #[serde(default)]
+    pub start_time: Option<NaiveTime>,
+    #[serde(default)]
+    pub end_time: Option<NaiveTime>,
+}
+
+impl Default for RuleSchedule {
+    /// Working hours: Monday to Friday, 09:00 to 17:00.
+    fn default() -> Self {
+        use chrono::Weekday::*;
+        Self {
+            active_days: [Mon, Tue, Wed, Thu, Fri].into_iter().collect(),
+            start_time: NaiveTime::from_hms_opt(9, 0, 0),
+            end_time: NaiveTime::from_hms_opt(17, 0, 0),
+        }
+    }
+}
+
+impl RuleSchedule {
+    pub fn is_active(&self, now: &chrono::DateTime<Local>) -> bool {
+        in_schedule(&self.active_days, self.start_time, self.end_time, now)
+    }
+
+    /// Short summary for rule cards, e.g. "Mon–Fri 09:00–17:00".
+    pub fn summary(&self) -> String {
+        const ORDER: [(Weekday, &str); 7] = [
+            (Weekday::Mon, "Mon"),
+            (Weekday::Tue, "Tue"),
+            (Weekday::Wed, "Wed"),
+            (Weekday::Thu, "Thu"),
+            (Weekday::Fri, "Fri"),
+            (Weekday::Sat, "Sat"),
+            (Weekday::Sun, "Sun"),
+        ];
+
+        let days: Vec<&str> = ORDER
+            .iter()
+            .filter(|(d, _)| self.active_days.contains(d))
+            .map(|(_, label)| *label)
+            .collect();
+        let days = match days.len() {
+            0 => "Never".to_string(),
+            7 => "Every day".to_string(),
+            5 if !self.active_days.contains(&Weekday::Sat)
+                && !self.active_days.contains(&Weekday::Sun) =>
+            {
+                "Mon–Fri".to_string()
+            }
+            _ => days.join(", "),
+        };
 
-        true
+        match (self.start_time, self.end_time) {
+            (Some(start), Some(end)) => {
+                format!("{} {}–{}", days, start.format("%H:%M"), end.format("%H:%M"))
+            }
+            _ => days,
+        }
     }
 }
 
+/// True when a rule has no schedule or its schedule covers `now`.
+fn schedule_allows(schedule: &Option<RuleSchedule>, now: &chrono::DateTime<Local>) -> bool {
+    schedule.as_ref().is_none_or(|s| s.is_active(now))
+}
+
 /// Organization-level priority and filtering.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrgRule {
@@ -199,6 +278,9 @@ pub struct OrgRule {
     /// Priority level (higher = more important).
     pub priority: i32,
     pub action: RuleAction,
+    /// Optional window outside of which the rule does not apply.
+    #[serde(default)]
+    pub schedule: Option<RuleSchedule>,
 }
 
 impl OrgRule {
@@ -210,6 +292,7 @@ impl OrgRule {
             org: org.into(),
             priority,
             action: RuleAction::Show,
+            schedule: None,
         }
     }
 }
@@ -228,6 +311,13 @@ pub struct TypeRule {
     #[serde(default)]
     pub priority: i32,
     pub action: RuleAction,
+    /// Optional window outside of which the rule does not apply.
+    #[serde(default)]
+    pub schedule: Option<RuleSchedule>,
+    /// Only match titles containing this text, ignoring case. Blank means
+    /// any title.
+    #[serde(default)]
+    pub title_pattern: Option<String>,
 }
 
 impl TypeRule {
@@ -243,6 +333,44 @@ impl TypeRule {
             account,
             priority,
             action: RuleAction::Show,
+            schedule: None,
+            title_pattern: None,
+        }
+    }
+
+    /// The title constraint, if there is a non-blank one.
+    pub fn title_pattern(&self) -> Option<&str> {
+        self.title_pattern
+            .as_deref()
+            .map(str::trim)
+            .filter(|p| !p.is_empty())
+    }
+}
+
+/// Rule keyed on who triggered the notification (comment, PR or issue author).
+///
+/// The actor is only known once it has been looked up, so these rules never
+/// match notifications whose actor is missing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActorRule {
+    pub id: String,
+    pub enabled: bool,
+    /// GitHub login, e.g. `dependabot[bot]`.
+    pub actor: String,
+    /// Priority level (higher = more important).
+    #[serde(default)]
+    pub priority: i32,
+    pub action: RuleAction,
+}
+
+impl ActorRule {
+    pub fn new(actor: impl Into<String>, priority: i32) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            enabled: true,
+            actor: actor.into(),
+            priority,
+            action: RuleAction::Show,
         }
     }
 }
@@ -265,6 +393,9 @@ pub struct NotificationRuleSet {
     pub org_rules: Vec<OrgRule>,
     /// Notification type filtering.
     pub type_rules: Vec<TypeRule>,
+    /// Rules for specific users who trigger notifications.
+    #[serde(default)]
+    pub actor_rules: Vec<ActorRule>,
 }
 
 fn default_rule_set_name() -> String {
@@ -343,6 +474,66 @@ impl NotificationRuleSet {
         Ok(())
     }
 
+    /// Write the whole rule set to `path` for sharing or backup.
+    pub fn export_to(&self, path: &Path) -> Result<(), String> {
+        let content = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        fs::write(path, content).map_err(|e| format!("Couldn't write {}: {e}", path.display()))
+    }
+
+    /// Read a rule set written by `export_to`.
+    pub fn import_from(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("Couldn't read {}: {e}", path.display()))?;
+        serde_json::from_str(&content).map_err(|e| format!("Not a GitTop rules file: {e}"))
+    }
+
+    /// Add `imported`'s rules to these, under fresh ids so nothing collides.
+    ///
+    /// An imported account rule replaces this set's rule for the same
+    /// account, since there is only ever one per account. Importing an
+    /// enabled set turns the engine on. Returns how many rules came in.
+    pub fn merge(&mut self, imported: NotificationRuleSet) -> usize {
+        let count = imported.account_rules.len()
+            + imported.org_rules.len()
+            + imported.type_rules.len()
+            + imported.actor_rules.len();
+
+        for mut rule in imported.account_rules {
+            match self
+                .account_rules
+                .iter_mut()
+                .find(|r| r.account.eq_ignore_ascii_case(&rule.account))
+            {
+                Some(existing) => {
+                    rule.id = std::mem::take(&mut existing.id);
+                    *existing = rule;
+                }
+                None => {
+                    rule.id = Uuid::new_v4().to_string();
+                    self.account_rules.push(rule);
+                }
+            }
+        }
+        self.org_rules
+            .extend(imported.org_rules.into_iter().map(|mut rule| {
+                rule.id = Uuid::new_v4().to_string();
+                rule
+            }));
+        self.type_rules
+            .extend(imported.type_rules.into_iter().map(|mut rule| {
+                rule.id = Uuid::new_v4().to_string();
+                rule
+            }));
+        self.actor_rules
+            .extend(imported.actor_rules.into_iter().map(|mut rule| {
+                rule.id = Uuid::new_v4().to_string();
+                rule
+            }));
+        self.enabled |= imported.enabled;
+
+        count
+    }
+
     /// Count total active rules.
     pub fn active_rule_count(&self) -> usize {
         if !self.enabled {
@@ -351,6 +542,19 @@ impl NotificationRuleSet {
         self.account_rules.iter().filter(|r| r.enabled).count()
             + self.org_rules.iter().filter(|r| r.enabled).count()
             + self.type_rules.iter().filter(|r| r.enabled).count()
+            + self.actor_rules.iter().filter(|r| r.enabled).count()
+    }
+
+    /// Rule counts for the debug report.
+    pub fn summary(&self) -> crate::diagnostics::RuleSummary {
+        crate::diagnostics::RuleSummary {
+            enabled: self.enabled,
+            account: self.account_rules.len(),
+            org: self.org_rules.len(),
+            subject_type: self.type_rules.len(),
+            actor: self.actor_rules.len(),
+            active: self.active_rule_count(),
+        }
     }
 
     // ========================================================================
@@ -383,6 +587,11 @@ impl NotificationRuleSet {
             .iter()
             .filter(|r| r.enabled && r.action == RuleAction::Hide)
             .count();
+        count += self
+            .actor_rules
+            .iter()
+            .filter(|r| r.enabled && r.action == RuleAction::Hide)
+            .count();
         count
     }
 
@@ -406,6 +615,13 @@ impl NotificationRuleSet {
                 r.enabled && (r.priority >= PRIORITY_HIGH || r.action == RuleAction::Important)
             })
             .count();
+        count += self
+            .actor_rules
+            .iter()
+            .filter(|r| {
+                r.enabled && (r.priority >= PRIORITY_HIGH || r.action == RuleAction::Important)
+            })
+            .count();
         count
     }
 
@@ -465,6 +681,20 @@ impl NotificationRuleSet {
             }
         }
 
+        // Actor rules with Hide or Important action
+        for rule in &self.actor_rules {
+            if rule.enabled
+                && (rule.action == RuleAction::Hide
+                    || rule.action == RuleAction::Important
+                    || rule.priority >= PRIORITY_HIGH)
+            {
+                rules.push(HighImpactRule {
+                    name: format!("@{}", rule.actor),
+                    action: rule.action,
+                });
+            }
+        }
+
         rules
     }
 }
@@ -473,20 +703,30 @@ impl NotificationRuleSet {
 // RULE ENGINE (EVALUATION)
 // ============================================================================
 
+/// What the rules look at when deciding on a notification. Anything `None`
+/// isn't known (yet), so rules keyed on it don't match.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RuleSubject<'a> {
+    /// Reason label, e.g. "Mentioned".
+    pub notification_type: &'a str,
+    pub repo_owner: Option<&'a str>,
+    pub account: Option<&'a str>,
+    pub actor: Option<&'a str>,
+    pub title: Option<&'a str>,
+}
+
 impl NotificationRuleSet {
     /// Evaluate with full trace of the decision.
     pub fn evaluate_detailed(
         &self,
-        notification_type: &str,
-        repo_owner: Option<&str>,
-        account: Option<&str>,
+        subject: &RuleSubject<'_>,
         now: &chrono::DateTime<Local>,
     ) -> (RuleAction, Option<RuleDecision>) {
         if !self.enabled {
             return (RuleAction::Show, None);
         }
 
-        let matches = self.trace(notification_type, repo_owner, account, now, false);
+        let matches = self.trace(subject, now, false);
 
         if matches.is_empty() {
             return (RuleAction::Show, None);
@@ -523,14 +763,23 @@ impl NotificationRuleSet {
     }
 
     /// Gather all applicable rules for a given context, sorted by valid priority order.
+    ///
+    /// With `allow_loose_match`, type rules scoped to an account or title
+    /// still match when the subject doesn't say which; the Test Lab uses this
+    /// to show everything that could apply.
     pub fn trace(
         &self,
-        notification_type: &str,
-        repo_owner: Option<&str>,
-        account: Option<&str>,
+        subject: &RuleSubject<'_>,
         now: &chrono::DateTime<Local>,
-        allow_loose_account_match: bool,
+        allow_loose_match: bool,
     ) -> Vec<MatchResult> {
+        let &RuleSubject {
+            notification_type,
+            repo_owner,
+            account,
+            actor,
+            title,
+        } = subject;
         let mut matches = Vec::new();
 
         // 1. Account Rules
@@ -577,7 +826,11 @@ impl NotificationRuleSet {
             matches.extend(
                 self.org_rules
                     .iter()
-                    .filter(|r| r.enabled && r.org.eq_ignore_ascii_case(owner))
+                    .filter(|r| {
+                        r.enabled
+                            && r.org.eq_ignore_ascii_case(owner)
+                            && schedule_allows(&r.schedule, now)
+                    })
                     .map(|r| MatchResult {
                         id: r.id.clone(),
                         priority: r.priority,
@@ -601,14 +854,25 @@ impl NotificationRuleSet {
                     if !r.notification_type.eq_ignore_ascii_case(notification_type) {
                         return false;
                     }
+                    if !schedule_allows(&r.schedule, now) {
+                        return false;
+                    }
 
-                    match (&r.account, account) {
+                    let account_matches = match (&r.account, account) {
                         (None, _) => true,
                         (Some(rule_acc), Some(notif_acc)) => {
                             rule_acc.eq_ignore_ascii_case(notif_acc)
                         }
-                        (Some(_), None) => allow_loose_account_match,
-                    }
+                        (Some(_), None) => allow_loose_match,
+                    };
+                    let title_matches = match (r.title_pattern(), title) {
+                        (None, _) => true,
+                        (Some(pattern), Some(title)) => {
+                            title.to_lowercase().contains(&pattern.to_lowercase())
+                        }
+                        (Some(_), None) => allow_loose_match,
+                    };
+                    account_matches && title_matches
                 })
                 .map(|r| MatchResult {
                     id: r.id.clone(),
@@ -616,15 +880,40 @@ impl NotificationRuleSet {
                     action: r.action,
                     reason: RuleDecisionReason::Type(r.notification_type.clone()),
                     rule_source: "Type".to_string(),
-                    name: format!(
-                        "{} ({})",
-                        r.notification_type,
-                        r.account.as_deref().unwrap_or("Global")
-                    ),
+                    name: match r.title_pattern() {
+                        Some(pattern) => format!(
+                            "{} ({}, title contains \"{pattern}\")",
+                            r.notification_type,
+                            r.account.as_deref().unwrap_or("Global")
+                        ),
+                        None => format!(
+                            "{} ({})",
+                            r.notification_type,
+                            r.account.as_deref().unwrap_or("Global")
+                        ),
+                    },
                     enabled: true,
                 }),
         );
 
+        // 4. Actor Rules (only once the actor has been looked up)
+        if let Some(actor) = actor {
+            matches.extend(
+                self.actor_rules
+                    .iter()
+                    .filter(|r| r.enabled && r.actor.eq_ignore_ascii_case(actor))
+                    .map(|r| MatchResult {
+                        id: r.id.clone(),
+                        priority: r.priority,
+                        action: r.action,
+                        reason: RuleDecisionReason::Actor(r.actor.clone()),
+                        rule_source: "Actor".to_string(),
+                        name: format!("@{}", r.actor),
+                        enabled: true,
+                    }),
+            );
+        }
+
         // Sorting Logic:
         // 1. Important action always wins (overrides Hide/Silent regardless of priority value)
         // 2. Then by numeric priority value (higher = more visible in UI)
@@ -669,13 +958,10 @@ impl RuleEngine {
 
     pub fn evaluate_detailed(
         &self,
-        notification_type: &str,
-        repo_owner: Option<&str>,
-        account: Option<&str>,
+        subject: &RuleSubject<'_>,
         now: &chrono::DateTime<Local>,
     ) -> (RuleAction, Option<RuleDecision>) {
-        self.rules
-            .evaluate_detailed(notification_type, repo_owner, account, now)
+        self.rules.evaluate_detailed(subject, now)
     }
 }
 
@@ -688,7 +974,7 @@ pub struct MatchResult {
     pub reason: RuleDecisionReason,
 
     // UI Helpers
-    pub rule_source: String, // "Account", "Org", "Type"
+    pub rule_source: String, // "Account", "Org", "Type", "Actor"
     pub name: String,
     pub enabled: bool,
 }
@@ -707,6 +993,19 @@ pub enum RuleDecisionReason {
     Account(String),
     Org(String),
     Type(String),
+    Actor(String),
+}
+
+impl RuleDecisionReason {
+    /// Short description of the matching rule, e.g. "Type rule: Comment".
+    pub fn describe(&self) -> String {
+        match self {
+            Self::Account(account) => format!("Account rule: {account}"),
+            Self::Org(org) => format!("Org rule: {org}"),
+            Self::Type(kind) => format!("Type rule: {kind}"),
+            Self::Actor(actor) => format!("Actor rule: {actor}"),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -782,6 +1081,7 @@ mod tests {
             org: "WorkOrg".to_string(),
             priority: 50,
             action: RuleAction::Important, // Force show!
+            schedule: None,
         };
         rules.org_rules.push(org_rule);
 
@@ -790,8 +1090,149 @@ mod tests {
 
         // Account rule says Hide. Org rule says Important.
         // Important should win.
-        let (action, _) =
-            engine.evaluate_detailed("mention", Some("WorkOrg"), Some("WorkAcc"), &now);
+        let subject = RuleSubject {
+            notification_type: "mention",
+            repo_owner: Some("WorkOrg"),
+            account: Some("WorkAcc"),
+            ..Default::default()
+        };
+        let (action, _) = engine.evaluate_detailed(&subject, &now);
+        assert_eq!(action, RuleAction::Important);
+    }
+
+    #[test]
+    fn test_type_rule_schedule() {
+        use chrono::TimeZone;
+
+        let mut rules = NotificationRuleSet {
+            enabled: true,
+            ..Default::default()
+        };
+
+        let mut rule = TypeRule::new("CI Activity", None, 0);
+        rule.action = RuleAction::Hide;
+        // Silence CI outside of working hours only.
+        rule.schedule = Some(RuleSchedule {
+            active_days: default_active_days(),
+            start_time: NaiveTime::from_hms_opt(17, 0, 0),
+            end_time: NaiveTime::from_hms_opt(9, 0, 0),
+        });
+        rules.type_rules.push(rule);
+
+        let evening = Local.with_ymd_and_hms(2024, 1, 3, 20, 0, 0).unwrap();
+        let working = Local.with_ymd_and_hms(2024, 1, 3, 11, 0, 0).unwrap();
+
+        let subject = RuleSubject {
+            notification_type: "CI Activity",
+            ..Default::default()
+        };
+        let (action, _) = rules.evaluate_detailed(&subject, &evening);
+        assert_eq!(action, RuleAction::Hide);
+
+        let (action, decision) = rules.evaluate_detailed(&subject, &working);
+        assert_eq!(action, RuleAction::Show);
+        assert!(decision.is_none());
+    }
+
+    #[test]
+    fn test_actor_rule_requires_known_actor() {
+        let mut rules = NotificationRuleSet {
+            enabled: true,
+            ..Default::default()
+        };
+
+        let mut rule = ActorRule::new("dependabot[bot]", 0);
+        rule.action = RuleAction::Silent;
+        rules.actor_rules.push(rule);
+
+        let now = Local::now();
+
+        let subject = RuleSubject {
+            notification_type: "Comment",
+            actor: Some("Dependabot[bot]"),
+            ..Default::default()
+        };
+        let (action, decision) = rules.evaluate_detailed(&subject, &now);
+        assert_eq!(action, RuleAction::Silent);
+        assert_eq!(
+            decision.map(|d| d.reason),
+            Some(RuleDecisionReason::Actor("dependabot[bot]".to_string()))
+        );
+
+        // Not looked up yet: no match.
+        let subject = RuleSubject {
+            actor: None,
+            ..subject
+        };
+        let (action, decision) = rules.evaluate_detailed(&subject, &now);
+        assert_eq!(action, RuleAction::Show);
+        assert!(decision.is_none());
+    }
+
+    #[test]
+    fn test_type_rule_title_pattern() {
+        let mut rules = NotificationRuleSet {
+            enabled: true,
+            ..Default::default()
+        };
+
+        let mut rule = TypeRule::new("Security Alert", None, 0);
+        rule.action = RuleAction::Important;
+        rule.title_pattern = Some("cve".to_string());
+        rules.type_rules.push(rule);
+
+        let now = Local::now();
+        let subject = |title| RuleSubject {
+            notification_type: "Security Alert",
+            title,
+            ..Default::default()
+        };
+
+        let (action, _) = rules.evaluate_detailed(&subject(Some("Fix CVE-2024-1234")), &now);
         assert_eq!(action, RuleAction::Important);
+        let (action, _) = rules.evaluate_detailed(&subject(Some("Bump serde")), &now);
+        assert_eq!(action, RuleAction::Show);
+
+        // A blank pattern is no constraint at all
+        rules.type_rules[0].title_pattern = Some("  ".to_string());
+        let (action, _) = rules.evaluate_detailed(&subject(Some("Bump serde")), &now);
+        assert_eq!(action, RuleAction::Important);
+    }
+
+    #[test]
+    fn test_merge_regenerates_ids_and_replaces_account_rules() {
+        let mut existing = NotificationRuleSet::default();
+        existing.account_rules.push(AccountRule::new("alice"));
+        existing
+            .type_rules
+            .push(TypeRule::new("Mentioned", None, 0));
+        let alice_id = existing.account_rules[0].id.clone();
+
+        // Exporting and importing the same set must not collide on ids
+        let mut imported = existing.clone();
+        imported.enabled = true;
+        imported.account_rules[0].outside_behavior = OutsideScheduleBehavior::Defer;
+        imported
+            .actor_rules
+            .push(ActorRule::new("dependabot[bot]", 0));
+
+        assert_eq!(existing.merge(imported), 3);
+        assert!(existing.enabled);
+
+        assert_eq!(existing.account_rules.len(), 1);
+        assert_eq!(existing.account_rules[0].id, alice_id);
+        assert_eq!(
+            existing.account_rules[0].outside_behavior,
+            OutsideScheduleBehavior::Defer
+        );
+
+        assert_eq!(existing.type_rules.len(), 2);
+        assert_ne!(existing.type_rules[0].id, existing.type_rules[1].id);
+        assert_eq!(existing.actor_rules.len(), 1);
+    }
+
+    #[test]
+    fn test_rule_schedule_summary() {
+        assert_eq!(RuleSchedule::default().summary(), "Mon–Fri 09:00–17:00");
     }
 }
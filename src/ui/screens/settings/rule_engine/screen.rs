@@ -3,16 +3,20 @@
 use iced::widget::{Space, button, column, container, row, text, toggler};
 use iced::{Alignment, Element, Fill, Length, Task};
 
+use crate::github::types::NotificationView;
 use crate::settings::{AppSettings, IconTheme};
+use crate::ui::context::AppContext;
 use crate::ui::effects::{AppEffect, NavigateTo};
 use crate::ui::icons;
+use crate::ui::screens::notifications::{NotificationEngine, RuleImpact};
 use crate::ui::screens::settings::rule_engine::rules::{AccountRule, NotificationRuleSet};
 use crate::ui::theme;
 
-use super::messages::{InspectorMessage, RuleEngineMessage, RuleTab};
+use super::messages::{InspectorMessage, OverviewMessage, RuleEngineMessage, RuleTab};
 
 // Feature imports
 use crate::ui::features::account_rules::{self, AccountRulesState};
+use crate::ui::features::actor_rules::{self, ActorRuleFormState};
 use crate::ui::features::org_rules::{self, OrgRulesState};
 use crate::ui::features::rule_overview::{self, RuleOverviewState};
 use crate::ui::features::type_rules::{self, TypeRuleFormState};
@@ -22,20 +26,29 @@ pub struct RuleEngineScreen {
     pub rules: NotificationRuleSet,
     pub accounts: Vec<String>,
     pub icon_theme: IconTheme,
+    /// The active account's current notifications, for previewing rule impact.
+    notifications: Vec<NotificationView>,
 
     // UI State
     active_tab: RuleTab,
     inspector_selected_rule: Option<String>,
+    /// Outcome of the last export or import, shown in the header.
+    transfer_status: Option<Result<String, String>>,
 
     // Feature States
     account_rules: AccountRulesState,
     type_rules: TypeRuleFormState,
+    actor_rules: ActorRuleFormState,
     org_rules: OrgRulesState,
     overview: RuleOverviewState,
 }
 
 impl RuleEngineScreen {
-    pub fn new(mut rules: NotificationRuleSet, settings: AppSettings) -> Self {
+    pub fn new(
+        mut rules: NotificationRuleSet,
+        settings: AppSettings,
+        notifications: Vec<NotificationView>,
+    ) -> Self {
         let accounts: Vec<String> = settings
             .accounts
             .iter()
@@ -57,11 +70,18 @@ impl RuleEngineScreen {
             rules,
             accounts,
             icon_theme: settings.icon_theme,
-            active_tab: RuleTab::Overview, // Default tab
+            notifications,
+            active_tab: settings
+                .rule_engine_tab
+                .as_deref()
+                .map(RuleTab::from_key)
+                .unwrap_or_default(),
             inspector_selected_rule: None,
+            transfer_status: None,
 
             account_rules: AccountRulesState::default(),
             type_rules: TypeRuleFormState::default(),
+            actor_rules: ActorRuleFormState::default(),
             org_rules: OrgRulesState::default(),
             overview: RuleOverviewState::default(),
         }
@@ -69,7 +89,7 @@ impl RuleEngineScreen {
 
     pub fn update(&mut self, message: RuleEngineMessage) -> Task<RuleEngineMessage> {
         match message {
-            RuleEngineMessage::Back => Task::none(), // Handled by parent
+            RuleEngineMessage::Back | RuleEngineMessage::Escape => Task::none(), // Handled by parent
             RuleEngineMessage::SelectTab(tab) => {
                 self.active_tab = tab;
                 Task::none()
@@ -82,6 +102,7 @@ impl RuleEngineScreen {
                     account_rules = self.rules.account_rules.len(),
                     org_rules = self.rules.org_rules.len(),
                     type_rules = self.rules.type_rules.len(),
+                    actor_rules = self.rules.actor_rules.len(),
                     active_rules = self.rules.active_rule_count(),
                     "Rule engine enabled state updated"
                 );
@@ -102,6 +123,11 @@ impl RuleEngineScreen {
                 let task = type_rules::update_type_rule(&mut self.type_rules, msg, &mut self.rules);
                 task.map(RuleEngineMessage::Type)
             }
+            RuleEngineMessage::Actor(msg) => {
+                let task =
+                    actor_rules::update_actor_rule(&mut self.actor_rules, msg, &mut self.rules);
+                task.map(RuleEngineMessage::Actor)
+            }
             RuleEngineMessage::Org(msg) => {
                 let task = org_rules::update::update(&mut self.org_rules, msg, &mut self.rules);
                 task.map(RuleEngineMessage::Org)
@@ -113,6 +139,45 @@ impl RuleEngineScreen {
                 task.map(RuleEngineMessage::Overview)
             }
 
+            RuleEngineMessage::ExportRules => {
+                Task::perform(export_with_dialog(self.rules.clone()), |result| {
+                    RuleEngineMessage::RulesExported(result)
+                })
+            }
+            RuleEngineMessage::RulesExported(result) => {
+                match result {
+                    Ok(Some(file_name)) => {
+                        tracing::info!("Rules exported");
+                        self.transfer_status = Some(Ok(format!("Exported to {file_name}")));
+                    }
+                    Ok(None) => {}
+                    Err(e) => {
+                        tracing::warn!(error = %e, "Rule export failed");
+                        self.transfer_status = Some(Err(e));
+                    }
+                }
+                Task::none()
+            }
+            RuleEngineMessage::ImportRules => {
+                Task::perform(import_with_dialog(), RuleEngineMessage::RulesImported)
+            }
+            RuleEngineMessage::RulesImported(result) => {
+                match result {
+                    Ok(Some(imported)) => {
+                        let count = self.rules.merge(imported);
+                        let _ = self.rules.save();
+                        tracing::info!(count, "Rules imported");
+                        self.transfer_status = Some(Ok(format!("Imported {count} rules")));
+                    }
+                    Ok(None) => {}
+                    Err(e) => {
+                        tracing::warn!(error = %e, "Rule import failed");
+                        self.transfer_status = Some(Err(e));
+                    }
+                }
+                Task::none()
+            }
+
             RuleEngineMessage::Inspector(msg) => match msg {
                 InspectorMessage::Select(id) => {
                     self.inspector_selected_rule = Some(id);
@@ -122,6 +187,10 @@ impl RuleEngineScreen {
                     self.inspector_selected_rule = None;
                     Task::none()
                 }
+                other => {
+                    super::inspector::update_schedule(&mut self.rules, other);
+                    Task::none()
+                }
             },
         }
     }
@@ -130,9 +199,30 @@ impl RuleEngineScreen {
     pub fn update_with_effect(
         &mut self,
         message: RuleEngineMessage,
+        ctx: &mut AppContext,
     ) -> (Task<RuleEngineMessage>, AppEffect) {
         match message {
             RuleEngineMessage::Back => (Task::none(), AppEffect::Navigate(NavigateTo::Back)),
+            RuleEngineMessage::Escape if self.inspector_selected_rule.is_some() => (
+                self.update(RuleEngineMessage::Inspector(InspectorMessage::Close)),
+                AppEffect::None,
+            ),
+            RuleEngineMessage::Escape => (Task::none(), AppEffect::Navigate(NavigateTo::Back)),
+            RuleEngineMessage::SelectTab(tab) => {
+                let task = self.update(message);
+                ctx.settings.rule_engine_tab = Some(tab.key().to_string());
+                ctx.settings.save_silent();
+                (task, AppEffect::None)
+            }
+            RuleEngineMessage::Overview(OverviewMessage::SimulateUrl) => (
+                rule_overview::update::simulate_url(
+                    &mut self.overview,
+                    &self.notifications,
+                    &ctx.sessions,
+                )
+                .map(RuleEngineMessage::Overview),
+                AppEffect::None,
+            ),
             other => (self.update(other), AppEffect::None),
         }
     }
@@ -169,6 +259,17 @@ impl RuleEngineScreen {
                 }),
             ],
             Space::new().width(Fill),
+            self.view_transfer_status(),
+            Space::new().width(12),
+            button(text("Import").size(12))
+                .style(theme::ghost_button)
+                .padding([6, 10])
+                .on_press(RuleEngineMessage::ImportRules),
+            button(text("Export").size(12))
+                .style(theme::ghost_button)
+                .padding([6, 10])
+                .on_press(RuleEngineMessage::ExportRules),
+            Space::new().width(12),
             toggler(self.rules.enabled)
                 .on_toggle(RuleEngineMessage::ToggleEnabled)
                 .width(Length::Shrink)
@@ -199,6 +300,11 @@ impl RuleEngineScreen {
                 self.active_tab == RuleTab::OrgRules,
                 RuleEngineMessage::SelectTab(RuleTab::OrgRules)
             ),
+            view_tab_title(
+                "Actor Rules",
+                self.active_tab == RuleTab::ActorRules,
+                RuleEngineMessage::SelectTab(RuleTab::ActorRules)
+            ),
         ]
         .spacing(24)
         .padding([0, 24]);
@@ -230,6 +336,34 @@ impl RuleEngineScreen {
         }
     }
 
+    fn view_transfer_status(&self) -> Element<'_, RuleEngineMessage> {
+        let p = theme::palette();
+        match &self.transfer_status {
+            Some(Ok(status)) => text(status).size(12).color(p.accent_success).into(),
+            Some(Err(e)) => text(e).size(12).color(p.accent_danger).into(),
+            None => Space::new().into(),
+        }
+    }
+
+    /// What adding the type rule form's draft would do to current notifications.
+    fn draft_type_rule_impact(&self) -> Option<RuleImpact> {
+        let draft = self.type_rules.draft_rule();
+        let draft_id = draft.id.clone();
+        let mut rules = self.rules.clone();
+        rules.type_rules.push(draft);
+        self.rule_impact(rules, &draft_id)
+    }
+
+    /// Dry-run `rule_id` within `rules` against the current notifications.
+    /// Evaluated as if the engine were on, so a paused engine still previews.
+    fn rule_impact(&self, mut rules: NotificationRuleSet, rule_id: &str) -> Option<RuleImpact> {
+        if self.notifications.is_empty() {
+            return None;
+        }
+        rules.enabled = true;
+        Some(NotificationEngine::new(rules).impact_of(rule_id, &self.notifications))
+    }
+
     fn view_tab_content(&self) -> Element<'_, RuleEngineMessage> {
         match self.active_tab {
             RuleTab::Overview => {
@@ -245,6 +379,7 @@ impl RuleEngineScreen {
                     &self.type_rules,
                     &self.accounts,
                     &self.type_rules.expanded_groups,
+                    self.draft_type_rule_impact(),
                 )
             }
             RuleTab::AccountRules => {
@@ -255,8 +390,15 @@ impl RuleEngineScreen {
                     &self.account_rules.selected_account_id,
                     &self.account_rules.expanded_time_windows,
                     &self.accounts,
+                    self.account_rules
+                        .selected_account_id
+                        .as_deref()
+                        .and_then(|id| self.rule_impact(self.rules.clone(), id)),
                 )
             }
+            RuleTab::ActorRules => {
+                actor_rules::view_actor_rules_tab(&self.rules, self.icon_theme, &self.actor_rules)
+            }
             RuleTab::OrgRules => {
                 // Returns OrgMessage -> map to RuleEngineMessage::Org
                 org_rules::view(&self.rules, self.icon_theme).map(RuleEngineMessage::Org)
@@ -300,3 +442,32 @@ fn view_tab_title<'a>(
         .on_press(on_press)
         .into()
 }
+
+/// Ask where to save `rules` and write them there. Returns the file name, or
+/// `None` if the dialog was cancelled.
+async fn export_with_dialog(rules: NotificationRuleSet) -> Result<Option<String>, String> {
+    let Some(file) = rfd::AsyncFileDialog::new()
+        .set_title("Export GitTop rules")
+        .set_file_name("gittop-rules.json")
+        .add_filter("JSON", &["json"])
+        .save_file()
+        .await
+    else {
+        return Ok(None);
+    };
+    rules.export_to(file.path())?;
+    Ok(Some(file.file_name()))
+}
+
+/// Ask for a rules file and read it. `None` if the dialog was cancelled.
+async fn import_with_dialog() -> Result<Option<NotificationRuleSet>, String> {
+    let Some(file) = rfd::AsyncFileDialog::new()
+        .set_title("Import GitTop rules")
+        .add_filter("JSON", &["json"])
+        .pick_file()
+        .await
+    else {
+        return Ok(None);
+    };
+    NotificationRuleSet::import_from(file.path()).map(Some)
+}
@@ -1,13 +1,18 @@
 //! Settings screen - main screen with tab navigation.
 
-use iced::widget::{Space, button, column, container, row, scrollable, text};
+use iced::widget::{Space, button, column, container, row, scrollable, stack, text};
 use iced::{Alignment, Element, Fill, Length, Task};
 
+use crate::backup::ConfigBackup;
+use crate::i18n::{Key, tr};
 use crate::settings::AppSettings;
 use crate::ui::context::AppContext;
 use crate::ui::effects::{AppEffect, NavigateTo, SessionEffect};
 use crate::ui::features::account_management::AccountMessage;
+use crate::ui::features::general_settings::GeneralMessage;
+use crate::ui::features::network_proxy::{ConnectionCheck, ProxyMessage};
 use crate::ui::features::power_mode::PowerModeMessage;
+use crate::ui::features::sidebar::{self, SidebarResize};
 use crate::ui::features::{account_management, general_settings, network_proxy, power_mode};
 use crate::ui::{icons, theme};
 
@@ -22,12 +27,14 @@ pub struct SettingsScreen {
     pub proxy: network_proxy::NetworkProxyState,
     pub general: general_settings::GeneralSettingsState,
     pub power_mode: power_mode::PowerModeState,
+    /// The sidebar divider is being dragged.
+    sidebar_resizing: bool,
 }
 
 impl SettingsScreen {
     pub fn new(settings: AppSettings) -> Self {
         let proxy = network_proxy::NetworkProxyState::new(&settings);
-        let general = general_settings::GeneralSettingsState::new();
+        let general = general_settings::GeneralSettingsState::new(&settings);
         let power_mode = power_mode::PowerModeState::new();
         let accounts = account_management::AccountManagementState::default();
 
@@ -38,6 +45,7 @@ impl SettingsScreen {
             proxy,
             general,
             power_mode,
+            sidebar_resizing: false,
         }
     }
 
@@ -72,6 +80,22 @@ impl SettingsScreen {
                 power_mode::update(&mut self.power_mode, msg, &mut self.settings)
                     .map(SettingsMessage::PowerMode)
             }
+            SettingsMessage::SidebarResize(step) => {
+                match step {
+                    SidebarResize::Start => self.sidebar_resizing = true,
+                    SidebarResize::Drag(x) if self.sidebar_resizing => {
+                        self.settings.sidebar_width = sidebar::resize::clamp_width(x);
+                    }
+                    SidebarResize::Drag(_) => {}
+                    SidebarResize::End if self.sidebar_resizing => {
+                        self.sidebar_resizing = false;
+                        let _ = self.settings.save();
+                        tracing::debug!(width = self.settings.sidebar_width, "Sidebar resized");
+                    }
+                    SidebarResize::End => {}
+                }
+                Task::none()
+            }
         }
     }
 
@@ -79,7 +103,7 @@ impl SettingsScreen {
     pub fn update_with_effect(
         &mut self,
         message: SettingsMessage,
-        _ctx: &mut AppContext,
+        ctx: &mut AppContext,
     ) -> (Task<SettingsMessage>, AppEffect) {
         match &message {
             // Navigation becomes effects
@@ -99,6 +123,24 @@ impl SettingsScreen {
                     AppEffect::Session(SessionEffect::RemoveAccount(username)),
                 )
             }
+            SettingsMessage::General(GeneralMessage::ConfirmReset) => {
+                (Task::none(), AppEffect::Session(SessionEffect::ResetAll))
+            }
+            SettingsMessage::General(GeneralMessage::RestoreLoaded(Ok(Some(backup)))) => {
+                (self.restore_config(backup), AppEffect::None)
+            }
+            SettingsMessage::General(GeneralMessage::SignInRestoredAccount(username))
+            | SettingsMessage::Account(AccountMessage::Reauthenticate(username)) => (
+                Task::none(),
+                AppEffect::Navigate(NavigateTo::Reauthenticate(username.clone())),
+            ),
+            // Show the new name in the tray and switchers right away
+            SettingsMessage::Account(AccountMessage::SaveDisplayName(username)) => {
+                if let Some(name) = self.accounts.display_name_drafts.get(username) {
+                    ctx.settings.set_display_name(username, name);
+                }
+                (self.update(message), AppEffect::None)
+            }
             SettingsMessage::Account(AccountMessage::TokenValidated(Ok(_username))) => {
                 // For token validation, we need to spawn the async restore task
                 // We handle this in the screen's normal update, not as an effect
@@ -106,6 +148,12 @@ impl SettingsScreen {
                 (task, AppEffect::None)
             }
 
+            // Saved proxy settings apply to the live clients right away
+            SettingsMessage::Proxy(ProxyMessage::Save) => {
+                let task = self.update(message);
+                (Task::batch([task, self.apply_proxy(ctx)]), AppEffect::None)
+            }
+
             // Power mode toggle with window resize - handled by app.rs effect executor
             SettingsMessage::PowerMode(PowerModeMessage::Toggle(_)) => {
                 let task = self.update(message);
@@ -117,6 +165,74 @@ impl SettingsScreen {
         }
     }
 
+    /// Replace settings and rules with a backup's, keeping the accounts
+    /// signed in here, and list the backup's accounts that need a token.
+    /// Save the backed-up configuration and apply it the way the settings
+    /// themselves would, including start on boot.
+    fn restore_config(&mut self, backup: &ConfigBackup) -> Task<SettingsMessage> {
+        let settings = backup.restored_settings(&self.settings);
+        if let Err(e) = backup.rules.save().and_then(|()| settings.save()) {
+            tracing::error!(error = %e, "Failed to write restored configuration");
+            self.general.backup_status = Some(Err(format!("Couldn't save: {e}")));
+            return Task::none();
+        }
+        settings.apply_theme();
+
+        self.settings = settings;
+        self.general = general_settings::GeneralSettingsState::new(&self.settings);
+        self.proxy = network_proxy::NetworkProxyState::new(&self.settings);
+        general_settings::update::apply_system_settings(&self.settings, &mut self.general);
+        self.general.backup_status = Some(Ok(
+            "Restored. Some window options apply after a restart.".to_string(),
+        ));
+        tracing::info!(
+            accounts = backup.accounts.len(),
+            "Configuration restored from backup"
+        );
+
+        match backup.start_on_boot {
+            Some(enabled) if enabled != self.general.start_on_boot_enabled => Task::done(
+                SettingsMessage::General(GeneralMessage::ToggleStartOnBoot(enabled)),
+            ),
+            _ => Task::none(),
+        }
+    }
+
+    /// Rebuild the clients with the just-saved proxy settings and check that
+    /// GitHub answers through them.
+    fn apply_proxy(&mut self, ctx: &mut AppContext) -> Task<SettingsMessage> {
+        if let Err(e) = ctx
+            .sessions
+            .rebuild_clients_with_proxy(&self.settings.proxy)
+        {
+            tracing::error!(
+                error = %e,
+                "Failed to rebuild GitHub clients with updated proxy settings"
+            );
+            self.proxy.check = ConnectionCheck::Failed(e.to_string());
+            return Task::none();
+        }
+        // Already applied; nothing left to rebuild on the way out
+        ctx.settings.proxy = self.settings.proxy.clone();
+        self.proxy.needs_rebuild = false;
+
+        let Some(session) = ctx.sessions.primary() else {
+            return Task::none();
+        };
+        let client = session.client.clone();
+        self.proxy.check = ConnectionCheck::Checking;
+        Task::perform(
+            async move {
+                client
+                    .get_authenticated_user()
+                    .await
+                    .map(|_| ())
+                    .map_err(|e| e.to_string())
+            },
+            |result| SettingsMessage::Proxy(ProxyMessage::Checked(result)),
+        )
+    }
+
     // ========================================================================
     // Main Layout
     // ========================================================================
@@ -126,13 +242,31 @@ impl SettingsScreen {
         let sidebar = self.view_sidebar();
         let content = self.view_content();
 
-        let main_area = row![sidebar, content].height(Fill);
+        let main_area = sidebar::resize::track(
+            row![
+                sidebar,
+                sidebar::resize::handle(self.sidebar_resizing, SettingsMessage::SidebarResize),
+                content
+            ]
+            .height(Fill),
+            self.sidebar_resizing,
+            SettingsMessage::SidebarResize,
+        );
 
-        column![header, main_area]
+        let screen = column![header, main_area]
             .spacing(0)
             .width(Fill)
-            .height(Fill)
-            .into()
+            .height(Fill);
+
+        match &self.accounts.pending_removal {
+            Some(username) => stack![
+                screen,
+                account_management::view::view_remove_confirmation(username)
+                    .map(SettingsMessage::Account)
+            ]
+            .into(),
+            None => screen.into(),
+        }
     }
 
     fn view_header(&self) -> Element<'_, SettingsMessage> {
@@ -143,7 +277,7 @@ impl SettingsScreen {
             row![
                 icons::icon_chevron_left(16.0, p.text_secondary, icon_theme),
                 Space::new().width(4),
-                text("Back").size(13).color(p.text_secondary),
+                text(tr(Key::Back)).size(13).color(p.text_secondary),
             ]
             .align_y(Alignment::Center),
         )
@@ -151,7 +285,7 @@ impl SettingsScreen {
         .padding([6, 10])
         .on_press(SettingsMessage::Back);
 
-        let title = text("Settings").size(18).color(p.text_primary);
+        let title = text(tr(Key::Settings)).size(18).color(p.text_primary);
 
         let header_row = row![
             back_btn,
@@ -177,22 +311,22 @@ impl SettingsScreen {
 
         let nav = column![
             self.nav_item(
-                "Power Mode",
+                tr(Key::PowerMode),
                 SettingsTab::PowerMode,
                 icons::icon_power(16.0, self.icon_color(SettingsTab::PowerMode), icon_theme)
             ),
             self.nav_item(
-                "General",
+                tr(Key::General),
                 SettingsTab::General,
                 icons::icon_settings(16.0, self.icon_color(SettingsTab::General), icon_theme)
             ),
             self.nav_item(
-                "Accounts",
+                tr(Key::Accounts),
                 SettingsTab::Accounts,
                 icons::icon_user(16.0, self.icon_color(SettingsTab::Accounts), icon_theme)
             ),
             self.nav_item(
-                "Network Proxy",
+                tr(Key::NetworkProxy),
                 SettingsTab::NetworkProxy,
                 icons::icon_wifi(16.0, self.icon_color(SettingsTab::NetworkProxy), icon_theme)
             ),
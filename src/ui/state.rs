@@ -2,9 +2,13 @@
 
 use std::sync::Mutex;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
 
+use chrono::{DateTime, Local};
 use iced::window::Id as WindowId;
-use iced::{Task, window};
+use iced::{Size, Task, window};
+
+use crate::settings::AppSettings;
 
 /// Power mode window dimensions
 const POWER_MODE_WIDTH: f32 = 1410.0;
@@ -13,6 +17,8 @@ const POWER_MODE_HEIGHT: f32 = 700.0;
 static MAIN_WINDOW_ID: Mutex<Option<WindowId>> = Mutex::new(None);
 static IS_WINDOW_HIDDEN: AtomicBool = AtomicBool::new(false);
 static IS_WINDOW_FOCUSED: AtomicBool = AtomicBool::new(true);
+static PAUSED_UNTIL: Mutex<Option<DateTime<Local>>> = Mutex::new(None);
+static LAST_ACTIVITY: Mutex<Option<Instant>> = Mutex::new(None);
 
 pub fn set_window_id(id: WindowId) {
     if let Ok(mut guard) = MAIN_WINDOW_ID.lock() {
@@ -20,6 +26,13 @@ pub fn set_window_id(id: WindowId) {
     }
 }
 
+/// The main window was closed (daemon mode keeps running without it).
+pub fn clear_window_id() {
+    if let Ok(mut guard) = MAIN_WINDOW_ID.lock() {
+        *guard = None;
+    }
+}
+
 pub fn get_window_id() -> Option<WindowId> {
     MAIN_WINDOW_ID.lock().ok().and_then(|guard| *guard)
 }
@@ -43,6 +56,102 @@ pub fn is_focused() -> bool {
 
 pub fn set_focused(focused: bool) {
     IS_WINDOW_FOCUSED.store(focused, Ordering::Relaxed);
+    mark_activity();
+}
+
+/// Note that the user just interacted with the window or tray.
+pub fn mark_activity() {
+    if let Ok(mut guard) = LAST_ACTIVITY.lock() {
+        *guard = Some(Instant::now());
+    }
+}
+
+/// Time since the last `mark_activity`, or `Duration::MAX` if never.
+pub fn since_activity() -> Duration {
+    LAST_ACTIVITY
+        .lock()
+        .ok()
+        .and_then(|guard| *guard)
+        .map_or(Duration::MAX, |at| at.elapsed())
+}
+
+/// Pause refreshes and desktop alerts for `minutes`.
+pub fn pause_for(minutes: u32) {
+    let until = Local::now() + chrono::TimeDelta::minutes(minutes.into());
+    tracing::info!(minutes, until = %until.format("%H:%M"), "Notifications paused");
+    if let Ok(mut guard) = PAUSED_UNTIL.lock() {
+        *guard = Some(until);
+    }
+}
+
+/// End a pause early.
+pub fn resume() {
+    if let Ok(mut guard) = PAUSED_UNTIL.lock()
+        && guard.take().is_some()
+    {
+        tracing::info!("Notifications resumed");
+    }
+}
+
+/// When the current pause ends, or `None` if not paused.
+pub fn paused_until() -> Option<DateTime<Local>> {
+    PAUSED_UNTIL
+        .lock()
+        .ok()
+        .and_then(|guard| *guard)
+        .filter(|until| *until > Local::now())
+}
+
+/// Clear a pause whose time is up. Returns true exactly once per expiry.
+pub fn take_expired_pause() -> bool {
+    let Ok(mut guard) = PAUSED_UNTIL.lock() else {
+        return false;
+    };
+    if guard.is_some_and(|until| until <= Local::now()) {
+        *guard = None;
+        tracing::info!("Notification pause ended");
+        return true;
+    }
+    false
+}
+
+/// Window size for the current layout; mini mode keeps its own.
+pub fn window_size(settings: &AppSettings) -> Size {
+    let (width, height) = if settings.mini_mode {
+        (settings.mini_window_width, settings.mini_window_height)
+    } else {
+        (settings.window_width, settings.window_height)
+    };
+    crate::settings::validated_window_size(width, height)
+        .map_or(Size::new(800.0, 640.0), |(width, height)| {
+            Size::new(width, height)
+        })
+}
+
+pub fn window_level(settings: &AppSettings) -> window::Level {
+    if settings.always_on_top || (settings.mini_mode && settings.mini_mode_on_top) {
+        window::Level::AlwaysOnTop
+    } else {
+        window::Level::Normal
+    }
+}
+
+/// Resize and re-level the window after entering or leaving mini mode.
+pub fn apply_window_mode<T: Send + 'static>(settings: &AppSettings) -> Task<T> {
+    get_window_id().map_or(Task::none(), |id| {
+        Task::batch([
+            window::resize::<T>(id, window_size(settings)),
+            window::set_level::<T>(id, window_level(settings)),
+        ])
+        .discard()
+    })
+}
+
+/// Re-level the window after an always-on-top setting changed.
+pub fn apply_window_level<T: Send + 'static>(settings: &AppSettings) -> Task<T> {
+    get_window_id().map_or(Task::none(), |id| {
+        window::set_level::<T>(id, window_level(settings)).discard()
+    })
 }
 
 pub fn resize_for_power_mode<T: Send + 'static>() -> Task<T> {
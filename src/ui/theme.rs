@@ -7,7 +7,7 @@
 
 use iced::widget::{button, container, pick_list, scrollable, text, text_input};
 use iced::{Background, Border, Color, Theme};
-use std::sync::atomic::{AtomicU8, AtomicU32, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU8, AtomicU32, Ordering};
 
 use crate::settings::AppTheme;
 
@@ -38,6 +38,25 @@ fn card_border(radius: f32) -> Border {
     }
 }
 
+/// Button border: invisible normally, but themes with an outline width
+/// (High Contrast) draw a thin resting border and a thick focus ring on
+/// hover/press so the target is obvious.
+#[inline]
+fn button_border(p: &ThemePalette, status: button::Status, radius: f32) -> Border {
+    if p.outline_width <= 0.0 {
+        return card_border(radius);
+    }
+    let (color, width) = match status {
+        button::Status::Hovered | button::Status::Pressed => (p.focus_ring, p.outline_width),
+        _ => (p.border, 1.0),
+    };
+    Border {
+        color,
+        width,
+        radius: radius.into(),
+    }
+}
+
 // ============================================================================
 // THEME PALETTE - Dynamic colors based on selected theme
 // ============================================================================
@@ -60,6 +79,12 @@ pub struct ThemePalette {
     pub accent_purple: Color,
     pub border: Color,
     pub border_subtle: Color,
+    /// Width of the outline drawn around hovered/pressed controls (0 = none).
+    pub outline_width: f32,
+    /// Outline color for hovered, pressed, and focused controls.
+    pub focus_ring: Color,
+    /// Extra padding around list items, for larger hit targets.
+    pub hit_padding: f32,
 }
 
 pub const LIGHT: ThemePalette = ThemePalette {
@@ -86,6 +111,11 @@ pub const LIGHT: ThemePalette = ThemePalette {
     // Borders - light grey
     border: Color::from_rgb(0.82, 0.82, 0.85),
     border_subtle: Color::from_rgb(0.90, 0.90, 0.92),
+
+    // Accessibility
+    outline_width: 0.0,
+    focus_ring: Color::from_rgb(0.10, 0.46, 0.82),
+    hit_padding: 0.0,
 };
 
 pub const STEAM: ThemePalette = ThemePalette {
@@ -112,6 +142,11 @@ pub const STEAM: ThemePalette = ThemePalette {
     // Borders
     border: Color::from_rgb(0.30, 0.38, 0.48),
     border_subtle: Color::from_rgb(0.22, 0.28, 0.36),
+
+    // Accessibility
+    outline_width: 0.0,
+    focus_ring: Color::from_rgb(0.40, 0.75, 0.95),
+    hit_padding: 0.0,
 };
 
 pub const GTK_DARK: ThemePalette = ThemePalette {
@@ -138,6 +173,11 @@ pub const GTK_DARK: ThemePalette = ThemePalette {
     // Borders
     border: Color::from_rgb(0.35, 0.35, 0.35),
     border_subtle: Color::from_rgb(0.25, 0.25, 0.25),
+
+    // Accessibility
+    outline_width: 0.0,
+    focus_ring: Color::from_rgb(0.21, 0.52, 0.89),
+    hit_padding: 0.0,
 };
 
 pub const WINDOWS11: ThemePalette = ThemePalette {
@@ -164,6 +204,11 @@ pub const WINDOWS11: ThemePalette = ThemePalette {
     // Borders
     border: Color::from_rgb(0.35, 0.35, 0.35),
     border_subtle: Color::from_rgb(0.25, 0.25, 0.25),
+
+    // Accessibility
+    outline_width: 0.0,
+    focus_ring: Color::from_rgb(0.38, 0.80, 1.0),
+    hit_padding: 0.0,
 };
 
 pub const MACOS: ThemePalette = ThemePalette {
@@ -190,6 +235,11 @@ pub const MACOS: ThemePalette = ThemePalette {
     // Borders
     border: Color::from_rgb(0.30, 0.30, 0.32),
     border_subtle: Color::from_rgb(0.22, 0.22, 0.24),
+
+    // Accessibility
+    outline_width: 0.0,
+    focus_ring: Color::from_rgb(0.04, 0.52, 1.0),
+    hit_padding: 0.0,
 };
 
 pub const HIGH_CONTRAST: ThemePalette = ThemePalette {
@@ -216,6 +266,11 @@ pub const HIGH_CONTRAST: ThemePalette = ThemePalette {
     // Borders - visible
     border: Color::from_rgb(0.50, 0.50, 0.50),
     border_subtle: Color::from_rgb(0.35, 0.35, 0.35),
+
+    // Accessibility
+    outline_width: 2.0,
+    focus_ring: Color::from_rgb(1.0, 0.85, 0.0),
+    hit_padding: 4.0,
 };
 
 impl ThemePalette {
@@ -248,6 +303,9 @@ mod theme_state {
     /// Global font scale for sidebar
     static SIDEBAR_FONT_SCALE: AtomicU32 = AtomicU32::new(1065353216); // 1.0f32 as u32 bits
 
+    /// Skip flashes and other transitions
+    static REDUCED_MOTION: AtomicBool = AtomicBool::new(false);
+
     pub fn set_theme(theme: AppTheme) {
         CURRENT_THEME.store(theme.to_u8(), Ordering::Relaxed);
     }
@@ -272,6 +330,14 @@ mod theme_state {
     pub fn sidebar_font_scale() -> f32 {
         f32::from_bits(SIDEBAR_FONT_SCALE.load(Ordering::Relaxed))
     }
+
+    pub fn set_reduced_motion(reduced: bool) {
+        REDUCED_MOTION.store(reduced, Ordering::Relaxed);
+    }
+
+    pub fn reduced_motion() -> bool {
+        REDUCED_MOTION.load(Ordering::Relaxed)
+    }
 }
 
 /// Set the current theme (call this when user changes theme in settings)
@@ -310,6 +376,16 @@ pub fn sidebar_font_scale() -> f32 {
     theme_state::sidebar_font_scale()
 }
 
+/// Turn off flashes and transitions (setting or OS preference)
+pub fn set_reduced_motion(reduced: bool) {
+    theme_state::set_reduced_motion(reduced);
+}
+
+/// Whether flashes and transitions should be skipped
+pub fn reduced_motion() -> bool {
+    theme_state::reduced_motion()
+}
+
 /// Get a scaled font size for sidebar
 #[inline]
 pub fn sidebar_scaled(base_size: f32) -> f32 {
@@ -357,6 +433,20 @@ pub fn sidebar(_: &Theme) -> container::Style {
     })
 }
 
+/// Divider between a sidebar and the content; lit up while being dragged.
+pub fn resize_handle(active: bool) -> impl Fn(&Theme) -> container::Style {
+    move |_| {
+        with_palette(|p| container::Style {
+            background: Some(Background::Color(if active {
+                p.accent
+            } else {
+                p.bg_sidebar
+            })),
+            ..Default::default()
+        })
+    }
+}
+
 pub fn priority_header_container(_: &Theme) -> container::Style {
     with_palette(|p| container::Style {
         background: Some(Background::Color(Color::from_rgba(
@@ -379,6 +469,25 @@ pub fn priority_header_container(_: &Theme) -> container::Style {
     })
 }
 
+/// Brighter variant of `priority_header_container`, shown briefly when a new
+/// priority item lands while the window is in use.
+pub fn priority_header_flash_container(_: &Theme) -> container::Style {
+    with_palette(|p| container::Style {
+        background: Some(Background::Color(Color::from_rgba(
+            p.accent_warning.r,
+            p.accent_warning.g,
+            p.accent_warning.b,
+            0.25,
+        ))),
+        border: Border {
+            radius: 6.0.into(),
+            color: p.accent_warning,
+            width: 1.0,
+        },
+        ..Default::default()
+    })
+}
+
 // ============================================================================
 // BUTTON STYLES
 // ============================================================================
@@ -397,7 +506,25 @@ pub fn primary_button(_: &Theme, status: button::Status) -> button::Style {
         button::Style {
             background: Some(Background::Color(bg)),
             text_color: Color::BLACK,
-            border: card_border(6.0),
+            border: button_border(&p, status, 6.0),
+            ..Default::default()
+        }
+    })
+}
+
+/// Solid button for destructive actions.
+pub fn danger_button(_: &Theme, status: button::Status) -> button::Style {
+    with_palette(|p| {
+        let c = p.accent_danger;
+        let bg = match status {
+            button::Status::Hovered => Color::from_rgba(c.r * 0.9, c.g * 0.9, c.b * 0.9, 1.0),
+            button::Status::Pressed => Color::from_rgba(c.r * 0.8, c.g * 0.8, c.b * 0.8, 1.0),
+            _ => c,
+        };
+        button::Style {
+            background: Some(Background::Color(bg)),
+            text_color: Color::WHITE,
+            border: button_border(&p, status, 6.0),
             ..Default::default()
         }
     })
@@ -415,7 +542,7 @@ pub fn ghost_button(_: &Theme, status: button::Status) -> button::Style {
             button::Status::Pressed => p.text_secondary,
             _ => p.text_primary,
         },
-        border: card_border(6.0),
+        border: button_border(&p, status, 6.0),
         ..Default::default()
     })
 }
@@ -465,7 +592,7 @@ pub fn notification_button(_: &Theme, status: button::Status) -> button::Style {
             p.bg_active,
         ))),
         text_color: p.text_primary,
-        border: card_border(6.0),
+        border: button_border(&p, status, 6.0),
         ..Default::default()
     })
 }
@@ -484,7 +611,7 @@ pub fn sidebar_button(selected: bool) -> impl Fn(&Theme, button::Status) -> butt
             button::Style {
                 background: Some(Background::Color(bg)),
                 text_color: text,
-                border: card_border(4.0),
+                border: button_border(&p, status, 4.0),
                 ..Default::default()
             }
         })
@@ -560,7 +687,9 @@ pub fn secondary_text(_: &Theme) -> text::Style {
 pub fn text_input_style(_: &Theme, status: text_input::Status) -> text_input::Style {
     with_palette(|p| {
         let (bg, border_color, border_width) = match status {
-            text_input::Status::Focused { .. } => (p.bg_base, p.accent, 2.0),
+            text_input::Status::Focused { .. } => {
+                (p.bg_base, p.focus_ring, p.outline_width.max(1.0) + 1.0)
+            }
             text_input::Status::Hovered => (p.bg_hover, p.border, 1.0),
             _ => (p.bg_control, p.border, 1.0),
         };
@@ -1,9 +1,14 @@
 //! Update checker for GitTop.
 //!
-//! Checks GitHub releases API for newer stable versions.
+//! Checks GitHub releases API for newer stable versions and fetches the
+//! notes for the running release after an upgrade.
+
+use std::time::Duration;
 
 use serde::Deserialize;
 
+use crate::settings::ProxySettings;
+
 /// Information about an available update.
 #[derive(Debug, Clone)]
 pub struct UpdateInfo {
@@ -13,8 +18,30 @@ pub struct UpdateInfo {
     pub latest: String,
     /// URL to the release page
     pub release_url: String,
+    /// Direct download for this platform, if the release has a matching asset
+    pub download_url: Option<String>,
+    /// File name of the platform download
+    pub download_name: Option<String>,
+    /// Published SHA-256 checksum for the download (per-file or a sums list)
+    pub checksum_url: Option<String>,
 }
 
+/// Notes for a release the user has just upgraded to.
+#[derive(Debug, Clone)]
+pub struct ReleaseNotes {
+    /// Version the notes belong to
+    pub version: String,
+    /// Short plain-text summary, `None` if it wasn't fetched
+    pub summary: Option<String>,
+    /// URL to the release page
+    pub release_url: String,
+}
+
+/// Lines of release notes shown in the in-app panel.
+const SUMMARY_MAX_LINES: usize = 8;
+
+const RELEASES_API: &str = "https://api.github.com/repos/AmarBego/GitTop/releases";
+
 /// GitHub release response (minimal fields we need)
 #[derive(Debug, Deserialize)]
 struct GitHubRelease {
@@ -22,26 +49,162 @@ struct GitHubRelease {
     html_url: String,
     prerelease: bool,
     draft: bool,
+    #[serde(default)]
+    body: Option<String>,
+    #[serde(default)]
+    assets: Vec<GitHubAsset>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubAsset {
+    name: String,
+    browser_download_url: String,
+}
+
+/// Keep the check well clear of startup, even behind a slow proxy.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+/// Installers are a few megabytes; allow for slow links.
+pub(crate) const DOWNLOAD_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Release-wide checksum lists, in `<sha256>  <file name>` format.
+const CHECKSUM_LISTS: &[&str] = &["sha256sums.txt", "sha256sums", "checksums.txt"];
+
+/// Unauthenticated client for the releases API (60 req/hour is plenty).
+///
+/// Requests set their own timeout; see `REQUEST_TIMEOUT`.
+pub(crate) fn releases_client(proxy_settings: &ProxySettings) -> Option<reqwest::Client> {
+    crate::github::build_http_client(proxy_settings)
+        .inspect_err(|e| tracing::debug!(error = %e, "Could not build releases client"))
+        .ok()
+}
+
+/// Pick the release asset that matches the running platform.
+fn platform_asset(assets: &[GitHubAsset]) -> Option<&GitHubAsset> {
+    let (markers, extensions): (&[&str], &[&str]) = if cfg!(target_os = "windows") {
+        (&["windows", "win64", "msvc"], &[".msi", ".exe", ".zip"])
+    } else if cfg!(target_os = "macos") {
+        (
+            &["macos", "darwin", "apple"],
+            &[".dmg", ".pkg", ".tar.gz", ".zip"],
+        )
+    } else if cfg!(target_os = "freebsd") {
+        (&["freebsd"], &[".tar.gz", ".pkg"])
+    } else {
+        (&["linux"], &[".tar.gz", ".appimage"])
+    };
+    pick_asset(assets, markers, extensions)
+}
+
+/// The first asset naming one of `markers`, trying `extensions` in order of
+/// preference (installers before archives) rather than in asset order.
+fn pick_asset<'a>(
+    assets: &'a [GitHubAsset],
+    markers: &[&str],
+    extensions: &[&str],
+) -> Option<&'a GitHubAsset> {
+    extensions.iter().find_map(|ext| {
+        assets.iter().find(|asset| {
+            let name = asset.name.to_lowercase();
+            markers.iter().any(|m| name.contains(m)) && name.ends_with(ext)
+        })
+    })
+}
+
+/// Find the checksum published for `asset_name`.
+fn checksum_asset<'a>(assets: &'a [GitHubAsset], asset_name: &str) -> Option<&'a GitHubAsset> {
+    let per_file = format!("{asset_name}.sha256").to_lowercase();
+    assets
+        .iter()
+        .find(|a| a.name.to_lowercase() == per_file)
+        .or_else(|| {
+            assets
+                .iter()
+                .find(|a| CHECKSUM_LISTS.contains(&a.name.to_lowercase().as_str()))
+        })
+}
+
+/// Returns true if `current` is a newer version than `seen`.
+///
+/// Unparseable versions are treated as not newer.
+pub fn is_newer(current: &str, seen: &str) -> bool {
+    let parse = |v: &str| semver::Version::parse(v.strip_prefix('v').unwrap_or(v)).ok();
+    match (parse(current), parse(seen)) {
+        (Some(current), Some(seen)) => current > seen,
+        _ => false,
+    }
+}
+
+/// Release notes for `version`.
+///
+/// With `fetch` false (update checks disabled) no request is made and only
+/// the link to the release page is returned.
+pub async fn release_notes(version: String, fetch: bool, proxy: ProxySettings) -> ReleaseNotes {
+    let summary = if fetch {
+        fetch_release_body(&version, &proxy)
+            .await
+            .map(|b| summarize(&b))
+    } else {
+        None
+    };
+
+    ReleaseNotes {
+        release_url: format!("https://github.com/AmarBego/GitTop/releases/tag/v{version}"),
+        summary: summary.filter(|s| !s.is_empty()),
+        version,
+    }
+}
+
+async fn fetch_release_body(version: &str, proxy: &ProxySettings) -> Option<String> {
+    let response = releases_client(proxy)?
+        .get(format!("{RELEASES_API}/tags/v{version}"))
+        .header("Accept", "application/vnd.github.v3+json")
+        .timeout(REQUEST_TIMEOUT)
+        .send()
+        .await
+        .ok()?;
+
+    if !response.status().is_success() {
+        tracing::debug!(
+            status = %response.status(),
+            version,
+            "Release notes: GitHub API returned non-success status"
+        );
+        return None;
+    }
+
+    let release: GitHubRelease = response.json().await.ok()?;
+    release.body
+}
+
+/// Reduce a markdown release body to a few plain lines.
+fn summarize(body: &str) -> String {
+    let mut lines = body
+        .lines()
+        .map(|l| l.trim().trim_start_matches('#').trim())
+        .filter(|l| !l.is_empty());
+
+    let mut summary: Vec<&str> = lines.by_ref().take(SUMMARY_MAX_LINES).collect();
+    if lines.next().is_some() {
+        summary.push("…");
+    }
+    summary.join("\n")
 }
 
 /// Check for updates by querying GitHub releases API.
 ///
 /// Returns `Some(UpdateInfo)` if a newer stable version is available,
-/// `None` if current version is up-to-date or on any error (fail silently).
-pub async fn check_for_update() -> Option<UpdateInfo> {
+/// `None` if current version is up-to-date, the latest version was skipped
+/// by the user, or on any error (fail silently).
+pub async fn check_for_update(
+    proxy: ProxySettings,
+    skipped_version: Option<String>,
+) -> Option<UpdateInfo> {
     let current = env!("CARGO_PKG_VERSION");
 
-    // Use reqwest to fetch latest release
-    // We use a simple client without auth (60 req/hour rate limit is plenty)
-    let client = reqwest::Client::builder()
-        .user_agent(concat!("GitTop/", env!("CARGO_PKG_VERSION")))
-        .timeout(std::time::Duration::from_secs(10))
-        .build()
-        .ok()?;
-
-    let response = client
-        .get("https://api.github.com/repos/AmarBego/GitTop/releases/latest")
+    let response = releases_client(&proxy)?
+        .get(format!("{RELEASES_API}/latest"))
         .header("Accept", "application/vnd.github.v3+json")
+        .timeout(REQUEST_TIMEOUT)
         .send()
         .await
         .ok()?;
@@ -72,15 +235,25 @@ pub async fn check_for_update() -> Option<UpdateInfo> {
     let current_ver = semver::Version::parse(current).ok()?;
     let latest_ver = semver::Version::parse(latest).ok()?;
 
+    if skipped_version.as_deref() == Some(latest) {
+        tracing::debug!(latest = %latest, "Update check: version skipped by user");
+        return None;
+    }
+
     if latest_ver > current_ver {
         tracing::info!(
             current = %current,
             latest = %latest,
             "Update available"
         );
+        let download = platform_asset(&release.assets);
+        let checksum = download.and_then(|a| checksum_asset(&release.assets, &a.name));
         Some(UpdateInfo {
             current: current.to_string(),
             latest: latest.to_string(),
+            download_url: download.map(|a| a.browser_download_url.clone()),
+            download_name: download.map(|a| a.name.clone()),
+            checksum_url: checksum.map(|a| a.browser_download_url.clone()),
             release_url: release.html_url,
         })
     } else {
@@ -92,3 +265,107 @@ pub async fn check_for_update() -> Option<UpdateInfo> {
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+
+    /// Accept one connection and return the request line it sent.
+    fn capture_request_line(listener: TcpListener) -> std::thread::JoinHandle<String> {
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut buf = Vec::new();
+            let mut chunk = [0u8; 1024];
+            while !buf.windows(4).any(|w| w == b"\r\n\r\n") {
+                let n = stream.read(&mut chunk).unwrap();
+                if n == 0 {
+                    break;
+                }
+                buf.extend_from_slice(&chunk[..n]);
+            }
+            stream
+                .write_all(b"HTTP/1.1 204 No Content\r\nContent-Length: 0\r\n\r\n")
+                .unwrap();
+            String::from_utf8_lossy(&buf)
+                .lines()
+                .next()
+                .unwrap_or_default()
+                .to_string()
+        })
+    }
+
+    fn assets(names: &[&str]) -> Vec<GitHubAsset> {
+        names
+            .iter()
+            .map(|name| GitHubAsset {
+                name: name.to_string(),
+                browser_download_url: format!("https://example.invalid/{name}"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_pick_asset_prefers_installers_over_archives() {
+        let assets = assets(&[
+            "gittop-windows-x86_64.zip",
+            "gittop-linux-x86_64.tar.gz",
+            "gittop-windows-x86_64.msi",
+            "gittop-windows-x86_64.exe",
+        ]);
+        let windows = ["windows", "win64", "msvc"];
+
+        let picked = pick_asset(&assets, &windows, &[".msi", ".exe", ".zip"]).unwrap();
+        assert_eq!(picked.name, "gittop-windows-x86_64.msi");
+
+        let picked = pick_asset(&assets[..2], &windows, &[".msi", ".exe", ".zip"]).unwrap();
+        assert_eq!(picked.name, "gittop-windows-x86_64.zip");
+
+        assert!(pick_asset(&assets, &["macos"], &[".dmg"]).is_none());
+    }
+
+    #[test]
+    fn test_checksum_asset_prefers_per_file_hash() {
+        let with_both = assets(&[
+            "sha256sums.txt",
+            "GitTop-macOS.dmg",
+            "gittop-macos.dmg.sha256",
+        ]);
+        let found = checksum_asset(&with_both, "GitTop-macOS.dmg").unwrap();
+        assert_eq!(found.name, "gittop-macos.dmg.sha256");
+
+        let list_only = assets(&["GitTop-macOS.dmg", "SHA256SUMS"]);
+        let found = checksum_asset(&list_only, "GitTop-macOS.dmg").unwrap();
+        assert_eq!(found.name, "SHA256SUMS");
+
+        assert!(checksum_asset(&assets(&["GitTop-macOS.dmg"]), "GitTop-macOS.dmg").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_releases_client_uses_enabled_proxy() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let request_line = capture_request_line(listener);
+
+        let proxy = ProxySettings {
+            enabled: true,
+            url: format!("http://127.0.0.1:{port}"),
+            has_credentials: false,
+        };
+        let client = releases_client(&proxy).expect("client builds");
+
+        let response = client
+            .get("http://releases.gittop.invalid/latest")
+            .timeout(REQUEST_TIMEOUT)
+            .send()
+            .await
+            .expect("request goes through the proxy");
+
+        assert_eq!(response.status().as_u16(), 204);
+        assert_eq!(
+            request_line.join().unwrap(),
+            "GET http://releases.gittop.invalid/latest HTTP/1.1"
+        );
+    }
+}